@@ -183,6 +183,9 @@ pub enum ArrowDataType {
     Union(Box<UnionType>),
 }
 
+/// Name of the canonical Arrow extension type for UUIDs, stored as `FixedSizeBinary(16)`.
+pub static UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -1,5 +1,6 @@
 //! APIs to read from Avro format to arrow.
 use std::io::Read;
+use std::ops::Range;
 
 use avro_schema::file::FileMetadata;
 use avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
@@ -8,7 +9,7 @@ use avro_schema::schema::Field as AvroField;
 
 mod deserialize;
 pub use deserialize::deserialize;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure, polars_err};
 
 mod nested;
 mod schema;
@@ -66,3 +67,64 @@ impl<R: Read> Iterator for Reader<R> {
             .map(|maybe_block| deserialize(maybe_block?, fields, avro_fields, projection))
     }
 }
+
+/// Location of a (compressed) block in an Avro file.
+#[derive(Debug, Clone)]
+pub struct BlockInfo {
+    /// Byte range of the block, including its header and sync marker.
+    pub range: Range<usize>,
+    /// Number of rows in the block.
+    pub num_rows: usize,
+}
+
+/// Reads the location and number of rows of all blocks in `data`, starting at `offset`
+/// (the end of the file header). The blocks are not decompressed.
+pub fn read_blocks_info(
+    data: &[u8],
+    offset: usize,
+    marker: [u8; 16],
+) -> PolarsResult<Vec<BlockInfo>> {
+    let mut blocks = vec![];
+    let mut start = offset;
+    while start < data.len() {
+        let mut reader = &data[start..];
+        let num_rows = util::zigzag_i64(&mut reader)?;
+        let num_bytes = util::zigzag_i64(&mut reader)?;
+        polars_ensure!(
+            num_rows >= 0 && num_bytes >= 0,
+            oos = "avro block has a negative length"
+        );
+
+        let end = (data.len() - reader.len())
+            .checked_add(num_bytes as usize + marker.len())
+            .filter(|&end| end <= data.len())
+            .ok_or_else(|| polars_err!(oos = "avro block exceeds the file length"))?;
+        polars_ensure!(
+            data[end - marker.len()..end] == marker,
+            oos = "avro block sync marker does not match the file marker"
+        );
+
+        if num_rows > 0 {
+            blocks.push(BlockInfo {
+                range: start..end,
+                num_rows: num_rows as usize,
+            });
+        }
+        start = end;
+    }
+    Ok(blocks)
+}
+
+/// Decompresses and deserializes a single block, as located by [`read_blocks_info`].
+pub fn read_block(
+    block: &[u8],
+    metadata: &FileMetadata,
+    fields: &ArrowSchema,
+    projection: &[bool],
+) -> PolarsResult<RecordBatchT<Box<dyn Array>>> {
+    let mut iter = block_iterator(block, metadata.compression, metadata.marker);
+    let block = iter
+        .next()?
+        .ok_or_else(|| polars_err!(oos = "expected an avro block"))?;
+    deserialize(block, fields, &metadata.record.fields, projection)
+}
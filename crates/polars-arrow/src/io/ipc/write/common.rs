@@ -270,6 +270,16 @@ fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
                 .sliced(first.to_usize(), last.to_usize() - first.to_usize());
             set_variadic_buffer_counts(counts, &*subslice)
         },
+        ArrowDataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = array.offsets().buffer();
+            let first = *offsets.first().unwrap();
+            let last = *offsets.last().unwrap();
+            let subslice = array
+                .field()
+                .sliced(first.to_usize(), last.to_usize() - first.to_usize());
+            set_variadic_buffer_counts(counts, &*subslice)
+        },
         ArrowDataType::FixedSizeList(_, _) => {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             set_variadic_buffer_counts(counts, array.values().as_ref())
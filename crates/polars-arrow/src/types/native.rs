@@ -508,7 +508,7 @@ impl f16 {
 
     #[inline]
     #[must_use]
-    pub(crate) const fn is_nan(self) -> bool {
+    pub const fn is_nan(self) -> bool {
         self.0 & 0x7FFFu16 > 0x7C00u16
     }
 
@@ -571,6 +571,12 @@ impl f16 {
         f32::from_bits(sign | exp | man)
     }
 
+    /// Casts this `f16` to `f64`
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
     /// Casts an `f32` into `f16`
     pub fn from_f32(value: f32) -> Self {
         let x: u32 = value.to_bits();
@@ -629,6 +635,67 @@ impl f16 {
             f16((half_sign | half_exp | half_man) as u16)
         }
     }
+
+    /// Casts an `f64` into `f16`
+    pub fn from_f64(value: f64) -> Self {
+        let val: u64 = value.to_bits();
+        let x = (val >> 32) as u32;
+
+        // Extract IEEE754 components
+        let sign = x & 0x8000_0000u32;
+        let exp = x & 0x7FF0_0000u32;
+        // The lower 32 bits only matter for rounding, keep them as a sticky bit.
+        let man = (x & 0x000F_FFFFu32) | u32::from(val as u32 != 0);
+
+        // Check for all exponent bits being set, which is Infinity or NaN
+        if exp == 0x7FF0_0000u32 {
+            // Set mantissa MSB for NaN (and also keep shifted mantissa bits)
+            let nan_bit = if man == 0 { 0 } else { 0x0200u32 };
+            return f16(((sign >> 16) | 0x7C00u32 | nan_bit | (man >> 10)) as u16);
+        }
+
+        // The number is normalized, start assembling half precision version
+        let half_sign = sign >> 16;
+        // Unbias the exponent, then bias for half precision
+        let unbiased_exp = ((exp >> 20) as i64) - 1023;
+        let half_exp = unbiased_exp + 15;
+
+        // Check for exponent overflow, return +infinity
+        if half_exp >= 0x1F {
+            return f16((half_sign | 0x7C00u32) as u16);
+        }
+
+        // Check for underflow
+        if half_exp <= 0 {
+            // Check mantissa for what we can do
+            if 10 - half_exp > 21 {
+                // No rounding possibility, so this is a full underflow, return signed zero
+                return f16(half_sign as u16);
+            }
+            // Don't forget about hidden leading mantissa bit when assembling mantissa
+            let man = man | 0x0010_0000u32;
+            let mut half_man = man >> (11 - half_exp);
+            // Check for rounding (see comment above functions)
+            let round_bit = 1 << (10 - half_exp);
+            if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+                half_man += 1;
+            }
+            // No exponent for subnormals
+            return f16((half_sign | half_man) as u16);
+        }
+
+        // Rebias the exponent
+        let half_exp = (half_exp as u32) << 10;
+        let half_man = man >> 10;
+        // Check for rounding (see comment above functions)
+        let round_bit = 0x0000_0200u32;
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            // Round it
+            f16(((half_sign | half_exp | half_man) + 1) as u16)
+        } else {
+            f16((half_sign | half_exp | half_man) as u16)
+        }
+    }
 }
 
 impl std::fmt::Debug for f16 {
@@ -656,18 +723,27 @@ impl TotalEq for f16 {
 
 impl TotalOrd for f16 {
     #[inline]
-    fn tot_cmp(&self, _other: &Self) -> std::cmp::Ordering {
-        unimplemented!()
+    fn tot_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_f32().tot_cmp(&other.to_f32())
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
     }
 }
 
 impl MinMax for f16 {
-    fn nan_min_lt(&self, _other: &Self) -> bool {
-        unimplemented!()
+    #[inline]
+    fn nan_min_lt(&self, other: &Self) -> bool {
+        self.to_f32().nan_min_lt(&other.to_f32())
     }
 
-    fn nan_max_lt(&self, _other: &Self) -> bool {
-        unimplemented!()
+    #[inline]
+    fn nan_max_lt(&self, other: &Self) -> bool {
+        self.to_f32().nan_max_lt(&other.to_f32())
     }
 }
 
@@ -857,4 +933,26 @@ mod test {
         assert_eq!(format!("{}", f16::from_f32(7.0)), "7".to_string());
         assert_eq!(format!("{:?}", f16::from_f32(7.0)), "7.0".to_string());
     }
+
+    #[test]
+    fn test_f16_from_f64() {
+        for bits in 0..=u16::MAX {
+            let f = f16::from_bits(bits);
+            if f.is_nan() {
+                assert!(f16::from_f64(f.to_f64()).is_nan());
+            } else {
+                assert_eq!(f16::from_f64(f.to_f64()).to_bits(), bits);
+            }
+        }
+
+        // Halfway between 1.0 and the next f16 rounds to even, anything above
+        // rounds up, even if only the low bits of the f64 are set.
+        let half_ulp = f16::EPSILON.to_f64() / 2.0;
+        assert_eq!(f16::from_f64(1.0 + half_ulp).to_f64(), 1.0);
+        let above = f64::from_bits((1.0 + half_ulp).to_bits() + 1);
+        assert_eq!(f16::from_f64(above).to_f64(), 1.0 + f16::EPSILON.to_f64());
+
+        assert_eq!(f16::from_f64(1e10).to_f64(), f64::INFINITY);
+        assert_eq!(f16::from_f64(-1e-10).to_bits(), 0x8000);
+    }
 }
@@ -247,3 +247,32 @@ pub fn binary_to_list<O: Offset>(from: &BinaryArray<O>, to_dtype: ArrowDataType)
         from.validity().cloned(),
     )
 }
+
+/// Conversion of `FixedSizeBinary` to a `FixedSizeList` of `UInt8`. This is zero-copy.
+pub fn fixed_size_binary_to_fixed_size_list(
+    from: &FixedSizeBinaryArray,
+    to_dtype: ArrowDataType,
+) -> FixedSizeListArray {
+    let values = PrimitiveArray::new(ArrowDataType::UInt8, from.values().clone(), None);
+    FixedSizeListArray::new(
+        to_dtype,
+        from.len(),
+        values.boxed(),
+        from.validity().cloned(),
+    )
+}
+
+/// Conversion of a `FixedSizeList` of `UInt8` to `FixedSizeBinary`. This is zero-copy.
+///
+/// The validity of the inner bytes is ignored.
+pub fn fixed_size_list_to_fixed_size_binary(
+    from: &FixedSizeListArray,
+    to_dtype: ArrowDataType,
+) -> FixedSizeBinaryArray {
+    let values = from
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<u8>>()
+        .unwrap();
+    FixedSizeBinaryArray::new(to_dtype, values.values().clone(), from.validity().cloned())
+}
@@ -1,4 +1,5 @@
 use arrow::array::*;
+use arrow::bitmap::MutableBitmap;
 #[cfg(feature = "dtype-decimal")]
 use arrow::compute::decimal::deserialize_decimal;
 use arrow::datatypes::{ArrowDataType, TimeUnit};
@@ -115,6 +116,30 @@ pub fn binview_to_decimal(
     ))
 }
 
+/// Cast [`BinaryViewArray`] to [`FixedSizeBinaryArray`]. Values that do not have exactly
+/// `size` bytes become null.
+pub fn binview_to_fixed_size_binary(from: &BinaryViewArray, size: usize) -> FixedSizeBinaryArray {
+    let mut values = Vec::with_capacity(from.len() * size);
+    let mut validity = MutableBitmap::with_capacity(from.len());
+    for val in from.iter() {
+        match val {
+            Some(val) if val.len() == size => {
+                values.extend_from_slice(val);
+                validity.push(true);
+            },
+            _ => {
+                values.resize(values.len() + size, 0);
+                validity.push(false);
+            },
+        }
+    }
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(size),
+        values.into(),
+        validity.into(),
+    )
+}
+
 pub(super) fn utf8view_to_naive_timestamp_dyn(
     from: &dyn Array,
     time_unit: TimeUnit,
@@ -13,7 +13,7 @@ pub use binary_to::*;
 #[cfg(feature = "dtype-decimal")]
 pub use binview_to::binview_to_decimal;
 use binview_to::utf8view_to_primitive_dyn;
-pub use binview_to::utf8view_to_utf8;
+pub use binview_to::{binview_to_fixed_size_binary, utf8view_to_utf8};
 pub use boolean_to::*;
 pub use decimal_to::*;
 pub mod temporal;
@@ -382,6 +382,8 @@ pub fn cast_unchecked(array: &dyn Array, to_type: &ArrowDataType) -> PolarsResul
 /// * List to Fixed Size List: the offsets are checked for valid order, then the
 ///   underlying type is cast.
 /// * List of UInt8 to Binary: the list of integers becomes binary data, nulls in the list means it becomes a null
+/// * Binary to Fixed Size Binary: values with a different width become null
+/// * Fixed Size List of UInt8 to/from Fixed Size Binary: zero-copy
 /// * Struct to Struct: the underlying fields are cast.
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
@@ -450,6 +452,24 @@ pub fn cast(
             options,
         )
         .map(|x| x.boxed()),
+        (FixedSizeList(field, size), FixedSizeBinary(to_size))
+            if matches!(field.dtype(), UInt8) && size == to_size =>
+        {
+            Ok(fixed_size_list_to_fixed_size_binary(
+                array.as_any().downcast_ref().unwrap(),
+                to_type.clone(),
+            )
+            .boxed())
+        },
+        (FixedSizeBinary(size), FixedSizeList(field, to_size))
+            if matches!(field.dtype(), UInt8) && size == to_size =>
+        {
+            Ok(fixed_size_binary_to_fixed_size_list(
+                array.as_any().downcast_ref().unwrap(),
+                to_type.clone(),
+            )
+            .boxed())
+        },
         (List(field), BinaryView) if matches!(field.dtype(), UInt8) => {
             cast_list_uint8_to_binary::<i32>(array.as_any().downcast_ref().unwrap())
                 .map(|arr| arr.boxed())
@@ -473,6 +493,11 @@ pub fn cast(
                 let bin_array = view_to_binary::<i64>(array.as_any().downcast_ref().unwrap());
                 Ok(binary_to_list(&bin_array, to_type.clone()).boxed())
             },
+            FixedSizeBinary(size) => Ok(binview_to_fixed_size_binary(
+                array.as_any().downcast_ref().unwrap(),
+                *size,
+            )
+            .boxed()),
             _ => polars_bail!(InvalidOperation:
                 "casting from {from_type:?} to {to_type:?} not supported",
             ),
@@ -535,6 +560,7 @@ pub fn cast(
                 Int64 => utf8view_to_primitive_dyn::<i64>(arr, to_type, options),
                 #[cfg(feature = "dtype-i128")]
                 Int128 => utf8view_to_primitive_dyn::<i128>(arr, to_type, options),
+                Float16 => primitive_to_f16_dyn(array, options),
                 Float32 => utf8view_to_primitive_dyn::<f32>(arr, to_type, options),
                 Float64 => utf8view_to_primitive_dyn::<f64>(arr, to_type, options),
                 Timestamp(time_unit, None) => {
@@ -569,6 +595,7 @@ pub fn cast(
             Int64 => primitive_to_boolean_dyn::<i64>(array, to_type.clone()),
             #[cfg(feature = "dtype-i128")]
             Int128 => primitive_to_boolean_dyn::<i128>(array, to_type.clone()),
+            Float16 => f16_to_primitive_dyn(array, to_type, options),
             Float32 => primitive_to_boolean_dyn::<f32>(array, to_type.clone()),
             Float64 => primitive_to_boolean_dyn::<f64>(array, to_type.clone()),
             Decimal(_, _) => primitive_to_boolean_dyn::<i128>(array, to_type.clone()),
@@ -587,6 +614,7 @@ pub fn cast(
             Int64 => boolean_to_primitive_dyn::<i64>(array),
             #[cfg(feature = "dtype-i128")]
             Int128 => boolean_to_primitive_dyn::<i128>(array),
+            Float16 => primitive_to_f16_dyn(array, options),
             Float32 => boolean_to_primitive_dyn::<f32>(array),
             Float64 => boolean_to_primitive_dyn::<f64>(array),
             Utf8View => boolean_to_utf8view_dyn(array),
@@ -820,6 +848,44 @@ pub fn cast(
             let from = array.as_any().downcast_ref().unwrap();
             Ok(f16_to_f32(from).boxed())
         },
+        (Float16, Float64) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f16_to_f64(from).boxed())
+        },
+        (
+            Float16,
+            UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Int8
+            | Int16
+            | Int32
+            | Int64
+            | Int128
+            | Decimal(_, _),
+        ) => f16_to_primitive_dyn(array, to_type, options),
+        (Float32, Float16) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f32_to_f16(from).boxed())
+        },
+        (Float64, Float16) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f64_to_f16(from).boxed())
+        },
+        (
+            UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Int8
+            | Int16
+            | Int32
+            | Int64
+            | Int128
+            | Decimal(_, _),
+            Float16,
+        ) => primitive_to_f16_dyn(array, options),
 
         (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
         (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type, options),
@@ -958,6 +1024,26 @@ fn cast_to_dictionary<K: DictionaryKey>(
     }
 }
 
+/// Casts a Float16 array by going through Float32, which can represent every
+/// f16 value exactly.
+fn f16_to_primitive_dyn(
+    array: &dyn Array,
+    to_type: &ArrowDataType,
+    options: CastOptionsImpl,
+) -> PolarsResult<Box<dyn Array>> {
+    let from = array.as_any().downcast_ref().unwrap();
+    cast(&f16_to_f32(from), to_type, options)
+}
+
+/// Casts an array to Float16 by going through Float64.
+fn primitive_to_f16_dyn(
+    array: &dyn Array,
+    options: CastOptionsImpl,
+) -> PolarsResult<Box<dyn Array>> {
+    let values = cast(array, &ArrowDataType::Float64, options)?;
+    Ok(f64_to_f16(values.as_any().downcast_ref().unwrap()).boxed())
+}
+
 fn from_to_binview(
     array: &dyn Array,
     from_type: &ArrowDataType,
@@ -974,6 +1060,10 @@ fn from_to_binview(
         Int32 => primitive_to_binview_dyn::<i32>(array),
         Int64 => primitive_to_binview_dyn::<i64>(array),
         Int128 => primitive_to_binview_dyn::<i128>(array),
+        Float16 => {
+            let from = array.as_any().downcast_ref().unwrap();
+            primitive_to_binview_dyn::<f32>(&f16_to_f32(from))
+        },
         Float32 => primitive_to_binview_dyn::<f32>(array),
         Float64 => primitive_to_binview_dyn::<f64>(array),
         Binary => binary_to_binview::<i32>(array.as_any().downcast_ref().unwrap()),
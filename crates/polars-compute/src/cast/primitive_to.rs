@@ -594,6 +594,21 @@ pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
     unary(from, |x| x.to_f32(), ArrowDataType::Float32)
 }
 
+/// Casts f16 into f64
+pub fn f16_to_f64(from: &PrimitiveArray<f16>) -> PrimitiveArray<f64> {
+    unary(from, |x| x.to_f64(), ArrowDataType::Float64)
+}
+
+/// Casts f32 into f16
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, ArrowDataType::Float16)
+}
+
+/// Casts f64 into f16
+pub fn f64_to_f16(from: &PrimitiveArray<f64>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f64, ArrowDataType::Float16)
+}
+
 /// Returns a [`Utf8Array`] where every element is the utf8 representation of the number.
 pub(super) fn primitive_to_binview<T: NativeType + SerPrimitive>(
     from: &PrimitiveArray<T>,
@@ -0,0 +1,404 @@
+//! # TDigest
+//!
+//! `tdigest` module contains an implementation of the merging t-digest for
+//! approximate quantile estimation, so that `approx_quantile` can be computed
+//! in a single pass with bounded memory and partial results can be combined.
+//!
+//! See Dunning & Ertl, "Computing Extremely Accurate Quantiles Using t-Digests".
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_compute::tdigest::*;
+//!     let mut digest = TDigest::new(0.01);
+//!     digest.extend((1..=100).map(|v| v as f64));
+//!
+//!     assert_eq!(digest.quantile(0.5), Some(50.5));
+//! ```
+
+use std::f64::consts::PI;
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+
+/// The number of values buffered per unit of compression before they are
+/// merged into the centroids.
+const BUFFER_FACTOR: usize = 4;
+
+/// Identifies the layout of the serialized form, bump on incompatible changes.
+const FORMAT_VERSION: u8 = 1;
+const HEADER_SIZE: usize = 1 + 3 * size_of::<f64>() + size_of::<u32>();
+const CENTROID_SIZE: usize = 2 * size_of::<f64>();
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// Sorted by mean.
+    centroids: Vec<Centroid>,
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Creates a new, empty TDigest.
+    ///
+    /// The `accuracy` is the targeted error in rank, the error is typically
+    /// smaller than this and much smaller for the extreme quantiles. The
+    /// number of centroids kept is in the order of `1 / accuracy`.
+    pub fn new(accuracy: f64) -> Self {
+        assert!(accuracy > 0.0 && accuracy <= 1.0);
+        Self {
+            compression: 1.0 / accuracy,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Adds a value to the digest. NaN values are ignored.
+    #[inline]
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.compress();
+        }
+    }
+
+    /// Merges another digest into this one.
+    ///
+    /// The accuracy of this digest is kept.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.is_empty() {
+            return;
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        let extra = other
+            .centroids
+            .iter()
+            .copied()
+            .chain(other.buffer.iter().map(|v| Centroid {
+                mean: *v,
+                weight: 1.0,
+            }));
+        self.merge_centroids(extra);
+    }
+
+    /// The number of values in the digest.
+    pub fn count(&self) -> f64 {
+        self.centroids.iter().map(|c| c.weight).sum::<f64>() + self.buffer.len() as f64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// Estimates the value at the given quantile, which must be in `[0, 1]`.
+    ///
+    /// This interpolates linearly between the values, which makes the result
+    /// exact as long as no values had to be merged, e.g. for small inputs.
+    /// Returns `None` if the digest is empty.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        debug_assert!((0.0..=1.0).contains(&quantile));
+        self.compress();
+        if self.centroids.is_empty() {
+            return None;
+        }
+
+        let total = self.count();
+        let rank = quantile * (total - 1.0);
+
+        // A centroid of weight w covers w consecutive ranks and is placed in
+        // the middle of those, the extreme values are at the first and last
+        // rank. Between those we interpolate linearly.
+        let mut prev = (0.0, self.min);
+        let mut cum_weight = 0.0;
+        for c in &self.centroids {
+            let center = cum_weight + (c.weight - 1.0) / 2.0;
+            if rank < center {
+                return Some(interpolate(prev, (center, c.mean), rank));
+            }
+            prev = (center, c.mean);
+            cum_weight += c.weight;
+        }
+        Some(interpolate(prev, (total - 1.0, self.max), rank))
+    }
+
+    /// Serializes the digest so that it can be restored with
+    /// [`TDigest::from_bytes`], e.g. to combine partial results.
+    pub fn to_bytes(&mut self) -> Vec<u8> {
+        self.compress();
+        let mut out = Vec::with_capacity(HEADER_SIZE + self.centroids.len() * CENTROID_SIZE);
+        out.push(FORMAT_VERSION);
+        out.extend_from_slice(&self.compression.to_le_bytes());
+        out.extend_from_slice(&self.min.to_le_bytes());
+        out.extend_from_slice(&self.max.to_le_bytes());
+        out.extend_from_slice(&(self.centroids.len() as u32).to_le_bytes());
+        for c in &self.centroids {
+            out.extend_from_slice(&c.mean.to_le_bytes());
+            out.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        out
+    }
+
+    /// Restores a digest serialized with [`TDigest::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() >= HEADER_SIZE,
+            ComputeError: "invalid t-digest: expected at least {HEADER_SIZE} bytes, got {}", bytes.len()
+        );
+        polars_ensure!(
+            bytes[0] == FORMAT_VERSION,
+            ComputeError: "invalid t-digest: unsupported format version {}", bytes[0]
+        );
+
+        let mut rest = &bytes[1..];
+        let mut read_f64 = || {
+            let (v, tail) = rest.split_first_chunk::<8>().unwrap();
+            rest = tail;
+            f64::from_le_bytes(*v)
+        };
+        let compression = read_f64();
+        let min = read_f64();
+        let max = read_f64();
+        let (num_centroids, mut rest) = rest.split_first_chunk::<4>().unwrap();
+        let num_centroids = u32::from_le_bytes(*num_centroids) as usize;
+        polars_ensure!(
+            rest.len() == num_centroids * CENTROID_SIZE,
+            ComputeError: "invalid t-digest: expected {num_centroids} centroids"
+        );
+        polars_ensure!(
+            compression >= 1.0,
+            ComputeError: "invalid t-digest: compression must be at least 1"
+        );
+
+        let mut centroids = Vec::with_capacity(num_centroids);
+        while let Some((c, tail)) = rest.split_first_chunk::<CENTROID_SIZE>() {
+            let (mean, weight) = c.split_at(8);
+            let mean = f64::from_le_bytes(mean.try_into().unwrap());
+            let weight = f64::from_le_bytes(weight.try_into().unwrap());
+            if !(weight > 0.0 && mean >= min && mean <= max) {
+                polars_bail!(ComputeError: "invalid t-digest: centroid out of range");
+            }
+            if centroids
+                .last()
+                .is_some_and(|prev: &Centroid| prev.mean > mean)
+            {
+                polars_bail!(ComputeError: "invalid t-digest: centroids are not sorted");
+            }
+            centroids.push(Centroid { mean, weight });
+            rest = tail;
+        }
+
+        Ok(Self {
+            compression,
+            centroids,
+            buffer: Vec::new(),
+            min,
+            max,
+        })
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        BUFFER_FACTOR * self.compression.ceil() as usize
+    }
+
+    /// Merges the buffered values into the centroids.
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let buffer = std::mem::take(&mut self.buffer);
+        self.merge_centroids(buffer.iter().map(|v| Centroid {
+            mean: *v,
+            weight: 1.0,
+        }));
+        self.buffer = buffer;
+        self.buffer.clear();
+    }
+
+    fn merge_centroids(&mut self, extra: impl Iterator<Item = Centroid>) {
+        let mut all = std::mem::take(&mut self.centroids);
+        all.extend(extra);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        if all.len() <= self.compression.ceil() as usize {
+            // Small enough as is, keeping the values unmerged keeps them exact.
+            self.centroids = all;
+            return;
+        }
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+
+        let mut merged = Vec::new();
+        let mut iter = all.into_iter();
+        let mut cur = iter.next().unwrap();
+        let mut weight_before = 0.0;
+        let mut k_lower = self.scale(0.0);
+        for next in iter {
+            let q = (weight_before + cur.weight + next.weight) / total;
+            if self.scale(q) - k_lower <= 1.0 {
+                cur.weight += next.weight;
+                cur.mean += (next.mean - cur.mean) * next.weight / cur.weight;
+            } else {
+                weight_before += cur.weight;
+                k_lower = self.scale(weight_before / total);
+                merged.push(cur);
+                cur = next;
+            }
+        }
+        merged.push(cur);
+        self.centroids = merged;
+    }
+
+    /// The k1 scale function, which keeps the centroids small near the tails.
+    fn scale(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q.clamp(0.0, 1.0) - 1.0).asin()
+    }
+}
+
+impl Extend<f64> for TDigest {
+    fn extend<T: IntoIterator<Item = f64>>(&mut self, iter: T) {
+        for v in iter {
+            self.add(v);
+        }
+    }
+}
+
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 <= x0 {
+        return y1;
+    }
+    let t = ((x - x0) / (x1 - x0)).clamp(0.0, 1.0);
+    y0 + t * (y1 - y0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact_quantile(sorted: &[f64], q: f64) -> f64 {
+        let idx = q * (sorted.len() - 1) as f64;
+        let lo = sorted[idx.floor() as usize];
+        let hi = sorted[idx.ceil() as usize];
+        lo + (hi - lo) * (idx - idx.floor())
+    }
+
+    /// Returns the error in rank of `value` as an estimate of quantile `q`.
+    fn rank_error(sorted: &[f64], value: f64, q: f64) -> f64 {
+        let lo = sorted.partition_point(|v| *v < value);
+        let hi = sorted.partition_point(|v| *v <= value);
+        let target = q * sorted.len() as f64;
+        let err = if target < lo as f64 {
+            lo as f64 - target
+        } else if target > hi as f64 {
+            target - hi as f64
+        } else {
+            0.0
+        };
+        err / sorted.len() as f64
+    }
+
+    fn pseudo_random(n: usize) -> Vec<f64> {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        (0..n)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 11) as f64 / (1u64 << 53) as f64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut digest = TDigest::new(0.01);
+        assert!(digest.is_empty());
+        assert_eq!(digest.quantile(0.5), None);
+        digest.add(f64::NAN);
+        assert_eq!(digest.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_small_is_exact() {
+        let mut digest = TDigest::new(0.01);
+        digest.extend([4.0, 1.0, 3.0, 2.0]);
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(2.5));
+        assert_eq!(digest.quantile(1.0), Some(4.0));
+
+        let mut digest = TDigest::new(0.01);
+        digest.add(7.0);
+        assert_eq!(digest.quantile(0.0), Some(7.0));
+        assert_eq!(digest.quantile(0.3), Some(7.0));
+        assert_eq!(digest.quantile(1.0), Some(7.0));
+    }
+
+    #[test]
+    fn test_accuracy() {
+        let values = pseudo_random(100_000);
+        let mut sorted = values.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        let mut digest = TDigest::new(0.01);
+        digest.extend(values.iter().copied());
+        assert!(digest.centroids.len() < 200);
+        for q in [
+            0.0, 0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999, 1.0,
+        ] {
+            let est = digest.quantile(q).unwrap();
+            assert!(rank_error(&sorted, est, q) <= 0.01, "q={q}");
+        }
+        assert_eq!(digest.quantile(0.0), Some(sorted[0]));
+        assert_eq!(digest.quantile(1.0), Some(*sorted.last().unwrap()));
+        assert!((digest.quantile(0.5).unwrap() - exact_quantile(&sorted, 0.5)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_merge() {
+        let values = pseudo_random(50_000);
+        let mut sorted = values.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        let mut merged = TDigest::new(0.01);
+        for chunk in values.chunks(999) {
+            let mut digest = TDigest::new(0.01);
+            digest.extend(chunk.iter().copied());
+            merged.merge(&digest);
+        }
+        assert_eq!(merged.count(), values.len() as f64);
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let est = merged.quantile(q).unwrap();
+            assert!(rank_error(&sorted, est, q) <= 0.01, "q={q}");
+        }
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let mut digest = TDigest::new(0.05);
+        digest.extend(pseudo_random(10_000));
+        let bytes = digest.to_bytes();
+        let mut restored = TDigest::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.count(), digest.count());
+        for q in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(restored.quantile(q), digest.quantile(q));
+        }
+
+        assert!(TDigest::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(TDigest::from_bytes(&[]).is_err());
+        let mut bad_version = bytes.clone();
+        bad_version[0] = 0;
+        assert!(TDigest::from_bytes(&bad_version).is_err());
+    }
+}
@@ -100,6 +100,12 @@ pub fn get_list_builder(
             list_capacity,
             Some(inner_type_logical.clone()),
         )),
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => Box::new(AnonymousOwnedListBuilder::new(
+            name,
+            list_capacity,
+            Some(inner_type_logical.clone()),
+        )),
         DataType::Null => Box::new(ListNullChunkedBuilder::new(name, list_capacity)),
         DataType::List(_) => Box::new(AnonymousOwnedListBuilder::new(
             name,
@@ -294,6 +294,8 @@ impl ChunkCast for StringChunked {
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
             },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => self.cast_to_uuid(options).map(|ca| ca.into_series()),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => match (precision, scale) {
                 (precision, Some(scale)) => {
@@ -388,6 +390,14 @@ impl ChunkCast for BinaryChunked {
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(size) => self
+                .cast_to_fixed_size_binary(*size, options)
+                .map(|ca| ca.into_series()),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => self
+                .cast_to_fixed_size_binary(16, options)?
+                .cast_with_options(dtype, options),
             _ => cast_impl(self.name().clone(), &self.chunks, dtype, options),
         }
     }
@@ -436,6 +446,28 @@ impl ChunkCast for BooleanChunked {
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl ChunkCast for Float16Chunked {
+    fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) => {
+                cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
+            },
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+                polars_bail!(InvalidOperation: "cannot cast Float16 to Categorical");
+            },
+            _ => cast_impl(self.name().clone(), &self.chunks, dtype, options),
+        }
+    }
+
+    unsafe fn cast_unchecked(&self, dtype: &DataType) -> PolarsResult<Series> {
+        self.cast_with_options(dtype, CastOptions::Overflowing)
+    }
+}
+
 /// We cannot cast anything to or from List/LargeList
 /// So this implementation casts the inner type
 impl ChunkCast for ListChunked {
@@ -493,6 +525,10 @@ impl ChunkCast for ListChunked {
                     ))
                 }
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => ca
+                .cast_to_map(key, value, options)
+                .map(|ca| ca.into_series()),
             #[cfg(feature = "dtype-u8")]
             Binary => {
                 polars_ensure!(
@@ -586,6 +622,17 @@ impl ChunkCast for ArrayChunked {
                     ))
                 }
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            dt if dt.is_fixed_size_binary() => {
+                let phys = ca.cast_with_options(&dt.to_physical(), options)?;
+                // SAFETY: we just cast to the physical type of `dt`.
+                let ca = unsafe {
+                    phys.array()?
+                        .clone()
+                        .into_fixed_size_binary_unchecked(dt.clone())
+                };
+                Ok(ca.into_series())
+            },
             _ => {
                 polars_bail!(
                     InvalidOperation: "cannot cast Array type (inner: '{:?}', to: '{:?}')",
@@ -738,4 +785,53 @@ mod test {
         let out = out.cast(&DataType::from_categories(cats)).unwrap();
         assert!(matches!(out.dtype(), &DataType::Categorical(_, _)))
     }
+
+    #[test]
+    #[cfg(feature = "dtype-f16")]
+    fn test_cast_f16() -> PolarsResult<()> {
+        let s = Series::new(
+            PlSmallStr::from_static("a"),
+            &[Some(0.1f64), None, Some(7e4)],
+        );
+        let half = s.cast(&DataType::Float16)?;
+        assert_eq!(half.dtype(), &DataType::Float16);
+
+        // Precision is lost on the way down and out of range values saturate.
+        let back = half.cast(&DataType::Float64)?;
+        let back = back.f64()?;
+        assert_eq!(back.get(0), Some(0.0999755859375));
+        assert_eq!(back.get(1), None);
+        assert_eq!(back.get(2), Some(f64::INFINITY));
+
+        let int = half.cast(&DataType::Int32)?;
+        assert_eq!(int.i32()?.get(0), Some(0));
+        assert_eq!(int.i32()?.get(2), None);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-uuid")]
+    fn test_cast_uuid() -> PolarsResult<()> {
+        let s = Series::new(
+            PlSmallStr::from_static("a"),
+            &[
+                Some("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+                None,
+                Some("not-a-uuid"),
+            ],
+        );
+        // Invalid UUIDs fail a strict cast.
+        assert!(s.strict_cast(&DataType::Uuid).is_err());
+
+        let uuid = s.cast(&DataType::Uuid)?;
+        assert_eq!(uuid.dtype(), &DataType::Uuid);
+        assert_eq!(uuid.null_count(), 2);
+
+        let back = uuid.cast(&DataType::String)?;
+        assert_eq!(
+            back.str()?.get(0),
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,260 @@
+use arrow::array::{FixedSizeBinaryArray, FixedSizeListArray};
+use arrow::bitmap::BitmapBuilder;
+use polars_compute::cast::{
+    binview_to_fixed_size_binary, fixed_size_binary_to_fixed_size_list,
+    fixed_size_list_to_fixed_size_binary,
+};
+
+use super::*;
+use crate::prelude::*;
+use crate::utils::handle_casting_failures;
+
+/// Fixed-size binary data, also used for [`DataType::Uuid`].
+pub type FixedSizeBinaryChunked = Logical<FixedSizeBinaryType, FixedSizeListType>;
+
+impl ArrayChunked {
+    /// Reinterpret an array of `UInt8` as fixed-size binary data.
+    ///
+    /// # Safety
+    /// The inner dtype must be `UInt8` and `dtype` must be a [`DataType::FixedSizeBinary`] of the
+    /// same width, or a [`DataType::Uuid`] if the width is 16.
+    pub unsafe fn into_fixed_size_binary_unchecked(
+        self,
+        dtype: DataType,
+    ) -> FixedSizeBinaryChunked {
+        debug_assert_eq!(self.dtype(), &dtype.to_physical());
+        unsafe { FixedSizeBinaryChunked::new_logical(self, dtype) }
+    }
+}
+
+impl BinaryChunked {
+    /// Cast to fixed-size binary data of `size` bytes per value.
+    ///
+    /// Values of a different length fail a strict cast and become null otherwise.
+    pub fn cast_to_fixed_size_binary(
+        &self,
+        size: usize,
+        options: CastOptions,
+    ) -> PolarsResult<FixedSizeBinaryChunked> {
+        polars_ensure!(size > 0, InvalidOperation: "fixed-size binary width must be positive");
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| binview_to_fixed_size_binary(arr, size));
+        let out = FixedSizeBinaryChunked::from_fixed_size_binary(
+            self.name().clone(),
+            chunks,
+            DataType::FixedSizeBinary(size),
+        );
+
+        if options.is_strict() && self.null_count() != out.null_count() {
+            handle_casting_failures(&self.clone().into_series(), &out.clone().into_series())?;
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(feature = "dtype-uuid")]
+impl StringChunked {
+    /// Parse UUIDs from their hyphenated, simple, braced or URN string form.
+    ///
+    /// Strings that are not valid UUIDs fail a strict cast and become null otherwise.
+    pub fn cast_to_uuid(&self, options: CastOptions) -> PolarsResult<FixedSizeBinaryChunked> {
+        let mut bytes = Vec::with_capacity(self.len() * 16);
+        let mut validity = BitmapBuilder::with_capacity(self.len());
+        for opt_s in self.iter() {
+            match opt_s.and_then(|s| uuid::Uuid::try_parse(s).ok()) {
+                Some(uuid) => {
+                    bytes.extend_from_slice(uuid.as_bytes());
+                    validity.push(true);
+                },
+                None => {
+                    bytes.extend_from_slice(&[0; 16]);
+                    validity.push(false);
+                },
+            }
+        }
+        let arr = FixedSizeBinaryArray::new(
+            ArrowDataType::FixedSizeBinary(16),
+            bytes.into(),
+            validity.into_opt_validity(),
+        );
+        let out = FixedSizeBinaryChunked::from_fixed_size_binary(
+            self.name().clone(),
+            [arr],
+            DataType::Uuid,
+        );
+
+        if options.is_strict() && self.null_count() != out.null_count() {
+            handle_casting_failures(&self.clone().into_series(), &out.clone().into_series())?;
+        }
+        Ok(out)
+    }
+}
+
+impl LogicalType for FixedSizeBinaryChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        let (chunk_idx, idx) = self.phys.index_to_chunked_index(i);
+        let arr = unsafe { self.phys.downcast_get_unchecked(chunk_idx) };
+        if !arr.is_valid(idx) {
+            return AnyValue::Null;
+        }
+        fixed_size_binary_any_value(arr, idx, &self.dtype)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        if self.dtype() == dtype {
+            return Ok(self.clone().into_series());
+        }
+        match dtype {
+            DataType::FixedSizeBinary(size) if *size == self.width() => {
+                // SAFETY: the width is unchanged.
+                Ok(unsafe { self.with_dtype(dtype.clone()) }.into_series())
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid if self.width() == 16 => {
+                // SAFETY: the width is 16.
+                Ok(unsafe { self.with_dtype(dtype.clone()) }.into_series())
+            },
+            DataType::Binary => Ok(self.to_binary().into_series()),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::String if self.is_uuid() => {
+                let mut builder = StringChunkedBuilder::new(self.name().clone(), self.len());
+                let mut buf = uuid::Uuid::encode_buffer();
+                for opt_bytes in self.iter() {
+                    builder.append_option(opt_bytes.map(|bytes| {
+                        let uuid = uuid::Uuid::from_slice(bytes).unwrap();
+                        &*uuid.hyphenated().encode_lower(&mut buf)
+                    }));
+                }
+                Ok(builder.finish().into_series())
+            },
+            DataType::Array(_, _) => self.phys.cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation: "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+impl FixedSizeBinaryChunked {
+    /// Create from [`FixedSizeBinaryArray`]s without copying the values.
+    pub fn from_fixed_size_binary<I>(name: PlSmallStr, chunks: I, dtype: DataType) -> Self
+    where
+        I: IntoIterator<Item = FixedSizeBinaryArray>,
+    {
+        let physical = dtype.to_physical();
+        let arrow_physical = physical.to_arrow(CompatLevel::newest());
+        let chunks = chunks
+            .into_iter()
+            .map(|arr| fixed_size_binary_to_fixed_size_list(&arr, arrow_physical.clone()).boxed())
+            .collect();
+        // SAFETY: the chunks are fixed-size lists of `UInt8` with the width of `dtype`.
+        unsafe {
+            ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, physical)
+                .into_fixed_size_binary_unchecked(dtype)
+        }
+    }
+
+    /// The number of bytes of every value.
+    pub fn width(&self) -> usize {
+        self.phys.width()
+    }
+
+    /// Whether the values are UUIDs.
+    pub fn is_uuid(&self) -> bool {
+        #[cfg(feature = "dtype-uuid")]
+        {
+            matches!(self.dtype, DataType::Uuid)
+        }
+        #[cfg(not(feature = "dtype-uuid"))]
+        {
+            false
+        }
+    }
+
+    /// Iterate over the bytes of every value.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&[u8]>> + '_ {
+        self.phys.downcast_iter().flat_map(|arr| {
+            (0..arr.len())
+                .map(move |idx| arr.is_valid(idx).then(|| fixed_size_list_value(arr, idx)))
+        })
+    }
+
+    /// Get the chunks as [`FixedSizeBinaryArray`]s, typed by the Arrow type of this array.
+    pub fn to_fixed_size_binary_chunks(
+        &self,
+        compat_level: CompatLevel,
+    ) -> impl Iterator<Item = FixedSizeBinaryArray> + '_ {
+        let dtype = self.dtype.to_arrow(compat_level);
+        self.phys
+            .downcast_iter()
+            .map(move |arr| fixed_size_list_to_fixed_size_binary(arr, dtype.clone()))
+    }
+
+    /// Convert to variable-length binary data.
+    pub fn to_binary(&self) -> BinaryChunked {
+        let chunks = self
+            .to_fixed_size_binary_chunks(CompatLevel::newest())
+            .map(|arr| polars_compute::cast::fixed_size_binary_to_binview(&arr));
+        BinaryChunked::from_chunk_iter(self.name().clone(), chunks)
+    }
+
+    /// Re-wrap a fixed-size binary array of the same width as this array.
+    ///
+    /// # Safety
+    /// `phys` must have the same dtype as [`FixedSizeBinaryChunked::physical`].
+    pub unsafe fn with_physical(&self, phys: ArrayChunked) -> FixedSizeBinaryChunked {
+        unsafe { FixedSizeBinaryChunked::new_logical(phys, self.dtype.clone()) }
+    }
+
+    /// # Safety
+    /// `dtype` must have the same physical type as this array.
+    unsafe fn with_dtype(&self, dtype: DataType) -> FixedSizeBinaryChunked {
+        unsafe { FixedSizeBinaryChunked::new_logical(self.phys.clone(), dtype) }
+    }
+}
+
+/// Get the valid value at `idx` of the physical array of a fixed-size binary `dtype`.
+pub(crate) fn fixed_size_binary_any_value<'a>(
+    arr: &'a FixedSizeListArray,
+    idx: usize,
+    dtype: &DataType,
+) -> AnyValue<'a> {
+    let bytes = fixed_size_list_value(arr, idx);
+    match dtype {
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => {
+            let uuid = uuid::Uuid::from_slice(bytes).unwrap();
+            let mut buf = uuid::Uuid::encode_buffer();
+            AnyValue::StringOwned(PlSmallStr::from_str(
+                uuid.hyphenated().encode_lower(&mut buf),
+            ))
+        },
+        _ => AnyValue::Binary(bytes),
+    }
+}
+
+fn fixed_size_list_value(arr: &FixedSizeListArray, idx: usize) -> &[u8] {
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<u8>>()
+        .unwrap()
+        .values();
+    let size = arr.size();
+    &values[idx * size..(idx + 1) * size]
+}
@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+
+use super::*;
+use crate::chunked_array::ops::ChunkNestingUtils;
+use crate::prelude::*;
+
+pub type MapChunked = Logical<MapType, ListType>;
+
+impl ListChunked {
+    /// Reinterpret a list of `{key, value}` structs as a map.
+    ///
+    /// # Safety
+    /// The inner dtype must be `Struct([key: key, value: value])` and the keys may not
+    /// contain nulls.
+    pub unsafe fn into_map_unchecked(self, key: DataType, value: DataType) -> MapChunked {
+        debug_assert_eq!(
+            self.inner_dtype(),
+            &map_entries_dtype(key.clone(), value.clone())
+        );
+        unsafe { MapChunked::new_logical(self, DataType::Map(Box::new(key), Box::new(value))) }
+    }
+
+    /// Interpret a list of two-field structs as a map, the first field holding the keys and the
+    /// second field holding the values.
+    ///
+    /// The fields are cast to `key` and `value` respectively. Keys are not allowed to be null.
+    pub fn cast_to_map(
+        &self,
+        key: &DataType,
+        value: &DataType,
+        options: CastOptions,
+    ) -> PolarsResult<MapChunked> {
+        let is_entries = matches!(
+            self.inner_dtype(),
+            DataType::Struct(fields) if fields.len() == 2
+        );
+        polars_ensure!(
+            is_entries || self.inner_dtype().is_null(),
+            InvalidOperation: "cannot cast List type (inner: '{:?}') to Map, expected a struct with a key and a value field",
+            self.inner_dtype(),
+        );
+
+        let ca = self
+            .trim_lists_to_normalized_offsets()
+            .map_or(Cow::Borrowed(self), Cow::Owned);
+        let ca = ca.propagate_nulls().map_or(ca, Cow::Owned);
+
+        let entries = ca.apply_to_inner(&|entries| {
+            let (keys, values) = match entries.dtype() {
+                DataType::Null => (
+                    Series::full_null(PlSmallStr::EMPTY, entries.len(), key),
+                    Series::full_null(PlSmallStr::EMPTY, entries.len(), value),
+                ),
+                _ => {
+                    let fields = entries.struct_()?.fields_as_series();
+                    (
+                        fields[0].cast_with_options(key, options)?,
+                        fields[1].cast_with_options(value, options)?,
+                    )
+                },
+            };
+            polars_ensure!(
+                !keys.has_nulls(),
+                ComputeError: "map keys cannot be null"
+            );
+
+            let fields = [
+                keys.with_name(PlSmallStr::from_static(MAP_KEY_NAME)),
+                values.with_name(PlSmallStr::from_static(MAP_VALUE_NAME)),
+            ];
+            Ok(
+                StructChunked::from_series(entries.name().clone(), entries.len(), fields.iter())?
+                    .into_series(),
+            )
+        })?;
+
+        // SAFETY: the entries were cast to the key and value types and contain no null keys.
+        Ok(unsafe { entries.into_map_unchecked(key.clone(), value.clone()) })
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        unsafe { self.phys.get_any_value_unchecked(i) }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Map(key, value) => {
+                if self.dtype() == dtype {
+                    return Ok(self.clone().into_series());
+                }
+                self.phys
+                    .cast_to_map(key, value, cast_options)
+                    .map(|ca| ca.into_series())
+            },
+            DataType::List(_) => self.phys.cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation: "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+impl MapChunked {
+    /// Get the key [`DataType`] of the map.
+    pub fn key_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(key, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the value [`DataType`] of the map.
+    pub fn value_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(_, value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Re-wrap a list of entries of the same type as this map.
+    ///
+    /// # Safety
+    /// `entries` must have the same inner dtype as [`MapChunked::physical`].
+    pub unsafe fn with_entries(&self, entries: ListChunked) -> MapChunked {
+        unsafe { MapChunked::new_logical(entries, self.dtype.clone()) }
+    }
+}
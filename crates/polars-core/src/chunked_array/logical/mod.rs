@@ -14,6 +14,14 @@ pub use decimal::*;
 mod duration;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
+#[cfg(feature = "dtype-fixed-size-binary")]
+mod fixed_size_binary;
+#[cfg(feature = "dtype-fixed-size-binary")]
+pub use fixed_size_binary::*;
+#[cfg(feature = "dtype-map")]
+mod map;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
 #[cfg(feature = "dtype-time")]
@@ -1,5 +1,7 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 
+#[cfg(feature = "dtype-fixed-size-binary")]
+use crate::chunked_array::logical::fixed_size_binary_any_value;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::extension::polars_extension::PolarsExtension;
 use crate::prelude::*;
@@ -44,6 +46,7 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
         DataType::Int32 => downcast_and_pack!(Int32Array, Int32),
         DataType::Int64 => downcast_and_pack!(Int64Array, Int64),
         DataType::Int128 => downcast_and_pack!(Int128Array, Int128),
+        DataType::Float16 => downcast_and_pack!(Float16Array, Float16),
         DataType::Float32 => downcast_and_pack!(Float32Array, Float32),
         DataType::Float64 => downcast_and_pack!(Float64Array, Float64),
         DataType::List(dt) => {
@@ -139,6 +142,11 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let arr = arr.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
             PolarsExtension::arr_to_av(arr, idx)
         },
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        dt if dt.is_fixed_size_binary() => {
+            let arr = &*(arr as *const dyn Array as *const FixedSizeListArray);
+            fixed_size_binary_any_value(arr, idx, dt)
+        },
         DataType::Null => AnyValue::Null,
         DataType::BinaryOffset => downcast_and_pack!(LargeBinaryArray, Binary),
         dt => panic!("not implemented for {dt:?}"),
@@ -200,6 +208,18 @@ where
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl ChunkAnyValue for Float16Chunked {
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue<'_> {
+        get_any_value_unchecked!(self, index)
+    }
+
+    fn get_any_value(&self, index: usize) -> PolarsResult<AnyValue<'_>> {
+        get_any_value!(self, index)
+    }
+}
+
 impl ChunkAnyValue for BooleanChunked {
     #[inline]
     unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue<'_> {
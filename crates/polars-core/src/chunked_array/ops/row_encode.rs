@@ -84,6 +84,7 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Int32
         | DataType::Int64
         | DataType::Int128
+        | DataType::Float16
         | DataType::Float32
         | DataType::Float64
         | DataType::String
@@ -120,6 +121,15 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         #[cfg(feature = "dtype-array")]
         DataType::Array(dtype, _) => get_row_encoding_context(dtype),
         DataType::List(dtype) => get_row_encoding_context(dtype),
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        DataType::FixedSizeBinary(_) => Some(RowEncodingContext::FixedSizeBinary),
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => Some(RowEncodingContext::FixedSizeBinary),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => get_row_encoding_context(&map_entries_dtype(
+            key.as_ref().clone(),
+            value.as_ref().clone(),
+        )),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(fs) => {
             let mut ctxts = Vec::new();
@@ -104,6 +104,7 @@ enum SerializableDataType {
     Int32,
     Int64,
     Int128,
+    Float16,
     Float32,
     Float64,
     String,
@@ -141,6 +142,12 @@ enum SerializableDataType {
     Decimal(Option<usize>, Option<usize>),
     #[cfg(feature = "object")]
     Object(String),
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    FixedSizeBinary(usize),
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -157,6 +164,7 @@ impl From<&DataType> for SerializableDataType {
             Int32 => Self::Int32,
             Int64 => Self::Int64,
             Int128 => Self::Int128,
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -191,6 +199,15 @@ impl From<&DataType> for SerializableDataType {
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(size) => Self::FixedSizeBinary(*size),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Self::Uuid,
         }
     }
 }
@@ -208,6 +225,7 @@ impl From<SerializableDataType> for DataType {
             Int32 => Self::Int32,
             Int64 => Self::Int64,
             Int128 => Self::Int128,
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -249,6 +267,12 @@ impl From<SerializableDataType> for DataType {
             Decimal(precision, scale) => Self::Decimal(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(size) => Self::FixedSizeBinary(size),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Self::Uuid,
         }
     }
 }
@@ -48,6 +48,8 @@ pub enum AnyValue<'a> {
     Int64(i64),
     /// A 128-bit integer number.
     Int128(i128),
+    /// A 16-bit floating point number.
+    Float16(f16),
     /// A 32-bit floating point number.
     Float32(f32),
     /// A 64-bit floating point number.
@@ -151,6 +153,7 @@ impl<'a> AnyValue<'a> {
             UInt16(_) => DataType::UInt16,
             UInt32(_) => DataType::UInt32,
             UInt64(_) => DataType::UInt64,
+            Float16(_) => DataType::Float16,
             Float32(_) => DataType::Float32,
             Float64(_) => DataType::Float64,
             String(_) | StringOwned(_) => DataType::String,
@@ -204,6 +207,7 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => NumCast::from(*v),
             UInt32(v) => NumCast::from(*v),
             UInt64(v) => NumCast::from(*v),
+            Float16(v) => NumCast::from(v.to_f32()),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-date")]
@@ -255,7 +259,10 @@ impl<'a> AnyValue<'a> {
     }
 
     pub fn is_float(&self) -> bool {
-        matches!(self, AnyValue::Float32(_) | AnyValue::Float64(_))
+        matches!(
+            self,
+            AnyValue::Float16(_) | AnyValue::Float32(_) | AnyValue::Float64(_)
+        )
     }
 
     pub fn is_integer(&self) -> bool {
@@ -282,6 +289,7 @@ impl<'a> AnyValue<'a> {
 
     pub fn is_nan(&self) -> bool {
         match self {
+            AnyValue::Float16(f) => f.is_nan(),
             AnyValue::Float32(f) => f.is_nan(),
             AnyValue::Float64(f) => f.is_nan(),
             _ => false,
@@ -318,6 +326,7 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int32) => AnyValue::Int32(av.extract::<i32>()?),
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
             (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
+            (av, DataType::Float16) => AnyValue::Float16(f16::from_f64(av.extract::<f64>()?)),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
 
@@ -331,6 +340,7 @@ impl<'a> AnyValue<'a> {
             (AnyValue::Int32(v), DataType::Boolean) => AnyValue::Boolean(*v != i32::default()),
             (AnyValue::Int64(v), DataType::Boolean) => AnyValue::Boolean(*v != i64::default()),
             (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
+            (AnyValue::Float16(v), DataType::Boolean) => AnyValue::Boolean(v.to_f32() != 0.0),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
 
@@ -603,6 +613,7 @@ impl<'a> AnyValue<'a> {
             | Self::Int32(_)
             | Self::Int64(_)
             | Self::Int128(_)
+            | Self::Float16(_)
             | Self::Float32(_)
             | Self::Float64(_) => self,
 
@@ -706,6 +717,7 @@ impl AnyValue<'_> {
             UInt64(v) => v.hash(state),
             String(v) => v.hash(state),
             StringOwned(v) => v.hash(state),
+            Float16(v) => v.to_bits().hash(state),
             Float32(v) => v.to_ne_bytes().hash(state),
             Float64(v) => v.to_ne_bytes().hash(state),
             Binary(v) => v.hash(state),
@@ -847,6 +859,7 @@ impl<'a> AnyValue<'a> {
 
     pub(crate) fn to_f64(&self) -> Option<f64> {
         match self {
+            AnyValue::Float16(v) => Some(v.to_f64()),
             AnyValue::Float32(v) => Some((*v).into()),
             AnyValue::Float64(v) => Some(*v),
             _ => None,
@@ -863,6 +876,7 @@ impl<'a> AnyValue<'a> {
             (Int64(l), Int64(r)) => Int64(l + r),
             (UInt32(l), UInt32(r)) => UInt32(l + r),
             (UInt64(l), UInt64(r)) => UInt64(l + r),
+            (Float16(l), Float16(r)) => Float16(f16::from_f32(l.to_f32() + r.to_f32())),
             (Float32(l), Float32(r)) => Float32(l + r),
             (Float64(l), Float64(r)) => Float64(l + r),
             #[cfg(feature = "dtype-duration")]
@@ -919,6 +933,7 @@ impl<'a> AnyValue<'a> {
             UInt32(v) => UInt32(v),
             UInt64(v) => UInt64(v),
             Boolean(v) => Boolean(v),
+            Float16(v) => Float16(v),
             Float32(v) => Float32(v),
             Float64(v) => Float64(v),
             #[cfg(feature = "dtype-datetime")]
@@ -1079,6 +1094,7 @@ impl AnyValue<'_> {
             (Int32(l), Int32(r)) => *l == *r,
             (Int64(l), Int64(r)) => *l == *r,
             (Int128(l), Int128(r)) => *l == *r,
+            (Float16(l), Float16(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float32(l), Float32(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float64(l), Float64(r)) => l.to_total_ord() == r.to_total_ord(),
             (String(l), String(r)) => l == r,
@@ -1259,6 +1275,7 @@ impl PartialOrd for AnyValue<'_> {
             (Int32(l), Int32(r)) => l.partial_cmp(r),
             (Int64(l), Int64(r)) => l.partial_cmp(r),
             (Int128(l), Int128(r)) => l.partial_cmp(r),
+            (Float16(l), Float16(r)) => Some(l.tot_cmp(r)),
             (Float32(l), Float32(r)) => Some(l.tot_cmp(r)),
             (Float64(l), Float64(r)) => Some(l.tot_cmp(r)),
             (String(l), String(r)) => l.partial_cmp(r),
@@ -1480,6 +1497,16 @@ impl GetAnyValue for ArrayRef {
                     Some(v) => AnyValue::UInt64(v),
                 }
             },
+            ArrowDataType::Float16 => {
+                let arr = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<f16>>()
+                    .unwrap_unchecked();
+                match arr.get_unchecked(index) {
+                    None => AnyValue::Null,
+                    Some(v) => AnyValue::Float16(v),
+                }
+            },
             ArrowDataType::Float32 => {
                 let arr = self
                     .as_any()
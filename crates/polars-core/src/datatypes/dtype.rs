@@ -3,6 +3,8 @@ use std::collections::BTreeMap;
 use arrow::datatypes::{
     DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY, DTYPE_ENUM_VALUES_NEW, Metadata,
 };
+#[cfg(feature = "dtype-uuid")]
+use arrow::datatypes::{ExtensionType, UUID_EXTENSION_NAME};
 #[cfg(feature = "dtype-array")]
 use polars_utils::format_tuple;
 use polars_utils::itertools::Itertools;
@@ -18,6 +20,22 @@ use crate::utils::materialize_dyn_int;
 static MAINTAIN_PL_TYPE: &str = "maintain_type";
 static PL_KEY: &str = "pl";
 
+/// Name of the struct field holding the keys of a [`DataType::Map`].
+pub const MAP_KEY_NAME: &str = "key";
+/// Name of the struct field holding the values of a [`DataType::Map`].
+pub const MAP_VALUE_NAME: &str = "value";
+/// Name of the Arrow field holding the `{key, value}` entries of a map.
+pub const MAP_ENTRIES_NAME: &str = "entries";
+
+/// The `{key, value}` struct type stored in every row of a [`DataType::Map`].
+#[cfg(feature = "dtype-map")]
+pub fn map_entries_dtype(key: DataType, value: DataType) -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from_static(MAP_KEY_NAME), key),
+        Field::new(PlSmallStr::from_static(MAP_VALUE_NAME), value),
+    ])
+}
+
 pub trait MetaDataExt: IntoMetadata {
     fn pl_enum_metadata(&self) -> Option<&str> {
         let md = self.into_metadata_ref();
@@ -98,6 +116,11 @@ pub enum DataType {
     Int32,
     Int64,
     Int128,
+    /// A 16-bit floating point number.
+    ///
+    /// Computations are done in 32-bit floating point precision, only the
+    /// results are stored in half precision.
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -109,6 +132,12 @@ pub enum DataType {
     String,
     Binary,
     BinaryOffset,
+    /// Binary data where every value has the same number of bytes.
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    FixedSizeBinary(usize),
+    /// A 128-bit universally unique identifier, stored as 16 bytes.
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
     Date,
@@ -136,6 +165,10 @@ pub enum DataType {
     Enum(Arc<FrozenCategories>, Arc<CategoricalMapping>),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A mapping from keys to values, stored as a list of `{key, value}` structs
+    /// in each row.
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -186,6 +219,10 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                (Map(l_key, l_value), Map(r_key, r_value)) => l_key == r_key && l_value == r_value,
+                #[cfg(feature = "dtype-fixed-size-binary")]
+                (FixedSizeBinary(l_size), FixedSizeBinary(r_size)) => l_size == r_size,
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -236,6 +273,8 @@ impl DataType {
             DataType::Array(inner, _) => inner.is_known(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
             DataType::Unknown(_) => false,
             _ => true,
         }
@@ -275,6 +314,11 @@ impl DataType {
                     })
                     .try_collect_vec()?,
             )),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown(allow_unknown)?),
+                Box::new(value.materialize_unknown(allow_unknown)?),
+            )),
             _ => Ok(self),
         }
     }
@@ -369,6 +413,10 @@ impl DataType {
             #[cfg(feature = "object")]
             (D::Object(_), _) | (_, D::Object(_)) => false,
 
+            (D::Float16, dt) | (dt, D::Float16) => {
+                dt.is_primitive_numeric() || dt.is_decimal() || matches!(dt, D::Boolean | D::String)
+            },
+
             (D::Boolean, dt) | (dt, D::Boolean) => match dt {
                 dt if dt.is_primitive_numeric() => true,
                 #[cfg(feature = "dtype-decimal")]
@@ -400,6 +448,18 @@ impl DataType {
 
                 true
             },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(l_key, l_value), D::Map(r_key, r_value)) => {
+                l_key.can_cast_to(r_key)? && l_value.can_cast_to(r_value)?
+            },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            (D::FixedSizeBinary(_), D::Binary) | (D::Binary, D::FixedSizeBinary(_)) => true,
+            #[cfg(feature = "dtype-uuid")]
+            (D::Uuid, D::String | D::Binary) | (D::String | D::Binary, D::Uuid) => true,
+            #[cfg(feature = "dtype-uuid")]
+            (D::Uuid, D::FixedSizeBinary(size)) | (D::FixedSizeBinary(size), D::Uuid) => {
+                *size == 16
+            },
 
             // @NOTE: we are being conversative
             _ => return None,
@@ -436,6 +496,15 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => List(Box::new(map_entries_dtype(
+                key.to_physical(),
+                value.to_physical(),
+            ))),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(size) => Array(Box::new(UInt8), *size),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Array(Box::new(UInt8), 16),
             _ => self.clone(),
         }
     }
@@ -492,8 +561,20 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a map.
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
     pub fn is_nested(&self) -> bool {
-        self.is_list() || self.is_struct() || self.is_array()
+        self.is_list() || self.is_struct() || self.is_array() || self.is_map()
     }
 
     /// Check if this [`DataType`] is a struct
@@ -512,6 +593,17 @@ impl DataType {
         matches!(self, DataType::Binary)
     }
 
+    /// Check if this [`DataType`] is a fixed-size binary or a UUID.
+    pub fn is_fixed_size_binary(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(_) => true,
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => true,
+            _ => false,
+        }
+    }
+
     pub fn is_date(&self) -> bool {
         matches!(self, DataType::Date)
     }
@@ -547,6 +639,8 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
             _ => false,
         }
     }
@@ -563,6 +657,8 @@ impl DataType {
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
             _ => false,
         }
     }
@@ -577,6 +673,8 @@ impl DataType {
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
             _ => false,
         }
     }
@@ -585,6 +683,8 @@ impl DataType {
         use DataType as D;
         match self {
             D::List(_) => true,
+            #[cfg(feature = "dtype-map")]
+            D::Map(_, _) => true,
             #[cfg(feature = "dtype-array")]
             D::Array(inner, _) => inner.contains_list_recursive(),
             #[cfg(feature = "dtype-struct")]
@@ -604,6 +704,8 @@ impl DataType {
             D::Array(inner, _) => inner.contains_unknown(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields.iter().any(|field| field.dtype.contains_unknown()),
+            #[cfg(feature = "dtype-map")]
+            D::Map(key, value) => key.contains_unknown() || value.contains_unknown(),
             _ => false,
         }
     }
@@ -615,7 +717,7 @@ impl DataType {
             || self.is_decimal()
             || matches!(
                 phys,
-                DataType::Binary | DataType::String | DataType::Boolean
+                DataType::Binary | DataType::String | DataType::Boolean | DataType::Float16
             )
     }
 
@@ -637,6 +739,11 @@ impl DataType {
         )
     }
 
+    /// Check if this [`DataType`] is a 16-bit floating point type.
+    pub fn is_float16(&self) -> bool {
+        matches!(self, DataType::Float16)
+    }
+
     /// Check if this [`DataType`] is an integer. Note, this also includes `Unknown(UnknownKind::Int)`.
     pub fn is_integer(&self) -> bool {
         matches!(
@@ -762,6 +869,7 @@ impl DataType {
             UInt16 => Scalar::from(u16::MAX),
             UInt32 => Scalar::from(u32::MAX),
             UInt64 => Scalar::from(u64::MAX),
+            Float16 => Scalar::new(Float16, AnyValue::Float16(f16::from_f32(f32::INFINITY))),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -784,6 +892,7 @@ impl DataType {
             UInt16 => Scalar::from(u16::MIN),
             UInt32 => Scalar::from(u32::MIN),
             UInt64 => Scalar::from(u64::MIN),
+            Float16 => Scalar::new(Float16, AnyValue::Float16(f16::from_f32(f32::NEG_INFINITY))),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -813,6 +922,7 @@ impl DataType {
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
             Int128 => Ok(ArrowDataType::Int128),
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -886,6 +996,32 @@ impl DataType {
                     .collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                // Arrow requires the keys of a map to be non-nullable.
+                let mut key =
+                    key.to_arrow_field(PlSmallStr::from_static(MAP_KEY_NAME), compat_level);
+                key.is_nullable = false;
+                let value =
+                    value.to_arrow_field(PlSmallStr::from_static(MAP_VALUE_NAME), compat_level);
+                let entries = ArrowDataType::Struct(vec![key, value]);
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new(
+                        PlSmallStr::from_static(MAP_ENTRIES_NAME),
+                        entries,
+                        false,
+                    )),
+                    false,
+                ))
+            },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(size) => Ok(ArrowDataType::FixedSizeBinary(*size)),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Ok(ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(UUID_EXTENSION_NAME),
+                inner: ArrowDataType::FixedSizeBinary(16),
+                metadata: None,
+            }))),
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             Unknown(kind) => {
                 let dt = match kind {
@@ -910,6 +1046,8 @@ impl DataType {
             Array(field, _) => field.is_nested_null(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.is_nested_null() && value.is_nested_null(),
             _ => false,
         }
     }
@@ -938,6 +1076,10 @@ impl DataType {
                 }
                 Ok(must_cast)
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(l_key, l_value), DataType::Map(r_key, r_value)) => {
+                Ok(l_key.matches_schema_type(r_key)? | l_value.matches_schema_type(r_value)?)
+            },
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(_, s1), DataType::Decimal(_, s2)) => Ok(s1 != s2),
@@ -1012,8 +1154,10 @@ impl DataType {
         Self::Enum(fcats, mapping)
     }
 
+    /// Check if this [`DataType`] is numeric. Unlike [`DataType::is_primitive_numeric`] this
+    /// includes Decimal and Float16, which don't have native kernels.
     pub fn is_numeric(&self) -> bool {
-        self.is_integer() || self.is_float() || self.is_decimal()
+        self.is_integer() || self.is_float() || self.is_decimal() || self.is_float16()
     }
 }
 
@@ -1031,6 +1175,7 @@ impl Display for DataType {
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
             DataType::Int128 => "i128",
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -1076,6 +1221,12 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(size) => return write!(f, "fixed_size_binary[{size}]"),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => "uuid",
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Ufunc => "unknown ufunc",
                 UnknownKind::Any => "unknown",
@@ -1122,6 +1273,11 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
             let merged = merge_dtypes(inner_l, inner_r)?;
             Array(Box::new(merged), *width_l)
         },
+        #[cfg(feature = "dtype-map")]
+        (Map(key_l, value_l), Map(key_r, value_r)) => Map(
+            Box::new(merge_dtypes(key_l, key_r)?),
+            Box::new(merge_dtypes(value_l, value_r)?),
+        ),
         (left, right) if left == right => left.clone(),
         _ => polars_bail!(ComputeError: "unable to merge datatypes"),
     })
@@ -1155,6 +1311,14 @@ fn collect_nested_types(
                 collect_nested_types(field.dtype(), result, include_compound_types);
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            collect_nested_types(key, result, include_compound_types);
+            collect_nested_types(value, result, include_compound_types);
+        },
         _ => {
             result.insert(dtype.clone());
         },
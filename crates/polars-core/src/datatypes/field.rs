@@ -1,4 +1,4 @@
-use arrow::datatypes::Metadata;
+use arrow::datatypes::{Metadata, UUID_EXTENSION_NAME};
 use polars_dtype::categorical::CategoricalPhysical;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -162,6 +162,9 @@ impl DataType {
             #[cfg(feature = "dtype-i128")]
             ArrowDataType::Int128 => DataType::Int128,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
@@ -239,6 +242,17 @@ impl DataType {
             ArrowDataType::Struct(_) => {
                 panic!("activate the 'dtype-struct' feature to handle struct data types")
             },
+            #[cfg(feature = "dtype-uuid")]
+            ArrowDataType::Extension(ext)
+                if ext.name.as_str() == UUID_EXTENSION_NAME
+                    && ext.inner == ArrowDataType::FixedSizeBinary(16) =>
+            {
+                DataType::Uuid
+            },
+            #[cfg(not(feature = "dtype-uuid"))]
+            ArrowDataType::Extension(ext) if ext.name.as_str() == UUID_EXTENSION_NAME => {
+                Self::from_arrow(&ext.inner, None)
+            },
             ArrowDataType::Extension(ext) if ext.name.as_str() == EXTENSION_NAME => {
                 #[cfg(feature = "object")]
                 {
@@ -266,7 +280,20 @@ impl DataType {
                 }
             },
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            ArrowDataType::FixedSizeBinary(size) => DataType::FixedSizeBinary(*size),
+            #[cfg(not(feature = "dtype-fixed-size-binary"))]
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, _is_sorted) => match Self::from_arrow_field(inner) {
+                DataType::Struct(mut fields) if fields.len() == 2 => {
+                    let value = fields.pop().unwrap();
+                    let key = fields.pop().unwrap();
+                    DataType::Map(Box::new(key.dtype), Box::new(value.dtype))
+                },
+                entries => DataType::List(Box::new(entries)),
+            },
+            #[cfg(not(feature = "dtype-map"))]
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
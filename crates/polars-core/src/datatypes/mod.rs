@@ -32,6 +32,7 @@ use arrow::datatypes::IntegerType;
 pub use arrow::datatypes::reshape::*;
 pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::NativeType;
+pub use arrow::types::f16;
 use bytemuck::Zeroable;
 pub use dtype::*;
 pub use field::*;
@@ -222,6 +223,8 @@ impl_polars_datatype!(StringType, DataType::String, Utf8ViewArray, 'a, &'a str,
 impl_polars_datatype!(BinaryType, DataType::Binary, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, TrueT);
 impl_polars_datatype!(BinaryOffsetType, DataType::BinaryOffset, BinaryArray<i64>, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, FalseT);
 impl_polars_datatype!(BooleanType, DataType::Boolean, BooleanArray, 'a, bool, bool, bool, FalseT);
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, DataType::Float16, PrimitiveArray<f16>, 'a, f16, f16, f16, FalseT);
 
 #[cfg(feature = "dtype-decimal")]
 impl_polars_datatype!(DecimalType, unimplemented!(), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
@@ -304,6 +307,44 @@ unsafe impl PolarsDataType for FixedSizeListType {
     }
 }
 
+#[cfg(feature = "dtype-map")]
+pub struct MapType {}
+#[cfg(feature = "dtype-map")]
+unsafe impl PolarsDataType for MapType {
+    type Physical<'a> = Box<dyn Array>;
+    type OwnedPhysical = Box<dyn Array>;
+    type ZeroablePhysical<'a> = Option<Box<dyn Array>>;
+    type Array = ListArray<i64>;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = FalseT;
+    type IsObject = FalseT;
+
+    fn get_static_dtype() -> DataType {
+        // Null as we cannot know anything without self.
+        DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null))
+    }
+}
+
+#[cfg(feature = "dtype-fixed-size-binary")]
+pub struct FixedSizeBinaryType {}
+#[cfg(feature = "dtype-fixed-size-binary")]
+unsafe impl PolarsDataType for FixedSizeBinaryType {
+    type Physical<'a> = Box<dyn Array>;
+    type OwnedPhysical = Box<dyn Array>;
+    type ZeroablePhysical<'a> = Option<Box<dyn Array>>;
+    type Array = FixedSizeListArray;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = FalseT;
+    type IsObject = FalseT;
+
+    fn get_static_dtype() -> DataType {
+        // Zero as we cannot know the width without self.
+        DataType::FixedSizeBinary(0)
+    }
+}
+
 #[cfg(feature = "object")]
 pub struct ObjectType<T>(T);
 #[cfg(feature = "object")]
@@ -370,6 +411,8 @@ impl_phys_dtype!(BooleanType);
 
 #[cfg(feature = "dtype-i128")]
 impl_phys_dtype!(Int128Type);
+#[cfg(feature = "dtype-f16")]
+impl_phys_dtype!(Float16Type);
 
 #[cfg(feature = "dtype-array")]
 impl_phys_dtype!(FixedSizeListType);
@@ -398,6 +441,8 @@ pub type Int32Chunked = ChunkedArray<Int32Type>;
 pub type Int64Chunked = ChunkedArray<Int64Type>;
 #[cfg(feature = "dtype-i128")]
 pub type Int128Chunked = ChunkedArray<Int128Type>;
+#[cfg(feature = "dtype-f16")]
+pub type Float16Chunked = ChunkedArray<Float16Type>;
 pub type Float32Chunked = ChunkedArray<Float32Type>;
 pub type Float64Chunked = ChunkedArray<Float64Type>;
 pub type StringChunked = ChunkedArray<StringType>;
@@ -365,6 +365,12 @@ impl Debug for Series {
                     format_array!(f, self.i128().unwrap(), "i128", self.name(), "Series")
                 )
             },
+            DataType::Float16 => {
+                feature_gated!(
+                    "dtype-f16",
+                    format_array!(f, self.f16().unwrap(), "f16", self.name(), "Series")
+                )
+            },
             DataType::Float32 => {
                 format_array!(f, self.f32().unwrap(), "f32", self.name(), "Series")
             },
@@ -399,6 +405,22 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            dt if dt.is_fixed_size_binary() => {
+                let dt = format!("{dt}");
+                format_array!(
+                    f,
+                    self.fixed_size_binary().unwrap(),
+                    &dt,
+                    self.name(),
+                    "Series"
+                )
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -1159,6 +1181,7 @@ impl Display for AnyValue<'_> {
             AnyValue::Int32(v) => fmt_integer(f, width, *v),
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
             AnyValue::Int128(v) => feature_gated!("dtype-i128", fmt_integer(f, width, *v)),
+            AnyValue::Float16(v) => fmt_float(f, width, v.to_f32()),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
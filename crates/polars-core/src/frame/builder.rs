@@ -81,7 +81,6 @@ impl DataFrameBuilder {
     /// if other does not match the schema of this builder.
     pub fn extend(&mut self, other: &DataFrame, share: ShareStrategy) {
         self.subslice_extend(other, 0, other.height(), share);
-        self.height += other.height();
     }
 
     /// Extends this builder with the contents of the given dataframe subslice.
@@ -264,3 +263,22 @@ impl DataFrameBuilder {
         self.height += idxs.len();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_extend_height() {
+        let df = df!["a" => [1, 2, 3], "b" => ["x", "y", "z"]].unwrap();
+        let mut builder = DataFrameBuilder::new(df.schema().clone());
+        builder.extend(&df, ShareStrategy::Always);
+        builder.subslice_extend(&df, 1, 2, ShareStrategy::Always);
+        builder.extend(&df, ShareStrategy::Never);
+        assert_eq!(builder.len(), 8);
+
+        let out = builder.freeze();
+        assert_eq!(out.height(), 8);
+        assert_eq!(out.column("a").unwrap().len(), 8);
+    }
+}
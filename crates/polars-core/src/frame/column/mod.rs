@@ -320,6 +320,14 @@ impl Column {
     pub fn try_array(&self) -> Option<&ArrayChunked> {
         self.as_materialized_series().try_array()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        self.as_materialized_series().try_map()
+    }
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    pub fn try_fixed_size_binary(&self) -> Option<&FixedSizeBinaryChunked> {
+        self.as_materialized_series().try_fixed_size_binary()
+    }
     #[cfg(feature = "dtype-categorical")]
     pub fn try_cat<T: PolarsCategoricalType>(&self) -> Option<&CategoricalChunked<T>> {
         self.as_materialized_series().try_cat::<T>()
@@ -414,6 +422,14 @@ impl Column {
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
         self.as_materialized_series().array()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map()
+    }
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    pub fn fixed_size_binary(&self) -> PolarsResult<&FixedSizeBinaryChunked> {
+        self.as_materialized_series().fixed_size_binary()
+    }
     #[cfg(feature = "dtype-categorical")]
     pub fn cat<T: PolarsCategoricalType>(&self) -> PolarsResult<&CategoricalChunked<T>> {
         self.as_materialized_series().cat::<T>()
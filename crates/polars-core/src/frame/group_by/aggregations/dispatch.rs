@@ -133,6 +133,13 @@ impl Series {
         use DataType::*;
         match s.dtype() {
             Boolean => s.cast(&Float64).unwrap().agg_mean(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_mean(groups)
+                .cast(&Float16)
+                .unwrap(),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_mean(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_mean(groups),
             dt if dt.is_primitive_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
@@ -187,6 +194,13 @@ impl Series {
         use DataType::*;
         match s.dtype() {
             Boolean => s.cast(&Float64).unwrap().agg_median(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_median(groups)
+                .cast(&Float16)
+                .unwrap(),
             Float32 => SeriesWrap(s.f32().unwrap().clone()).agg_median(groups),
             Float64 => SeriesWrap(s.f64().unwrap().clone()).agg_median(groups),
             dt if dt.is_primitive_numeric() => {
@@ -247,6 +261,13 @@ impl Series {
 
         use DataType::*;
         match s.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_quantile(groups, quantile, method)
+                .cast(&Float16)
+                .unwrap(),
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, method),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, method),
             dt if dt.is_primitive_numeric() || dt.is_temporal() => {
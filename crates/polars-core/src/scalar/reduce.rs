@@ -1,3 +1,5 @@
+use arrow::types::f16;
+
 use crate::datatypes::AnyValue;
 use crate::datatypes::time_unit::TimeUnit;
 #[cfg(feature = "dtype-date")]
@@ -6,6 +8,10 @@ use crate::prelude::{DataType, Scalar};
 
 pub fn mean_reduce(value: Option<f64>, dtype: DataType) -> Scalar {
     match dtype {
+        DataType::Float16 => {
+            let val = value.map(|m| AnyValue::Float16(f16::from_f64(m)));
+            Scalar::new(dtype, val.unwrap_or(AnyValue::Null))
+        },
         DataType::Float32 => {
             let val = value.map(|m| m as f32);
             Scalar::new(dtype, val.into())
@@ -1,4 +1,5 @@
 use arrow::array::IntoBoxedArray;
+use arrow::types::f16;
 use polars_error::{PolarsError, PolarsResult, polars_bail};
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
@@ -73,6 +74,8 @@ pub enum SerializableScalar {
     UInt32(u32),
     /// An unsigned 64-bit integer number.
     UInt64(u64),
+    /// A 16-bit floating point number, stored as its bits.
+    Float16(u16),
     /// A 32-bit floating point number.
     Float32(f32),
     /// A 64-bit floating point number.
@@ -146,6 +149,7 @@ impl TryFrom<Scalar> for SerializableScalar {
             AnyValue::UInt16(v) => Self::UInt16(v),
             AnyValue::UInt32(v) => Self::UInt32(v),
             AnyValue::UInt64(v) => Self::UInt64(v),
+            AnyValue::Float16(v) => Self::Float16(v.to_bits()),
             AnyValue::Float32(v) => Self::Float32(v),
             AnyValue::Float64(v) => Self::Float64(v),
             AnyValue::List(series) => Self::List(series),
@@ -276,6 +280,7 @@ impl TryFrom<SerializableScalar> for Scalar {
             S::UInt16(v) => Self::from(v),
             S::UInt32(v) => Self::from(v),
             S::UInt64(v) => Self::from(v),
+            S::Float16(v) => Self::from(f16::from_bits(v)),
             S::Float32(v) => Self::from(v),
             S::Float64(v) => Self::from(v),
             S::List(v) => Self::new_list(v),
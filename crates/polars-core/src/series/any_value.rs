@@ -5,6 +5,8 @@ use arrow::bitmap::MutableBitmap;
 #[cfg(feature = "dtype-categorical")]
 use crate::chunked_array::builder::CategoricalChunkedBuilder;
 use crate::chunked_array::builder::{AnonymousOwnedListBuilder, get_list_builder};
+#[cfg(any(feature = "dtype-map", feature = "dtype-fixed-size-binary"))]
+use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
 use crate::utils::any_values_to_supertype;
 
@@ -109,6 +111,8 @@ impl Series {
             DataType::UInt16 => any_values_to_integer::<UInt16Type>(values, strict)?.into_series(),
             DataType::UInt32 => any_values_to_integer::<UInt32Type>(values, strict)?.into_series(),
             DataType::UInt64 => any_values_to_integer::<UInt64Type>(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f16(values, strict)?.into_series(),
             DataType::Float32 => any_values_to_f32(values, strict)?.into_series(),
             DataType::Float64 => any_values_to_f64(values, strict)?.into_series(),
             DataType::Boolean => any_values_to_bool(values, strict)?.into_series(),
@@ -134,6 +138,21 @@ impl Series {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = map_entries_dtype(key.as_ref().clone(), value.as_ref().clone());
+                any_values_to_list(values, &entries, strict)?
+                    .cast_to_map(key, value, CastOptions::Strict)?
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(size) => any_values_to_binary(values, strict)?
+                .cast_to_fixed_size_binary(*size, CastOptions::Strict)?
+                .into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => any_values_to_string(values, strict)?
+                .cast_to_uuid(CastOptions::Strict)?
+                .into_series(),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
@@ -194,6 +213,27 @@ fn any_values_to_integer<T: PolarsIntegerType>(
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+fn any_values_to_f16(values: &[AnyValue], strict: bool) -> PolarsResult<Float16Chunked> {
+    let arr: PrimitiveArray<f16> = if strict {
+        let mut arr = MutablePrimitiveArray::with_capacity(values.len());
+        for av in values {
+            match av {
+                AnyValue::Float16(v) => arr.push(Some(*v)),
+                AnyValue::Null => arr.push_null(),
+                av => return Err(invalid_value_error(&DataType::Float16, av)),
+            }
+        }
+        arr.into()
+    } else {
+        values
+            .iter()
+            .map(|av| av.extract::<f64>().map(f16::from_f64))
+            .collect()
+    };
+    Ok(Float16Chunked::with_chunk(PlSmallStr::EMPTY, arr))
+}
+
 fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32Chunked> {
     fn any_values_to_f32_strict(values: &[AnyValue]) -> PolarsResult<Float32Chunked> {
         let mut builder =
@@ -201,6 +241,7 @@ fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32C
         for av in values {
             match av {
                 AnyValue::Float32(i) => builder.append_value(*i),
+                AnyValue::Float16(i) => builder.append_value(i.to_f32()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float32, av)),
             }
@@ -221,6 +262,7 @@ fn any_values_to_f64(values: &[AnyValue], strict: bool) -> PolarsResult<Float64C
             match av {
                 AnyValue::Float64(i) => builder.append_value(*i),
                 AnyValue::Float32(i) => builder.append_value(*i as f64),
+                AnyValue::Float16(i) => builder.append_value(i.to_f64()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float64, av)),
             }
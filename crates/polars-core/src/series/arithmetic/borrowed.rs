@@ -981,4 +981,33 @@ mod test {
         let out = s_f64.checked_div_num(0.0f64).unwrap();
         assert_eq!(Vec::from(out.f64().unwrap()), &[None, None, None]);
     }
+
+    #[test]
+    #[cfg(feature = "dtype-f16")]
+    fn test_arithmetic_f16() -> PolarsResult<()> {
+        let s = Series::new("foo".into(), [1.5f32, -2.25, 65504.0]).cast(&DataType::Float16)?;
+
+        let out = (&s + &s)?;
+        assert_eq!(out.dtype(), &DataType::Float16);
+        assert_eq!(
+            Vec::from(out.cast(&DataType::Float32)?.f32()?),
+            [Some(3.0), Some(-4.5), Some(f32::INFINITY)]
+        );
+        let out = (&s / &s)?;
+        assert_eq!(out.dtype(), &DataType::Float16);
+
+        // Mixing with a wider float computes in the wider type.
+        let wide = Series::new("bar".into(), [1.0f32, 1.0, 1.0]);
+        assert_eq!((&s - &wide)?.dtype(), &DataType::Float32);
+
+        assert_eq!(
+            Vec::from(&s.gt(&wide)?),
+            [Some(true), Some(false), Some(true)]
+        );
+        assert_eq!(
+            Vec::from(&s.equal(1.5)?),
+            [Some(true), Some(false), Some(false)]
+        );
+        Ok(())
+    }
 }
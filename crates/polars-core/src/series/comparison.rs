@@ -1,5 +1,7 @@
 //! Comparison operations on Series.
 
+use std::borrow::Cow;
+
 use polars_error::feature_gated;
 
 use crate::prelude::*;
@@ -71,6 +73,11 @@ macro_rules! impl_eq_compare {
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
             Int128 => feature_gated!("dtype-i128", lhs.i128().unwrap().$method(rhs.i128().unwrap())),
+            Float16 => {
+                let lhs = lhs.cast(&Float32)?;
+                let rhs = rhs.cast(&Float32)?;
+                lhs.f32().unwrap().$method(rhs.f32().unwrap())
+            },
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
             List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
@@ -168,6 +175,11 @@ macro_rules! impl_ineq_compare {
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
             Int128 => feature_gated!("dtype-i128", lhs.i128().unwrap().$method(rhs.i128().unwrap())),
+            Float16 => {
+                let lhs = lhs.cast(&Float32)?;
+                let rhs = rhs.cast(&Float32)?;
+                lhs.f32().unwrap().$method(rhs.f32().unwrap())
+            },
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
             List(_) => bail_invalid_ineq!(lhs, rhs, $op),
@@ -187,7 +199,7 @@ fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     use DataType::*;
 
     match (left, right) {
-        (String, dt) | (dt, String) if dt.is_primitive_numeric() => {
+        (String, dt) | (dt, String) if dt.is_primitive_numeric() || dt.is_float16() => {
             polars_bail!(ComputeError: "cannot compare string with numeric type ({})", dt)
         },
         #[cfg(feature = "dtype-categorical")]
@@ -249,6 +261,16 @@ impl ChunkCompareIneq<&Series> for Series {
     }
 }
 
+/// The physical representation used to compare with a numeric scalar.
+///
+/// Float16 has no native kernels and is compared in Float32 precision.
+fn physical_numeric_repr(s: &Series) -> PolarsResult<Cow<'_, Series>> {
+    match s.dtype() {
+        DataType::Float16 => Ok(Cow::Owned(s.cast(&DataType::Float32)?)),
+        _ => Ok(s.to_physical_repr()),
+    }
+}
+
 impl<Rhs> ChunkCompareEq<Rhs> for Series
 where
     Rhs: NumericNative,
@@ -257,25 +279,25 @@ where
 
     fn equal(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = physical_numeric_repr(self)?;
         Ok(apply_method_physical_numeric!(&s, equal, rhs))
     }
 
     fn equal_missing(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = physical_numeric_repr(self)?;
         Ok(apply_method_physical_numeric!(&s, equal_missing, rhs))
     }
 
     fn not_equal(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = physical_numeric_repr(self)?;
         Ok(apply_method_physical_numeric!(&s, not_equal, rhs))
     }
 
     fn not_equal_missing(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = physical_numeric_repr(self)?;
         Ok(apply_method_physical_numeric!(&s, not_equal_missing, rhs))
     }
 }
@@ -288,25 +310,25 @@ where
 
     fn gt(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = physical_numeric_repr(self)?;
         Ok(apply_method_physical_numeric!(&s, gt, rhs))
     }
 
     fn gt_eq(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = physical_numeric_repr(self)?;
         Ok(apply_method_physical_numeric!(&s, gt_eq, rhs))
     }
 
     fn lt(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = physical_numeric_repr(self)?;
         Ok(apply_method_physical_numeric!(&s, lt, rhs))
     }
 
     fn lt_eq(&self, rhs: Rhs) -> Self::Item {
         validate_types(self.dtype(), &DataType::Int8)?;
-        let s = self.to_physical_repr();
+        let s = physical_numeric_repr(self)?;
         Ok(apply_method_physical_numeric!(&s, lt_eq, rhs))
     }
 }
@@ -1,6 +1,6 @@
 #[cfg(feature = "dtype-categorical")]
 use arrow::compute::concatenate::concatenate_unchecked;
-use arrow::datatypes::Metadata;
+use arrow::datatypes::{Metadata, UUID_EXTENSION_NAME};
 #[cfg(any(
     feature = "dtype-date",
     feature = "dtype-datetime",
@@ -95,6 +95,23 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let entries_dtype = map_entries_dtype(key.as_ref().clone(), value.as_ref().clone());
+                ListChunked::from_chunks_and_dtype_unchecked(
+                    name,
+                    chunks,
+                    List(Box::new(entries_dtype)),
+                )
+                .into_map_unchecked(key.as_ref().clone(), value.as_ref().clone())
+                .into_series()
+            },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            dt if dt.is_fixed_size_binary() => {
+                ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dt.to_physical())
+                    .into_fixed_size_binary_unchecked(dt.clone())
+                    .into_series()
+            },
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -105,6 +122,8 @@ impl Series {
                 })
             },
             Boolean => BooleanChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Float16Chunked::from_chunks(name, chunks).into_series(),
             Float32 => Float32Chunked::from_chunks(name, chunks).into_series(),
             Float64 => Float64Chunked::from_chunks(name, chunks).into_series(),
             BinaryOffset => BinaryOffsetChunked::from_chunks(name, chunks).into_series(),
@@ -221,6 +240,9 @@ impl Series {
                 "dtype-i128",
                 Ok(Int128Chunked::from_chunks(name, chunks).into_series())
             ),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => Ok(Float16Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -506,17 +528,67 @@ impl Series {
                     Ok(ca.into_series())
                 }
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            ArrowDataType::FixedSizeBinary(_) => {
+                let chunks = chunks.iter().map(|arr| {
+                    arr.as_any()
+                        .downcast_ref::<FixedSizeBinaryArray>()
+                        .unwrap()
+                        .clone()
+                });
+                Ok(FixedSizeBinaryChunked::from_fixed_size_binary(
+                    name,
+                    chunks,
+                    DataType::from_arrow_dtype(dtype),
+                )
+                .into_series())
+            },
+            #[cfg(not(feature = "dtype-fixed-size-binary"))]
             ArrowDataType::FixedSizeBinary(_) => {
                 let chunks = cast_chunks(&chunks, &DataType::Binary, CastOptions::NonStrict)?;
                 Ok(BinaryChunked::from_chunks(name, chunks).into_series())
             },
-            ArrowDataType::Map(_, _) => map_arrays_to_series(name, chunks),
+            ArrowDataType::Extension(ext) if ext.name == UUID_EXTENSION_NAME => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        arr.as_any()
+                            .downcast_ref::<FixedSizeBinaryArray>()
+                            .unwrap()
+                            .clone()
+                            .to(ext.inner.clone())
+                            .boxed()
+                    })
+                    .collect();
+                let s = Self::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                #[cfg(feature = "dtype-uuid")]
+                let s = s.cast(&DataType::Uuid)?;
+                Ok(s)
+            },
+            ArrowDataType::Map(_, _) => map_arrays_to_series(name, chunks, dtype),
             dt => polars_bail!(ComputeError: "cannot create series from {:?}", dt),
         }
     }
 }
 
-fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult<Series> {
+#[cfg_attr(not(feature = "dtype-map"), allow(unused_variables))]
+fn map_arrays_to_series(
+    name: PlSmallStr,
+    chunks: Vec<ArrayRef>,
+    dtype: &ArrowDataType,
+) -> PolarsResult<Series> {
+    let list = map_arrays_to_list_series(name, chunks)?;
+    #[cfg(feature = "dtype-map")]
+    if let DataType::Map(key, value) = DataType::from_arrow_dtype(dtype) {
+        return list
+            .list()?
+            .cast_to_map(&key, &value, CastOptions::Strict)
+            .map(|ca| ca.into_series());
+    }
+    Ok(list)
+}
+
+fn map_arrays_to_list_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult<Series> {
     let chunks = chunks
         .iter()
         .map(|arr| {
@@ -552,10 +624,16 @@ unsafe fn to_physical_and_dtype(
             let chunks = cast_chunks(&arrays, &DataType::String, CastOptions::NonStrict).unwrap();
             (chunks, DataType::String)
         },
+        #[cfg(not(feature = "dtype-fixed-size-binary"))]
         ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::FixedSizeBinary(_) => {
             let chunks = cast_chunks(&arrays, &DataType::Binary, CastOptions::NonStrict).unwrap();
             (chunks, DataType::Binary)
         },
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        ArrowDataType::Binary | ArrowDataType::LargeBinary => {
+            let chunks = cast_chunks(&arrays, &DataType::Binary, CastOptions::NonStrict).unwrap();
+            (chunks, DataType::Binary)
+        },
         #[allow(unused_variables)]
         dt @ ArrowDataType::Dictionary(_, _, _) => {
             feature_gated!("dtype-categorical", {
@@ -684,7 +762,8 @@ unsafe fn to_physical_and_dtype(
             })
         },
         // Use Series architecture to convert nested logical types to physical.
-        dt @ (ArrowDataType::Duration(_)
+        dt @ (ArrowDataType::Map(_, _)
+        | ArrowDataType::Duration(_)
         | ArrowDataType::Time32(_)
         | ArrowDataType::Time64(_)
         | ArrowDataType::Timestamp(_, _)
@@ -696,6 +775,19 @@ unsafe fn to_physical_and_dtype(
             let dtype = s.dtype().clone();
             (std::mem::take(s.chunks_mut()), dtype)
         },
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        dt @ ArrowDataType::FixedSizeBinary(_) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        dt @ ArrowDataType::Extension(ext) if ext.name == UUID_EXTENSION_NAME => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
         dt => {
             let dtype = DataType::from_arrow(dt, md);
             (arrays, dtype)
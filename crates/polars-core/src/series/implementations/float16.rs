@@ -0,0 +1,395 @@
+//! Float16 has no compute kernels of its own. Operations that only move values
+//! around work on the half precision data directly, all other operations are
+//! done in Float32, which represents every Float16 value exactly, and their
+//! results are cast back to Float16 where the data type is preserved.
+use polars_compute::rolling::QuantileMethod;
+
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Float16Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl SeriesWrap<Float16Chunked> {
+    fn upcast(&self) -> Float32Chunked {
+        let s = self
+            .0
+            .cast_with_options(&DataType::Float32, CastOptions::NonStrict)
+            .unwrap();
+        s.f32().unwrap().clone()
+    }
+
+    /// The values as their 16-bit representation, to reuse the kernels of
+    /// [`UInt16Chunked`] for operations that don't look at the values.
+    fn to_bits(&self) -> UInt16Chunked {
+        let chunks = self.0.downcast_iter().map(|arr| arr.clone().transmute());
+        UInt16Chunked::from_chunk_iter(self.0.name().clone(), chunks)
+    }
+
+    fn from_bits(ca: UInt16Chunked) -> Series {
+        let name = ca.name().clone();
+        let chunks = ca.downcast_into_iter().map(|arr| arr.transmute::<f16>());
+        Float16Chunked::from_chunk_iter(name, chunks).into_series()
+    }
+
+    fn downcast(s: Series) -> Series {
+        s.cast_with_options(
+            &s.dtype().cast_leaf(DataType::Float16),
+            CastOptions::Overflowing,
+        )
+        .unwrap()
+    }
+
+    fn downcast_scalar(sc: Scalar) -> Scalar {
+        let av = sc.value().cast(&DataType::Float16).into_static();
+        Scalar::new(DataType::Float16, av)
+    }
+
+    fn arithmetic<F>(&self, rhs: &Series, op: F) -> PolarsResult<Series>
+    where
+        F: Fn(&Series, &Series) -> PolarsResult<Series>,
+    {
+        let lhs = self.upcast().into_series();
+        let rhs = rhs.cast(&DataType::Float32)?;
+        op(&lhs, &rhs).map(Self::downcast)
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Float16Chunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Borrowed(self.0.ref_field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.ref_field().dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other: &Float16Chunked = other.as_ref().as_ref();
+        self.0
+            .get_unchecked(idx_self)
+            .tot_eq(&other.get_unchecked(idx_other))
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = Self(other.f16()?.clone()).to_bits();
+        let out = self.to_bits().zip_with(mask, &other)?;
+        Ok(Self::from_bits(out))
+    }
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        (&self.0).into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        (&self.0).into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.upcast().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.upcast().vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        Self::downcast(self.upcast().agg_min(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        Self::downcast(self.upcast().agg_max(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        Self::downcast(self.upcast().agg_sum(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        Self::downcast(self.upcast().into_series().agg_std(groups, ddof))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        Self::downcast(self.upcast().into_series().agg_var(groups, ddof))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        Self::downcast(self.upcast().agg_list(groups))
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l - r)
+    }
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l + r)
+    }
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l * r)
+    }
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l / r)
+    }
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.arithmetic(rhs, |l, r| l % r)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        IntoGroupsType::group_tuples(&self.upcast(), multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.upcast().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Float16Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append(other.as_ref().as_ref())?;
+        Ok(())
+    }
+    fn append_owned(&mut self, other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append_owned(other.take_inner())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        self.0.append(other.as_ref().as_ref())?;
+        self.0.rechunk_mut();
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.upcast()._sum_as_f64()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.upcast().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.upcast().median().map(|v| v as f64)
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.upcast().std(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.upcast().var(ddof)
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0.take_unchecked(indices).into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0.take_unchecked(indices).into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_owned().into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        Self::from_bits(self.to_bits().new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        let idx = self.upcast().arg_sort(options);
+        // SAFETY: the sort indices are in bounds.
+        let mut out = unsafe { self.0.take_unchecked(&idx) };
+        out.set_sorted_flag(if options.descending {
+            IsSorted::Descending
+        } else {
+            IsSorted::Ascending
+        });
+        Ok(out.into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.upcast().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.upcast()
+            .unique()
+            .map(|ca| Self::downcast(ca.into_series()))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.upcast().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.upcast().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        Self::from_bits(self.to_bits().reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        Self::from_bits(self.to_bits().shift(periods))
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Self::downcast_scalar(self.upcast().sum_reduce()))
+    }
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Self::downcast_scalar(self.upcast().max_reduce()))
+    }
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Self::downcast_scalar(self.upcast().min_reduce()))
+    }
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Self::downcast_scalar(self.upcast().median_reduce()))
+    }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        Ok(Self::downcast_scalar(self.upcast().var_reduce(ddof)))
+    }
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        Ok(Self::downcast_scalar(self.upcast().std_reduce(ddof)))
+    }
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        self.upcast()
+            .quantile_reduce(quantile, method)
+            .map(Self::downcast_scalar)
+    }
+
+    #[cfg(feature = "approx_unique")]
+    fn approx_n_unique(&self) -> PolarsResult<IdxSize> {
+        Ok(ChunkApproxNUnique::approx_n_unique(&self.upcast()))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
@@ -0,0 +1,270 @@
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::row_encode::_get_rows_encoded_ca_unordered;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for MapChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<MapChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl SeriesWrap<MapChunked> {
+    fn wrap(&self, entries: ListChunked) -> Series {
+        // SAFETY: the entries keep their dtype.
+        unsafe { self.0.with_entries(entries) }.into_series()
+    }
+
+    fn other_physical(other: &Series) -> PolarsResult<Series> {
+        Ok(other.map()?.physical().clone().into_series())
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<MapChunked> {
+    fn compute_len(&mut self) {
+        self.0.physical_mut().compute_len()
+    }
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.physical().get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.physical_mut().set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = Self::other_physical(other).unwrap();
+        self.0.physical().equal_element(idx_self, idx_other, &other)
+    }
+
+    fn vec_hash(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, &[self.0.clone().into_column()])?
+            .vec_hash(build_hasher, buf)
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, &[self.0.clone().into_column()])?
+            .vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.map()?;
+        let entries = self.0.physical().zip_with(mask, other.physical())?;
+        Ok(self.wrap(entries))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        let out = self.0.physical().agg_list(groups);
+        let chunks = out.chunks().clone();
+        // SAFETY: the entries are unchanged, we only restore the logical inner type.
+        Series::from_chunks_and_dtype_unchecked(
+            out.name().clone(),
+            chunks,
+            &DataType::List(Box::new(self.0.dtype().clone())),
+        )
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        IntoGroupsType::group_tuples(self.0.physical(), multithreaded, sorted)
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.physical().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        invalid_operation_panic!(into_total_ord_inner, self)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<MapChunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.physical().chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.physical_mut().chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.physical_mut().shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append(other.map()?.physical())
+    }
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<MapChunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        self.0.physical_mut().extend(other.map()?.physical())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        ChunkFilter::filter(self.0.physical(), filter).map(|ca| self.wrap(ca))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        self.0.physical().take(indices).map(|ca| self.wrap(ca))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.wrap(self.0.physical().take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        self.0.physical().take(indices).map(|ca| self.wrap(ca))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.wrap(self.0.physical().take_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.wrap(self.0.physical().rechunk().into_owned())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.wrap(ChunkExpandAtIndex::new_from_index(
+            self.0.physical(),
+            index,
+            length,
+        ))
+    }
+
+    fn trim_lists_to_normalized_offsets(&self) -> Option<Series> {
+        self.0
+            .physical()
+            .trim_lists_to_normalized_offsets()
+            .map(|ca| self.wrap(ca))
+    }
+
+    fn propagate_nulls(&self) -> Option<Series> {
+        self.0.physical().propagate_nulls().map(|ca| self.wrap(ca))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        match self.len() {
+            0 => Ok(0),
+            1 => Ok(1),
+            _ => {
+                let main_thread = POOL.current_thread_index().is_none();
+                let groups = IntoGroupsType::group_tuples(self.0.physical(), main_thread, false)?;
+                Ok(groups.len())
+            },
+        }
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.wrap(ChunkReverse::reverse(self.0.physical()))
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.wrap(ChunkShift::shift(self.0.physical(), periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.physical().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
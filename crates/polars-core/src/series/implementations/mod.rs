@@ -14,8 +14,14 @@ mod datetime;
 mod decimal;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-fixed-size-binary")]
+mod fixed_size_binary;
+#[cfg(feature = "dtype-f16")]
+mod float16;
 mod floats;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
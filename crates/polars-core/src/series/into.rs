@@ -6,6 +6,8 @@
 ))]
 use polars_compute::cast::cast_default as cast;
 use polars_compute::cast::cast_unchecked;
+#[cfg(feature = "dtype-fixed-size-binary")]
+use polars_compute::cast::fixed_size_list_to_fixed_size_binary;
 
 use crate::prelude::*;
 
@@ -85,6 +87,53 @@ impl Series {
                 );
                 Box::new(arr)
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            dt if dt.is_fixed_size_binary() => {
+                let ca = self.fixed_size_binary().unwrap();
+                let arr = ca.physical().chunks[chunk_idx].clone();
+                let arr = arr.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                Box::new(fixed_size_list_to_fixed_size_binary(
+                    arr,
+                    dt.to_arrow(compat_level),
+                ))
+            },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let ca = self.map().unwrap();
+                let arr = ca.physical().chunks[chunk_idx].clone();
+                let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+
+                let entries_dtype = map_entries_dtype(key.as_ref().clone(), value.as_ref().clone());
+                let entries = unsafe {
+                    Series::from_chunks_and_dtype_unchecked(
+                        PlSmallStr::EMPTY,
+                        vec![arr.values().clone()],
+                        &entries_dtype.to_physical(),
+                    )
+                    .from_physical_unchecked(&entries_dtype)
+                    .unwrap()
+                };
+                let entries = entries.to_arrow(0, compat_level);
+                let entries = entries.as_any().downcast_ref::<StructArray>().unwrap();
+
+                // The map dtype marks the keys as non-nullable.
+                let dtype = self.dtype().to_arrow(compat_level);
+                let ArrowDataType::Map(field, _) = &dtype else {
+                    unreachable!()
+                };
+                let entries = StructArray::new(
+                    field.dtype().clone(),
+                    entries.len(),
+                    entries.values().to_vec(),
+                    entries.validity().cloned(),
+                );
+                Box::new(MapArray::new(
+                    dtype,
+                    arr.offsets().try_into().unwrap(),
+                    entries.boxed(),
+                    arr.validity().cloned(),
+                ))
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, width) => {
                 let ca = self.array().unwrap();
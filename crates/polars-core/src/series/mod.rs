@@ -564,15 +564,36 @@ impl Series {
                     .from_physical_unchecked(to.as_slice())
                     .map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(key, value)) => unsafe {
+                let entries_dtype = map_entries_dtype(key.as_ref().clone(), value.as_ref().clone());
+                self.list()
+                    .unwrap()
+                    .from_physical_unchecked(entries_dtype)
+                    .map(|ca| {
+                        ca.into_map_unchecked(key.as_ref().clone(), value.as_ref().clone())
+                            .into_series()
+                    })
+            },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            (D::Array(_, _), dt) if dt.is_fixed_size_binary() => unsafe {
+                Ok(self
+                    .array()
+                    .unwrap()
+                    .clone()
+                    .into_fixed_size_binary_unchecked(dt.clone())
+                    .into_series())
+            },
 
             _ => panic!("invalid from_physical({dtype:?}) for {:?}", self.dtype()),
         }
     }
 
-    /// Cast numerical types to f64, and keep floats as is.
+    /// Cast numerical types to f64, and keep floats as is. Float16 is cast to f32.
     pub fn to_float(&self) -> PolarsResult<Series> {
         match self.dtype() {
             DataType::Float32 | DataType::Float64 => Ok(self.clone()),
+            DataType::Float16 => self.cast(&DataType::Float32),
             _ => self.cast_with_options(&DataType::Float64, CastOptions::Overflowing),
         }
     }
@@ -627,6 +648,7 @@ impl Series {
     /// Check if numeric value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            DataType::Float16 => self.cast(&DataType::Float32)?.is_nan(),
             DataType::Float32 => Ok(self.f32().unwrap().is_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_nan()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
@@ -642,6 +664,7 @@ impl Series {
     /// Check if numeric value is NaN (note this is different than missing/null)
     pub fn is_not_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            DataType::Float16 => self.cast(&DataType::Float32)?.is_not_nan(),
             DataType::Float32 => Ok(self.f32().unwrap().is_not_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_not_nan()),
             dt if dt.is_primitive_numeric() => {
@@ -656,6 +679,7 @@ impl Series {
     /// Check if numeric value is finite
     pub fn is_finite(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            DataType::Float16 => self.cast(&DataType::Float32)?.is_finite(),
             DataType::Float32 => Ok(self.f32().unwrap().is_finite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_finite()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
@@ -671,6 +695,7 @@ impl Series {
     /// Check if numeric value is infinite
     pub fn is_infinite(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
+            DataType::Float16 => self.cast(&DataType::Float32)?.is_infinite(),
             DataType::Float32 => Ok(self.f32().unwrap().is_infinite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_infinite()),
             DataType::Null => Ok(BooleanChunked::full_null(self.name().clone(), self.len())),
@@ -740,6 +765,19 @@ impl Series {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => {
+                let entries = self.map().unwrap().physical();
+                Cow::Owned(entries.to_physical_repr().into_owned().into_series())
+            },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            dt if dt.is_fixed_size_binary() => Cow::Owned(
+                self.fixed_size_binary()
+                    .unwrap()
+                    .physical()
+                    .clone()
+                    .into_series(),
+            ),
             _ => Cow::Borrowed(self),
         }
     }
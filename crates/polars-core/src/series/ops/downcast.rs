@@ -68,6 +68,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Int128 => Int128Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_f16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn try_f32(&self) -> Option<&Float32Chunked> {
         try_unpack_chunked!(self, DataType::Float32 => Float32Chunked)
@@ -159,6 +165,18 @@ impl Series {
         try_unpack_chunked!(self, DataType::Array(_, _) => ArrayChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(_, _) => MapChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::FixedSizeBinary`] or [`DataType::Uuid`]
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    pub fn try_fixed_size_binary(&self) -> Option<&FixedSizeBinaryChunked> {
+        try_unpack_chunked!(self, dt if dt.is_fixed_size_binary() => FixedSizeBinaryChunked)
+    }
+
     #[cfg(feature = "dtype-categorical")]
     pub fn try_cat<T: PolarsCategoricalType>(&self) -> Option<&CategoricalChunked<T>> {
         try_unpack_chunked!(self, dt @ DataType::Enum(_, _) | dt @ DataType::Categorical(_, _) if dt.cat_physical().unwrap() == T::physical() => CategoricalChunked<T>)
@@ -242,6 +260,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Int128"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_f16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         self.try_f32()
@@ -350,6 +375,20 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Array"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.try_map()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::FixedSizeBinary`] or [`DataType::Uuid`]
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    pub fn fixed_size_binary(&self) -> PolarsResult<&FixedSizeBinaryChunked> {
+        self.try_fixed_size_binary()
+            .ok_or_else(|| unpack_chunked_err!(self => "FixedSizeBinary | Uuid"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Categorical`] or [`DataType::Enum`].
     #[cfg(feature = "dtype-categorical")]
     pub fn cat<T: PolarsCategoricalType>(&self) -> PolarsResult<&CategoricalChunked<T>> {
@@ -17,6 +17,23 @@ impl Series {
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = map_entries_dtype(key.as_ref().clone(), value.as_ref().clone());
+                let ca = ListChunked::full_null_with_dtype(name, size, &entries);
+                // SAFETY: an all-null map has no keys.
+                unsafe { ca.into_map_unchecked(key.as_ref().clone(), value.as_ref().clone()) }
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            dt if dt.is_fixed_size_binary() => {
+                let DataType::Array(_, width) = dt.to_physical() else {
+                    unreachable!()
+                };
+                let ca = ArrayChunked::full_null_with_dtype(name, size, &DataType::UInt8, width);
+                // SAFETY: the physical type matches `dt`.
+                unsafe { ca.into_fixed_size_binary_unchecked(dt.clone()) }.into_series()
+            },
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(_, _) | DataType::Enum(_, _)) => {
                 with_match_categorical_physical_type!(dt.cat_physical().unwrap(), |$C| {
@@ -84,6 +101,11 @@ impl Series {
                 }
                 .into_series()
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let arr = PrimitiveArray::<f16>::new_null(ArrowDataType::Float16, size);
+                Float16Chunked::with_chunk(name, arr).into_series()
+            },
             DataType::Null => Series::new_null(name, size),
             DataType::Unknown(kind) => {
                 let dtype = kind.materialize().unwrap_or(DataType::Null);
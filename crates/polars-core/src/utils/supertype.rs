@@ -197,7 +197,7 @@ pub fn get_supertype_with_options(
             #[cfg(feature = "dtype-i128")]
             (a, Int128) if a.is_integer() | a.is_bool() => Some(Int128),
             #[cfg(feature = "dtype-i128")]
-            (a, Int128) if a.is_float() => Some(Float64),
+            (a, Int128) if a.is_float() || a.is_float16() => Some(Float64),
             #[cfg(feature = "dtype-i128")]
 
 
@@ -278,6 +278,20 @@ pub fn get_supertype_with_options(
 
             (Float64, Float32) => Some(Float64),
 
+            // Follow numpy: small integers fit in a half float, wider ones don't.
+            (Float16, Boolean) => Some(Float16),
+            #[cfg(feature = "dtype-i8")]
+            (Float16, Int8) => Some(Float16),
+            #[cfg(feature = "dtype-u8")]
+            (Float16, UInt8) => Some(Float16),
+            #[cfg(feature = "dtype-i16")]
+            (Float16, Int16) => Some(Float32),
+            #[cfg(feature = "dtype-u16")]
+            (Float16, UInt16) => Some(Float32),
+            (Float16, Int32 | Int64 | UInt32 | UInt64) => Some(Float64),
+            (Float16, Float32) => Some(Float32),
+            (Float16, Float64) => Some(Float64),
+
             // Time related dtypes
             #[cfg(feature = "dtype-date")]
             (Date, UInt32) => Some(Int64),
@@ -407,7 +421,7 @@ pub fn get_supertype_with_options(
                         }
                     },
                     // numeric vs float|str -> always float|str|decimal
-                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
+                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_float16() | dt.is_decimal() => Some(dt.clone()),
                     UnknownKind::Float if dt.is_integer() => Some(Unknown(UnknownKind::Float)),
                     // Materialize float to float or decimal
                     UnknownKind::Float if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
@@ -451,6 +465,14 @@ pub fn get_supertype_with_options(
             (Struct(fields_a), Struct(fields_b)) => {
                 super_type_structs(fields_a, fields_b)
             }
+            #[cfg(feature = "dtype-map")]
+            (Map(key_a, value_a), Map(key_b, value_b)) => {
+                let key = get_supertype(key_a, key_b)?;
+                let value = get_supertype(value_a, value_b)?;
+                Some(Map(Box::new(key), Box::new(value)))
+            }
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            (FixedSizeBinary(_), FixedSizeBinary(_) | Binary) => Some(Binary),
             #[cfg(feature = "dtype-struct")]
             (Struct(fields_a), rhs) if rhs.is_primitive_numeric() => {
                 let mut new_fields = Vec::with_capacity(fields_a.len());
@@ -465,7 +487,7 @@ pub fn get_supertype_with_options(
                 Some(Decimal((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
             }
             #[cfg(feature = "dtype-decimal")]
-            (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
+            (Decimal(_, _), f @ (Float16 | Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
             (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
             _ => None,
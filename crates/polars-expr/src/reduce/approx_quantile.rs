@@ -0,0 +1,164 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use polars_compute::tdigest::TDigest;
+use polars_ops::series::{cast_approx_quantiles, tdigest_values};
+
+use super::*;
+
+pub fn new_approx_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+    accuracy: f64,
+) -> Box<dyn GroupedReduction> {
+    Box::new(ApproxQuantileGroupedReduction::new(
+        dtype,
+        Some(quantile),
+        accuracy,
+    ))
+}
+
+pub fn new_approx_quantile_sketch_reduction(
+    dtype: DataType,
+    accuracy: f64,
+) -> Box<dyn GroupedReduction> {
+    Box::new(ApproxQuantileGroupedReduction::new(dtype, None, accuracy))
+}
+
+/// Keeps a t-digest per group.
+///
+/// If no quantile is given the digests themselves are the result, in
+/// serialized form. Binary input consists of serialized digests, which are
+/// merged.
+pub struct ApproxQuantileGroupedReduction {
+    in_dtype: DataType,
+    quantile: Option<f64>,
+    accuracy: f64,
+    digests: Vec<TDigest>,
+    evicted_digests: Vec<TDigest>,
+}
+
+impl ApproxQuantileGroupedReduction {
+    fn new(in_dtype: DataType, quantile: Option<f64>, accuracy: f64) -> Self {
+        Self {
+            in_dtype,
+            quantile,
+            accuracy,
+            digests: Vec::new(),
+            evicted_digests: Vec::new(),
+        }
+    }
+}
+
+impl GroupedReduction for ApproxQuantileGroupedReduction {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(
+            self.in_dtype.clone(),
+            self.quantile,
+            self.accuracy,
+        ))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.digests.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.digests
+            .resize_with(num_groups as usize, || TDigest::new(self.accuracy));
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        let values = values.as_materialized_series(); // @scalar-opt
+        polars_ops::series::tdigest_extend(&mut self.digests[group_idx as usize], values)
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        let values = values.as_materialized_series(); // @scalar-opt
+        let sketches = values.binary().ok().map(|ca| ca.rechunk().into_owned());
+        let floats = match &sketches {
+            None => Some(tdigest_values(values)?.rechunk().into_owned()),
+            Some(_) => None,
+        };
+
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let digest = self.digests.get_unchecked_mut(g.idx());
+            if g.should_evict() {
+                let new = TDigest::new(self.accuracy);
+                self.evicted_digests.push(core::mem::replace(digest, new));
+            }
+            if let Some(ca) = &floats {
+                if let Some(v) = ca.downcast_as_array().get_unchecked(*i as usize) {
+                    digest.add(v);
+                }
+            } else if let Some(ca) = &sketches {
+                if let Some(bytes) = ca.downcast_as_array().get_unchecked(*i as usize) {
+                    digest.merge(&TDigest::from_bytes(bytes)?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(self.in_dtype == other.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        // SAFETY: indices are in-bounds guaranteed by trait.
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let other_digest = other.digests.get_unchecked(*i as usize);
+            self.digests
+                .get_unchecked_mut(*g as usize)
+                .merge(other_digest);
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            in_dtype: self.in_dtype.clone(),
+            quantile: self.quantile,
+            accuracy: self.accuracy,
+            digests: core::mem::take(&mut self.evicted_digests),
+            evicted_digests: Vec::new(),
+        })
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let mut digests = core::mem::take(&mut self.digests);
+        match self.quantile {
+            Some(quantile) => {
+                let quantiles: Float64Chunked =
+                    digests.iter_mut().map(|d| d.quantile(quantile)).collect();
+                cast_approx_quantiles(quantiles, &self.in_dtype)
+            },
+            None => {
+                let sketches: BinaryChunked =
+                    digests.iter_mut().map(|d| Some(d.to_bytes())).collect();
+                Ok(sketches.into_series())
+            },
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -3,15 +3,22 @@ use polars_plan::prelude::*;
 use polars_utils::arena::{Arena, Node};
 
 use super::*;
+#[cfg(feature = "approx_quantile")]
+use crate::reduce::approx_quantile::{
+    new_approx_quantile_reduction, new_approx_quantile_sketch_reduction,
+};
 #[cfg(feature = "bitwise")]
 use crate::reduce::bitwise::{
     new_bitwise_and_reduction, new_bitwise_or_reduction, new_bitwise_xor_reduction,
 };
 use crate::reduce::count::CountReduce;
 use crate::reduce::first_last::{new_first_reduction, new_last_reduction};
+use crate::reduce::implode::new_implode_reduction;
 use crate::reduce::len::LenReduce;
 use crate::reduce::mean::new_mean_reduction;
 use crate::reduce::min_max::{new_max_reduction, new_min_reduction};
+use crate::reduce::n_unique::new_n_unique_reduction;
+use crate::reduce::quantile::{new_median_reduction, new_quantile_reduction};
 use crate::reduce::sum::new_sum_reduction;
 use crate::reduce::var_std::new_var_std_reduction;
 
@@ -51,11 +58,31 @@ pub fn into_reduction(
                 let count = Box::new(CountReduce::new(*include_nulls)) as Box<_>;
                 (count, *input)
             },
-            IRAggExpr::Quantile { .. } => todo!(),
-            IRAggExpr::Median(_) => todo!(),
-            IRAggExpr::NUnique(_) => todo!(),
-            IRAggExpr::Implode(_) => todo!(),
-            IRAggExpr::AggGroups(_) => todo!(),
+            IRAggExpr::Quantile {
+                expr,
+                quantile,
+                method,
+            } => {
+                let quantile = match expr_arena.get(*quantile) {
+                    AExpr::Literal(lit) if lit.is_scalar() => lit
+                        .to_any_value()
+                        .and_then(|av| av.extract::<f64>())
+                        .ok_or_else(
+                            || polars_err!(ComputeError: "quantile must be a numeric literal"),
+                        )?,
+                    _ => polars_bail!(ComputeError: "quantile must be a literal"),
+                };
+                (
+                    new_quantile_reduction(get_dt(*expr)?, quantile, *method),
+                    *expr,
+                )
+            },
+            IRAggExpr::Median(input) => (new_median_reduction(get_dt(*input)?), *input),
+            IRAggExpr::NUnique(input) => (new_n_unique_reduction(get_dt(*input)?), *input),
+            IRAggExpr::Implode(input) => (new_implode_reduction(get_dt(*input)?), *input),
+            // The input is expected to be a row index, collecting it per group
+            // gives the row indices of each group.
+            IRAggExpr::AggGroups(input) => (new_implode_reduction(get_dt(*input)?), *input),
         },
         AExpr::Len => {
             if let Some(first_column) = schema.iter_names().next() {
@@ -91,6 +118,28 @@ pub fn into_reduction(
                 _ => unreachable!(),
             }
         },
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::ApproxQuantile { quantile, accuracy },
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let reduction = new_approx_quantile_reduction(get_dt(input)?, *quantile, *accuracy);
+            (reduction, input)
+        },
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input: inner_exprs,
+            function: IRFunctionExpr::ApproxQuantileSketch { accuracy },
+            options: _,
+        } => {
+            assert!(inner_exprs.len() == 1);
+            let input = inner_exprs[0].node();
+            let reduction = new_approx_quantile_sketch_reduction(get_dt(input)?, *accuracy);
+            (reduction, input)
+        },
         _ => unreachable!(),
     };
     Ok(out)
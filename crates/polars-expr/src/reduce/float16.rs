@@ -0,0 +1,88 @@
+use super::*;
+
+/// Runs a reduction over Float16 values in Float32.
+///
+/// Float16 has no native compute kernels, the wrapped reduction is created
+/// for Float32 and sees the values upcast. Float results are cast back to
+/// Float16, other results are returned as is.
+pub struct Float16GroupedReduction {
+    inner: Box<dyn GroupedReduction>,
+}
+
+impl Float16GroupedReduction {
+    pub fn new(inner: Box<dyn GroupedReduction>) -> Self {
+        Self { inner }
+    }
+
+    fn upcast(values: &Column) -> PolarsResult<Column> {
+        assert!(values.dtype() == &DataType::Float16);
+        values.cast(&DataType::Float32)
+    }
+}
+
+impl GroupedReduction for Float16GroupedReduction {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.inner.new_empty()))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.inner.resize(num_groups)
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        self.inner
+            .update_group(&Self::upcast(values)?, group_idx, seq_id)
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        self.inner
+            .update_groups_while_evicting(&Self::upcast(values)?, subset, group_idxs, seq_id)
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        self.inner
+            .combine_subset(other.inner.as_ref(), subset, group_idxs)
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.inner.take_evictions()))
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let out = self.inner.finalize()?;
+        if out.dtype().leaf_dtype().is_float() {
+            out.cast(&out.dtype().cast_leaf(DataType::Float16))
+        } else {
+            Ok(out)
+        }
+    }
+
+    fn needs_row_order(&self) -> bool {
+        self.inner.needs_row_order()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
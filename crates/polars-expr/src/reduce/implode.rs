@@ -0,0 +1,249 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use super::*;
+
+pub fn new_implode_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
+    Box::new(ImplodeGroupedReduction::new(dtype))
+}
+
+/// Collects the values of each group into a list.
+///
+/// The values are appended to a shared buffer, remembering the slot of the
+/// group and the sequence id of each value. Slots are never reused, which lets
+/// us tell the values of evicted groups apart from those of their replacement.
+/// On finalization the values are gathered per group, in order of sequence id
+/// and insertion, and turned into lists.
+pub struct ImplodeGroupedReduction {
+    in_dtype: DataType,
+    chunks: Vec<Series>,
+    value_slots: Vec<IdxSize>,
+    value_seqs: Vec<u64>,
+    group_slots: Vec<IdxSize>,
+    evicted_slots: Vec<IdxSize>,
+    num_slots: IdxSize,
+}
+
+impl ImplodeGroupedReduction {
+    fn new(in_dtype: DataType) -> Self {
+        Self {
+            in_dtype,
+            chunks: Vec::new(),
+            value_slots: Vec::new(),
+            value_seqs: Vec::new(),
+            group_slots: Vec::new(),
+            evicted_slots: Vec::new(),
+            num_slots: 0,
+        }
+    }
+
+    fn new_slot(&mut self) -> IdxSize {
+        self.num_slots += 1;
+        self.num_slots - 1
+    }
+
+    /// All buffered values as a single contiguous Series.
+    fn values(&self) -> Series {
+        let mut out = Series::new_empty(PlSmallStr::EMPTY, &self.in_dtype);
+        for chunk in &self.chunks {
+            out.append(chunk).unwrap();
+        }
+        out.rechunk()
+    }
+
+    /// Moves the buffered values for which `slot_map` gives a new slot into
+    /// `dst`, keeping the rest.
+    fn drain_mapped_into(&mut self, slot_map: &[IdxSize], dst: &mut Self) {
+        let values = self.values();
+        let mut keep_idxs = Vec::new();
+        let mut move_idxs = Vec::new();
+        let mut keep_slots = Vec::new();
+        let mut keep_seqs = Vec::new();
+        for (i, (slot, seq)) in self.value_slots.iter().zip(&self.value_seqs).enumerate() {
+            let new_slot = slot_map[*slot as usize];
+            if new_slot == IdxSize::MAX {
+                keep_idxs.push(i as IdxSize);
+                keep_slots.push(*slot);
+                keep_seqs.push(*seq);
+            } else {
+                move_idxs.push(i as IdxSize);
+                dst.value_slots.push(new_slot);
+                dst.value_seqs.push(*seq);
+            }
+        }
+
+        unsafe {
+            // SAFETY: the indices are in-bounds by construction.
+            dst.chunks.push(values.take_slice_unchecked(&move_idxs));
+            self.chunks = vec![values.take_slice_unchecked(&keep_idxs)];
+        }
+        self.value_slots = keep_slots;
+        self.value_seqs = keep_seqs;
+    }
+}
+
+impl GroupedReduction for ImplodeGroupedReduction {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.in_dtype.clone()))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.group_slots.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.group_slots.truncate(num_groups as usize);
+        while self.group_slots.len() < num_groups as usize {
+            let slot = self.new_slot();
+            self.group_slots.push(slot);
+        }
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        let slot = self.group_slots[group_idx as usize];
+        self.value_slots
+            .extend(std::iter::repeat_n(slot, values.len()));
+        self.value_seqs
+            .extend(std::iter::repeat_n(seq_id, values.len()));
+        self.chunks.push(values.as_materialized_series().clone()); // @scalar-opt
+        Ok(())
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        self.value_slots.reserve(subset.len());
+        for g in group_idxs {
+            if g.should_evict() {
+                let new_slot = self.new_slot();
+                let grp_slot = self.group_slots.get_unchecked_mut(g.idx());
+                self.evicted_slots
+                    .push(core::mem::replace(grp_slot, new_slot));
+            }
+            self.value_slots
+                .push(*self.group_slots.get_unchecked(g.idx()));
+        }
+        self.value_seqs
+            .extend(std::iter::repeat_n(seq_id, subset.len()));
+        let values = values.as_materialized_series(); // @scalar-opt
+        self.chunks.push(values.take_slice_unchecked(subset));
+        Ok(())
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(self.in_dtype == other.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+
+        let mut slot_map = vec![IdxSize::MAX; other.num_slots as usize];
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let other_slot = *other.group_slots.get_unchecked(*i as usize);
+            *slot_map.get_unchecked_mut(other_slot as usize) =
+                *self.group_slots.get_unchecked(*g as usize);
+        }
+
+        let mut idxs = Vec::new();
+        for (i, (slot, seq)) in other.value_slots.iter().zip(&other.value_seqs).enumerate() {
+            let new_slot = *slot_map.get_unchecked(*slot as usize);
+            if new_slot != IdxSize::MAX {
+                idxs.push(i as IdxSize);
+                self.value_slots.push(new_slot);
+                self.value_seqs.push(*seq);
+            }
+        }
+        if !idxs.is_empty() {
+            self.chunks.push(other.values().take_slice_unchecked(&idxs));
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        let mut evicted = Self::new(self.in_dtype.clone());
+        let evicted_slots = core::mem::take(&mut self.evicted_slots);
+        if evicted_slots.is_empty() {
+            return Box::new(evicted);
+        }
+
+        // The evicted groups get slots 0..n in the new reduction.
+        evicted.resize(evicted_slots.len() as IdxSize);
+        let mut slot_map = vec![IdxSize::MAX; self.num_slots as usize];
+        for (g, slot) in evicted_slots.into_iter().enumerate() {
+            slot_map[slot as usize] = g as IdxSize;
+        }
+        self.drain_mapped_into(&slot_map, &mut evicted);
+        Box::new(evicted)
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values = self.values();
+        let num_groups = self.group_slots.len();
+
+        // Bucket the values by group, values belonging to no group are dropped.
+        let mut slot_to_group = vec![IdxSize::MAX; self.num_slots as usize];
+        for (g, slot) in self.group_slots.iter().enumerate() {
+            slot_to_group[*slot as usize] = g as IdxSize;
+        }
+        let mut offsets = vec![0 as IdxSize; num_groups + 1];
+        for slot in &self.value_slots {
+            let g = slot_to_group[*slot as usize];
+            if g != IdxSize::MAX {
+                offsets[g as usize + 1] += 1;
+            }
+        }
+        for g in 0..num_groups {
+            offsets[g + 1] += offsets[g];
+        }
+        let mut order = vec![0 as IdxSize; offsets[num_groups] as usize];
+        let mut write_offsets = offsets[..num_groups].to_vec();
+        for (i, slot) in self.value_slots.iter().enumerate() {
+            let g = slot_to_group[*slot as usize];
+            if g != IdxSize::MAX {
+                order[write_offsets[g as usize] as usize] = i as IdxSize;
+                write_offsets[g as usize] += 1;
+            }
+        }
+
+        // Within a group restore the order in which the values were seen.
+        // This sort is stable and thus keeps the insertion order for equal
+        // sequence ids.
+        let groups = (0..num_groups)
+            .map(|g| {
+                let (start, stop) = (offsets[g], offsets[g + 1]);
+                order[start as usize..stop as usize].sort_by_key(|i| self.value_seqs[*i as usize]);
+                [start, stop - start]
+            })
+            .collect();
+
+        let values = unsafe { values.take_slice_unchecked(&order) };
+        *self = Self::new(self.in_dtype.clone());
+        let groups = GroupsType::Slice {
+            groups,
+            rolling: false,
+        };
+        // SAFETY: the slices are in-bounds by construction.
+        Ok(unsafe { values.agg_list(&groups) })
+    }
+
+    fn needs_row_order(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
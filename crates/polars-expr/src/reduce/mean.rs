@@ -10,6 +10,10 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
     use VecGroupedReduction as VGR;
     match dtype {
         Boolean => Box::new(VGR::new(dtype, BoolMeanReducer)),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(super::float16::Float16GroupedReduction::new(
+            new_mean_reduction(Float32),
+        )),
         _ if dtype.is_primitive_numeric() || dtype.is_temporal() => {
             with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
                 Box::new(VGR::new(dtype, NumMeanReducer::<$T>(PhantomData)))
@@ -18,6 +18,10 @@ pub fn new_min_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
     use VecMaskGroupedReduction as VMGR;
     match &dtype {
         Boolean => Box::new(BoolMinGroupedReduction::default()),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(super::float16::Float16GroupedReduction::new(
+            new_min_reduction(Float32, propagate_nans),
+        )),
         #[cfg(feature = "propagate_nans")]
         Float32 if propagate_nans => {
             Box::new(VMGR::new(dtype, NumReducer::<NanMin<Float32Type>>::new()))
@@ -50,6 +54,10 @@ pub fn new_max_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
     use VecMaskGroupedReduction as VMGR;
     match &dtype {
         Boolean => Box::new(BoolMaxGroupedReduction::default()),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(super::float16::Float16GroupedReduction::new(
+            new_max_reduction(Float32, propagate_nans),
+        )),
         #[cfg(feature = "propagate_nans")]
         Float32 if propagate_nans => {
             Box::new(VMGR::new(dtype, NumReducer::<NanMax<Float32Type>>::new()))
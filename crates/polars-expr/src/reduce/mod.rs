@@ -1,12 +1,19 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 #[cfg(feature = "bitwise")]
 mod bitwise;
 mod convert;
 mod count;
 mod first_last;
+#[cfg(feature = "dtype-f16")]
+mod float16;
+mod implode;
 mod len;
 mod mean;
 mod min_max;
+mod n_unique;
+mod quantile;
 mod sum;
 mod var_std;
 
@@ -105,6 +112,15 @@ pub trait GroupedReduction: Any + Send + Sync {
     /// After this operation the number of groups is reset to 0.
     fn finalize(&mut self) -> PolarsResult<Series>;
 
+    /// Returns true if this reduction depends on the order of the values
+    /// within a group, beyond what can be resolved through seq_id.
+    ///
+    /// Group-by nodes must then pass the values of a group from the same morsel
+    /// in row order, and not split them over multiple updates.
+    fn needs_row_order(&self) -> bool {
+        false
+    }
+
     /// Returns this GroupedReduction as a dyn Any.
     fn as_any(&self) -> &dyn Any;
 }
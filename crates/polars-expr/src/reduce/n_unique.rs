@@ -0,0 +1,187 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use arrow::array::BinaryArray;
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca_unordered;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrdWrap;
+
+use super::*;
+
+pub fn new_n_unique_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
+    match dtype {
+        _ if dtype.is_primitive_numeric() || dtype.is_temporal() => {
+            with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
+                Box::new(NUniqueGroupedReduction::<NumKeys<$T>>::new(dtype))
+            })
+        },
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => {
+            Box::new(NUniqueGroupedReduction::<NumKeys<Int128Type>>::new(dtype))
+        },
+        _ => Box::new(NUniqueGroupedReduction::<RowEncodedKeys>::new(dtype)),
+    }
+}
+
+/// Encodes the values of a column into hashable keys.
+trait KeyEncoder: Send + Sync + 'static {
+    type Key: Hash + Eq + Clone + Send + Sync + 'static;
+    type Encoded;
+
+    fn encode(values: &Column) -> PolarsResult<Self::Encoded>;
+
+    /// # Safety
+    /// The idx is in-bounds.
+    unsafe fn insert(set: &mut PlHashSet<Self::Key>, encoded: &Self::Encoded, idx: usize);
+}
+
+struct NumKeys<T>(PhantomData<T>);
+
+impl<T: PolarsNumericType> KeyEncoder for NumKeys<T> {
+    type Key = Option<TotalOrdWrap<T::Native>>;
+    type Encoded = PrimitiveArray<T::Native>;
+
+    fn encode(values: &Column) -> PolarsResult<Self::Encoded> {
+        let values = values.as_materialized_series().to_physical_repr(); // @scalar-opt
+        let ca: &ChunkedArray<T> = values.as_ref().as_ref().as_ref();
+        Ok(ca.rechunk().downcast_as_array().clone())
+    }
+
+    #[inline(always)]
+    unsafe fn insert(set: &mut PlHashSet<Self::Key>, encoded: &Self::Encoded, idx: usize) {
+        set.insert(encoded.get_unchecked(idx).map(TotalOrdWrap));
+    }
+}
+
+/// Encodes arbitrary values with the unordered row encoding, which also
+/// distinguishes nulls.
+struct RowEncodedKeys;
+
+impl KeyEncoder for RowEncodedKeys {
+    type Key = Box<[u8]>;
+    type Encoded = BinaryArray<i64>;
+
+    fn encode(values: &Column) -> PolarsResult<Self::Encoded> {
+        let ca = _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, std::slice::from_ref(values))?;
+        Ok(ca.rechunk().downcast_as_array().clone())
+    }
+
+    #[inline(always)]
+    unsafe fn insert(set: &mut PlHashSet<Self::Key>, encoded: &Self::Encoded, idx: usize) {
+        set.get_or_insert_with(encoded.value_unchecked(idx), |v| v.into());
+    }
+}
+
+/// Counts the distinct values per group by keeping a hash set per group.
+struct NUniqueGroupedReduction<E: KeyEncoder> {
+    in_dtype: DataType,
+    sets: Vec<PlHashSet<E::Key>>,
+    evicted_sets: Vec<PlHashSet<E::Key>>,
+    encoder: PhantomData<fn() -> E>,
+}
+
+impl<E: KeyEncoder> NUniqueGroupedReduction<E> {
+    fn new(in_dtype: DataType) -> Self {
+        Self {
+            in_dtype,
+            sets: Vec::new(),
+            evicted_sets: Vec::new(),
+            encoder: PhantomData,
+        }
+    }
+}
+
+impl<E: KeyEncoder> GroupedReduction for NUniqueGroupedReduction<E> {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.in_dtype.clone()))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.sets.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.sets
+            .resize_with(num_groups as usize, PlHashSet::default);
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        let encoded = E::encode(values)?;
+        let set = &mut self.sets[group_idx as usize];
+        for i in 0..values.len() {
+            unsafe { E::insert(set, &encoded, i) };
+        }
+        Ok(())
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        let encoded = E::encode(values)?;
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let set = self.sets.get_unchecked_mut(g.idx());
+                if g.should_evict() {
+                    self.evicted_sets.push(core::mem::take(set));
+                }
+                E::insert(set, &encoded, *i as usize);
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(self.in_dtype == other.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let other_set = other.sets.get_unchecked(*i as usize);
+                let set = self.sets.get_unchecked_mut(*g as usize);
+                set.extend(other_set.iter().cloned());
+            }
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            in_dtype: self.in_dtype.clone(),
+            sets: core::mem::take(&mut self.evicted_sets),
+            evicted_sets: Vec::new(),
+            encoder: PhantomData,
+        })
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let counts = core::mem::take(&mut self.sets)
+            .into_iter()
+            .map(|set| set.len() as IdxSize)
+            .collect();
+        Ok(IdxCa::from_vec(PlSmallStr::EMPTY, counts).into_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,210 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use std::marker::PhantomData;
+
+use polars_core::with_match_physical_numeric_polars_type;
+
+use super::*;
+
+pub fn new_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+    method: QuantileMethod,
+) -> Box<dyn GroupedReduction> {
+    new_reduction(dtype, QuantileAgg::Quantile(quantile, method))
+}
+
+pub fn new_median_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
+    new_reduction(dtype, QuantileAgg::Median)
+}
+
+fn new_reduction(dtype: DataType, agg: QuantileAgg) -> Box<dyn GroupedReduction> {
+    use DataType::*;
+    use QuantileGroupedReduction as QGR;
+    match dtype {
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(super::float16::Float16GroupedReduction::new(new_reduction(
+            Float32, agg,
+        ))),
+        _ if dtype.is_primitive_numeric() || dtype.is_temporal() => {
+            with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
+                Box::new(QGR::<$T>::new(dtype.clone(), dtype, agg))
+            })
+        },
+
+        // The median of these is computed as a float, like the current engine does.
+        Boolean if agg == QuantileAgg::Median => {
+            Box::new(QGR::<Float64Type>::new(dtype, Float64, agg))
+        },
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) if agg == QuantileAgg::Median => {
+            Box::new(QGR::<Float64Type>::new(dtype, Float64, agg))
+        },
+
+        // For compatibility with the current engine, should probably be an error.
+        _ => Box::new(super::NullGroupedReduction::new(dtype)),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum QuantileAgg {
+    Quantile(f64, QuantileMethod),
+    Median,
+}
+
+/// Computes an exact quantile by buffering all non-null values per group.
+///
+/// The values are stored physically as `buf_dtype`, the finalized quantiles
+/// are computed by the same group-by kernels as the in-memory engine uses.
+pub struct QuantileGroupedReduction<T: PolarsNumericType> {
+    in_dtype: DataType,
+    buf_dtype: DataType,
+    agg: QuantileAgg,
+    values: Vec<Vec<T::Native>>,
+    evicted_values: Vec<Vec<T::Native>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: PolarsNumericType> QuantileGroupedReduction<T> {
+    fn new(in_dtype: DataType, buf_dtype: DataType, agg: QuantileAgg) -> Self {
+        Self {
+            in_dtype,
+            buf_dtype,
+            agg,
+            values: Vec::new(),
+            evicted_values: Vec::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    fn cast_values(&self, values: &Column) -> PolarsResult<ChunkedArray<T>> {
+        assert!(values.dtype() == &self.in_dtype);
+        let values = values.as_materialized_series(); // @scalar-opt
+        let values = values.cast(&self.buf_dtype)?;
+        let values = values.to_physical_repr();
+        let ca: &ChunkedArray<T> = values.as_ref().as_ref().as_ref();
+        Ok(ca.clone())
+    }
+}
+
+impl<T: PolarsNumericType> GroupedReduction for QuantileGroupedReduction<T> {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(
+            self.in_dtype.clone(),
+            self.buf_dtype.clone(),
+            self.agg,
+        ))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.values.resize_with(num_groups as usize, Vec::new);
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        let ca = self.cast_values(values)?;
+        let grp = &mut self.values[group_idx as usize];
+        for arr in ca.downcast_iter() {
+            if arr.has_nulls() {
+                grp.extend(arr.non_null_values_iter());
+            } else {
+                grp.extend_from_slice(arr.values());
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        _seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(subset.len() == group_idxs.len());
+        let ca = self.cast_values(values)?;
+        let ca = ca.rechunk();
+        let arr = ca.downcast_as_array();
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let grp = self.values.get_unchecked_mut(g.idx());
+                if g.should_evict() {
+                    self.evicted_values.push(core::mem::take(grp));
+                }
+                if let Some(v) = arr.get_unchecked(*i as usize) {
+                    grp.push(v);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(self.in_dtype == other.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            for (i, g) in subset.iter().zip(group_idxs) {
+                let v = other.values.get_unchecked(*i as usize);
+                let grp = self.values.get_unchecked_mut(*g as usize);
+                grp.extend_from_slice(v);
+            }
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            in_dtype: self.in_dtype.clone(),
+            buf_dtype: self.buf_dtype.clone(),
+            agg: self.agg,
+            values: core::mem::take(&mut self.evicted_values),
+            evicted_values: Vec::new(),
+            phantom: PhantomData,
+        })
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let values = core::mem::take(&mut self.values);
+        let mut flat = Vec::with_capacity(values.iter().map(|v| v.len()).sum());
+        let mut groups = Vec::with_capacity(values.len());
+        for v in values {
+            groups.push([flat.len() as IdxSize, v.len() as IdxSize]);
+            flat.extend(v);
+        }
+
+        let ca = ChunkedArray::<T>::from_vec(PlSmallStr::EMPTY, flat);
+        let s = ca.into_series().cast(&self.buf_dtype)?;
+        let groups = GroupsType::Slice {
+            groups,
+            rolling: false,
+        };
+        unsafe {
+            // SAFETY: the slices are in-bounds by construction.
+            Ok(match self.agg {
+                QuantileAgg::Quantile(quantile, method) => {
+                    s.agg_quantile(&groups, quantile, method)
+                },
+                QuantileAgg::Median => s.agg_median(&groups),
+            })
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
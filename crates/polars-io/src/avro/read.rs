@@ -1,13 +1,35 @@
 use std::io::{Read, Seek};
 
+use arrow::io::avro::avro_schema::file::FileMetadata;
 use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroScanOptions;
+
+/// Reads the metadata of an Avro file, returning it together with the length of the file header.
+pub fn read_metadata_from_bytes(data: &[u8]) -> PolarsResult<(FileMetadata, usize)> {
+    let mut reader = data;
+    let metadata = avro::avro_schema::read::read_metadata(&mut reader).map_err(to_compute_err)?;
+    Ok((metadata, data.len() - reader.len()))
+}
+
+/// Counts the number of rows in an Avro file without decoding its blocks.
+pub fn count_rows(data: &[u8]) -> PolarsResult<usize> {
+    let (metadata, header_len) = read_metadata_from_bytes(data)?;
+    let blocks = read::read_blocks_info(data, header_len, metadata.marker)?;
+    Ok(blocks.iter().map(|block| block.num_rows).sum())
+}
+
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
 /// [Apache Avro]: https://avro.apache.org
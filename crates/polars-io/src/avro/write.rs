@@ -1,14 +1,108 @@
 use std::io::Write;
 
-pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+pub use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// Compression codec of the blocks of an Avro file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCompression {
+    Deflate,
+    Snappy,
+}
+
+impl From<AvroCompression> for Compression {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => Compression::Deflate,
+            AvroCompression::Snappy => Compression::Snappy,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Block compression.
+    pub compression: Option<AvroCompression>,
+    /// Name of the top-level record in the Avro schema.
+    pub name: PlSmallStr,
+}
+
+impl AvroWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> AvroWriter<W> {
+        AvroWriter::new(writer)
+            .with_compression(self.compression)
+            .with_name(self.name.to_string())
+    }
+}
+
+/// Converts a [`Schema`] into the Avro record describing a row.
+pub fn schema_to_record(schema: &Schema, name: &str) -> PolarsResult<Record> {
+    let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+    write::to_record(&schema, name.to_string())
+}
+
+/// Writes the Avro file header, i.e. the schema, codec and sync marker.
+pub fn write_header<W: Write>(
+    writer: &mut W,
+    record: &Record,
+    compression: Option<AvroCompression>,
+) -> PolarsResult<()> {
+    avro_schema::write::write_metadata(writer, record.clone(), compression.map(Into::into))
+        .map_err(to_compute_err)
+}
+
+/// Serializes every chunk of `df` into a separate Avro block.
+///
+/// The chunks of `df` must be aligned.
+pub fn write_blocks<W: Write>(
+    writer: &mut W,
+    df: &DataFrame,
+    record: &Record,
+    compression: Option<AvroCompression>,
+    parallel: bool,
+) -> PolarsResult<()> {
+    let compression = compression.map(Into::into);
+
+    let mut data = vec![];
+    let mut compressed_block = avro_schema::file::CompressedBlock::default();
+    for chunk in df.iter_chunks(CompatLevel::oldest(), parallel) {
+        let mut serializers = chunk
+            .iter()
+            .zip(record.fields.iter())
+            .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+
+        let mut block = avro_schema::file::Block::new(chunk.len(), std::mem::take(&mut data));
+        write::serialize(&mut serializers, &mut block);
+        let _was_compressed =
+            avro_schema::write::compress(&mut block, &mut compressed_block, compression)
+                .map_err(to_compute_err)?;
+
+        avro_schema::write::write_block(writer, &compressed_block).map_err(to_compute_err)?;
+        // reuse block for next iteration.
+        data = block.data;
+        data.clear();
+
+        // reuse block for next iteration
+        compressed_block.data.clear();
+        compressed_block.number_of_rows = 0
+    }
+
+    Ok(())
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -64,39 +158,10 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
-
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
-        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
-            let mut serializers = chunk
-                .iter()
-                .zip(record.fields.iter())
-                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
-                .collect::<Vec<_>>();
-
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
-
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
-
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
-        }
+        let record = schema_to_record(df.schema(), &self.name)?;
+        write_header(&mut self.writer, &record, self.compression)?;
 
-        Ok(())
+        df.align_chunks_par();
+        write_blocks(&mut self.writer, df, &record, self.compression, true)
     }
 }
@@ -23,13 +23,17 @@ mod read_impl;
 mod reader;
 pub mod schema_inference;
 mod splitfields;
+mod transcode;
 mod utils;
 
 pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
-pub use parser::{count_rows, count_rows_from_slice, count_rows_from_slice_par};
+pub use parser::{
+    count_rows, count_rows_from_slice, count_rows_from_slice_par, count_rows_from_slice_transcoded,
+};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
 pub use schema_inference::infer_file_schema;
+pub use transcode::{TranscodedChunks, leading_lines, maybe_transcode_prefix};
 
 pub mod _csv_read_internal {
     pub use super::buffer::validate_utf8;
@@ -49,6 +49,9 @@ pub struct CsvReadOptions {
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct CsvParseOptions {
     pub separator: u8,
+    /// A separator spanning multiple bytes, e.g. `||`. If set, `separator` holds its first byte.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub multi_byte_separator: Option<PlSmallStr>,
     pub quote_char: Option<u8>,
     pub eol_char: u8,
     pub encoding: CsvEncoding,
@@ -97,6 +100,7 @@ impl Default for CsvParseOptions {
     fn default() -> Self {
         Self {
             separator: b',',
+            multi_byte_separator: None,
             quote_char: Some(b'"'),
             eol_char: b'\n',
             encoding: Default::default(),
@@ -263,9 +267,29 @@ impl CsvParseOptions {
     /// is most often a comma ','.
     pub fn with_separator(mut self, separator: u8) -> Self {
         self.separator = separator;
+        self.multi_byte_separator = None;
         self
     }
 
+    /// Set a separator that may span multiple bytes, e.g. `||` or `\x1f\x1e`.
+    /// A separator of a single byte is the same as [with_separator][Self::with_separator].
+    pub fn with_multi_byte_separator(mut self, separator: &str) -> Self {
+        match separator.as_bytes() {
+            [separator] => return self.with_separator(*separator),
+            [first, ..] => self.separator = *first,
+            [] => {},
+        }
+        self.multi_byte_separator = Some(PlSmallStr::from_str(separator));
+        self
+    }
+
+    /// The bytes of a multi-byte separator that follow its first byte, `separator`.
+    pub(crate) fn separator_tail(&self) -> &[u8] {
+        self.multi_byte_separator
+            .as_ref()
+            .map_or(&[], |s| s.as_bytes().get(1..).unwrap_or_default())
+    }
+
     /// Set the character used for field quoting. This is most often double
     /// quotes '"'. Set this to [None] to disable quote parsing.
     pub fn with_quote_char(mut self, quote_char: Option<u8>) -> Self {
@@ -339,6 +363,24 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1 (Latin-1) encoding.
+    Latin1,
+    /// Windows-1252 encoding.
+    Windows1252,
+    /// UTF-16 little-endian encoding.
+    Utf16Le,
+    /// UTF-16 big-endian encoding.
+    Utf16Be,
+}
+
+impl CsvEncoding {
+    /// Whether the data has to be transcoded to utf8 before it can be parsed.
+    ///
+    /// The data is transcoded in chunks of whole lines while it is parsed. Compressed data is
+    /// transcoded while it is decompressed.
+    pub fn needs_transcoding(&self) -> bool {
+        !matches!(self, CsvEncoding::Utf8 | CsvEncoding::LossyUtf8)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
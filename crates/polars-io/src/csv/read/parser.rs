@@ -10,8 +10,9 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::Buffer;
-use super::options::{CommentPrefix, NullValuesCompiled};
+use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::splitfields::SplitFields;
+use super::transcode::{TranscodedChunks, leading_lines};
 use super::utils::get_file_chunks;
 use crate::prelude::_csv_read_internal::find_starting_point;
 use crate::utils::compression::maybe_decompress_bytes;
@@ -25,6 +26,7 @@ pub fn count_rows(
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
+    encoding: CsvEncoding,
     has_header: bool,
     skip_lines: usize,
     skip_rows_before_header: usize,
@@ -48,12 +50,13 @@ pub fn count_rows(
     let owned = &mut vec![];
     let reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
 
-    count_rows_from_slice_par(
+    count_rows_from_slice_transcoded(
         reader_bytes,
         separator,
         quote_char,
         comment_prefix,
         eol_char,
+        encoding,
         has_header,
         skip_lines,
         skip_rows_before_header,
@@ -106,13 +109,15 @@ pub fn count_rows_from_slice_par(
     const MIN_ROWS_PER_THREAD: usize = 1024;
     let max_threads = POOL.current_num_threads();
 
-    // Determine if parallelism is beneficial and how many threads
+    // Determine if parallelism is beneficial and how many threads. The separator is only used to
+    // validate the number of fields, which we don't know here.
     let n_threads = get_line_stats(
         bytes,
         MIN_ROWS_PER_THREAD,
         eol_char,
         None,
         separator,
+        &[],
         quote_char,
     )
     .map(|(mean, std)| {
@@ -126,19 +131,10 @@ pub fn count_rows_from_slice_par(
     }
 
     let file_chunks: Vec<(usize, usize)> =
-        get_file_chunks(bytes, n_threads, None, separator, quote_char, eol_char);
+        get_file_chunks(bytes, n_threads, None, separator, &[], quote_char, eol_char);
 
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
-        let bytes = &bytes[start..stop];
-
-        if comment_prefix.is_some() {
-            SplitLines::new(bytes, quote_char, eol_char, comment_prefix)
-                .filter(|line| !is_comment_line(line, comment_prefix))
-                .count()
-        } else {
-            CountLines::new(quote_char, eol_char).count(bytes).0
-                + bytes.last().is_some_and(|x| *x != b'\n') as usize
-        }
+        count_chunk_rows(&bytes[start..stop], quote_char, comment_prefix, eol_char)
     });
 
     let n: usize = POOL.install(|| iter.sum());
@@ -146,6 +142,83 @@ pub fn count_rows_from_slice_par(
     Ok(n)
 }
 
+fn count_chunk_rows(
+    bytes: &[u8],
+    quote_char: Option<u8>,
+    comment_prefix: Option<&CommentPrefix>,
+    eol_char: u8,
+) -> usize {
+    if comment_prefix.is_some() {
+        SplitLines::new(bytes, quote_char, eol_char, comment_prefix)
+            .filter(|line| !is_comment_line(line, comment_prefix))
+            .count()
+    } else {
+        CountLines::new(quote_char, eol_char).count(bytes).0
+            + bytes.last().is_some_and(|x| *x != b'\n') as usize
+    }
+}
+
+/// Read the number of rows of data in `encoding` without parsing columns.
+///
+/// Data that is not utf8 is transcoded in chunks of whole lines, so the transcoded data is never
+/// materialized at once.
+#[allow(clippy::too_many_arguments)]
+pub fn count_rows_from_slice_transcoded(
+    bytes: &[u8],
+    separator: u8,
+    quote_char: Option<u8>,
+    comment_prefix: Option<&CommentPrefix>,
+    eol_char: u8,
+    encoding: CsvEncoding,
+    has_header: bool,
+    skip_lines: usize,
+    skip_rows_before_header: usize,
+    skip_rows_after_header: usize,
+) -> PolarsResult<usize> {
+    if !encoding.needs_transcoding() {
+        return count_rows_from_slice_par(
+            bytes,
+            separator,
+            quote_char,
+            comment_prefix,
+            eol_char,
+            has_header,
+            skip_lines,
+            skip_rows_before_header,
+            skip_rows_after_header,
+        );
+    }
+
+    let mut chunks = TranscodedChunks::new(bytes, encoding, quote_char, eol_char);
+
+    // The first chunk holds the skipped lines and the header.
+    let n_lines = leading_lines(
+        skip_lines,
+        skip_rows_before_header,
+        has_header,
+        skip_rows_after_header,
+        Some(1),
+    );
+    let first = chunks.next_chunk(n_lines)?.unwrap_or_default();
+    let mut n = count_rows_from_slice_par(
+        &first,
+        separator,
+        quote_char,
+        comment_prefix,
+        eol_char,
+        has_header,
+        skip_lines,
+        skip_rows_before_header,
+        skip_rows_after_header,
+    )?;
+
+    while let Some(chunk) = chunks.next_chunk(Some(1))? {
+        n += count_chunk_rows(&chunk, quote_char, comment_prefix, eol_char);
+    }
+
+    Ok(n)
+}
+
 /// Read the number of rows without parsing columns
 pub fn count_rows_from_slice(
     mut bytes: &[u8],
@@ -222,6 +295,7 @@ pub(super) fn next_line_position(
     mut input: &[u8],
     mut expected_fields: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<usize> {
@@ -229,12 +303,19 @@ pub(super) fn next_line_position(
         line: &[u8],
         expected_fields: usize,
         separator: u8,
+        separator_tail: &[u8],
         eol_char: u8,
         quote_char: Option<u8>,
     ) -> bool {
         let mut count = 0usize;
-        for (field, _) in SplitFields::new(line, separator, quote_char, eol_char) {
-            if memchr2_iter(separator, eol_char, field).count() >= expected_fields {
+        let fields = SplitFields::new(line, separator, quote_char, eol_char)
+            .with_separator_tail(separator_tail);
+        for (field, _) in fields {
+            // Only count complete (multi-byte) separators.
+            let n_separators = memchr2_iter(separator, eol_char, field)
+                .filter(|&i| field[i] == eol_char || field[i + 1..].starts_with(separator_tail))
+                .count();
+            if n_separators >= expected_fields {
                 return false;
             }
             count += 1;
@@ -286,10 +367,24 @@ pub(super) fn next_line_position(
         match (line, expected_fields) {
             // count the fields, and determine if they are equal to what we expect from the schema
             (Some(line), Some(expected_fields)) => {
-                if accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                if accept_line(
+                    line,
+                    expected_fields,
+                    separator,
+                    separator_tail,
+                    eol_char,
+                    quote_char,
+                ) {
                     let mut valid = true;
                     for line in lines.take(2) {
-                        if !accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                        if !accept_line(
+                            line,
+                            expected_fields,
+                            separator,
+                            separator_tail,
+                            eol_char,
+                            quote_char,
+                        ) {
                             valid = false;
                             break;
                         }
@@ -358,6 +453,7 @@ pub(super) fn get_line_stats(
     eol_char: u8,
     expected_fields: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
 ) -> Option<(f32, f32)> {
     let mut lengths = Vec::with_capacity(n_lines);
@@ -374,6 +470,7 @@ pub(super) fn get_line_stats(
             bytes_trunc,
             expected_fields,
             separator,
+            separator_tail,
             quote_char,
             eol_char,
         )?;
@@ -973,6 +1070,8 @@ pub(super) fn parse_lines(
         truncate_ragged_lines = true
     }
 
+    let separator_tail = parse_options.separator_tail();
+
     // we use the pointers to track the no of bytes read.
     let start = bytes.as_ptr() as usize;
     let original_bytes_len = bytes.len();
@@ -1006,7 +1105,8 @@ pub(super) fn parse_lines(
             parse_options.separator,
             parse_options.quote_char,
             parse_options.eol_char,
-        );
+        )
+        .with_separator_tail(separator_tail);
         let mut idx = 0u32;
         let mut read_sol = 0;
         loop {
@@ -1019,8 +1119,12 @@ pub(super) fn parse_lines(
                 Some((mut field, needs_escaping)) => {
                     let field_len = field.len();
 
-                    // +1 is the split character that is consumed by the iterator.
+                    // +1 is the split character that is consumed by the iterator, unless the
+                    // field ended at a multi-byte separator.
                     read_sol += field_len + 1;
+                    if !iter.finished {
+                        read_sol += separator_tail.len();
+                    }
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
@@ -1,6 +1,7 @@
 pub(super) mod batched;
 
 use std::fmt;
+use std::io::Cursor;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
@@ -19,7 +20,8 @@ use super::parser::{
     skip_lines_naive, skip_this_line,
 };
 use super::reader::prepare_csv_schema;
-use super::schema_inference::{check_decimal_comma, infer_file_schema};
+use super::schema_inference::{check_decimal_comma, check_multi_byte_separator, infer_file_schema};
+use super::transcode::{TranscodedChunks, leading_lines};
 #[cfg(feature = "decompress")]
 use super::utils::decompress;
 use crate::RowIndex;
@@ -121,6 +123,8 @@ pub(crate) struct CoreReader<'a> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_index: Option<RowIndex>,
+    /// The chunks after `reader_bytes` if the data is transcoded in chunks.
+    transcoded_chunks: Option<TranscodedChunks<Cursor<ReaderBytes<'a>>>>,
 }
 
 impl fmt::Debug for CoreReader<'_> {
@@ -160,7 +164,7 @@ impl<'a> CoreReader<'a> {
         let separator = parse_options.separator;
 
         check_decimal_comma(parse_options.decimal_comma, separator)?;
-        #[cfg(feature = "decompress")]
+        check_multi_byte_separator(&parse_options)?;
         let mut reader_bytes = reader_bytes;
 
         if !cfg!(feature = "decompress") && SupportedCompression::check(&reader_bytes).is_some() {
@@ -172,19 +176,53 @@ impl<'a> CoreReader<'a> {
         // We keep track of the inferred schema bool
         // In case the file is compressed this schema inference is wrong and has to be done
         // again after decompression.
+        // Compressed data is transcoded while it is decompressed.
         #[cfg(feature = "decompress")]
-        {
+        let decompressed = {
             let total_n_rows =
                 n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
                 separator,
+                parse_options.separator_tail(),
                 parse_options.quote_char,
                 parse_options.eol_char,
-            ) {
+                parse_options.encoding,
+            )? {
                 reader_bytes = ReaderBytes::Owned(b.into());
+                true
+            } else {
+                false
             }
+        };
+        #[cfg(not(feature = "decompress"))]
+        let decompressed = false;
+
+        // Other data is transcoded in chunks while it is parsed. The first chunk holds the rows
+        // that are skipped and those that are used for schema inference.
+        let mut transcoded_chunks = None;
+        if parse_options.encoding.needs_transcoding() && !decompressed {
+            let mut chunks = TranscodedChunks::new(
+                Cursor::new(reader_bytes),
+                parse_options.encoding,
+                parse_options.quote_char,
+                parse_options.eol_char,
+            );
+            let n_lines = leading_lines(
+                skip_lines,
+                skip_rows,
+                has_header,
+                skip_rows_after_header,
+                if schema.is_some() {
+                    Some(1)
+                } else {
+                    max_records
+                },
+            );
+            let first = chunks.next_chunk(n_lines)?.unwrap_or_default();
+            reader_bytes = ReaderBytes::Owned(first.into());
+            transcoded_chunks = Some(chunks);
         }
 
         let mut schema = match schema {
@@ -251,6 +289,7 @@ impl<'a> CoreReader<'a> {
             predicate,
             to_cast,
             row_index,
+            transcoded_chunks,
         })
     }
 
@@ -322,7 +361,7 @@ impl<'a> CoreReader<'a> {
     // In case malformed CSV is detected, a warning or an error will be issued.
     // Not all malformed CSV will be detected, as that would impact performance.
     fn parse_csv(&mut self, bytes: &[u8]) -> PolarsResult<DataFrame> {
-        let (bytes, _) = self.find_starting_point(
+        let (mut bytes, _) = self.find_starting_point(
             bytes,
             self.parse_options.quote_char,
             self.parse_options.eol_char,
@@ -330,6 +369,21 @@ impl<'a> CoreReader<'a> {
 
         let projection = self.get_projection()?;
 
+        // The chunks that follow `bytes` if the data is transcoded in chunks.
+        let mut transcoded_chunks = self.transcoded_chunks.take();
+        let mut next_block;
+        if bytes.is_empty() {
+            if let Some(chunk) = transcoded_chunks
+                .as_mut()
+                .map(|chunks| chunks.next_chunk(Some(1)))
+                .transpose()?
+                .flatten()
+            {
+                next_block = chunk;
+                bytes = &next_block;
+            }
+        }
+
         // An empty file with a schema should return an empty DataFrame with that schema
         if bytes.is_empty() {
             let mut df = if projection.len() == self.schema.len() {
@@ -354,23 +408,6 @@ impl<'a> CoreReader<'a> {
 
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
 
-        // This is chosen by benchmarking on ny city trip csv dataset.
-        // We want small enough chunks such that threads start working as soon as possible
-        // But we also want them large enough, so that we have less chunks related overhead, but
-        // We minimize chunks to 16 MB to still fit L3 cache.
-        let n_parts_hint = n_threads * 16;
-        let chunk_size = std::cmp::min(bytes.len() / n_parts_hint, 16 * 1024 * 1024);
-
-        // Use a small min chunk size to catch failures in tests.
-        #[cfg(debug_assertions)]
-        let min_chunk_size = 64;
-        #[cfg(not(debug_assertions))]
-        let min_chunk_size = 1024 * 4;
-
-        let mut chunk_size = std::cmp::max(chunk_size, min_chunk_size);
-        let mut total_bytes_offset = 0;
-
-        let results = Arc::new(Mutex::new(vec![]));
         // We have to do this after parsing as there can be comments.
         let total_line_count = &AtomicUsize::new(0);
 
@@ -390,122 +427,169 @@ impl<'a> CoreReader<'a> {
         let pool = &POOL;
 
         let counter = CountLines::new(self.parse_options.quote_char, self.parse_options.eol_char);
-        let mut total_offset = 0;
-        let mut previous_total_offset = 0;
         let check_utf8 = matches!(self.parse_options.encoding, CsvEncoding::Utf8)
             && self.schema.iter_fields().any(|f| f.dtype().is_string());
 
-        pool.scope(|s| {
-            // Pass 1: identify chunks for parallel processing (line parsing).
-            loop {
-                let b = unsafe { bytes.get_unchecked(total_offset..) };
-                if b.is_empty() {
-                    break;
-                }
-                debug_assert!(
-                    total_offset == 0 || bytes[total_offset - 1] == self.parse_options.eol_char
-                );
-
-                // Count is the number of rows for the next chunk. In case of malformed CSV data,
-                // count may not be as expected.
-                let (count, position) = counter.find_next(b, &mut chunk_size);
-                debug_assert!(count == 0 || b[position] == self.parse_options.eol_char);
-
-                let (b, count) = if count == 0
-                    && unsafe {
-                        std::ptr::eq(b.as_ptr().add(b.len()), bytes.as_ptr().add(bytes.len()))
-                    } {
-                    total_offset = bytes.len();
-                    (b, 1)
-                } else {
-                    if count == 0 {
-                        chunk_size *= 2;
-                        continue;
+        let mut dfs = vec![];
+        let mut block_offset = 0;
+        let mut is_first_block = true;
+
+        // Without transcoding all data is parsed as a single block. Transcoded data is parsed
+        // block by block, while the next block is transcoded.
+        loop {
+            // This is chosen by benchmarking on ny city trip csv dataset.
+            // We want small enough chunks such that threads start working as soon as possible
+            // But we also want them large enough, so that we have less chunks related overhead, but
+            // We minimize chunks to 16 MB to still fit L3 cache.
+            let n_parts_hint = n_threads * 16;
+            let chunk_size = std::cmp::min(bytes.len() / n_parts_hint, 16 * 1024 * 1024);
+
+            // Use a small min chunk size to catch failures in tests.
+            #[cfg(debug_assertions)]
+            let min_chunk_size = 64;
+            #[cfg(not(debug_assertions))]
+            let min_chunk_size = 1024 * 4;
+
+            let mut chunk_size = std::cmp::max(chunk_size, min_chunk_size);
+            let mut total_bytes_offset = 0;
+
+            let results = Arc::new(Mutex::new(vec![]));
+
+            let mut total_offset = 0;
+            let mut previous_total_offset = 0;
+
+            let next = pool.scope(|s| {
+                // Pass 1: identify chunks for parallel processing (line parsing).
+                loop {
+                    let b = unsafe { bytes.get_unchecked(total_offset..) };
+                    if b.is_empty() {
+                        break;
                     }
-
-                    let end = total_offset + position + 1;
-                    let b = unsafe { bytes.get_unchecked(total_offset..end) };
-
-                    previous_total_offset = total_offset;
-                    total_offset = end;
-                    (b, count)
-                };
-
-                // Pass 2: process each individual chunk in parallel (field parsing)
-                if !b.is_empty() {
-                    let results = results.clone();
-                    let projection = projection.as_ref();
-                    let slf = &(*self);
-                    s.spawn(move |_| {
-                        if check_utf8 && !super::buffer::validate_utf8(b) {
-                            let mut results = results.lock().unwrap();
-                            results.push((
-                                b.as_ptr() as usize,
-                                Err(polars_err!(ComputeError: "invalid utf-8 sequence")),
-                            ));
-                            return;
+                    debug_assert!(
+                        total_offset == 0 || bytes[total_offset - 1] == self.parse_options.eol_char
+                    );
+
+                    // Count is the number of rows for the next chunk. In case of malformed CSV data,
+                    // count may not be as expected.
+                    let (count, position) = counter.find_next(b, &mut chunk_size);
+                    debug_assert!(count == 0 || b[position] == self.parse_options.eol_char);
+
+                    let (b, count) = if count == 0
+                        && unsafe {
+                            std::ptr::eq(b.as_ptr().add(b.len()), bytes.as_ptr().add(bytes.len()))
+                        } {
+                        total_offset = bytes.len();
+                        (b, 1)
+                    } else {
+                        if count == 0 {
+                            chunk_size *= 2;
+                            continue;
                         }
 
-                        let result = slf
-                            .read_chunk(b, projection, 0, count, Some(0), b.len())
-                            .and_then(|mut df| {
-
-                                // Check malformed
-                                if df.height() > count || (df.height() < count && slf.parse_options.comment_prefix.is_none()) {
-                                    // Note: in case data is malformed, df.height() is more likely to be correct than count.
-                                    let msg = format!("CSV malformed: expected {} rows, actual {} rows, in chunk starting at byte offset {}, length {}",
-                                        count, df.height(), previous_total_offset, b.len());
-                                    if slf.ignore_errors {
-                                        polars_warn!(msg);
-                                    } else {
-                                        polars_bail!(ComputeError: msg);
+                        let end = total_offset + position + 1;
+                        let b = unsafe { bytes.get_unchecked(total_offset..end) };
+
+                        previous_total_offset = total_offset;
+                        total_offset = end;
+                        (b, count)
+                    };
+
+                    // Pass 2: process each individual chunk in parallel (field parsing)
+                    if !b.is_empty() {
+                        let results = results.clone();
+                        let projection = projection.as_ref();
+                        let slf = &(*self);
+                        let chunk_offset = block_offset + previous_total_offset;
+                        s.spawn(move |_| {
+                            if check_utf8 && !super::buffer::validate_utf8(b) {
+                                let mut results = results.lock().unwrap();
+                                results.push((
+                                    b.as_ptr() as usize,
+                                    Err(polars_err!(ComputeError: "invalid utf-8 sequence")),
+                                ));
+                                return;
+                            }
+
+                            let result = slf
+                                .read_chunk(b, projection, 0, count, Some(0), b.len())
+                                .and_then(|mut df| {
+
+                                    // Check malformed
+                                    if df.height() > count || (df.height() < count && slf.parse_options.comment_prefix.is_none()) {
+                                        // Note: in case data is malformed, df.height() is more likely to be correct than count.
+                                        let msg = format!("CSV malformed: expected {} rows, actual {} rows, in chunk starting at byte offset {}, length {}",
+                                            count, df.height(), chunk_offset, b.len());
+                                        if slf.ignore_errors {
+                                            polars_warn!(msg);
+                                        } else {
+                                            polars_bail!(ComputeError: msg);
+                                        }
                                     }
-                                }
-
-                                if slf.n_rows.is_some() {
-                                    total_line_count.fetch_add(df.height(), Ordering::Relaxed);
-                                }
-
-                                // We cannot use the line count as there can be comments in the lines so we must correct line counts later.
-                                if let Some(rc) = &slf.row_index {
-                                    // is first chunk
-                                    let offset = if std::ptr::eq(b.as_ptr(), bytes.as_ptr()) {
-                                        Some(rc.offset)
-                                    } else {
-                                        None
+
+                                    if slf.n_rows.is_some() {
+                                        total_line_count.fetch_add(df.height(), Ordering::Relaxed);
+                                    }
+
+                                    // We cannot use the line count as there can be comments in the lines so we must correct line counts later.
+                                    if let Some(rc) = &slf.row_index {
+                                        // is first chunk
+                                        let offset = if is_first_block && std::ptr::eq(b.as_ptr(), bytes.as_ptr()) {
+                                            Some(rc.offset)
+                                        } else {
+                                            None
+                                        };
+
+                                        unsafe { df.with_row_index_mut(rc.name.clone(), offset) };
                                     };
 
-                                    unsafe { df.with_row_index_mut(rc.name.clone(), offset) };
-                                };
-
-                                if let Some(predicate) = slf.predicate.as_ref() {
-                                    let s = predicate.evaluate_io(&df)?;
-                                    let mask = s.bool()?;
-                                    df = df.filter(mask)?;
-                                }
-                                Ok(df)
-                            });
-
-                        results.lock().unwrap().push((b.as_ptr() as usize, result));
-                    });
-
-                    // Check just after we spawned a chunk. That mean we processed all data up until
-                    // row count.
-                    if self.n_rows.is_some()
-                        && total_line_count.load(Ordering::Relaxed) > self.n_rows.unwrap()
-                    {
-                        break;
+                                    if let Some(predicate) = slf.predicate.as_ref() {
+                                        let s = predicate.evaluate_io(&df)?;
+                                        let mask = s.bool()?;
+                                        df = df.filter(mask)?;
+                                    }
+                                    Ok(df)
+                                });
+
+                            results.lock().unwrap().push((b.as_ptr() as usize, result));
+                        });
+
+                        // Check just after we spawned a chunk. That mean we processed all data up until
+                        // row count.
+                        if self.n_rows.is_some()
+                            && total_line_count.load(Ordering::Relaxed) > self.n_rows.unwrap()
+                        {
+                            return Ok(None);
+                        }
                     }
+                    total_bytes_offset += b.len();
                 }
-                total_bytes_offset += b.len();
+
+                // Transcode the next block while this one is parsed.
+                transcoded_chunks
+                    .as_mut()
+                    .map(|chunks| chunks.next_chunk(Some(1)))
+                    .transpose()
+                    .map(Option::flatten)
+            });
+            let mut results = std::mem::take(&mut *results.lock().unwrap());
+            results.sort_unstable_by_key(|k| k.0);
+            for (_, df) in results {
+                dfs.push(df?);
             }
-        });
-        let mut results = std::mem::take(&mut *results.lock().unwrap());
-        results.sort_unstable_by_key(|k| k.0);
-        let mut dfs = results
-            .into_iter()
-            .map(|k| k.1)
-            .collect::<PolarsResult<Vec<_>>>()?;
+
+            let done = self
+                .n_rows
+                .is_some_and(|n_rows| total_line_count.load(Ordering::Relaxed) > n_rows);
+            match next? {
+                Some(block) if !done => {
+                    block_offset += bytes.len();
+                    is_first_block = false;
+                    next_block = block;
+                    bytes = &next_block;
+                },
+                _ => break,
+            }
+        }
 
         if let Some(rc) = &self.row_index {
             update_row_counts2(&mut dfs, rc.offset)
@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::io::Cursor;
 use std::ops::Deref;
 
 use polars_core::POOL;
@@ -13,6 +14,7 @@ use super::{CoreReader, CountLines, cast_columns, read_chunk};
 use crate::RowIndex;
 use crate::csv::read::CsvReader;
 use crate::csv::read::options::NullValuesCompiled;
+use crate::csv::read::transcode::TranscodedChunks;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::{CsvParseOptions, update_row_counts2};
 
@@ -175,6 +177,7 @@ impl<'a> CoreReader<'a> {
             remaining: self.n_rows.unwrap_or(usize::MAX),
             schema: self.schema,
             rows_read: 0,
+            transcoded_chunks: self.transcoded_chunks,
         })
     }
 }
@@ -194,6 +197,8 @@ pub struct BatchedCsvReader<'a> {
     remaining: usize,
     schema: SchemaRef,
     rows_read: IdxSize,
+    /// The chunks after `reader_bytes` if the data is transcoded in chunks.
+    transcoded_chunks: Option<TranscodedChunks<Cursor<ReaderBytes<'a>>>>,
 }
 
 impl BatchedCsvReader<'_> {
@@ -205,9 +210,13 @@ impl BatchedCsvReader<'_> {
         // get next `n` offset positions.
         let file_chunks_iter = (&mut self.file_chunks_iter).take(n);
         self.file_chunks.extend(file_chunks_iter);
-        // depleted the offsets iterator, we are done as well.
-        if self.file_chunks.is_empty() {
-            return Ok(None);
+        while self.file_chunks.is_empty() {
+            // depleted the offsets iterator, we are done as well.
+            if !self.next_transcoded_chunk()? {
+                return Ok(None);
+            }
+            let file_chunks_iter = (&mut self.file_chunks_iter).take(n);
+            self.file_chunks.extend(file_chunks_iter);
         }
         let chunks = &self.file_chunks;
 
@@ -261,6 +270,35 @@ impl BatchedCsvReader<'_> {
         }
         Ok(Some(chunks))
     }
+
+    /// Continue with the next chunk of transcoded data. Returns `false` if there is none.
+    fn next_transcoded_chunk(&mut self) -> PolarsResult<bool> {
+        let Some(chunks) = self.transcoded_chunks.as_mut() else {
+            return Ok(false);
+        };
+        let Some(chunk) = chunks.next_chunk(Some(1))? else {
+            return Ok(false);
+        };
+
+        let reader_bytes = ReaderBytes::Owned(chunk.into());
+        // extend lifetime. It is bound to `reader_bytes` and we keep track of that
+        // lifetime so this is sound.
+        let bytes = unsafe { std::mem::transmute::<&[u8], &'static [u8]>(&reader_bytes) };
+        let iter = &self.file_chunks_iter;
+        self.file_chunks_iter = ChunkOffsetIter {
+            bytes,
+            offsets: VecDeque::with_capacity(iter.n_chunks),
+            last_offset: 0,
+            n_chunks: iter.n_chunks,
+            chunk_size: iter.chunk_size,
+            rows_per_batch: iter.rows_per_batch,
+            quote_char: iter.quote_char,
+            eol_char: iter.eol_char,
+        };
+        self.reader_bytes = reader_bytes;
+        self.starting_point_offset = Some(0);
+        Ok(true)
+    }
 }
 
 pub struct OwnedBatchedCsvReader {
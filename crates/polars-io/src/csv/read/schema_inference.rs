@@ -171,10 +171,11 @@ pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bo
 #[inline]
 fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<'_, str>> {
     Ok(match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
+        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
+        // Other encodings are transcoded to utf8 before parsing.
+        _ => simdutf8::basic::from_utf8(bytes)
             .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
             .into(),
-        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
     })
 }
 
@@ -249,7 +250,8 @@ fn infer_file_schema_inner(
             parse_options.separator,
             parse_options.quote_char,
             parse_options.eol_char,
-        );
+        )
+        .with_separator_tail(parse_options.separator_tail());
         if has_header {
             let headers = byterecord
                 .map(|(slice, needs_escaping)| {
@@ -373,7 +375,8 @@ fn infer_file_schema_inner(
             parse_options.separator,
             parse_options.quote_char,
             parse_options.eol_char,
-        );
+        )
+        .with_separator_tail(parse_options.separator_tail());
 
         for (i, (slice, needs_escaping)) in record.enumerate() {
             // When `has_header = False` and ``
@@ -515,6 +518,19 @@ pub(super) fn check_decimal_comma(decimal_comma: bool, separator: u8) -> PolarsR
     Ok(())
 }
 
+pub(super) fn check_multi_byte_separator(parse_options: &CsvParseOptions) -> PolarsResult<()> {
+    if let Some(separator) = &parse_options.multi_byte_separator {
+        let separator = separator.as_bytes();
+        polars_ensure!(!separator.is_empty(), InvalidOperation: "'separator' cannot be empty");
+        polars_ensure!(
+            !separator.contains(&parse_options.eol_char)
+                && parse_options.quote_char.is_none_or(|q| !separator.contains(&q)),
+            InvalidOperation: "'separator' cannot contain the 'eol_char' or 'quote_char'"
+        );
+    }
+    Ok(())
+}
+
 /// Infer the schema of a CSV file by reading through the first n rows of the file,
 /// with `max_read_rows` controlling the maximum number of rows to read.
 ///
@@ -537,6 +553,7 @@ pub fn infer_file_schema(
     raise_if_empty: bool,
 ) -> PolarsResult<(Schema, usize, usize)> {
     check_decimal_comma(parse_options.decimal_comma, parse_options.separator)?;
+    check_multi_byte_separator(parse_options)?;
 
     if skip_lines > 0 {
         polars_ensure!(skip_rows == 0, InvalidOperation: "only one of 'skip_rows'/'skip_lines' may be set");
@@ -6,7 +6,8 @@ mod inner {
     pub(crate) struct SplitFields<'a> {
         v: &'a [u8],
         separator: u8,
-        finished: bool,
+        separator_tail: &'a [u8],
+        pub finished: bool,
         quote_char: u8,
         quoting: bool,
         eol_char: u8,
@@ -22,6 +23,7 @@ mod inner {
             Self {
                 v: slice,
                 separator,
+                separator_tail: &[],
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
@@ -29,6 +31,19 @@ mod inner {
             }
         }
 
+        /// Only split on `separator` if it is followed by `tail`, for separators that span
+        /// multiple bytes.
+        pub(crate) fn with_separator_tail(mut self, tail: &'a [u8]) -> Self {
+            self.separator_tail = tail;
+            self
+        }
+
+        /// Whether the separator byte at `idx` starts a complete separator.
+        #[inline]
+        fn is_separator_at(&self, idx: usize) -> bool {
+            self.separator_tail.is_empty() || self.v[idx + 1..].starts_with(self.separator_tail)
+        }
+
         unsafe fn finish_eol(
             &mut self,
             need_escaping: bool,
@@ -97,8 +112,10 @@ mod inner {
                                 self.finish_eol(needs_escaping, current_idx as usize)
                             };
                         }
-                        idx = current_idx;
-                        break;
+                        if self.is_separator_at(current_idx as usize) {
+                            idx = current_idx;
+                            break;
+                        }
                     }
                     current_idx += 1;
                 }
@@ -109,17 +126,23 @@ mod inner {
 
                 idx as usize
             } else {
-                match self.v.iter().position(|&c| self.eof_eol(c)) {
-                    None => return self.finish(needs_escaping),
-                    Some(idx) => unsafe {
-                        // SAFETY:
-                        // idx was just found
-                        if *self.v.get_unchecked(idx) == self.eol_char {
-                            return self.finish_eol(needs_escaping, idx);
-                        } else {
-                            idx
-                        }
-                    },
+                let mut start = 0;
+                loop {
+                    match self.v[start..].iter().position(|&c| self.eof_eol(c)) {
+                        None => return self.finish(needs_escaping),
+                        Some(idx) => unsafe {
+                            let idx = start + idx;
+                            // SAFETY:
+                            // idx was just found
+                            if *self.v.get_unchecked(idx) == self.eol_char {
+                                return self.finish_eol(needs_escaping, idx);
+                            } else if self.is_separator_at(idx) {
+                                break idx;
+                            } else {
+                                start = idx + 1;
+                            }
+                        },
+                    }
                 }
             };
 
@@ -128,7 +151,7 @@ mod inner {
                 // SAFETY:
                 // we are in bounds
                 let ret = Some((self.v.get_unchecked(..pos), needs_escaping));
-                self.v = self.v.get_unchecked(pos + 1..);
+                self.v = self.v.get_unchecked(pos + 1 + self.separator_tail.len()..);
                 ret
             }
         }
@@ -149,6 +172,7 @@ mod inner {
     pub(crate) struct SplitFields<'a> {
         pub v: &'a [u8],
         separator: u8,
+        separator_tail: &'a [u8],
         pub finished: bool,
         quote_char: u8,
         quoting: bool,
@@ -175,6 +199,7 @@ mod inner {
             Self {
                 v: slice,
                 separator,
+                separator_tail: &[],
                 finished: false,
                 quote_char,
                 quoting,
@@ -186,6 +211,38 @@ mod inner {
             }
         }
 
+        /// Only split on `separator` if it is followed by `tail`, for separators that span
+        /// multiple bytes.
+        pub(crate) fn with_separator_tail(mut self, tail: &'a [u8]) -> Self {
+            self.separator_tail = tail;
+            self
+        }
+
+        /// Whether the separator byte at `idx` starts a complete separator.
+        #[inline]
+        fn is_separator_at(&self, idx: usize) -> bool {
+            self.separator_tail.is_empty() || self.v[idx + 1..].starts_with(self.separator_tail)
+        }
+
+        /// Clears the bits of `mask` (starting at `offset` in `v`) that point to a separator byte
+        /// which does not start a complete multi-byte separator.
+        #[inline]
+        fn retain_full_separators(&self, mut mask: u64, offset: usize) -> u64 {
+            if self.separator_tail.is_empty() {
+                return mask;
+            }
+            let mut candidates = mask;
+            while candidates != 0 {
+                let pos = candidates.trailing_zeros() as usize;
+                candidates &= candidates - 1;
+                let idx = offset + pos;
+                if self.v[idx] == self.separator && !self.is_separator_at(idx) {
+                    mask &= !(1 << pos);
+                }
+            }
+            mask
+        }
+
         unsafe fn finish_eol(
             &mut self,
             need_escaping: bool,
@@ -284,6 +341,7 @@ mod inner {
                         not_in_field_previous_iter =
                             (not_in_quote_field & (1 << (SIMD_SIZE - 1))) > 0;
                         end_mask &= not_in_quote_field;
+                        end_mask = self.retain_full_separators(end_mask, total_idx);
 
                         if end_mask != 0 {
                             let pos = end_mask.trailing_zeros() as usize;
@@ -293,7 +351,8 @@ mod inner {
                                     || self.v[total_idx] == self.separator
                             );
 
-                            if pos == SIMD_SIZE - 1 {
+                            // The cached ends assume separators of a single byte.
+                            if pos == SIMD_SIZE - 1 || !self.separator_tail.is_empty() {
                                 self.previous_valid_ends = 0;
                             } else {
                                 self.previous_valid_ends = end_mask >> (pos + 1) as u64;
@@ -333,8 +392,10 @@ mod inner {
                                         self.finish_eol(needs_escaping, current_idx + total_idx)
                                     };
                                 }
-                                idx = current_idx;
-                                break;
+                                if self.is_separator_at(current_idx + total_idx) {
+                                    idx = current_idx;
+                                    break;
+                                }
                             }
                             current_idx += 1;
                         }
@@ -369,7 +430,10 @@ mod inner {
                         let simd_bytes = SimdVec::from(lane);
                         let has_eol_char = simd_bytes.simd_eq(self.simd_eol_char);
                         let has_separator = simd_bytes.simd_eq(self.simd_separator);
-                        let has_any_mask = (has_separator | has_eol_char).to_bitmask();
+                        let has_any_mask = self.retain_full_separators(
+                            (has_separator | has_eol_char).to_bitmask(),
+                            total_idx,
+                        );
 
                         if has_any_mask != 0 {
                             total_idx += has_any_mask.trailing_zeros() as usize;
@@ -382,7 +446,12 @@ mod inner {
                             None => return self.finish(needs_escaping),
                             Some(idx) => {
                                 total_idx += idx;
-                                break;
+                                if self.v[total_idx] == self.eol_char
+                                    || self.is_separator_at(total_idx)
+                                {
+                                    break;
+                                }
+                                total_idx += 1;
                             },
                         }
                     }
@@ -401,7 +470,7 @@ mod inner {
                 // SAFETY:
                 // we are in bounds
                 let ret = Some((self.v.get_unchecked(..pos), needs_escaping));
-                self.v = self.v.get_unchecked(pos + 1..);
+                self.v = self.v.get_unchecked(pos + 1 + self.separator_tail.len()..);
                 ret
             }
         }
@@ -431,4 +500,30 @@ mod test {
         assert_eq!(fields2.next(), Some(("12345".as_bytes(), false)));
         assert_eq!(fields2.next(), None);
     }
+
+    #[test]
+    fn test_splitfields_multi_byte_separator() {
+        let input = "a|b||\"c||d\"||e|||f\n";
+        let mut fields =
+            SplitFields::new(input.as_bytes(), b'|', Some(b'"'), b'\n').with_separator_tail(b"|");
+
+        assert_eq!(fields.next(), Some(("a|b".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("\"c||d\"".as_bytes(), true)));
+        assert_eq!(fields.next(), Some(("e".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("|f".as_bytes(), false)));
+        assert_eq!(fields.next(), None);
+
+        // Long enough to take the SIMD paths.
+        let field = "x|".repeat(40) + "y";
+        let input = format!("{field}||\"{field}\"||{field}");
+        let fields = SplitFields::new(input.as_bytes(), b'|', Some(b'"'), b'\n')
+            .with_separator_tail(b"|")
+            .map(|(f, _)| f)
+            .collect::<Vec<_>>();
+        let quoted = format!("\"{field}\"");
+        assert_eq!(
+            fields,
+            [field.as_bytes(), quoted.as_bytes(), field.as_bytes()]
+        );
+    }
 }
@@ -0,0 +1,349 @@
+use std::io::Read;
+
+use polars_core::prelude::*;
+
+use super::CsvEncoding;
+use super::parser::CountLines;
+
+/// The number of raw bytes that are transcoded at once.
+// Use a small chunk size to catch failures in tests.
+#[cfg(debug_assertions)]
+const CHUNK_SIZE: usize = 64;
+#[cfg(not(debug_assertions))]
+const CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+/// Code points of the Windows-1252 bytes `0x80..=0x9F`. The other bytes map to the same code point
+/// as in Latin-1. The undefined bytes map to their C1 control character, like the WHATWG decoder.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// Reader that transcodes the data of the inner reader to utf8.
+pub(crate) struct TranscodingReader<R> {
+    inner: R,
+    encoding: CsvEncoding,
+    /// Raw bytes that are not transcoded yet, as they don't form a whole character.
+    pending: Vec<u8>,
+    /// Transcoded bytes that are not read yet.
+    buffer: Vec<u8>,
+    offset: usize,
+    at_start: bool,
+}
+
+impl<R: Read> TranscodingReader<R> {
+    pub(crate) fn new(inner: R, encoding: CsvEncoding) -> Self {
+        Self {
+            inner,
+            encoding,
+            pending: vec![],
+            buffer: vec![],
+            offset: 0,
+            at_start: true,
+        }
+    }
+
+    /// Read up to `n` bytes from the inner reader and append them to `out` after transcoding.
+    ///
+    /// Returns `false` once the inner reader is exhausted.
+    fn transcode_next(&mut self, n: usize, out: &mut Vec<u8>) -> PolarsResult<bool> {
+        let n_read = (&mut self.inner)
+            .take(n as u64)
+            .read_to_end(&mut self.pending)?;
+
+        if std::mem::take(&mut self.at_start) {
+            let bom_len =
+                self.pending.len() - skip_encoding_bom(&self.pending, self.encoding).len();
+            self.pending.drain(..bom_len);
+        }
+
+        // Keep incomplete characters until the next read, unless there is nothing left to read.
+        let end = if n_read == 0 {
+            self.pending.len()
+        } else {
+            self.decodable_len()
+        };
+        transcode_chunk(&self.pending[..end], self.encoding, out)?;
+        self.pending.drain(..end);
+
+        Ok(n_read > 0)
+    }
+
+    /// The length of the longest prefix of the pending bytes that does not split a character.
+    fn decodable_len(&self) -> usize {
+        match self.encoding {
+            CsvEncoding::Utf16Le | CsvEncoding::Utf16Be => {
+                let end = self.pending.len() & !1;
+                // Don't split a surrogate pair.
+                match end.checked_sub(2) {
+                    Some(last)
+                        if (0xD800..0xDC00).contains(&decode_utf16_unit(
+                            &self.pending[last..end],
+                            self.encoding,
+                        )) =>
+                    {
+                        last
+                    },
+                    _ => end,
+                }
+            },
+            _ => self.pending.len(),
+        }
+    }
+}
+
+impl<R: Read> Read for TranscodingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.offset == self.buffer.len() {
+            let mut buffer = std::mem::take(&mut self.buffer);
+            buffer.clear();
+            self.offset = 0;
+
+            // Read about as much as requested, to not decompress more than needed when the
+            // inner reader is a decoder.
+            let has_more = self
+                .transcode_next(buf.len().max(4), &mut buffer)
+                .map_err(std::io::Error::other)?;
+            self.buffer = buffer;
+
+            if !has_more && self.buffer.is_empty() {
+                return Ok(0);
+            }
+        }
+
+        let n = buf.len().min(self.buffer.len() - self.offset);
+        buf[..n].copy_from_slice(&self.buffer[self.offset..self.offset + n]);
+        self.offset += n;
+        Ok(n)
+    }
+}
+
+/// Transcodes data to utf8 in chunks that end at a line boundary.
+///
+/// Every chunk only holds whole lines, so the chunks can be parsed independently and the transcoded
+/// data never has to be materialized at once.
+pub struct TranscodedChunks<R> {
+    reader: TranscodingReader<R>,
+    line_counter: CountLines,
+    /// Transcoded data after the last line ending of the previous chunk.
+    remainder: Vec<u8>,
+    exhausted: bool,
+}
+
+impl<R: Read> TranscodedChunks<R> {
+    pub fn new(reader: R, encoding: CsvEncoding, quote_char: Option<u8>, eol_char: u8) -> Self {
+        Self {
+            reader: TranscodingReader::new(reader, encoding),
+            line_counter: CountLines::new(quote_char, eol_char),
+            remainder: vec![],
+            exhausted: false,
+        }
+    }
+
+    /// Returns the next chunk, which holds at least `min_lines` whole lines, or all remaining
+    /// lines if there are fewer. All remaining data is returned if `min_lines` is `None`.
+    ///
+    /// Returns `None` once all data has been returned.
+    pub fn next_chunk(&mut self, min_lines: Option<usize>) -> PolarsResult<Option<Vec<u8>>> {
+        let mut chunk = std::mem::take(&mut self.remainder);
+        let mut read_size = CHUNK_SIZE;
+
+        while !self.exhausted {
+            self.exhausted = !self.reader.transcode_next(read_size, &mut chunk)?;
+
+            if let (Some(min_lines), false) = (min_lines, self.exhausted) {
+                let (count, position) = self.line_counter.count(&chunk);
+                if count >= min_lines.max(1) {
+                    self.remainder = chunk.split_off(position + 1);
+                    return Ok(Some(chunk));
+                }
+            }
+
+            // A line can be longer than a chunk.
+            read_size = read_size.saturating_mul(2);
+        }
+
+        Ok((!chunk.is_empty()).then_some(chunk))
+    }
+}
+
+/// The number of lines that hold the skipped rows, the header and the `n_infer` rows that are
+/// used for schema inference. Returns `None` if the schema is inferred from all rows.
+pub fn leading_lines(
+    skip_lines: usize,
+    skip_rows: usize,
+    has_header: bool,
+    skip_rows_after_header: usize,
+    n_infer: Option<usize>,
+) -> Option<usize> {
+    n_infer.map(|n| skip_lines + skip_rows + has_header as usize + skip_rows_after_header + n)
+}
+
+/// Transcode the start of `bytes` that holds at least `n_lines` whole lines to utf8 if the encoding
+/// is not a utf8 encoding, otherwise simply return `bytes`. All of `bytes` is transcoded if
+/// `n_lines` is `None`. An `out` vec must be given for ownership of the transcoded data.
+pub fn maybe_transcode_prefix<'a>(
+    bytes: &'a [u8],
+    encoding: CsvEncoding,
+    quote_char: Option<u8>,
+    eol_char: u8,
+    n_lines: Option<usize>,
+    out: &'a mut Vec<u8>,
+) -> PolarsResult<&'a [u8]> {
+    assert!(out.is_empty());
+
+    if !encoding.needs_transcoding() {
+        return Ok(bytes);
+    }
+
+    if let Some(chunk) =
+        TranscodedChunks::new(bytes, encoding, quote_char, eol_char).next_chunk(n_lines)?
+    {
+        *out = chunk;
+    }
+    Ok(out)
+}
+
+fn skip_encoding_bom(bytes: &[u8], encoding: CsvEncoding) -> &[u8] {
+    let bom: &[u8] = match encoding {
+        CsvEncoding::Utf16Le => b"\xff\xfe",
+        CsvEncoding::Utf16Be => b"\xfe\xff",
+        _ => return bytes,
+    };
+    bytes.strip_prefix(bom).unwrap_or(bytes)
+}
+
+#[inline]
+fn decode_utf16_unit(unit: &[u8], encoding: CsvEncoding) -> u16 {
+    let unit = [unit[0], unit[1]];
+    match encoding {
+        CsvEncoding::Utf16Be => u16::from_be_bytes(unit),
+        _ => u16::from_le_bytes(unit),
+    }
+}
+
+#[inline]
+fn push_char(out: &mut Vec<u8>, c: char) {
+    let mut buf = [0; 4];
+    out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+}
+
+fn transcode_chunk(bytes: &[u8], encoding: CsvEncoding, out: &mut Vec<u8>) -> PolarsResult<()> {
+    match encoding {
+        CsvEncoding::Latin1 | CsvEncoding::Windows1252 => {
+            out.reserve(bytes.len() + bytes.len() / 8);
+            for &b in bytes {
+                if b.is_ascii() {
+                    out.push(b);
+                } else if matches!(encoding, CsvEncoding::Windows1252) && b < 0xA0 {
+                    push_char(out, WINDOWS_1252_HIGH[(b - 0x80) as usize]);
+                } else {
+                    push_char(out, b as char);
+                }
+            }
+        },
+        CsvEncoding::Utf16Le | CsvEncoding::Utf16Be => {
+            polars_ensure!(
+                bytes.len() % 2 == 0,
+                ComputeError: "invalid utf-16 sequence: odd number of bytes"
+            );
+            out.reserve(bytes.len() / 2);
+            let units = bytes
+                .chunks_exact(2)
+                .map(|unit| decode_utf16_unit(unit, encoding));
+            for c in char::decode_utf16(units) {
+                let c = c.map_err(|_| polars_err!(ComputeError: "invalid utf-16 sequence"))?;
+                push_char(out, c);
+            }
+        },
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => out.extend_from_slice(bytes),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn transcode(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Vec<u8>> {
+        let mut out = vec![];
+        maybe_transcode_prefix(bytes, encoding, Some(b'"'), b'\n', None, &mut out)?;
+        Ok(out)
+    }
+
+    fn utf16(s: &str, encoding: CsvEncoding) -> Vec<u8> {
+        s.encode_utf16()
+            .flat_map(|unit| match encoding {
+                CsvEncoding::Utf16Be => unit.to_be_bytes(),
+                _ => unit.to_le_bytes(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_transcode() {
+        let out = transcode(b"a;\xe9\xdf\n", CsvEncoding::Latin1).unwrap();
+        assert_eq!(out, "a;éß\n".as_bytes());
+
+        let out = transcode(b"\x80;\x93x\x94\n", CsvEncoding::Windows1252).unwrap();
+        assert_eq!(out, "€;“x”\n".as_bytes());
+
+        let input = "a,😀\n";
+        let le = [0xFF, 0xFE]
+            .into_iter()
+            .chain(utf16(input, CsvEncoding::Utf16Le))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            transcode(&le, CsvEncoding::Utf16Le).unwrap(),
+            input.as_bytes()
+        );
+
+        let be = utf16(input, CsvEncoding::Utf16Be);
+        assert_eq!(
+            transcode(&be, CsvEncoding::Utf16Be).unwrap(),
+            input.as_bytes()
+        );
+
+        assert!(transcode(&le[..le.len() - 1], CsvEncoding::Utf16Le).is_err());
+        // A lone high surrogate at the end.
+        assert!(transcode(&le[..le.len() - 4], CsvEncoding::Utf16Le).is_err());
+    }
+
+    #[test]
+    fn test_transcoding_reader() {
+        // The reads split the surrogate pairs and code units at every possible offset.
+        let input = "😀a".repeat(100);
+        let le = utf16(&input, CsvEncoding::Utf16Le);
+        let mut out = String::new();
+        TranscodingReader::new(le.as_slice(), CsvEncoding::Utf16Le)
+            .read_to_string(&mut out)
+            .unwrap();
+        assert_eq!(out, input);
+    }
+
+    #[test]
+    fn test_transcoded_chunks() {
+        let input = (0..100)
+            .map(|i| format!("{i},\"é\n😀{i}\"\n"))
+            .collect::<String>();
+        let le = utf16(&input, CsvEncoding::Utf16Le);
+        let mut chunks =
+            TranscodedChunks::new(le.as_slice(), CsvEncoding::Utf16Le, Some(b'"'), b'\n');
+
+        let first = chunks.next_chunk(Some(50)).unwrap().unwrap();
+        let mut out = vec![first];
+        while let Some(chunk) = chunks.next_chunk(Some(1)).unwrap() {
+            out.push(chunk);
+        }
+
+        assert!(out[0].iter().filter(|&&b| b == b'\n').count() >= 100);
+        for chunk in &out {
+            // Every chunk ends at a line ending outside of the quoted field.
+            assert!(chunk.ends_with(b"\"\n"));
+            assert_eq!(chunk.iter().filter(|&&b| b == b'"').count() % 2, 0);
+        }
+        assert_eq!(out.concat(), input.as_bytes());
+    }
+}
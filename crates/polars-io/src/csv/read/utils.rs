@@ -3,10 +3,17 @@
 use std::io::Read;
 use std::mem::MaybeUninit;
 
+#[cfg(feature = "decompress")]
+use polars_core::prelude::PolarsResult;
+
+#[cfg(feature = "decompress")]
+use super::CsvEncoding;
 use super::parser::next_line_position;
 #[cfg(feature = "decompress")]
 use super::parser::next_line_position_naive;
 use super::splitfields::SplitFields;
+#[cfg(feature = "decompress")]
+use super::transcode::TranscodingReader;
 
 /// TODO: Remove this in favor of parallel CountLines::analyze_chunk
 ///
@@ -16,6 +23,7 @@ pub(crate) fn get_file_chunks(
     n_chunks: usize,
     expected_fields: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Vec<(usize, usize)> {
@@ -34,6 +42,7 @@ pub(crate) fn get_file_chunks(
             &bytes[search_pos..],
             expected_fields,
             separator,
+            separator_tail,
             quote_char,
             eol_char,
         ) {
@@ -54,16 +63,17 @@ fn decompress_impl<R: Read>(
     decoder: &mut R,
     n_rows: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
-) -> Option<Vec<u8>> {
+) -> std::io::Result<Vec<u8>> {
     let chunk_size = 4096;
-    Some(match n_rows {
+    Ok(match n_rows {
         None => {
             // decompression in a preallocated buffer does not work with zlib-ng
             // and will put the original compressed data in the buffer.
             let mut out = Vec::new();
-            decoder.read_to_end(&mut out).ok()?;
+            decoder.read_to_end(&mut out)?;
             out
         },
         Some(n_rows) => {
@@ -73,19 +83,20 @@ fn decompress_impl<R: Read>(
             // make sure that we have enough bytes to decode the header (even if it has embedded new line chars)
             // those extra bytes in the buffer don't matter, we don't need to track them
             loop {
-                let read = decoder.take(chunk_size).read_to_end(&mut out).ok()?;
+                let read = decoder.take(chunk_size).read_to_end(&mut out)?;
                 if read == 0 {
                     break;
                 }
                 if next_line_position_naive(&out, eol_char).is_some() {
                     // an extra shot
-                    let read = decoder.take(chunk_size).read_to_end(&mut out).ok()?;
+                    let read = decoder.take(chunk_size).read_to_end(&mut out)?;
                     if read == 0 {
                         break;
                     }
                     // now that we have enough, we compute the number of fields (also takes embedding into account)
-                    expected_fields =
-                        SplitFields::new(&out, separator, quote_char, eol_char).count();
+                    expected_fields = SplitFields::new(&out, separator, quote_char, eol_char)
+                        .with_separator_tail(separator_tail)
+                        .count();
                     break;
                 }
             }
@@ -99,6 +110,7 @@ fn decompress_impl<R: Read>(
                     &out[buf_pos + 1..],
                     Some(expected_fields),
                     separator,
+                    separator_tail,
                     quote_char,
                     eol_char,
                 ) {
@@ -108,7 +120,7 @@ fn decompress_impl<R: Read>(
                     },
                     None => {
                         // take more bytes so that we might find a new line the next iteration
-                        let read = decoder.take(chunk_size).read_to_end(&mut out).ok()?;
+                        let read = decoder.take(chunk_size).read_to_end(&mut out)?;
                         // we depleted the reader
                         if read == 0 {
                             break;
@@ -125,34 +137,45 @@ fn decompress_impl<R: Read>(
     })
 }
 
+/// Decompress the data if it is compressed, and transcode it to utf8 if the encoding is not a utf8
+/// encoding. Returns `None` if the data is not compressed.
+///
+/// Transcoding happens while decompressing, so that only the first `n_rows` lines of the
+/// transcoded data are kept.
 #[cfg(feature = "decompress")]
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn decompress(
     bytes: &[u8],
     n_rows: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
-) -> Option<Vec<u8>> {
+    encoding: CsvEncoding,
+) -> PolarsResult<Option<Vec<u8>>> {
     use crate::utils::compression::SupportedCompression;
 
-    if let Some(algo) = SupportedCompression::check(bytes) {
-        match algo {
-            SupportedCompression::GZIP => {
-                let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-            SupportedCompression::ZLIB => {
-                let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-            SupportedCompression::ZSTD => {
-                let mut decoder = zstd::Decoder::with_buffer(bytes).ok()?;
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-        }
-    } else {
-        None
+    let Some(algo) = SupportedCompression::check(bytes) else {
+        return Ok(None);
+    };
+    let mut decoder: Box<dyn Read + '_> = match algo {
+        SupportedCompression::GZIP => Box::new(flate2::read::MultiGzDecoder::new(bytes)),
+        SupportedCompression::ZLIB => Box::new(flate2::read::ZlibDecoder::new(bytes)),
+        SupportedCompression::ZSTD => Box::new(zstd::Decoder::with_buffer(bytes)?),
+    };
+    if encoding.needs_transcoding() {
+        decoder = Box::new(TranscodingReader::new(decoder, encoding));
     }
+
+    let out = decompress_impl(
+        &mut decoder,
+        n_rows,
+        separator,
+        separator_tail,
+        quote_char,
+        eol_char,
+    )?;
+    Ok(Some(out))
 }
 
 /// replace double quotes by single ones
@@ -199,10 +222,12 @@ mod test {
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
         assert!(
-            (get_file_chunks(bytes, 10, Some(4), b',', None, b'\n').len() as i32 - 10).abs() <= 1
+            (get_file_chunks(bytes, 10, Some(4), b',', &[], None, b'\n').len() as i32 - 10).abs()
+                <= 1
         );
         assert!(
-            (get_file_chunks(bytes, 8, Some(4), b',', None, b'\n').len() as i32 - 8).abs() <= 1
+            (get_file_chunks(bytes, 8, Some(4), b',', &[], None, b'\n').len() as i32 - 8).abs()
+                <= 1
         );
     }
 }
@@ -3,6 +3,8 @@ use std::num::NonZeroUsize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::utils::compression::ExternalCompression;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +14,8 @@ pub struct CsvWriterOptions {
     pub include_header: bool,
     pub batch_size: NonZeroUsize,
     pub serialize_options: SerializeOptions,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub compression: ExternalCompression,
 }
 
 impl Default for CsvWriterOptions {
@@ -21,6 +25,7 @@ impl Default for CsvWriterOptions {
             include_header: true,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             serialize_options: SerializeOptions::default(),
+            compression: ExternalCompression::default(),
         }
     }
 }
@@ -4,11 +4,12 @@ use std::num::NonZeroUsize;
 use polars_core::POOL;
 use polars_core::frame::DataFrame;
 use polars_core::schema::Schema;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 
 use super::write_impl::{write, write_bom, write_header};
 use super::{QuoteStyle, SerializeOptions};
 use crate::shared::SerWriter;
+use crate::utils::compression::{CompressedWriter, ExternalCompression};
 
 /// Write a DataFrame to csv.
 ///
@@ -22,6 +23,7 @@ pub struct CsvWriter<W: Write> {
     bom: bool,
     batch_size: NonZeroUsize,
     n_threads: usize,
+    compression: ExternalCompression,
 }
 
 impl<W> SerWriter<W> for CsvWriter<W>
@@ -42,28 +44,27 @@ where
             bom: false,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             n_threads: POOL.current_num_threads(),
+            compression: ExternalCompression::Uncompressed,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        if self.bom {
-            write_bom(&mut self.buffer)?;
+        if self.compression.is_uncompressed() {
+            return self.write_df(df);
         }
-        let names = df
-            .get_column_names()
-            .into_iter()
-            .map(|x| x.as_str())
-            .collect::<Vec<_>>();
-        if self.header {
-            write_header(&mut self.buffer, names.as_slice(), &self.options)?;
-        }
-        write(
-            &mut self.buffer,
-            df,
-            self.batch_size.into(),
-            &self.options,
-            self.n_threads,
-        )
+
+        let mut writer = CsvWriter {
+            buffer: CompressedWriter::new(&mut self.buffer, self.compression),
+            options: self.options.clone(),
+            header: self.header,
+            bom: self.bom,
+            batch_size: self.batch_size,
+            n_threads: self.n_threads,
+            compression: ExternalCompression::Uncompressed,
+        };
+        writer.write_df(df)?;
+        writer.buffer.finish()?;
+        Ok(())
     }
 }
 
@@ -171,7 +172,38 @@ where
         self
     }
 
+    /// Compress the output with gzip or zstd. Not supported by the [`BatchedWriter`].
+    pub fn with_compression(mut self, compression: ExternalCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    fn write_df(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        if self.bom {
+            write_bom(&mut self.buffer)?;
+        }
+        let names = df
+            .get_column_names()
+            .into_iter()
+            .map(|x| x.as_str())
+            .collect::<Vec<_>>();
+        if self.header {
+            write_header(&mut self.buffer, names.as_slice(), &self.options)?;
+        }
+        write(
+            &mut self.buffer,
+            df,
+            self.batch_size.into(),
+            &self.options,
+            self.n_threads,
+        )
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        polars_ensure!(
+            self.compression.is_uncompressed(),
+            InvalidOperation: "the batched csv writer does not support compression"
+        );
         let expects_bom = self.bom;
         let expects_header = self.header;
         Ok(BatchedWriter {
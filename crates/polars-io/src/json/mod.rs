@@ -79,11 +79,15 @@ use simd_json::BorrowedValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
+use crate::utils::compression::{CompressedWriter, ExternalCompression};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub struct JsonWriterOptions {}
+pub struct JsonWriterOptions {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub compression: ExternalCompression,
+}
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array)
 /// or `JsonLines` (each row output on a separate line).
@@ -118,6 +122,7 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    compression: ExternalCompression,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -125,6 +130,12 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Compress the output with gzip or zstd.
+    pub fn with_compression(mut self, compression: ExternalCompression) -> Self {
+        self.compression = compression;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -137,6 +148,7 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            compression: ExternalCompression::Uncompressed,
         }
     }
 
@@ -154,21 +166,35 @@ where
             .iter_chunks(CompatLevel::newest(), false)
             .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
 
-        match self.json_format {
-            JsonFormat::JsonLines => {
-                let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-                let writer =
-                    polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
-                writer.collect::<PolarsResult<()>>()?;
-            },
-            JsonFormat::Json => {
-                let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
-            },
+        if self.compression.is_uncompressed() {
+            write_batches(&mut self.buffer, batches, &self.json_format)
+        } else {
+            let mut writer = CompressedWriter::new(&mut self.buffer, self.compression);
+            write_batches(&mut writer, batches, &self.json_format)?;
+            writer.finish()?;
+            Ok(())
         }
+    }
+}
 
-        Ok(())
+fn write_batches<W: Write>(
+    writer: &mut W,
+    batches: impl Iterator<Item = PolarsResult<ArrayRef>>,
+    json_format: &JsonFormat,
+) -> PolarsResult<()> {
+    match json_format {
+        JsonFormat::JsonLines => {
+            let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+            let writer = polars_json::ndjson::write::FileWriter::new(writer, serializer);
+            writer.collect::<PolarsResult<()>>()?;
+        },
+        JsonFormat::Json => {
+            let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
+            polars_json::json::write::write(writer, serializer)?;
+        },
     }
+
+    Ok(())
 }
 
 pub struct BatchedWriter<W: Write> {
@@ -0,0 +1,76 @@
+use polars_core::prelude::{AnyValue, ArrowField, DataType};
+use polars_core::scalar::Scalar;
+pub use polars_parquet::parquet::bloom_filter::deserialize_header as deserialize_bloom_filter_header;
+use polars_parquet::parquet::bloom_filter::{hash_byte, hash_native, is_in_set};
+use polars_parquet::read::{ColumnChunkMetadata, PhysicalType};
+
+/// Hashes `values` the way they are hashed in the bloom filter of `column`, which is read as
+/// `field`.
+///
+/// Returns `None` if the bloom filter cannot tell whether the column contains one of the values,
+/// i.e. if one of them is null, if their dtype is not the one the column is read as or if the
+/// values of the column are transformed when they are read.
+pub fn bloom_filter_hashes(
+    column: &ColumnChunkMetadata,
+    field: &ArrowField,
+    values: &[Scalar],
+) -> Option<Vec<u64>> {
+    let dtype = DataType::from_arrow_field(field);
+    let physical_type = column.physical_type();
+
+    values
+        .iter()
+        .map(|v| {
+            if v.dtype() != &dtype {
+                return None;
+            }
+            hash_value(physical_type, v.value())
+        })
+        .collect()
+}
+
+/// Returns whether the bloom filter `bitset` may contain a value with one of `hashes`.
+pub fn bloom_filter_may_contain(bitset: &[u8], hashes: &[u64]) -> bool {
+    hashes.iter().any(|&hash| is_in_set(bitset, hash))
+}
+
+fn hash_value(physical_type: PhysicalType, value: &AnyValue) -> Option<u64> {
+    use AnyValue as AV;
+
+    // The casts below match the ones done when writing, the values are hashed in their plain
+    // encoding.
+    let hash = match physical_type {
+        PhysicalType::Int32 => hash_native(match value {
+            AV::Int8(v) => *v as i32,
+            AV::Int16(v) => *v as i32,
+            AV::Int32(v) => *v,
+            AV::UInt8(v) => *v as i32,
+            AV::UInt16(v) => *v as i32,
+            AV::UInt32(v) => *v as i32,
+            #[cfg(feature = "dtype-date")]
+            AV::Date(v) => *v,
+            _ => return None,
+        }),
+        PhysicalType::Int64 => hash_native(match value {
+            AV::Int8(v) => *v as i64,
+            AV::Int16(v) => *v as i64,
+            AV::Int32(v) => *v as i64,
+            AV::Int64(v) => *v,
+            AV::UInt8(v) => *v as i64,
+            AV::UInt16(v) => *v as i64,
+            AV::UInt32(v) => *v as i64,
+            AV::UInt64(v) => *v as i64,
+            _ => return None,
+        }),
+        PhysicalType::ByteArray | PhysicalType::FixedLenByteArray(_) => match value {
+            AV::String(v) => hash_byte(v),
+            AV::StringOwned(v) => hash_byte(v.as_str()),
+            AV::Binary(v) => hash_byte(v),
+            AV::BinaryOwned(v) => hash_byte(v),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(hash)
+}
@@ -16,6 +16,7 @@
 
 #[cfg(feature = "cloud")]
 mod async_impl;
+mod bloom_filter;
 mod mmap;
 mod options;
 mod predicates;
@@ -36,13 +37,20 @@ pub use options::{ParallelStrategy, ParquetOptions};
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
 pub use polars_parquet::read::FileMetadata;
+pub(crate) use read_impl::should_copy_sortedness;
 pub use read_impl::{create_sorting_map, try_set_sorted_flag};
 pub use reader::ParquetReader;
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
+    pub use super::bloom_filter::{
+        bloom_filter_hashes, bloom_filter_may_contain, deserialize_bloom_filter_header,
+    };
     pub use super::mmap::to_deserializer;
-    pub use super::predicates::collect_statistics_with_live_columns;
+    pub use super::predicates::{
+        PageStatistics, collect_page_statistics_with_live_columns,
+        collect_statistics_with_live_columns,
+    };
     pub use super::read_impl::{PrefilterMaskSetting, calc_prefilter_cost};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
@@ -1,8 +1,11 @@
-use arrow::array::{MutablePrimitiveArray, PrimitiveArray};
+use arrow::array::builder::{ArrayBuilder, ShareStrategy, make_builder};
+use arrow::array::{Array, MutablePrimitiveArray, PrimitiveArray};
 use arrow::pushable::Pushable;
 use polars_core::prelude::*;
-use polars_parquet::read::RowGroupMetadata;
-use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
+use polars_parquet::read::statistics::{
+    ArrowColumnStatisticsArrays, deserialize_all, deserialize_page_index,
+};
+use polars_parquet::read::{ColumnIndex, OffsetIndex, RowGroupMetadata};
 
 /// Collect the statistics in a row-group
 pub fn collect_statistics_with_live_columns(
@@ -96,3 +99,173 @@ pub fn collect_statistics_with_live_columns(
         })
         .collect::<PolarsResult<Vec<_>>>()
 }
+
+/// Statistics of the live columns in a row-group, collected from their page indexes.
+///
+/// The rows of the row-group are split into ranges that do not cross a page boundary of any of
+/// the live columns, so that every range has the statistics of a single page per column.
+pub struct PageStatistics {
+    /// The start of every row range, followed by the number of rows in the row-group.
+    pub row_offsets: Vec<usize>,
+    pub columns: Vec<Option<ArrowColumnStatisticsArrays>>,
+}
+
+impl PageStatistics {
+    pub fn num_ranges(&self) -> usize {
+        self.row_offsets.len() - 1
+    }
+}
+
+/// Collect the page statistics of the live columns in a row-group.
+///
+/// `page_indexes` holds the column and offset index of each of the live columns, if the column
+/// has them. Returns `None` if none of the live columns has usable page statistics.
+pub fn collect_page_statistics_with_live_columns(
+    row_group: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    live_columns: &PlIndexSet<PlSmallStr>,
+    row_index: Option<(&PlSmallStr, IdxSize)>,
+    page_indexes: &[Option<(ColumnIndex, OffsetIndex)>],
+) -> PolarsResult<Option<PageStatistics>> {
+    assert_eq!(live_columns.len(), page_indexes.len());
+    let num_rows = row_group.num_rows();
+
+    // The first row of every page, for the columns that have page statistics.
+    let mut page_row_offsets = Vec::with_capacity(live_columns.len());
+    let mut page_stats = Vec::with_capacity(live_columns.len());
+    for (c, page_index) in live_columns.iter().zip(page_indexes) {
+        let (Some(field), Some((column_index, offset_index))) = (schema.get(c), page_index) else {
+            page_row_offsets.push(None);
+            page_stats.push(None);
+            continue;
+        };
+
+        // We don't support reading nested statistics for now (see above).
+        let idxs = row_group.columns_idxs_under_root_iter(&field.name);
+        let stats = match idxs {
+            Some([idx]) if !field.dtype().is_nested() => {
+                deserialize_page_index(field, &row_group.parquet_columns()[*idx], column_index)?
+            },
+            _ => None,
+        };
+
+        let row_offsets = offset_index
+            .page_locations
+            .iter()
+            .map(|loc| usize::try_from(loc.first_row_index).ok())
+            .collect::<Option<Vec<_>>>();
+
+        // Ignore indexes that don't describe the pages of this row-group.
+        let row_offsets = row_offsets.filter(|offsets| {
+            offsets.first() == Some(&0)
+                && offsets.windows(2).all(|w| w[0] < w[1])
+                && offsets.last().is_some_and(|&last| last < num_rows)
+                && stats
+                    .as_ref()
+                    .is_some_and(|s| s.null_count.len() == offsets.len())
+        });
+
+        match row_offsets {
+            Some(row_offsets) => {
+                page_row_offsets.push(Some(row_offsets));
+                page_stats.push(stats);
+            },
+            None => {
+                page_row_offsets.push(None);
+                page_stats.push(None);
+            },
+        }
+    }
+
+    if page_stats.iter().all(Option::is_none) || num_rows == 0 {
+        return Ok(None);
+    }
+
+    let mut row_offsets = page_row_offsets
+        .iter()
+        .flatten()
+        .flatten()
+        .copied()
+        .chain([num_rows])
+        .collect::<Vec<_>>();
+    row_offsets.sort_unstable();
+    row_offsets.dedup();
+    let num_ranges = row_offsets.len() - 1;
+
+    let columns = live_columns
+        .iter()
+        .zip(page_row_offsets.iter().zip(page_stats))
+        .map(|(c, (page_row_offsets, stats))| {
+            if let Some((_, offset)) = row_index.filter(|(name, _)| *name == c) {
+                return Some(row_index_statistics(&row_offsets, offset));
+            }
+
+            let (page_row_offsets, stats) = (page_row_offsets.as_ref()?, stats?);
+
+            // The page that contains the start of every row range.
+            let pages = row_offsets[..num_ranges]
+                .iter()
+                .map(|start| (page_row_offsets.partition_point(|&o| o <= *start) - 1) as IdxSize)
+                .collect::<Vec<_>>();
+            let gather = |array: &dyn Array| {
+                let mut builder = make_builder(array.dtype());
+                builder.opt_gather_extend(array, &pages, ShareStrategy::Always);
+                builder.freeze()
+            };
+            let gather_idx = |array: &PrimitiveArray<IdxSize>| {
+                gather(array)
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<IdxSize>>()
+                    .unwrap()
+                    .clone()
+            };
+
+            Some(ArrowColumnStatisticsArrays {
+                null_count: gather_idx(&stats.null_count),
+                distinct_count: gather_idx(&stats.distinct_count),
+                min_value: gather(stats.min_value.as_ref()),
+                max_value: gather(stats.max_value.as_ref()),
+            })
+        })
+        .collect();
+
+    Ok(Some(PageStatistics {
+        row_offsets,
+        columns,
+    }))
+}
+
+/// The statistics of the row index column over the given row ranges of a row-group, where
+/// `offset` is the row index of the first row of the row-group.
+fn row_index_statistics(row_offsets: &[usize], offset: IdxSize) -> ArrowColumnStatisticsArrays {
+    let num_ranges = row_offsets.len() - 1;
+    let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(num_ranges);
+    let mut min_value = MutablePrimitiveArray::<IdxSize>::with_capacity(num_ranges);
+    let mut max_value = MutablePrimitiveArray::<IdxSize>::with_capacity(num_ranges);
+
+    for range in row_offsets.windows(2) {
+        let n_rows = IdxSize::try_from(range[1] - range[0]).unwrap_or(IdxSize::MAX);
+        distinct_count.push_value(n_rows);
+
+        let start = IdxSize::try_from(range[0])
+            .ok()
+            .and_then(|start| offset.checked_add(start));
+        match start.and_then(|start| Some((start, start.checked_add(n_rows - 1)?))) {
+            Some((min, max)) => {
+                min_value.push_value(min);
+                max_value.push_value(max);
+            },
+            None => {
+                min_value.push_null();
+                max_value.push_null();
+            },
+        }
+    }
+
+    ArrowColumnStatisticsArrays {
+        null_count: PrimitiveArray::<IdxSize>::full(num_ranges, 0, ArrowDataType::IDX_DTYPE),
+        distinct_count: distinct_count.freeze(),
+        min_value: min_value.freeze().boxed(),
+        max_value: max_value.freeze().boxed(),
+    }
+}
@@ -30,34 +30,40 @@ fn assert_dtypes(dtype: &ArrowDataType) {
         // These should all be cast to the BinaryView / Utf8View variants
         D::Utf8 | D::Binary | D::LargeUtf8 | D::LargeBinary => unreachable!(),
 
-        // These should be cast to Float32
-        D::Float16 => unreachable!(),
-
         // This should have been converted to a LargeList
         D::List(_) => unreachable!(),
 
-        // This should have been converted to a LargeList(Struct(_))
-        D::Map(_, _) => unreachable!(),
-
         // Recursive checks
         D::Dictionary(_, dtype, _) => assert_dtypes(dtype),
         D::Extension(ext) => assert_dtypes(&ext.inner),
         D::LargeList(inner) => assert_dtypes(&inner.dtype),
-        D::FixedSizeList(inner, _) => assert_dtypes(&inner.dtype),
+        D::FixedSizeList(inner, _) | D::Map(inner, _) => assert_dtypes(&inner.dtype),
         D::Struct(fields) => fields.iter().for_each(|f| assert_dtypes(f.dtype())),
 
         _ => {},
     }
 }
 
-fn should_copy_sortedness(dtype: &DataType) -> bool {
-    // @NOTE: For now, we are a bit conservative with this.
+/// Whether the sort order of Polars for `dtype` is the sort order of Parquet for the column it is
+/// written to, so that the sortedness of the column can be copied to and from `sorting_columns`.
+pub(crate) fn should_copy_sortedness(dtype: &DataType) -> bool {
+    // @NOTE: Floats are excluded since Polars orders NaN and -0.0 differently than Parquet.
     use DataType as D;
 
     matches!(
         dtype,
-        D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
-    )
+        D::Boolean
+            | D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::String
+            | D::Binary
+    ) || dtype.is_temporal()
 }
 
 pub fn try_set_sorted_flag(series: &mut Series, col_idx: usize, sorting_map: &[(usize, IsSorted)]) {
@@ -235,7 +241,10 @@ fn rg_to_dfs_optionally_par_over_columns(
                 store,
             )?;
 
-            try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+            // The sorting columns refer to the leaf columns of the Parquet schema.
+            if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+            }
             Ok(series.into_column())
         };
 
@@ -376,7 +385,10 @@ fn rg_to_dfs_par_over_rg(
                             store,
                         )?;
 
-                        try_set_sorted_flag(&mut series, *column_i, &sorting_map);
+                        // The sorting columns refer to the leaf columns of the Parquet schema.
+                        if let Some(&[leaf_idx]) = md.columns_idxs_under_root_iter(name) {
+                            try_set_sorted_flag(&mut series, leaf_idx, &sorting_map);
+                        }
                         Ok(series.into_column())
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
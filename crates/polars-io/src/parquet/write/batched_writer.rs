@@ -4,15 +4,26 @@ use std::sync::Mutex;
 use arrow::record_batch::RecordBatch;
 use polars_core::POOL;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    SchemaDescriptor, SortingColumn, WriteOptions, array_to_bloom_filters, array_to_columns,
+    schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
 use super::{KeyValueMetadata, ParquetMetadataContext};
+use crate::parquet::read::should_copy_sortedness;
+
+/// The pages of the columns of a row group, the bitsets of their bloom filters and the columns
+/// its rows are sorted by.
+pub type EncodedRowGroup = (
+    RowGroupIterColumns<'static, PolarsError>,
+    Vec<Option<Vec<u8>>>,
+    Vec<SortingColumn>,
+);
 
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
@@ -49,7 +60,8 @@ impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
+        let sorting_columns = get_sorting_columns(df.get_columns(), &self.parquet_schema);
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
@@ -59,6 +71,7 @@ impl<W: Write> BatchedWriter<W> {
                     self.parquet_schema.fields(),
                     self.column_options.as_ref(),
                     self.options,
+                    sorting_columns.clone(),
                 );
 
                 Some(row_group)
@@ -81,7 +94,9 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (group, bloom_filters, sorting_columns) = group?;
+            writer.write_with_bloom_filters(group, &bloom_filters)?;
+            writer.set_sorting_columns(sorting_columns)?;
         }
         Ok(())
     }
@@ -91,14 +106,22 @@ impl<W: Write> BatchedWriter<W> {
         writer.parquet_schema()
     }
 
-    pub fn write_row_group(&mut self, rg: &[Vec<CompressedPage>]) -> PolarsResult<()> {
+    /// Write a row group of compressed pages, followed by the bloom filters of its columns.
+    /// `sorting_columns` are the columns that the rows of the row group are sorted by.
+    pub fn write_row_group(
+        &mut self,
+        rg: &[Vec<CompressedPage>],
+        bloom_filters: &[Option<Vec<u8>>],
+        sorting_columns: Vec<SortingColumn>,
+    ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
             Ok(DynStreamingIterator::new(
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(rg)?;
+        writer.write_with_bloom_filters(rg, bloom_filters)?;
+        writer.set_sorting_columns(sorting_columns)?;
         Ok(())
     }
 
@@ -106,14 +129,12 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
-        for group in rgs {
-            writer.write(group)?;
+        for (group, bloom_filters, sorting_columns) in rgs {
+            writer.write_with_bloom_filters(group, &bloom_filters)?;
+            writer.set_sorting_columns(sorting_columns)?;
         }
         Ok(())
     }
@@ -150,7 +171,9 @@ fn prepare_rg_iter<'a>(
     column_options: &'a [ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
+    // The chunks of a column that is flagged as sorted are sorted as well.
+    let sorting_columns = get_sorting_columns(df.get_columns(), parquet_schema);
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
@@ -161,6 +184,7 @@ fn prepare_rg_iter<'a>(
                 column_options,
                 options,
                 parallel,
+                sorting_columns.clone(),
             );
 
             Some(row_group)
@@ -210,21 +234,27 @@ fn create_serializer(
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+    sorting_columns: Vec<SortingColumn>,
+) -> PolarsResult<EncodedRowGroup> {
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
-    )| { array_to_pages_iter(array, type_, column_options, options) };
+    )| {
+        (
+            array_to_pages_iter(array, type_, column_options, options),
+            array_to_bloom_filters(array, type_.clone(), column_options),
+        )
+    };
 
-    let columns = if parallel {
+    let (columns, bloom_filters): (Vec<_>, Vec<_>) = if parallel {
         POOL.install(|| {
             batch
                 .columns()
                 .par_iter()
                 .zip(fields)
                 .zip(column_options)
-                .flat_map(func)
-                .collect::<Vec<_>>()
+                .map(func)
+                .unzip()
         })
     } else {
         batch
@@ -232,13 +262,14 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(column_options)
-            .flat_map(func)
-            .collect::<Vec<_>>()
+            .map(func)
+            .unzip()
     };
 
-    let row_group = DynIter::new(columns.into_iter());
+    let row_group = DynIter::new(columns.into_iter().flatten());
+    let bloom_filters = bloom_filters.into_iter().flatten().collect();
 
-    Ok(row_group)
+    Ok((row_group, bloom_filters, sorting_columns))
 }
 
 /// This serializer encodes and compresses all eagerly in memory.
@@ -248,21 +279,68 @@ fn create_eager_serializer(
     fields: &[ParquetType],
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+    sorting_columns: Vec<SortingColumn>,
+) -> PolarsResult<EncodedRowGroup> {
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
-    )| { array_to_pages_iter(array, type_, column_options, options) };
+    )| {
+        (
+            array_to_pages_iter(array, type_, column_options, options),
+            array_to_bloom_filters(array, type_.clone(), column_options),
+        )
+    };
 
-    let columns = batch
+    let (columns, bloom_filters): (Vec<_>, Vec<_>) = batch
         .columns()
         .iter()
         .zip(fields)
         .zip(column_options)
-        .flat_map(func)
-        .collect::<Vec<_>>();
+        .map(func)
+        .unzip();
 
-    let row_group = DynIter::new(columns.into_iter());
+    let row_group = DynIter::new(columns.into_iter().flatten());
+    let bloom_filters = bloom_filters.into_iter().flatten().collect();
 
-    Ok(row_group)
+    Ok((row_group, bloom_filters, sorting_columns))
+}
+
+/// The columns that the rows of a row group of `columns` are sorted by, i.e. the non-nested
+/// `columns` that are flagged as sorted.
+fn get_sorting_columns(
+    columns: &[Column],
+    parquet_schema: &SchemaDescriptor,
+) -> Vec<SortingColumn> {
+    columns
+        .iter()
+        .filter_map(|c| get_sorting_column(c, parquet_schema))
+        .collect()
+}
+
+/// The [`SortingColumn`] of `column` if it is non-nested and flagged as sorted.
+pub fn get_sorting_column(
+    column: &Column,
+    parquet_schema: &SchemaDescriptor,
+) -> Option<SortingColumn> {
+    let descending = match column.is_sorted_flag() {
+        IsSorted::Ascending => false,
+        IsSorted::Descending => true,
+        IsSorted::Not => return None,
+    };
+    if column.is_empty() || !should_copy_sortedness(column.dtype()) {
+        return None;
+    }
+
+    let column_idx = parquet_schema
+        .columns()
+        .iter()
+        .position(|c| matches!(c.path_in_schema.as_slice(), [name] if name == column.name()))?;
+    // The nulls of a sorted column are either all at its start or all at its end.
+    let nulls_first = column.has_nulls() && column.get(0).is_ok_and(|v| v.is_null());
+
+    Some(SortingColumn {
+        column_idx: column_idx as i32,
+        descending,
+        nulls_first,
+    })
 }
@@ -5,11 +5,11 @@ mod key_value_metadata;
 mod options;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
+pub use batched_writer::{BatchedWriter, EncodedRowGroup, get_sorting_column};
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
 pub use options::{
-    BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetCompression,
-    ParquetFieldOverwrites, ParquetWriteOptions, ZstdLevel,
+    BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetBloomFilterOptions,
+    ParquetCompression, ParquetEncoding, ParquetFieldOverwrites, ParquetWriteOptions, ZstdLevel,
 };
 pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
 pub use writer::{ParquetWriter, get_column_write_options};
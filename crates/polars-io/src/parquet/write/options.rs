@@ -1,9 +1,10 @@
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BloomFilterOptions, BrotliLevel as BrotliLevelParquet, CompressionOptions,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::total_ord::TotalOrdWrap;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -57,6 +58,88 @@ pub struct ParquetFieldOverwrites {
     pub required: Option<bool>,
     pub field_id: Option<i32>,
     pub metadata: Option<Vec<MetadataKeyValue>>,
+    /// Write a bloom filter for the values of this (leaf) field.
+    pub bloom_filter: Option<ParquetBloomFilterOptions>,
+    /// The encoding of the data pages of this (leaf) field.
+    pub encoding: Option<ParquetEncoding>,
+}
+
+/// The encoding used for the data pages of a Parquet column.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ParquetEncoding {
+    Plain,
+    /// Dictionary encoding, falling back to plain encoding for columns with many distinct values.
+    Dictionary,
+    /// Only for integer and temporal columns.
+    DeltaBinaryPacked,
+    /// Only for string and binary columns.
+    DeltaLengthByteArray,
+    /// Only for string and binary columns.
+    DeltaByteArray,
+    /// Only for integer, temporal and float columns.
+    ByteStreamSplit,
+    /// Dictionary encoding if it is the default for the column and the column has few distinct
+    /// values. Otherwise, every data page is encoded with each of the encodings that fit the type
+    /// of the column, and the smallest result is kept.
+    Adaptive,
+}
+
+/// The options of a split-block bloom filter written for a Parquet column.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetBloomFilterOptions {
+    fpp: TotalOrdWrap<f64>,
+    ndv: Option<u64>,
+}
+
+impl ParquetBloomFilterOptions {
+    /// Bloom filter options with a false positive probability of `fpp`, sized for `ndv` distinct
+    /// values. If `ndv` is `None`, the bloom filter of every row group is sized for its number of
+    /// non-null values.
+    pub fn try_new(fpp: f64, ndv: Option<u64>) -> PolarsResult<Self> {
+        polars_ensure!(
+            fpp > 0.0 && fpp < 1.0,
+            InvalidOperation: "bloom filter false positive probability must be between 0 and 1 (exclusive), got {fpp}"
+        );
+        polars_ensure!(
+            ndv != Some(0),
+            InvalidOperation: "bloom filter number of distinct values must be positive"
+        );
+        Ok(Self {
+            fpp: TotalOrdWrap(fpp),
+            ndv,
+        })
+    }
+
+    pub fn fpp(&self) -> f64 {
+        self.fpp.0
+    }
+
+    pub fn ndv(&self) -> Option<u64> {
+        self.ndv
+    }
+}
+
+impl Default for ParquetBloomFilterOptions {
+    fn default() -> Self {
+        let BloomFilterOptions { fpp, ndv } = BloomFilterOptions::default();
+        Self {
+            fpp: TotalOrdWrap(fpp),
+            ndv,
+        }
+    }
+}
+
+impl From<ParquetBloomFilterOptions> for BloomFilterOptions {
+    fn from(value: ParquetBloomFilterOptions) -> Self {
+        BloomFilterOptions {
+            fpp: value.fpp.0,
+            ndv: value.ndv,
+        }
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
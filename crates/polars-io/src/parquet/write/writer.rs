@@ -12,7 +12,10 @@ use polars_parquet::write::{
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
-use super::{KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetWriteOptions};
+use super::{
+    KeyValueMetadata, MetadataKeyValue, ParquetEncoding, ParquetFieldOverwrites,
+    ParquetWriteOptions,
+};
 use crate::prelude::ChildFieldOverwrites;
 use crate::shared::schema_to_arrow_checked;
 
@@ -27,6 +30,7 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_overwrites(self.field_overwrites.clone())
     }
 }
 
@@ -107,6 +111,12 @@ where
         self
     }
 
+    /// Set the write options of (nested) fields, e.g. their field ids or bloom filters.
+    pub fn with_field_overwrites(mut self, field_overwrites: Vec<ParquetFieldOverwrites>) -> Self {
+        self.field_overwrites = field_overwrites;
+        self
+    }
+
     /// Set custom file-level key value metadata for the Parquet file
     pub fn with_key_value_metadata(mut self, key_value_metadata: Option<KeyValueMetadata>) -> Self {
         self.key_value_metadata = key_value_metadata;
@@ -186,6 +196,8 @@ fn to_column_write_options_rec(
         // Dummy value.
         children: ChildWriteOptions::Leaf(FieldWriteOptions {
             encoding: Encoding::Plain,
+            bloom_filter: None,
+            adaptive_encoding: false,
         }),
     };
 
@@ -199,11 +211,25 @@ fn to_column_write_options_rec(
     match field.dtype().to_physical_type() {
         Null | Boolean | Primitive(_) | Binary | FixedSizeBinary | LargeBinary | Utf8
         | Dictionary(_) | LargeUtf8 | BinaryView | Utf8View => {
+            let (encoding, adaptive_encoding) = match overwrites.and_then(|o| o.encoding) {
+                None => (encoding_map(field.dtype()), false),
+                Some(ParquetEncoding::Adaptive) => (encoding_map(field.dtype()), true),
+                Some(ParquetEncoding::Plain) => (Encoding::Plain, false),
+                Some(ParquetEncoding::Dictionary) => (Encoding::RleDictionary, false),
+                Some(ParquetEncoding::DeltaBinaryPacked) => (Encoding::DeltaBinaryPacked, false),
+                Some(ParquetEncoding::DeltaLengthByteArray) => {
+                    (Encoding::DeltaLengthByteArray, false)
+                },
+                Some(ParquetEncoding::DeltaByteArray) => (Encoding::DeltaByteArray, false),
+                Some(ParquetEncoding::ByteStreamSplit) => (Encoding::ByteStreamSplit, false),
+            };
             column_options.children = ChildWriteOptions::Leaf(FieldWriteOptions {
-                encoding: encoding_map(field.dtype()),
+                encoding,
+                bloom_filter: overwrites.and_then(|o| o.bloom_filter.map(Into::into)),
+                adaptive_encoding,
             });
         },
-        List | FixedSizeList | LargeList => {
+        List | FixedSizeList | LargeList | Map => {
             let child_overwrites = overwrites.and_then(|o| match &o.children {
                 ChildFieldOverwrites::None => None,
                 ChildFieldOverwrites::ListLike(child_overwrites) => Some(child_overwrites.as_ref()),
@@ -217,6 +243,8 @@ fn to_column_write_options_rec(
                 to_column_write_options_rec(inner, child_overwrites)
             } else if let ArrowDataType::FixedSizeList(inner, _) = a {
                 to_column_write_options_rec(inner, child_overwrites)
+            } else if let ArrowDataType::Map(inner, _) = a {
+                to_column_write_options_rec(inner, child_overwrites)
             } else {
                 unreachable!()
             };
@@ -253,7 +281,7 @@ fn to_column_write_options_rec(
             }
         },
 
-        Map | Union => unreachable!(),
+        Union => unreachable!(),
     }
 
     column_options
@@ -1,7 +1,11 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
+use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
@@ -59,3 +63,211 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Compression applied to the output of the text writers (CSV and NDJSON).
+///
+/// The output is written as a sequence of independently compressed frames (gzip members or zstd
+/// frames), so blocks can be compressed in parallel. Concatenated frames are a valid stream that
+/// standard tools, and [`maybe_decompress_bytes`], decompress as a whole.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ExternalCompression {
+    #[default]
+    Uncompressed,
+    /// Gzip with a level in `0..=9`, or the default level (6).
+    Gzip { level: Option<u32> },
+    /// Zstandard with a level in `1..=22`, or the default level (3).
+    Zstd { level: Option<i32> },
+}
+
+impl ExternalCompression {
+    pub fn is_uncompressed(&self) -> bool {
+        matches!(self, Self::Uncompressed)
+    }
+
+    /// The file extension suffix of the compression, e.g. `gz` for `data.csv.gz`.
+    pub fn file_suffix(&self) -> Option<&'static str> {
+        match self {
+            Self::Uncompressed => None,
+            Self::Gzip { .. } => Some("gz"),
+            Self::Zstd { .. } => Some("zst"),
+        }
+    }
+
+    /// Compress `bytes` into a single self-contained frame that is appended to `out`.
+    #[cfg_attr(not(feature = "compress"), allow(unused_variables))]
+    pub fn compress_frame(&self, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        match *self {
+            Self::Uncompressed => {
+                out.extend_from_slice(bytes);
+                Ok(())
+            },
+            Self::Gzip { level } => feature_gated!("compress", {
+                let level = match level {
+                    None => flate2::Compression::default(),
+                    Some(level) => {
+                        polars_ensure!(
+                            level <= 9,
+                            InvalidOperation: "invalid gzip compression level {level}, expected 0..=9"
+                        );
+                        flate2::Compression::new(level)
+                    },
+                };
+                let mut encoder = flate2::write::GzEncoder::new(out, level);
+                encoder.write_all(bytes)?;
+                encoder.finish()?;
+                Ok(())
+            }),
+            Self::Zstd { level } => feature_gated!("compress", {
+                let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+                polars_ensure!(
+                    zstd::compression_level_range().contains(&level),
+                    InvalidOperation: "invalid zstd compression level {level}"
+                );
+                let mut encoder = zstd::Encoder::new(out, level)?;
+                encoder.write_all(bytes)?;
+                encoder.finish()?;
+                Ok(())
+            }),
+        }
+    }
+}
+
+/// Writer that compresses everything written to it in blocks of `BLOCK_SIZE`. The blocks are
+/// buffered and compressed in parallel on the thread pool.
+///
+/// [`CompressedWriter::finish`] must be called to flush the last blocks.
+pub struct CompressedWriter<W: Write> {
+    inner: W,
+    compression: ExternalCompression,
+    buf: Vec<u8>,
+    has_written: bool,
+}
+
+impl<W: Write> CompressedWriter<W> {
+    const BLOCK_SIZE: usize = 1 << 22;
+
+    pub fn new(inner: W, compression: ExternalCompression) -> Self {
+        Self {
+            inner,
+            compression,
+            buf: Vec::new(),
+            has_written: false,
+        }
+    }
+
+    fn buffer_limit() -> usize {
+        Self::BLOCK_SIZE * POOL.current_num_threads()
+    }
+
+    fn write_blocks(&mut self) -> PolarsResult<()> {
+        let compression = self.compression;
+        let blocks = POOL.install(|| {
+            self.buf
+                .par_chunks(Self::BLOCK_SIZE)
+                .map(|block| {
+                    let mut out = Vec::with_capacity(block.len() / 2);
+                    compression.compress_frame(block, &mut out)?;
+                    Ok(out)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        for block in blocks {
+            self.inner.write_all(&block)?;
+            self.has_written = true;
+        }
+        self.buf.clear();
+        Ok(())
+    }
+
+    /// Compress and write the remaining buffered data.
+    pub fn finish(mut self) -> PolarsResult<W> {
+        if !self.buf.is_empty() {
+            self.write_blocks()?;
+        }
+        // Always write at least one frame, an empty file is not a valid compressed stream.
+        if !self.has_written {
+            let mut out = vec![];
+            self.compression.compress_frame(&[], &mut out)?;
+            self.inner.write_all(&out)?;
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        if self.buf.len() >= Self::buffer_limit() {
+            self.write_blocks().map_err(std::io::Error::other)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // Flushing a partial block would hurt the compression ratio, the data is written on
+        // `finish`.
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "compress", feature = "decompress"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compressed_writer_roundtrip() {
+        let data = (0..200_000)
+            .map(|i| format!("{i},abc\n"))
+            .collect::<String>();
+
+        for compression in [
+            ExternalCompression::Gzip { level: None },
+            ExternalCompression::Gzip { level: Some(1) },
+            ExternalCompression::Zstd { level: None },
+            ExternalCompression::Zstd { level: Some(9) },
+        ] {
+            let mut writer = CompressedWriter::new(vec![], compression);
+            // Write in pieces that don't line up with the blocks.
+            for piece in data.as_bytes().chunks(7777) {
+                writer.write_all(piece).unwrap();
+            }
+            let compressed = writer.finish().unwrap();
+            assert!(compressed.len() < data.len());
+
+            let mut out = vec![];
+            let decompressed = maybe_decompress_bytes(&compressed, &mut out).unwrap();
+            assert_eq!(decompressed, data.as_bytes());
+
+            // Separately compressed frames form one stream.
+            let mut frames = vec![];
+            compression.compress_frame(b"a\n", &mut frames).unwrap();
+            compression.compress_frame(b"b\n", &mut frames).unwrap();
+            let mut out = vec![];
+            assert_eq!(
+                maybe_decompress_bytes(&frames, &mut out).unwrap(),
+                b"a\nb\n"
+            );
+        }
+
+        // An empty output is still a valid stream.
+        let compressed = CompressedWriter::new(vec![], ExternalCompression::Zstd { level: None })
+            .finish()
+            .unwrap();
+        let mut out = vec![];
+        assert!(
+            maybe_decompress_bytes(&compressed, &mut out)
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut out = vec![];
+        assert!(
+            ExternalCompression::Gzip { level: Some(10) }
+                .compress_frame(b"", &mut out)
+                .is_err()
+        );
+    }
+}
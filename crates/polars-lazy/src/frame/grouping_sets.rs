@@ -0,0 +1,201 @@
+//! Aggregation over multiple grouping sets (`GROUPING SETS`, `ROLLUP` and `CUBE`).
+//!
+//! Every grouping set is aggregated separately and the results are concatenated vertically. The
+//! input is cached so that it is only computed (and scanned) once, regardless of the number of
+//! grouping sets.
+use polars_core::prelude::*;
+use polars_plan::plans::typed_lit;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::prelude::*;
+
+/// The maximum number of keys of [`LazyFrame::cube`], which produces `2^n` grouping sets.
+pub const MAX_CUBE_KEYS: usize = 12;
+
+/// Utility struct for aggregating a `LazyFrame` over multiple grouping sets.
+///
+/// The output has one column per distinct key (in order of first appearance), followed by the
+/// aggregations and a `UInt32` grouping id column. Keys that do not participate in a grouping set
+/// are null in the rows produced by that set. The grouping id follows the SQL `GROUPING(...)`
+/// convention: the first key maps to the most significant bit, and a bit is set if that key is
+/// *not* part of the grouping set that produced the row.
+#[derive(Clone)]
+#[must_use]
+pub struct LazyGroupingSets {
+    input: LazyFrame,
+    sets: Vec<Vec<Expr>>,
+    grouping_id_name: PlSmallStr,
+}
+
+impl LazyGroupingSets {
+    pub(crate) fn new(input: LazyFrame, sets: Vec<Vec<Expr>>) -> Self {
+        Self {
+            input,
+            sets,
+            grouping_id_name: PlSmallStr::from_static("grouping_id"),
+        }
+    }
+
+    /// Set the name of the grouping id column. Defaults to `"grouping_id"`.
+    pub fn with_grouping_id_name(mut self, name: impl Into<PlSmallStr>) -> Self {
+        self.grouping_id_name = name.into();
+        self
+    }
+
+    /// Aggregate every grouping set and concatenate the results.
+    ///
+    /// The empty grouping set aggregates over the whole frame and, as in SQL, always produces a
+    /// single row, even if the input is empty.
+    pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            !self.sets.is_empty(),
+            InvalidOperation: "at least one grouping set is required"
+        );
+
+        // The distinct keys over all grouping sets, in order of first appearance.
+        let mut key_names: Vec<PlSmallStr> = vec![];
+        let sets = self
+            .sets
+            .iter()
+            .map(|set| {
+                set.iter()
+                    .map(|e| {
+                        let name = expr_output_name(e)?;
+                        let idx = match key_names.iter().position(|n| n == &name) {
+                            Some(idx) => idx,
+                            None => {
+                                key_names.push(name);
+                                key_names.len() - 1
+                            },
+                        };
+                        Ok(idx)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        polars_ensure!(
+            key_names.len() <= 32,
+            InvalidOperation: "grouping sets support at most 32 distinct keys (found {})", key_names.len()
+        );
+
+        let aggs = aggs.as_ref();
+        let input = self.input.cache();
+        let branches = self
+            .sets
+            .into_iter()
+            .zip(sets)
+            .map(|(set, idxs)| {
+                let aggregated = if set.is_empty() {
+                    input.clone().select(aggs)
+                } else {
+                    input.clone().group_by(set).agg(aggs)
+                };
+
+                let mut grouping_id = 0u32;
+                let mut projection = Vec::with_capacity(key_names.len() + 2);
+                for (i, name) in key_names.iter().enumerate() {
+                    if idxs.contains(&i) {
+                        projection.push(col(name.clone()));
+                    } else {
+                        grouping_id |= 1 << (key_names.len() - 1 - i);
+                        projection.push(lit(NULL).alias(name.clone()));
+                    }
+                }
+                projection.push((all() - by_name(key_names.iter().cloned(), false)).as_expr());
+                projection.push(typed_lit(grouping_id).alias(self.grouping_id_name.clone()));
+                aggregated.select(projection)
+            })
+            .collect::<Vec<_>>();
+
+        concat(
+            branches,
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )
+    }
+}
+
+impl LazyFrame {
+    /// Aggregate over several grouping sets at once, sharing a single scan of the input.
+    ///
+    /// This is the equivalent of SQL's `GROUP BY GROUPING SETS (...)`; see [`LazyGroupingSets`]
+    /// for a description of the output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<LazyFrame> {
+    ///       df.lazy()
+    ///        .group_by_grouping_sets([
+    ///            vec![col("region"), col("product")],
+    ///            vec![col("region")],
+    ///            vec![],
+    ///        ])
+    ///        .agg([col("sales").sum()])
+    /// }
+    /// ```
+    pub fn group_by_grouping_sets<I, E, IE>(self, sets: I) -> LazyGroupingSets
+    where
+        I: IntoIterator<Item = E>,
+        E: AsRef<[IE]>,
+        IE: Into<Expr> + Clone,
+    {
+        let sets = sets
+            .into_iter()
+            .map(|set| set.as_ref().iter().map(|e| e.clone().into()).collect())
+            .collect();
+        LazyGroupingSets::new(self, sets)
+    }
+
+    /// Aggregate over the hierarchy of the given keys (SQL `GROUP BY ROLLUP (...)`).
+    ///
+    /// For keys `[a, b, c]` this aggregates over the grouping sets `(a, b, c)`, `(a, b)`, `(a)`
+    /// and `()`.
+    pub fn rollup<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupingSets {
+        let keys = by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<Expr>>();
+        let sets = (0..=keys.len())
+            .rev()
+            .map(|n| keys[..n].to_vec())
+            .collect::<Vec<_>>();
+        self.group_by_grouping_sets(sets)
+    }
+
+    /// Aggregate over every combination of the given keys (SQL `GROUP BY CUBE (...)`).
+    ///
+    /// For `n` keys this produces `2^n` grouping sets, ordered from the full set of keys down to
+    /// the empty set. At most [`MAX_CUBE_KEYS`] keys are supported.
+    pub fn cube<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+    ) -> PolarsResult<LazyGroupingSets> {
+        let n = by.as_ref().len();
+        polars_ensure!(
+            n <= MAX_CUBE_KEYS,
+            InvalidOperation: "cube supports at most {} keys (found {})", MAX_CUBE_KEYS, n
+        );
+        let keys = by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<Expr>>();
+        let sets = (0..1usize << n)
+            .map(|mask| {
+                keys.iter()
+                    .enumerate()
+                    .filter(|(i, _)| mask & (1 << (n - 1 - i)) == 0)
+                    .map(|(_, e)| e.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        Ok(self.group_by_grouping_sets(sets))
+    }
+}
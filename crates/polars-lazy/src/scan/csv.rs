@@ -2,6 +2,7 @@ use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, infer_file_schema,
+    leading_lines, maybe_transcode_prefix,
 };
 use polars_io::path_utils::expand_paths;
 use polars_io::utils::compression::maybe_decompress_bytes;
@@ -143,6 +144,12 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_separator(separator))
     }
 
+    /// Set the CSV file's column separator, which may span multiple bytes (e.g. `||`).
+    #[must_use]
+    pub fn with_multi_byte_separator(self, separator: &str) -> Self {
+        self.map_parse_options(|opts| opts.with_multi_byte_separator(separator))
+    }
+
     /// Set the comment prefix for this instance. Lines starting with this prefix will be ignored.
     #[must_use]
     pub fn with_comment_prefix(self, comment_prefix: Option<PlSmallStr>) -> Self {
@@ -253,6 +260,22 @@ impl LazyCsvReader {
 
             let mut owned = vec![];
             let bytes = maybe_decompress_bytes(bytes.as_ref(), &mut owned)?;
+            let mut transcoded = vec![];
+            // Only the lines that are used for schema inference are transcoded.
+            let bytes = maybe_transcode_prefix(
+                bytes,
+                parse_options.encoding,
+                parse_options.quote_char,
+                parse_options.eol_char,
+                leading_lines(
+                    skip_lines,
+                    skip_rows,
+                    self.read_options.has_header,
+                    self.read_options.skip_rows_after_header,
+                    self.read_options.infer_schema_length,
+                ),
+                &mut transcoded,
+            )?;
 
             PolarsResult::Ok(
                 infer_file_schema(
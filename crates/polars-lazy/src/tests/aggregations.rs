@@ -566,3 +566,51 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+fn test_rollup_and_cube() -> PolarsResult<()> {
+    let df = df![
+        "region" => ["eu", "eu", "us", "us"],
+        "product" => ["a", "b", "a", "a"],
+        "sales" => [1, 2, 3, 4]
+    ]?;
+    let sort_by = [col("grouping_id"), col("region"), col("product")];
+
+    let out = df
+        .clone()
+        .lazy()
+        .rollup([col("region"), col("product")])
+        .agg([col("sales").sum()])?
+        .sort_by_exprs(sort_by.clone(), Default::default())
+        .collect()?;
+    let expected = df![
+        "region" => [Some("eu"), Some("eu"), Some("us"), Some("eu"), Some("us"), None],
+        "product" => [Some("a"), Some("b"), Some("a"), None, None, None],
+        "sales" => [1, 2, 7, 3, 7, 10],
+        "grouping_id" => [0u32, 0, 0, 1, 1, 3]
+    ]?;
+    assert!(out.equals_missing(&expected), "{out:?}");
+    assert_eq!(out.column("grouping_id")?.dtype(), &DataType::UInt32);
+
+    let out = df
+        .lazy()
+        .cube([col("region"), col("product")])?
+        .with_grouping_id_name("gid")
+        .agg([col("sales").sum()])?
+        .filter(col("gid").eq(lit(2u32)))
+        .sort_by_exprs([col("product")], Default::default())
+        .collect()?;
+    let expected = df![
+        "region" => [None::<&str>, None],
+        "product" => ["a", "b"],
+        "sales" => [8, 2],
+        "gid" => [2u32, 2]
+    ]?;
+    assert!(out.equals_missing(&expected), "{out:?}");
+
+    let keys = (0..=MAX_CUBE_KEYS)
+        .map(|i| col(format!("k{i}")))
+        .collect::<Vec<_>>();
+    assert!(LazyFrame::default().cube(keys).is_err());
+    Ok(())
+}
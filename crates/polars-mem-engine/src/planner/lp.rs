@@ -281,14 +281,7 @@ fn create_physical_plan_impl(
                             match &file_type {
                                 #[cfg(feature = "parquet")]
                                 FileType::Parquet(options) => {
-                                    use polars_io::parquet::write::ParquetWriter;
-                                    ParquetWriter::new(BufWriter::new(writer))
-                                        .with_compression(options.compression)
-                                        .with_statistics(options.statistics)
-                                        .with_row_group_size(options.row_group_size)
-                                        .with_data_page_size(options.data_page_size)
-                                        .with_key_value_metadata(options.key_value_metadata.clone())
-                                        .finish(&mut df)?;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
                                 },
                                 #[cfg(feature = "ipc")]
                                 FileType::Ipc(options) => {
@@ -330,17 +323,24 @@ fn create_physical_plan_impl(
                                         .with_decimal_comma(options.serialize_options.decimal_comma)
                                         .with_null_value(options.serialize_options.null.clone())
                                         .with_quote_style(options.serialize_options.quote_style)
+                                        .with_compression(options.compression)
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
 
                                     JsonWriter::new(BufWriter::new(writer))
                                         .with_json_format(JsonFormat::JsonLines)
+                                        .with_compression(options.compression)
                                         .finish(&mut df)?;
                                 },
+                                #[cfg(feature = "avro")]
+                                FileType::Avro(options) => {
+                                    use polars_io::SerWriter;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
+                                },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
                             }
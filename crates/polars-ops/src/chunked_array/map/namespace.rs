@@ -0,0 +1,335 @@
+use arrow::array::Array;
+use arrow::bitmap::BitmapBuilder;
+use arrow::offset::Offsets;
+use polars_core::chunked_array::cast::CastOptions;
+
+use super::*;
+
+/// Flatten the entries of `ca` into a single chunk whose offsets start at zero.
+fn normalized_entries(ca: &MapChunked) -> ListChunked {
+    let entries = ca.physical().rechunk().into_owned();
+    entries
+        .trim_lists_to_normalized_offsets()
+        .unwrap_or(entries)
+}
+
+/// For every map, find the position of the first entry with the given key in the flattened
+/// entries. Missing keys and null maps get no position.
+fn find_key(entries: &ListChunked, key: &Series) -> PolarsResult<Vec<Option<IdxSize>>> {
+    let arr = entries.downcast_as_array();
+    let flat = entries.get_inner();
+    let keys = flat.struct_()?.fields_as_series().swap_remove(0);
+
+    let key = if key.len() == 1 {
+        key.clone()
+    } else {
+        // Broadcast the key of every row to each of its entries.
+        let mut idx = Vec::with_capacity(keys.len());
+        for (row, w) in arr.offsets().buffer().windows(2).enumerate() {
+            idx.extend(std::iter::repeat_n(row as IdxSize, (w[1] - w[0]) as usize));
+        }
+        key.take_slice(&idx)?
+    };
+    let mask = keys.equal(&key)?.rechunk().into_owned();
+    let mask = mask.downcast_as_array();
+
+    Ok((0..arr.len())
+        .map(|row| {
+            if !arr.is_valid(row) {
+                return None;
+            }
+            let (start, end) = arr.offsets().start_end(row);
+            (start..end)
+                .find(|&i| mask.get(i) == Some(true))
+                .map(|i| i as IdxSize)
+        })
+        .collect())
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the value belonging to `key` in every map, or null if the key is not present.
+    ///
+    /// `key` is either a single key that is looked up in all maps, or holds one key per map.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let key = key.strict_cast(ca.key_dtype())?;
+        polars_ensure!(
+            key.len() == 1 || key.len() == ca.len(),
+            length_mismatch = "map.get",
+            ca.len(),
+            key.len()
+        );
+
+        let entries = normalized_entries(ca);
+        let positions = find_key(&entries, &key)?;
+        let values = entries
+            .get_inner()
+            .struct_()?
+            .fields_as_series()
+            .swap_remove(1);
+        let idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, positions.into_iter());
+        Ok(values.take(&idx)?.with_name(ca.name().clone()))
+    }
+
+    /// Whether every map contains `key`.
+    ///
+    /// `key` is either a single key that is looked up in all maps, or holds one key per map.
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let key = key.strict_cast(ca.key_dtype())?;
+        polars_ensure!(
+            key.len() == 1 || key.len() == ca.len(),
+            length_mismatch = "map.contains_key",
+            ca.len(),
+            key.len()
+        );
+
+        let entries = normalized_entries(ca);
+        let positions = find_key(&entries, &key)?;
+        let arr = entries.downcast_as_array();
+        Ok(BooleanChunked::from_iter_options(
+            ca.name().clone(),
+            positions
+                .iter()
+                .enumerate()
+                .map(|(row, pos)| arr.is_valid(row).then_some(pos.is_some())),
+        ))
+    }
+
+    /// Get the keys of every map as a list.
+    fn map_keys(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_map();
+        ca.physical()
+            .apply_to_inner(&|entries| Ok(entries.struct_()?.fields_as_series().swap_remove(0)))
+    }
+
+    /// Get the values of every map as a list.
+    fn map_values(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_map();
+        ca.physical()
+            .apply_to_inner(&|entries| Ok(entries.struct_()?.fields_as_series().swap_remove(1)))
+    }
+
+    /// Get the entries of every map as a list of `{key, value}` structs.
+    fn map_entries(&self) -> ListChunked {
+        self.as_map().physical().clone()
+    }
+}
+
+impl MapNameSpace for MapChunked {}
+
+/// Create maps by zipping a list of keys with a list of values.
+///
+/// The lists in every row must have the same length and the keys cannot be null. Rows where either
+/// list is null become null and a length of one is broadcast.
+pub fn map_from_lists(keys: &ListChunked, values: &ListChunked) -> PolarsResult<MapChunked> {
+    let len = match (keys.len(), values.len()) {
+        (1, len) | (len, 1) => len,
+        (lhs, rhs) => {
+            polars_ensure!(lhs == rhs, length_mismatch = "map.from_lists", lhs, rhs);
+            lhs
+        },
+    };
+    let broadcast = |ca: &ListChunked| {
+        if ca.len() == len {
+            ca.rechunk().into_owned()
+        } else {
+            ca.new_from_index(0, len)
+        }
+    };
+    let (keys, values) = (broadcast(keys), broadcast(values));
+    let (key_arr, value_arr) = (keys.downcast_as_array(), values.downcast_as_array());
+
+    let mut key_idx = Vec::new();
+    let mut value_idx = Vec::new();
+    let mut offsets = Offsets::<i64>::with_capacity(len);
+    let mut validity = BitmapBuilder::with_capacity(len);
+    for row in 0..len {
+        let is_valid = key_arr.is_valid(row) && value_arr.is_valid(row);
+        let mut length = 0;
+        if is_valid {
+            let (key_start, key_end) = key_arr.offsets().start_end(row);
+            let (value_start, value_end) = value_arr.offsets().start_end(row);
+            length = key_end - key_start;
+            polars_ensure!(
+                length == value_end - value_start,
+                ShapeMismatch: "map keys and values must have the same length, got {} keys and {} values in row {}",
+                length, value_end - value_start, row
+            );
+            key_idx.extend((key_start..key_end).map(|i| i as IdxSize));
+            value_idx.extend((value_start..value_end).map(|i| i as IdxSize));
+        }
+        offsets.try_push(length)?;
+        validity.push(is_valid);
+    }
+
+    let map_keys = keys.get_inner().take_slice(&key_idx)?;
+    polars_ensure!(!map_keys.has_nulls(), ComputeError: "map keys cannot be null");
+
+    let fields = [
+        map_keys.with_name(PlSmallStr::from_static(MAP_KEY_NAME)),
+        values
+            .get_inner()
+            .take_slice(&value_idx)?
+            .with_name(PlSmallStr::from_static(MAP_VALUE_NAME)),
+    ];
+    let entries = StructChunked::from_series(keys.name().clone(), key_idx.len(), fields.iter())?
+        .into_series()
+        .rechunk();
+    let entries_dtype = entries.dtype().clone();
+    let entries = entries.chunks()[0].clone();
+
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(entries.dtype().clone()),
+        offsets.into(),
+        entries,
+        validity.into_opt_validity(),
+    );
+    // SAFETY: the list holds the entries we just created.
+    let list = unsafe {
+        Series::from_chunks_and_dtype_unchecked(
+            keys.name().clone(),
+            vec![arr.boxed()],
+            &DataType::List(Box::new(entries_dtype)),
+        )
+    };
+    list.list()?.cast_to_map(
+        keys.inner_dtype(),
+        values.inner_dtype(),
+        CastOptions::Strict,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn list(rows: Vec<Option<Series>>) -> ListChunked {
+        rows.into_iter().collect()
+    }
+
+    /// `{a: 1, b: 2}`, `{b: 3}`, null and `{}`.
+    fn maps() -> MapChunked {
+        let keys = list(vec![
+            Some(Series::new("".into(), ["a", "b"])),
+            Some(Series::new("".into(), ["b"])),
+            None,
+            Some(Series::new_empty("".into(), &DataType::String)),
+        ]);
+        let values = list(vec![
+            Some(Series::new("".into(), [1, 2])),
+            Some(Series::new("".into(), [3])),
+            Some(Series::new("".into(), [4])),
+            Some(Series::new_empty("".into(), &DataType::Int32)),
+        ]);
+        map_from_lists(&keys, &values).unwrap()
+    }
+
+    fn assert_series_eq(actual: Series, expected: Series) {
+        assert!(
+            actual.equals_missing(&expected),
+            "expected = {expected:?}\nactual = {actual:?}"
+        );
+    }
+
+    #[test]
+    fn test_map_get() {
+        let ca = maps();
+
+        let out = ca.map_get(&Series::new("".into(), ["b"])).unwrap();
+        assert_series_eq(out, Series::new("".into(), [Some(2), Some(3), None, None]));
+
+        let key = Series::new("".into(), ["a", "b", "a", "a"]);
+        let out = ca.map_get(&key).unwrap();
+        assert_series_eq(out, Series::new("".into(), [Some(1), Some(3), None, None]));
+
+        let out = ca.map_get(&Series::new("".into(), ["c"])).unwrap();
+        assert_series_eq(
+            out,
+            Series::new_null("".into(), 4)
+                .cast(&DataType::Int32)
+                .unwrap(),
+        );
+
+        assert!(ca.map_get(&Series::new("".into(), ["a", "b"])).is_err());
+    }
+
+    #[test]
+    fn test_map_contains_key() {
+        let ca = maps();
+
+        let out = ca.map_contains_key(&Series::new("".into(), ["b"])).unwrap();
+        assert_series_eq(
+            out.into_series(),
+            Series::new("".into(), [Some(true), Some(true), None, Some(false)]),
+        );
+
+        let key = Series::new("".into(), ["a", "a", "a", "a"]);
+        let out = ca.map_contains_key(&key).unwrap();
+        assert_series_eq(
+            out.into_series(),
+            Series::new("".into(), [Some(true), Some(false), None, Some(false)]),
+        );
+
+        assert!(
+            ca.map_contains_key(&Series::new("".into(), ["a", "b"]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_map_keys_values_entries() {
+        let ca = maps();
+
+        let keys = ca.map_keys().unwrap();
+        let expected = list(vec![
+            Some(Series::new("".into(), ["a", "b"])),
+            Some(Series::new("".into(), ["b"])),
+            None,
+            Some(Series::new_empty("".into(), &DataType::String)),
+        ]);
+        assert_series_eq(keys.into_series(), expected.into_series());
+
+        let values = ca.map_values().unwrap();
+        let expected = list(vec![
+            Some(Series::new("".into(), [1, 2])),
+            Some(Series::new("".into(), [3])),
+            None,
+            Some(Series::new_empty("".into(), &DataType::Int32)),
+        ]);
+        assert_series_eq(values.into_series(), expected.into_series());
+
+        let entries = ca.map_entries();
+        assert_eq!(
+            entries.inner_dtype(),
+            &map_entries_dtype(DataType::String, DataType::Int32)
+        );
+        let lengths = (0..entries.len())
+            .map(|i| entries.get_as_series(i).map(|s| s.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, [Some(2), Some(1), None, Some(0)]);
+    }
+
+    #[test]
+    fn test_map_from_lists_invalid() {
+        let keys = list(vec![Some(Series::new("".into(), ["a", "b"]))]);
+
+        // Mismatched number of keys and values.
+        let values = list(vec![Some(Series::new("".into(), [1]))]);
+        assert!(map_from_lists(&keys, &values).is_err());
+
+        // Mismatched number of rows.
+        let values = list(vec![
+            Some(Series::new("".into(), [1, 2])),
+            Some(Series::new("".into(), [1, 2])),
+            Some(Series::new("".into(), [1, 2])),
+        ]);
+        let keys = list(vec![keys.get_as_series(0), keys.get_as_series(0)]);
+        assert!(map_from_lists(&keys, &values).is_err());
+
+        // Null keys.
+        let keys = list(vec![Some(Series::new("".into(), [Some("a"), None]))]);
+        let values = list(vec![Some(Series::new("".into(), [1, 2]))]);
+        assert!(map_from_lists(&keys, &values).is_err());
+    }
+}
@@ -20,6 +20,10 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         nulls_equal: bool,
     ) -> PolarsResult<LeftJoinIds> {
         let s_self = self.as_series();
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        if let Some((lhs, rhs)) = encode_fixed_size_binary_keys(s_self, other)? {
+            return lhs.hash_join_left(&rhs, validate, nulls_equal);
+        }
         let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
         validate.validate_probe(&lhs, &rhs, false, nulls_equal)?;
 
@@ -125,6 +129,10 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         nulls_equal: bool,
     ) -> PolarsResult<Vec<IdxSize>> {
         let s_self = self.as_series();
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        if let Some((lhs, rhs)) = encode_fixed_size_binary_keys(s_self, other)? {
+            return lhs.hash_join_semi_anti(&rhs, anti, nulls_equal);
+        }
         let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
 
         let lhs_dtype = lhs.dtype();
@@ -220,6 +228,10 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         nulls_equal: bool,
     ) -> PolarsResult<(InnerJoinIds, bool)> {
         let s_self = self.as_series();
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        if let Some((lhs, rhs)) = encode_fixed_size_binary_keys(s_self, other)? {
+            return lhs.hash_join_inner(&rhs, validate, nulls_equal);
+        }
         let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
         validate.validate_probe(&lhs, &rhs, true, nulls_equal)?;
 
@@ -337,6 +349,10 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         nulls_equal: bool,
     ) -> PolarsResult<(PrimitiveArray<IdxSize>, PrimitiveArray<IdxSize>)> {
         let s_self = self.as_series();
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        if let Some((lhs, rhs)) = encode_fixed_size_binary_keys(s_self, other)? {
+            return lhs.hash_join_outer(&rhs, validate, nulls_equal);
+        }
         let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
         validate.validate_probe(&lhs, &rhs, true, nulls_equal)?;
 
@@ -417,6 +433,24 @@ pub trait SeriesJoin: SeriesSealed + Sized {
 
 impl SeriesJoin for Series {}
 
+/// Row-encode fixed-size binary keys, keeping null keys null so that `nulls_equal` is respected.
+#[cfg(feature = "dtype-fixed-size-binary")]
+fn encode_fixed_size_binary_keys(
+    lhs: &Series,
+    rhs: &Series,
+) -> PolarsResult<Option<(Series, Series)>> {
+    use polars_core::chunked_array::ops::row_encode::encode_rows_vertical_par_unordered_broadcast_nulls;
+
+    if !lhs.dtype().is_fixed_size_binary() {
+        return Ok(None);
+    }
+    let encode = |s: &Series| {
+        encode_rows_vertical_par_unordered_broadcast_nulls(&[s.clone().into_column()])
+            .map(|ca| ca.into_series())
+    };
+    Ok(Some((encode(lhs)?, encode(rhs)?)))
+}
+
 fn chunks_as_slices<T>(splitted: &[ChunkedArray<T>]) -> Vec<&[T::Native]>
 where
     T: PolarsNumericType,
@@ -12,6 +12,8 @@ pub fn abs(s: &Series) -> PolarsResult<Series> {
         Int64 => s.i64().unwrap().wrapping_abs().into_series(),
         #[cfg(feature = "dtype-i128")]
         Int128 => s.i128().unwrap().wrapping_abs().into_series(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => abs(&s.cast(&Float32)?)?.cast(&Float16)?,
         Float32 => s.f32().unwrap().wrapping_abs().into_series(),
         Float64 => s.f64().unwrap().wrapping_abs().into_series(),
         #[cfg(feature = "dtype-decimal")]
@@ -0,0 +1,99 @@
+use polars_compute::tdigest::TDigest;
+use polars_core::prelude::*;
+
+pub fn validate_approx_quantile_args(quantile: Option<f64>, accuracy: f64) -> PolarsResult<()> {
+    if let Some(quantile) = quantile {
+        polars_ensure!(
+            (0.0..=1.0).contains(&quantile),
+            ComputeError: "`quantile` should be between 0.0 and 1.0, got {quantile}"
+        );
+    }
+    polars_ensure!(
+        accuracy > 0.0 && accuracy <= 1.0,
+        ComputeError: "`accuracy` should be in (0.0, 1.0], got {accuracy}"
+    );
+    Ok(())
+}
+
+/// The dtype of the approximate quantile of values of `dtype`.
+///
+/// Binary values are serialized sketches and give Float64 quantiles.
+pub fn approx_quantile_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Float16 => DataType::Float16,
+        DataType::Float32 => DataType::Float32,
+        DataType::Binary | DataType::Boolean => DataType::Float64,
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(..) => DataType::Float64,
+        dt if dt.is_primitive_numeric() => DataType::Float64,
+        dt => dt.clone(),
+    }
+}
+
+/// The values of `s` as they are added to a [`TDigest`].
+pub fn tdigest_values(s: &Series) -> PolarsResult<Float64Chunked> {
+    let dtype = s.dtype();
+    polars_ensure!(
+        dtype.is_primitive_numeric()
+            || dtype.is_float16()
+            || dtype.is_temporal()
+            || dtype.is_decimal()
+            || dtype.is_bool()
+            || dtype.is_null(),
+        opq = approx_quantile,
+        dtype
+    );
+    let s = if dtype.is_temporal() {
+        s.to_physical_repr().cast(&DataType::Float64)?
+    } else {
+        s.cast(&DataType::Float64)?
+    };
+    Ok(s.f64()?.clone())
+}
+
+/// Adds the values of `s` to the digest.
+///
+/// Binary values are interpreted as serialized digests, as created by
+/// [`approx_quantile_sketch`], and merged into it.
+pub fn tdigest_extend(digest: &mut TDigest, s: &Series) -> PolarsResult<()> {
+    if let DataType::Binary = s.dtype() {
+        for bytes in s.binary()?.iter().flatten() {
+            digest.merge(&TDigest::from_bytes(bytes)?);
+        }
+    } else {
+        for arr in tdigest_values(s)?.downcast_iter() {
+            digest.extend(arr.non_null_values_iter());
+        }
+    }
+    Ok(())
+}
+
+/// Casts the quantiles estimated for values of `dtype` to the output dtype.
+pub fn cast_approx_quantiles(quantiles: Float64Chunked, dtype: &DataType) -> PolarsResult<Series> {
+    let out_dtype = approx_quantile_dtype(dtype);
+    let s = quantiles.into_series();
+    if out_dtype.is_temporal() {
+        s.cast(&out_dtype.to_physical())?.cast(&out_dtype)
+    } else {
+        s.cast(&out_dtype)
+    }
+}
+
+/// Estimates the quantile of the values in `s` with a t-digest.
+pub fn approx_quantile(s: &Series, quantile: f64, accuracy: f64) -> PolarsResult<Series> {
+    validate_approx_quantile_args(Some(quantile), accuracy)?;
+    let mut digest = TDigest::new(accuracy);
+    tdigest_extend(&mut digest, s)?;
+    let ca = Float64Chunked::from_slice_options(s.name().clone(), &[digest.quantile(quantile)]);
+    cast_approx_quantiles(ca, s.dtype())
+}
+
+/// Builds a t-digest of the values in `s` and returns it serialized, such that
+/// it can later be merged with other sketches to estimate quantiles.
+pub fn approx_quantile_sketch(s: &Series, accuracy: f64) -> PolarsResult<Series> {
+    validate_approx_quantile_args(None, accuracy)?;
+    let mut digest = TDigest::new(accuracy);
+    tdigest_extend(&mut digest, s)?;
+    let ca = BinaryChunked::from_slice(s.name().clone(), &[digest.to_bytes()]);
+    Ok(ca.into_series())
+}
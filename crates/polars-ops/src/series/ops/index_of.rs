@@ -1,4 +1,5 @@
 use arrow::array::{BinaryArray, BinaryViewArray, PrimitiveArray};
+use polars_core::chunked_array::cast::CastOptions;
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::prelude::*;
 use polars_utils::total_ord::TotalEq;
@@ -111,6 +112,10 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
                 needle
             ))
         },
+        DT::Float16 => index_of(
+            &series.cast(&DT::Float32)?,
+            needle.cast_with_options(&DT::Float32, CastOptions::Strict)?,
+        ),
         DT::String => Ok(index_of_value::<_, BinaryViewArray>(
             &series.str()?.as_binary(),
             needle.value().extract_str().unwrap().as_bytes(),
@@ -153,6 +158,12 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         DT::Decimal(..) => unreachable!(),
         #[cfg(feature = "dtype-categorical")]
         DT::Categorical(..) | DT::Enum(..) => unreachable!(),
+        #[cfg(feature = "dtype-map")]
+        DT::Map(..) => unreachable!(),
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        DT::FixedSizeBinary(_) => unreachable!(),
+        #[cfg(feature = "dtype-uuid")]
+        DT::Uuid => unreachable!(),
         DT::Date | DT::Datetime(..) | DT::Duration(..) | DT::Time => unreachable!(),
 
         DT::Object(_) | DT::Unknown(_) => polars_bail!(op = "index_of", series.dtype()),
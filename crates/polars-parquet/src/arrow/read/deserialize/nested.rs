@@ -19,7 +19,11 @@ pub fn columns_to_iter_recursive(
     mut init: Vec<InitNested>,
     filter: Option<Filter>,
 ) -> ParquetResult<(NestedState, Box<dyn Array>, Bitmap)> {
-    if !field.dtype().is_nested() {
+    let is_primitive = match field.dtype() {
+        ArrowDataType::Extension(ext) => !ext.inner.is_nested(),
+        dtype => !dtype.is_nested(),
+    };
+    if is_primitive {
         let pages = columns.pop().unwrap();
         init.push(InitNested::Primitive(field.is_nullable));
         let type_ = types.pop().unwrap();
@@ -362,7 +362,7 @@ pub fn page_iter_to_array(
         .collect_boxed(filter)?,
 
         // Float16
-        (PhysicalType::FixedLenByteArray(2), Float32) => {
+        (PhysicalType::FixedLenByteArray(2), Float16 | Float32) => {
             // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder.
 
             let (nested, mut fsb_array, ptm) = PageDecoder::new(
@@ -378,20 +378,19 @@ pub fn page_iter_to_array(
             let values = fsb_array.values().as_slice();
             assert_eq!(values.len() % 2, 0);
             let values = values.chunks_exact(2);
-            let values = values
-                .map(|v| {
-                    // SAFETY: We know that `v` is always of size two.
-                    let le_bytes: [u8; 2] = unsafe { v.try_into().unwrap_unchecked() };
-                    let v = arrow::types::f16::from_le_bytes(le_bytes);
-                    v.to_f32()
-                })
-                .collect();
+            let values = values.map(|v| {
+                // SAFETY: We know that `v` is always of size two.
+                let le_bytes: [u8; 2] = unsafe { v.try_into().unwrap_unchecked() };
+                arrow::types::f16::from_le_bytes(le_bytes)
+            });
+            let array = if matches!(dtype, Float16) {
+                PrimitiveArray::new(dtype, values.collect(), validity).to_boxed()
+            } else {
+                let values = values.map(|v| v.to_f32()).collect();
+                PrimitiveArray::<f32>::new(dtype, values, validity).to_boxed()
+            };
 
-            (
-                nested,
-                PrimitiveArray::<f32>::new(dtype, values, validity).to_boxed(),
-                ptm,
-            )
+            (nested, array, ptm)
         },
 
         (PhysicalType::Float, Float32) => PageDecoder::new(
@@ -28,8 +28,9 @@ pub use crate::parquet::{
     metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
-        BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State, decompress,
-        get_column_iterator, read_metadata as _read_metadata,
+        BasicDecompressor, ColumnIndex, MutStreamingIterator, OffsetIndex, PageLocation,
+        PageReader, ReadColumnIterator, State, decompress, deserialize_column_index,
+        deserialize_offset_index, get_column_iterator, read_metadata as _read_metadata,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -1,5 +1,7 @@
 //! This module has entry points, [`parquet_to_arrow_schema`] and the more configurable [`parquet_to_arrow_schema_with_options`].
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, TimeUnit};
+use arrow::datatypes::{
+    ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, TimeUnit, UUID_EXTENSION_NAME,
+};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::arrow::read::schema::SchemaInferenceOptions;
@@ -173,6 +175,14 @@ fn from_fixed_len_byte_array(
         (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
             ArrowDataType::Decimal(precision, scale)
         },
+        (Some(PrimitiveLogicalType::Float16), _) if length == 2 => ArrowDataType::Float16,
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => {
+            ArrowDataType::Extension(Box::new(ExtensionType {
+                name: UUID_EXTENSION_NAME.into(),
+                inner: ArrowDataType::FixedSizeBinary(16),
+                metadata: None,
+            }))
+        },
         (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
             ArrowDataType::Decimal(precision, scale)
         },
@@ -244,9 +254,9 @@ fn non_repeated_group(
     match (logical_type, converted_type) {
         (Some(GroupLogicalType::List), _) => to_list(fields, parent_name, options),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name, options),
-        (Some(GroupLogicalType::Map), _) => to_list(fields, parent_name, options),
-        (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
-            to_map(fields, options)
+        (Some(GroupLogicalType::Map), _)
+        | (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
+            to_map(fields, parent_name, options)
         },
         _ => to_struct(fields, options),
     }
@@ -266,11 +276,29 @@ fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option
     }
 }
 
-/// Converts a parquet group type to an arrow [`ArrowDataType::Struct`].
-/// Returns [`None`] if all its fields are empty
-fn to_map(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<ArrowDataType> {
-    let inner = to_field(&fields[0], options)?;
-    Some(ArrowDataType::Map(Box::new(inner), false))
+/// Converts a parquet map group to an arrow [`ArrowDataType::Map`].
+///
+/// The repeated `key_value` group becomes the non-nullable entries struct of the map. Groups that
+/// do not follow this layout are read as a list.
+fn to_map(
+    fields: &[ParquetType],
+    parent_name: &str,
+    options: &SchemaInferenceOptions,
+) -> Option<ArrowDataType> {
+    match &fields[0] {
+        ParquetType::GroupType {
+            field_info,
+            fields: kv_fields,
+            ..
+        } if kv_fields.len() == 2 => {
+            let entries = to_struct(kv_fields, options)?;
+            Some(ArrowDataType::Map(
+                Box::new(Field::new(field_info.name.clone(), entries, false)),
+                false,
+            ))
+        },
+        _ => to_list(fields, parent_name, options),
+    }
 }
 
 /// Entry point for converting parquet group type.
@@ -463,6 +491,7 @@ mod tests {
         message test_schema {
             REQUIRED BYTE_ARRAY binary;
             REQUIRED FIXED_LEN_BYTE_ARRAY (20) fixed_binary;
+            OPTIONAL FIXED_LEN_BYTE_ARRAY (16) uuid (UUID);
         }
         ";
         let expected = vec![
@@ -472,6 +501,15 @@ mod tests {
                 ArrowDataType::FixedSizeBinary(20),
                 false,
             ),
+            Field::new(
+                "uuid".into(),
+                ArrowDataType::Extension(Box::new(ExtensionType {
+                    name: UUID_EXTENSION_NAME.into(),
+                    inner: ArrowDataType::FixedSizeBinary(16),
+                    metadata: None,
+                })),
+                true,
+            ),
         ];
 
         let parquet_schema = SchemaDescriptor::try_from_message(message)?;
@@ -12,7 +12,8 @@ use polars_utils::pl_str::PlSmallStr;
 
 use super::{ParquetTimeUnit, RowGroupMetadata};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+use crate::parquet::read::{ColumnIndex, deserialize_page_statistics};
+use crate::parquet::schema::types::{PhysicalType as ParquetPhysicalType, PrimitiveType};
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
@@ -217,7 +218,10 @@ impl ColumnStatistics {
                 })
             },
 
-            // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+            (D::Float16, PPT::FixedLenByteArray(2)) => {
+                rmap!(expect_fixedlen, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+            },
+            // Float16 values that are read as Float32.
             (_, PPT::FixedLenByteArray(2))
                 if matches!(
                     self.logical_type.as_ref(),
@@ -299,6 +303,48 @@ pub fn deserialize_all(
     field_idx: usize,
 ) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     assert!(!row_groups.is_empty());
+    let primitive_type = &row_groups[0].parquet_columns()[field_idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+
+    deserialize_arrays(
+        field,
+        primitive_type,
+        row_groups.len(),
+        row_groups
+            .iter()
+            .map(|rg| rg.parquet_columns()[field_idx].statistics().transpose()),
+    )
+}
+
+/// Deserializes the statistics of the data pages of `column` from its [`ColumnIndex`], with
+/// one value per page.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_page_index(
+    field: &Field,
+    column: &ColumnChunkMetadata,
+    column_index: &ColumnIndex,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    let primitive_type = &column.descriptor().descriptor.primitive_type;
+    let statistics = deserialize_page_statistics(column_index, primitive_type)?;
+
+    deserialize_arrays(
+        field,
+        primitive_type,
+        statistics.len(),
+        statistics.into_iter().map(|s| Ok(Some(s))),
+    )
+}
+
+fn deserialize_arrays(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    num_batches: usize,
+    statistics: impl Iterator<Item = ParquetResult<Option<ParquetStatistics>>>,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     use ArrowDataType as D;
     match field.dtype() {
         // @TODO: These are all a bit more complex, skip for now.
@@ -308,26 +354,19 @@ pub fn deserialize_all(
         D::Struct(..) => Ok(None),
 
         _ => {
-            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-            let mut distinct_count =
-                MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-
-            let primitive_type = &row_groups[0].parquet_columns()[field_idx]
-                .descriptor()
-                .descriptor
-                .primitive_type;
+            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(num_batches);
+            let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(num_batches);
 
             let logical_type = &primitive_type.logical_type;
             let physical_type = &primitive_type.physical_type;
 
             macro_rules! rmap {
                 ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
-                    let mut min_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-                    let mut max_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
+                    let mut min_arr = <$arr>::with_capacity(num_batches$(, $arg)?);
+                    let mut max_arr = <$arr>::with_capacity(num_batches$(, $arg)?);
 
-                    for rg in row_groups {
-                        let column = &rg.parquet_columns()[field_idx];
-                        let s = column.statistics().transpose()?;
+                    for s in statistics {
+                        let s = s?;
 
                         let (v_min, v_max, v_null_count, v_distinct_count) = match s {
                             None => (None, None, None, None),
@@ -401,8 +440,8 @@ pub fn deserialize_all(
             use {ArrowDataType as D, ParquetPhysicalType as PPT};
             let (min_value, max_value) = match (field.dtype(), physical_type) {
                 (D::Null, _) => (
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, num_batches).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, num_batches).to_boxed(),
                 ),
 
                 (D::Boolean, _) => rmap!(
@@ -468,7 +507,10 @@ pub fn deserialize_all(
                     })
                 },
 
-                // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+                (D::Float16, PPT::FixedLenByteArray(2)) => {
+                    rmap!(expect_fixedlen, MutablePrimitiveArray::<f16>, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+                },
+                // Float16 values that are read as Float32.
                 (_, PPT::FixedLenByteArray(2))
                     if matches!(logical_type.as_ref(), Some(PrimitiveLogicalType::Float16)) =>
                 {
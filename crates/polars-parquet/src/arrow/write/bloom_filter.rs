@@ -0,0 +1,132 @@
+use arrow::array::*;
+use arrow::bitmap::MutableBitmap;
+use arrow::datatypes::ArrowDataType;
+use arrow::match_integer_type;
+use arrow::types::NativeType;
+
+use super::{ColumnWriteOptions, ParquetType, to_leaves, to_parquet_leaves};
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+/// Builds the bitsets of the bloom filters of the parquet columns of `array`, one per leaf of
+/// `type_`. A leaf has no bloom filter if it is not enabled in its [`super::FieldWriteOptions`]
+/// or if its type is not supported.
+pub fn array_to_bloom_filters<A: AsRef<dyn Array>>(
+    array: A,
+    type_: ParquetType,
+    column_options: &ColumnWriteOptions,
+) -> Vec<Option<Vec<u8>>> {
+    let mut field_options = Vec::new();
+    column_options.to_leaves(&mut field_options);
+
+    if field_options.iter().all(|o| o.bloom_filter.is_none()) {
+        return vec![None; field_options.len()];
+    }
+
+    let types = to_parquet_leaves(type_);
+    assert_eq!(field_options.len(), types.len());
+
+    let mut values = Vec::new();
+    to_leaves(array.as_ref(), &mut values);
+
+    values
+        .iter()
+        .zip(field_options)
+        .map(|(values, field_options)| {
+            let options = field_options.bloom_filter?;
+            let ndv = options
+                .ndv
+                .unwrap_or((values.len() - values.null_count()) as u64);
+
+            let mut bitset = vec![0; optimal_num_bytes(ndv, options.fpp)];
+            insert_values(&mut bitset, values.as_ref()).then_some(bitset)
+        })
+        .collect()
+}
+
+/// Inserts the non-null values of `array` into `bitset`, hashed the way they are encoded by
+/// `array_to_page_simple`. Returns `false` if the type of `array` is not supported.
+fn insert_values(bitset: &mut [u8], array: &dyn Array) -> bool {
+    use ArrowDataType as D;
+    match array.dtype().to_logical_type() {
+        // casts below MUST match the casts done at the metadata (field -> parquet type).
+        D::UInt8 => insert_primitive::<u8, i32>(bitset, array),
+        D::UInt16 => insert_primitive::<u16, i32>(bitset, array),
+        D::UInt32 => insert_primitive::<u32, i32>(bitset, array),
+        D::UInt64 => insert_primitive::<u64, i64>(bitset, array),
+        D::Int8 => insert_primitive::<i8, i32>(bitset, array),
+        D::Int16 => insert_primitive::<i16, i32>(bitset, array),
+        D::Int32 | D::Date32 | D::Time32(_) => insert_primitive::<i32, i32>(bitset, array),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            insert_primitive::<i64, i64>(bitset, array)
+        },
+        D::Float32 => insert_primitive::<f32, f32>(bitset, array),
+        D::Float64 => insert_primitive::<f64, f64>(bitset, array),
+        D::Utf8 => insert_bytes(
+            bitset,
+            array_as::<Utf8Array<i32>>(array).non_null_values_iter(),
+        ),
+        D::LargeUtf8 => insert_bytes(
+            bitset,
+            array_as::<Utf8Array<i64>>(array).non_null_values_iter(),
+        ),
+        D::Utf8View => insert_bytes(
+            bitset,
+            array_as::<Utf8ViewArray>(array).non_null_values_iter(),
+        ),
+        D::Binary => insert_bytes(
+            bitset,
+            array_as::<BinaryArray<i32>>(array).non_null_values_iter(),
+        ),
+        D::LargeBinary => insert_bytes(
+            bitset,
+            array_as::<BinaryArray<i64>>(array).non_null_values_iter(),
+        ),
+        D::BinaryView => insert_bytes(
+            bitset,
+            array_as::<BinaryViewArray>(array).non_null_values_iter(),
+        ),
+        D::FixedSizeBinary(_) => insert_bytes(
+            bitset,
+            array_as::<FixedSizeBinaryArray>(array).iter().flatten(),
+        ),
+        D::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            let array = array_as::<DictionaryArray<$T>>(array);
+
+            // Only the values that are referenced by a key are in the column.
+            let mut used = MutableBitmap::from_len_zeroed(array.values().len());
+            for idx in array.keys_iter().flatten() {
+                used.set(idx, true);
+            }
+            let values = array.values();
+            let validity = match values.validity() {
+                None => used.freeze(),
+                Some(validity) => &used.freeze() & validity,
+            };
+            insert_values(bitset, values.with_validity(Some(validity)).as_ref())
+        }),
+        _ => false,
+    }
+}
+
+fn array_as<T: Array>(array: &dyn Array) -> &T {
+    array.as_any().downcast_ref().unwrap()
+}
+
+fn insert_primitive<T, P>(bitset: &mut [u8], array: &dyn Array) -> bool
+where
+    T: NativeType + num_traits::AsPrimitive<P>,
+    P: ParquetNativeType,
+{
+    for x in array_as::<PrimitiveArray<T>>(array).non_null_values_iter() {
+        insert(bitset, hash_native(x.as_()));
+    }
+    true
+}
+
+fn insert_bytes<B: AsRef<[u8]>>(bitset: &mut [u8], values: impl Iterator<Item = B>) -> bool {
+    for x in values {
+        insert(bitset, hash_byte(x));
+    }
+    true
+}
@@ -5,7 +5,7 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{ColumnWriteOptions, ThriftFileMetadata, WriteOptions, to_parquet_schema};
-use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
+use crate::parquet::metadata::{KeyValue, SchemaDescriptor, SortingColumn};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
 /// An interface to write a parquet to a [`Write`]
@@ -83,6 +83,23 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file, followed by the bloom filters of its parquet columns.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        bloom_filters: &[Option<Vec<u8>>],
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_bloom_filters(row_group, bloom_filters)?)
+    }
+
+    /// Sets the columns that the rows of the last written row group are sorted by.
+    pub fn set_sorting_columns(&mut self, sorting_columns: Vec<SortingColumn>) -> PolarsResult<()> {
+        Ok(self.writer.set_sorting_columns(sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
@@ -2,7 +2,8 @@ mod basic;
 mod nested;
 
 use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
-use arrow::types::i256;
+use arrow::datatypes::ArrowDataType;
+use arrow::types::{NativeType, f16, i256};
 pub use basic::array_to_page;
 pub use nested::array_to_page as nested_array_to_page;
 
@@ -60,6 +61,50 @@ pub(super) fn build_statistics(
     }
 }
 
+/// Converts Float16 values to their little-endian bytes, as they are stored in Parquet.
+pub(super) fn f16_to_fixed_size_binary(array: &PrimitiveArray<f16>) -> FixedSizeBinaryArray {
+    let values = array
+        .values()
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect::<Vec<_>>();
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(2),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// NaN values are left out of the min and max, as the Parquet spec requires.
+pub(super) fn build_statistics_f16(
+    array: &PrimitiveArray<f16>,
+    primitive_type: PrimitiveType,
+    options: &StatisticsOptions,
+) -> FixedLenStatistics {
+    let non_nan = || array.non_null_values_iter().filter(|x| !x.is_nan());
+    FixedLenStatistics {
+        primitive_type,
+        null_count: options.null_count.then_some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: options
+            .max_value
+            .then(|| {
+                non_nan()
+                    .max_by(|x, y| x.to_f32().total_cmp(&y.to_f32()))
+                    .map(|x| x.to_le_bytes().to_vec())
+            })
+            .flatten(),
+        min_value: options
+            .min_value
+            .then(|| {
+                non_nan()
+                    .min_by(|x, y| x.to_f32().total_cmp(&y.to_f32()))
+                    .map(|x| x.to_le_bytes().to_vec())
+            })
+            .flatten(),
+    }
+}
+
 pub(super) fn build_statistics_decimal(
     array: &PrimitiveArray<i128>,
     primitive_type: PrimitiveType,
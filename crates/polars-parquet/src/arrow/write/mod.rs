@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -27,7 +29,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, f16, i256};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -36,7 +38,7 @@ pub use utils::write_def_levels;
 pub use crate::parquet::compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel};
 pub use crate::parquet::encoding::Encoding;
 pub use crate::parquet::metadata::{
-    Descriptor, FileMetadata, KeyValue, SchemaDescriptor, ThriftFileMetadata,
+    Descriptor, FileMetadata, KeyValue, SchemaDescriptor, SortingColumn, ThriftFileMetadata,
 };
 pub use crate::parquet::page::{CompressedDataPage, CompressedPage, Page};
 use crate::parquet::schema::Repetition;
@@ -124,6 +126,29 @@ impl ColumnWriteOptions {
 #[derive(Clone)]
 pub struct FieldWriteOptions {
     pub encoding: Encoding,
+    pub bloom_filter: Option<BloomFilterOptions>,
+    /// Whether to choose the encoding of every data page that isn't dictionary encoded by
+    /// encoding it with each of the encodings that fit its type, and keeping the smallest.
+    pub adaptive_encoding: bool,
+}
+
+/// The options of the bloom filter written for a column
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The false positive probability
+    pub fpp: f64,
+    /// The number of distinct values the bloom filter is sized for. Defaults to the number of
+    /// non-null values of the column chunk.
+    pub ndv: Option<u64>,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            fpp: 0.05,
+            ndv: None,
+        }
+    }
 }
 
 impl ColumnWriteOptions {
@@ -139,7 +164,11 @@ impl ColumnWriteOptions {
 
 impl FieldWriteOptions {
     pub fn default_with_encoding(encoding: Encoding) -> Self {
-        Self { encoding }
+        Self {
+            encoding,
+            bloom_filter: None,
+            adaptive_encoding: false,
+        }
     }
 
     pub fn into_default_column_write_options(self) -> ColumnWriteOptions {
@@ -159,6 +188,8 @@ pub struct StructFieldWriteOptions {
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::array_to_bloom_filters;
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
 use polars_error::{PolarsResult, polars_bail};
@@ -355,6 +386,7 @@ pub fn array_to_pages(
     field_options: &FieldWriteOptions,
 ) -> PolarsResult<DynIter<'static, PolarsResult<Page>>> {
     let mut encoding = field_options.encoding;
+    let adaptive_encoding = field_options.adaptive_encoding;
     if let ArrowDataType::Dictionary(key_type, _, _) = primitive_array.dtype().to_logical_type() {
         return match_integer_type!(key_type, |$T| {
             dictionary::array_to_pages::<$T>(
@@ -416,6 +448,10 @@ pub fn array_to_pages(
         let mut right_nested = nested.clone();
         slice_parquet_array(right_array.as_mut(), &mut right_nested, offset, length);
 
+        if adaptive_encoding && right_nested.len() == 1 {
+            return array_to_page_adaptive(right_array.as_ref(), type_.clone(), options);
+        }
+
         array_to_page(
             right_array.as_ref(),
             type_.clone(),
@@ -427,6 +463,72 @@ pub fn array_to_pages(
     Ok(DynIter::new(pages))
 }
 
+/// The encodings that are tried when the encoding of a page of `dtype` is chosen adaptively.
+fn adaptive_encoding_candidates(dtype: &ArrowDataType) -> &'static [Encoding] {
+    use ArrowDataType as D;
+    match dtype.to_logical_type() {
+        D::Int8
+        | D::Int16
+        | D::Int32
+        | D::Int64
+        | D::UInt8
+        | D::UInt16
+        | D::UInt32
+        | D::UInt64
+        | D::Date32
+        | D::Date64
+        | D::Time32(_)
+        | D::Time64(_)
+        | D::Timestamp(_, _)
+        | D::Duration(_) => &[
+            Encoding::Plain,
+            Encoding::DeltaBinaryPacked,
+            Encoding::ByteStreamSplit,
+        ],
+        D::Float32 | D::Float64 => &[Encoding::Plain, Encoding::ByteStreamSplit],
+        D::LargeUtf8 | D::LargeBinary | D::Utf8View | D::BinaryView => &[
+            Encoding::Plain,
+            Encoding::DeltaLengthByteArray,
+            Encoding::DeltaByteArray,
+        ],
+        _ => &[Encoding::Plain],
+    }
+}
+
+/// Converts a non-nested [`Array`] to a [`Page`] with the encoding that results in the smallest
+/// page once compressed with `options.compression`.
+fn array_to_page_adaptive(
+    array: &dyn Array,
+    type_: ParquetPrimitiveType,
+    options: WriteOptions,
+) -> PolarsResult<Page> {
+    let mut best: Option<(usize, Page)> = None;
+    let mut compressed = vec![];
+    for &encoding in adaptive_encoding_candidates(array.dtype()) {
+        let page = array_to_page_simple(array, type_.clone(), options, encoding)?;
+        let Page::Data(data_page) = &page else {
+            unreachable!("array_to_page_simple only returns data pages")
+        };
+
+        let size = if options.compression == CompressionOptions::Uncompressed {
+            data_page.buffer().len()
+        } else {
+            compressed.clear();
+            crate::parquet::compression::compress(
+                options.compression,
+                data_page.buffer(),
+                &mut compressed,
+            )?;
+            compressed.len()
+        };
+
+        if best.as_ref().is_none_or(|(best_size, _)| size < *best_size) {
+            best = Some((size, page));
+        }
+    }
+    Ok(best.unwrap().1)
+}
+
 /// Converts an [`Array`] to a [`CompressedPage`] based on options, descriptor and `encoding`.
 pub fn array_to_page(
     array: &dyn Array,
@@ -531,16 +633,36 @@ pub fn array_to_page_simple(
                 encoding,
             );
         },
-        ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
-        ArrowDataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
+        ArrowDataType::Float16 => {
+            let array: &PrimitiveArray<f16> = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_f16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let array = fixed_size_binary::f16_to_fixed_size_binary(array);
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
+        ArrowDataType::Float32 => {
+            return primitive::array_to_page_float::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
+        ArrowDataType::Float64 => {
+            return primitive::array_to_page_float::<f64, f64>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 polars_compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
@@ -910,6 +1032,20 @@ fn array_to_page_nested(
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<i64, i64>(array, options, type_, nested)
         },
+        Float16 => {
+            let array: &PrimitiveArray<f16> = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_f16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let array = fixed_size_binary::f16_to_fixed_size_binary(array);
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
         Float32 => {
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested)
@@ -1090,6 +1226,19 @@ fn array_to_page_nested(
             );
             fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
         },
+        FixedSizeBinary(_) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::nested_array_to_page(array, options, type_, nested, statistics)
+        },
         other => polars_bail!(nyi = "Writing nested parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
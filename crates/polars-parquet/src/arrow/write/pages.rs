@@ -239,9 +239,10 @@ fn to_nested_recursive(
         },
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let type_ = if let ParquetType::GroupType { fields, .. } = type_ {
+            // The repeated `key_value` group holds the key and value fields directly.
+            let kv_fields = if let ParquetType::GroupType { fields, .. } = type_ {
                 if let ParquetType::GroupType { fields, .. } = &fields[0] {
-                    &fields[0]
+                    fields
                 } else {
                     polars_bail!(InvalidOperation:
                         "Parquet type must be a group for a map array",
@@ -252,13 +253,27 @@ fn to_nested_recursive(
                     "Parquet type must be a group for a map array",
                 )
             };
+            let entries = array
+                .field()
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap();
 
             parents.push(Nested::List(ListNested::new(
                 array.offsets().clone(),
                 array.validity().cloned(),
                 is_optional,
             )));
-            to_nested_recursive(array.field().as_ref(), type_, nested, parents)?;
+            // The entries are never null, their repetition is accounted for by the map itself.
+            parents.push(Nested::Struct(StructNested {
+                is_optional: false,
+                validity: None,
+                length: entries.len(),
+            }));
+
+            for (type_, array) in kv_fields.iter().zip(entries.values()) {
+                to_nested_recursive(array.as_ref(), type_, nested, parents.clone())?;
+            }
         },
         _ => {
             parents.push(Nested::Primitive(PrimitiveNested {
@@ -273,24 +288,26 @@ fn to_nested_recursive(
 }
 
 fn expand_list_validity<'a, O: Offset>(
-    array: &'a ListArray<O>,
+    offsets: &OffsetsBuffer<O>,
+    values: &'a dyn Array,
     validity: BitmapState,
     array_stack: &mut Vec<(&'a dyn Array, BitmapState)>,
 ) {
     let BitmapState::SomeSet(list_validity) = validity else {
         array_stack.push((
-            array.values().as_ref(),
+            values,
             match validity {
                 BitmapState::AllSet => BitmapState::AllSet,
                 BitmapState::SomeSet(_) => unreachable!(),
-                BitmapState::AllUnset(_) => BitmapState::AllUnset(array.values().len()),
+                BitmapState::AllUnset(_) => BitmapState::AllUnset(values.len()),
             },
         ));
         return;
     };
 
-    let offsets = array.offsets().buffer();
-    let mut validity = MutableBitmap::with_capacity(array.values().len());
+    let num_lists = offsets.len_proxy();
+    let offsets = offsets.buffer();
+    let mut validity = MutableBitmap::with_capacity(values.len());
     let mut list_validity_iter = list_validity.iter();
 
     // @NOTE: We need to take into account here that the list might only point to a slice of the
@@ -312,13 +329,13 @@ fn expand_list_validity<'a, O: Offset>(
 
         idx += num_zeros;
     }
-    validity.extend_constant(array.values().len() - validity.len(), false);
+    validity.extend_constant(values.len() - validity.len(), false);
 
-    debug_assert_eq!(idx, array.len());
+    debug_assert_eq!(idx, num_lists);
     let validity = validity.freeze();
 
-    debug_assert_eq!(validity.len(), array.values().len());
-    array_stack.push((array.values().as_ref(), BitmapState::SomeSet(validity)));
+    debug_assert_eq!(validity.len(), values.len());
+    array_stack.push((values, BitmapState::SomeSet(validity)));
 }
 
 #[derive(Clone)]
@@ -409,11 +426,21 @@ pub fn to_leaves(array: &dyn Array, leaves: &mut Vec<Box<dyn Array>>) {
             },
             P::List => {
                 let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-                expand_list_validity(array, validity, &mut array_stack);
+                expand_list_validity(
+                    array.offsets(),
+                    array.values().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::LargeList => {
                 let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-                expand_list_validity(array, validity, &mut array_stack);
+                expand_list_validity(
+                    array.offsets(),
+                    array.values().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::FixedSizeList => {
                 let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
@@ -459,7 +486,12 @@ pub fn to_leaves(array: &dyn Array, leaves: &mut Vec<Box<dyn Array>>) {
             },
             P::Map => {
                 let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-                array_stack.push((array.field().as_ref(), validity));
+                expand_list_validity(
+                    array.offsets(),
+                    array.field().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::Null
             | P::Boolean
@@ -594,7 +626,7 @@ mod tests {
     use super::*;
     use crate::parquet::schema::Repetition;
     use crate::parquet::schema::types::{
-        GroupLogicalType, PrimitiveConvertedType, PrimitiveLogicalType,
+        GroupConvertedType, GroupLogicalType, PrimitiveConvertedType, PrimitiveLogicalType,
     };
 
     #[test]
@@ -894,8 +926,8 @@ mod tests {
 
         let type_ = ParquetType::GroupType {
             field_info: FieldInfo {
-                name: "kv".into(),
-                repetition: Repetition::Optional,
+                name: "key_value".into(),
+                repetition: Repetition::Repeated,
                 id: None,
             },
             logical_type: None,
@@ -931,17 +963,8 @@ mod tests {
                 id: None,
             },
             logical_type: Some(GroupLogicalType::Map),
-            converted_type: None,
-            fields: vec![ParquetType::GroupType {
-                field_info: FieldInfo {
-                    name: "map".into(),
-                    repetition: Repetition::Repeated,
-                    id: None,
-                },
-                logical_type: None,
-                converted_type: None,
-                fields: vec![type_],
-            }],
+            converted_type: Some(GroupConvertedType::Map),
+            fields: vec![type_],
         };
 
         let a = to_nested(&array, &type_).unwrap();
@@ -955,7 +978,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
                 vec![
@@ -964,7 +987,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
             ]
@@ -6,8 +6,8 @@ use polars_error::{PolarsResult, polars_bail};
 use super::super::{WriteOptions, utils};
 use crate::arrow::read::schema::is_nullable;
 use crate::arrow::write::utils::ExactSizedIter;
-use crate::parquet::encoding::Encoding;
 use crate::parquet::encoding::delta_bitpacked::encode;
+use crate::parquet::encoding::{Encoding, byte_stream_split};
 use crate::parquet::page::DataPage;
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::PrimitiveStatistics;
@@ -106,6 +106,31 @@ where
     buffer
 }
 
+pub(crate) fn encode_byte_stream_split<T, P>(
+    array: &PrimitiveArray<T>,
+    options: EncodeNullability,
+    mut buffer: Vec<u8>,
+) -> Vec<u8>
+where
+    T: NativeType,
+    P: ParquetNativeType,
+    T: num_traits::AsPrimitive<P>,
+{
+    let is_optional = options.is_optional();
+
+    if is_optional {
+        // append the non-null values
+        let iterator = array.non_null_values_iter().map(|x| x.as_());
+        let iterator = ExactSizedIter::new(iterator, array.len() - array.null_count());
+        byte_stream_split::encode::<P, _>(iterator, &mut buffer)
+    } else {
+        // append all values
+        let iterator = array.values().iter().map(|x| x.as_());
+        byte_stream_split::encode::<P, _>(iterator, &mut buffer)
+    }
+    buffer
+}
+
 pub fn array_to_page_plain<T, P>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
@@ -134,11 +159,35 @@ where
     match encoding {
         Encoding::Plain => array_to_page(array, options, type_, encoding, encode_plain),
         Encoding::DeltaBinaryPacked => array_to_page(array, options, type_, encoding, encode_delta),
+        Encoding::ByteStreamSplit => {
+            array_to_page(array, options, type_, encoding, encode_byte_stream_split)
+        },
         other => polars_bail!(nyi = "Encoding integer as {other:?}"),
     }
     .map(Page::Data)
 }
 
+pub fn array_to_page_float<T, P>(
+    array: &PrimitiveArray<T>,
+    options: WriteOptions,
+    type_: PrimitiveType,
+    encoding: Encoding,
+) -> PolarsResult<Page>
+where
+    T: NativeType,
+    P: ParquetNativeType,
+    T: num_traits::AsPrimitive<P>,
+{
+    match encoding {
+        Encoding::Plain => array_to_page(array, options, type_, encoding, encode_plain),
+        Encoding::ByteStreamSplit => {
+            array_to_page(array, options, type_, encoding, encode_byte_stream_split)
+        },
+        other => polars_bail!(nyi = "Encoding float as {other:?}"),
+    }
+    .map(Page::Data)
+}
+
 pub fn array_to_page<T, P, F: Fn(&PrimitiveArray<T>, EncodeNullability, Vec<u8>) -> Vec<u8>>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, TimeUnit};
+use arrow::datatypes::{
+    ArrowDataType, ArrowSchema, ExtensionType, Field, TimeUnit, UUID_EXTENSION_NAME,
+};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
 use base64::engine::general_purpose;
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_utils::pl_str::PlSmallStr;
 
 use super::super::ARROW_SCHEMA_META_KEY;
@@ -32,6 +34,7 @@ fn convert_dtype(dtype: ArrowDataType) -> ArrowDataType {
     use ArrowDataType as D;
     match dtype {
         D::LargeList(field) => D::LargeList(Box::new(convert_field(*field))),
+        D::Map(field, sorted) => D::Map(Box::new(convert_field(*field)), sorted),
         D::Struct(mut fields) => {
             for field in &mut fields {
                 *field = convert_field(std::mem::take(field))
@@ -115,7 +118,7 @@ fn insert_field_metadata(field: &mut Cow<Field>, options: &ColumnWriteOptions) {
                 .to_mut()
                 .map_dtype_mut(|dtype| *dtype = D::Struct(new_fields));
         },
-        D::List(f) | D::FixedSizeList(f, _) | D::LargeList(f) => {
+        D::List(f) | D::FixedSizeList(f, _) | D::LargeList(f) | D::Map(f, _) => {
             let ChildWriteOptions::ListLike(o) = &options.children else {
                 unreachable!();
             };
@@ -130,6 +133,7 @@ fn insert_field_metadata(field: &mut Cow<Field>, options: &ColumnWriteOptions) {
                         D::List(_) => D::List(child_field),
                         D::LargeList(_) => D::LargeList(child_field),
                         D::FixedSizeList(_, width) => D::FixedSizeList(child_field, *width),
+                        D::Map(_, sorted) => D::Map(child_field, *sorted),
                         _ => unreachable!(),
                     }
                 });
@@ -212,6 +216,11 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
         // no natural representation in parquet; leave it as is.
         // arrow consumers MAY use the arrow schema in the metadata to parse them.
         ArrowDataType::Date64 => (PhysicalType::Int64, None, None),
+        ArrowDataType::Float16 => (
+            PhysicalType::FixedLenByteArray(2),
+            None,
+            Some(PrimitiveLogicalType::Float16),
+        ),
         ArrowDataType::Float32 => (PhysicalType::Float, None, None),
         ArrowDataType::Float64 => (PhysicalType::Double, None, None),
         ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView => {
@@ -329,7 +338,12 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
             return to_parquet_type(&dict_field, options);
         },
         ArrowDataType::FixedSizeBinary(size) => {
-            (PhysicalType::FixedLenByteArray(*size), None, None)
+            let is_uuid = matches!(
+                field.dtype(),
+                ArrowDataType::Extension(ext) if ext.name == UUID_EXTENSION_NAME && *size == 16
+            );
+            let logical_type = is_uuid.then_some(PrimitiveLogicalType::Uuid);
+            (PhysicalType::FixedLenByteArray(*size), None, logical_type)
         },
         ArrowDataType::Decimal(precision, scale) => {
             let precision = *precision;
@@ -410,6 +424,48 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                 field_id,
             ));
         },
+        ArrowDataType::Map(f, _) => {
+            let ArrowDataType::Struct(kv_fields) = f.dtype() else {
+                polars_bail!(InvalidOperation: "map entries must be a struct, got {:?}", f.dtype());
+            };
+            polars_ensure!(
+                kv_fields.len() == 2,
+                InvalidOperation: "map entries must have exactly two fields, got {}", kv_fields.len()
+            );
+
+            let ChildWriteOptions::ListLike(map_write_options) = &options.children else {
+                unreachable!();
+            };
+            let ChildWriteOptions::Struct(kv_write_options) = &map_write_options.child.children
+            else {
+                unreachable!();
+            };
+
+            let mut key = kv_fields[0].clone();
+            key.name = PlSmallStr::from_static("key");
+            key.is_nullable = false;
+            let mut value = kv_fields[1].clone();
+            value.name = PlSmallStr::from_static("value");
+
+            return Ok(ParquetType::from_group(
+                name,
+                repetition,
+                Some(GroupConvertedType::Map),
+                Some(GroupLogicalType::Map),
+                vec![ParquetType::from_group(
+                    PlSmallStr::from_static("key_value"),
+                    Repetition::Repeated,
+                    None,
+                    None,
+                    vec![
+                        to_parquet_type(&key, &kv_write_options.children[0])?,
+                        to_parquet_type(&value, &kv_write_options.children[1])?,
+                    ],
+                    None,
+                )],
+                field_id,
+            ));
+        },
         other => polars_bail!(nyi = "Writing the data type {other:?} is not yet implemented"),
     };
 
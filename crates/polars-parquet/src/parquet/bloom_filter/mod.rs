@@ -1,11 +1,13 @@
-//! API to read and use bloom filters
+//! API to read, write and use bloom filters
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
+pub use read::{deserialize_header, read};
 pub use split_block::{insert, is_in_set};
+pub use write::{optimal_num_bytes, write};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,23 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn write_read() {
+        assert_eq!(optimal_num_bytes(0, 0.05), 32);
+        assert_eq!(optimal_num_bytes(1_000_000, 0.01), 2 * 1024 * 1024);
+
+        let mut bitset = vec![0; optimal_num_bytes(100, 0.05)];
+        for a in 0..100i32 {
+            insert(&mut bitset, hash_native(a));
+        }
+
+        let mut data = vec![];
+        let len = write(&mut data, &bitset).unwrap();
+        assert_eq!(len as usize, data.len());
+
+        let (header_len, num_bytes) = deserialize_header(&data).unwrap().unwrap();
+        assert_eq!(num_bytes, bitset.len());
+        assert_eq!(&data[header_len..], bitset.as_slice());
+    }
 }
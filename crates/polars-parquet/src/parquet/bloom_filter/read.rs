@@ -49,3 +49,24 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Deserializes the header of the bloom filter at the start of `data`.
+///
+/// Returns the length of the header and the length of the bitset that follows it, or `None` if
+/// the algorithm or compression of the bloom filter is not supported.
+/// # Error
+/// Errors if the header can't be deserialized.
+pub fn deserialize_header(mut data: &[u8]) -> ParquetResult<Option<(usize, usize)>> {
+    let len = data.len();
+    let mut prot = TCompactInputProtocol::new(&mut data, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+    let header_len = len - data.len();
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        || header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+    {
+        return Ok(None);
+    }
+
+    Ok(Some((header_len, header.num_bytes.try_into()?)))
+}
@@ -0,0 +1,48 @@
+use std::io::Write;
+
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::ParquetResult;
+
+/// The size of a block of the split-block bloom filter.
+const BLOCK_SIZE: usize = 32;
+/// The maximum size of a bloom filter, as recommended by the specification.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the number of bytes of a bloom filter that holds `ndv` distinct values with a false
+/// positive probability of `fpp`.
+///
+/// The result is a power of two between 32 bytes and 128 MiB.
+pub fn optimal_num_bytes(ndv: u64, fpp: f64) -> usize {
+    // SPEC: see https://github.com/apache/parquet-format/blob/master/BloomFilter.md#sizing-an-sbbf
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil();
+
+    if num_bytes.is_nan() || num_bytes >= MAX_NUM_BYTES as f64 {
+        return MAX_NUM_BYTES;
+    }
+    (num_bytes as usize)
+        .next_power_of_two()
+        .clamp(BLOCK_SIZE, MAX_NUM_BYTES)
+}
+
+/// Writes the header and `bitset` of a split-block bloom filter to `writer`. Returns the number
+/// of bytes written.
+pub fn write<W: Write>(mut writer: &mut W, bitset: &[u8]) -> ParquetResult<u64> {
+    let header = BloomFilterHeader {
+        num_bytes: bitset.len().try_into()?,
+        algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        hash: BloomFilterHash::XXHASH(XxHash {}),
+        compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    };
+
+    let mut protocol = TCompactOutputProtocol::new(&mut writer);
+    let header_len = header.write_to_out_protocol(&mut protocol)? as u64;
+    writer.write_all(bitset)?;
+
+    Ok(header_len + bitset.len() as u64)
+}
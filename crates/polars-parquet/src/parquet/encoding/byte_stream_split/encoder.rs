@@ -0,0 +1,18 @@
+use crate::parquet::types::NativeType;
+
+/// Encodes `values` according to BYTE_STREAM_SPLIT: the `n`-th bytes of all the values form the
+/// `n`-th stream, and the streams are appended to `buffer` one after the other.
+pub fn encode<T: NativeType, I: ExactSizeIterator<Item = T>>(values: I, buffer: &mut Vec<u8>) {
+    let element_size = size_of::<T>();
+    let num_elements = values.len();
+
+    let start = buffer.len();
+    buffer.resize(start + num_elements * element_size, 0);
+    let streams = &mut buffer[start..];
+
+    for (i, value) in values.enumerate() {
+        for (n, byte) in value.to_le_bytes().as_ref().iter().enumerate() {
+            streams[num_elements * n + i] = *byte;
+        }
+    }
+}
@@ -161,6 +161,16 @@ impl ColumnChunkMetadata {
         self.metadata().dictionary_page_offset
     }
 
+    /// Returns the offset for the bloom filter, if any.
+    pub fn bloom_filter_offset(&self) -> Option<i64> {
+        self.metadata().bloom_filter_offset
+    }
+
+    /// Returns the length in bytes of the bloom filter (header and bitset), if known.
+    pub fn bloom_filter_length(&self) -> Option<i32> {
+        self.metadata().bloom_filter_length
+    }
+
     /// Returns the encoding for this column
     pub fn column_encoding(&self) -> &Vec<Encoding> {
         &self.metadata().encodings
@@ -172,6 +182,24 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Returns the offset and length in bytes of the column index (page statistics) of this
+    /// column chunk within the file, if it has one.
+    pub fn column_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.column_index_offset,
+            self.column_chunk.column_index_length,
+        )
+    }
+
+    /// Returns the offset and length in bytes of the offset index (page locations) of this
+    /// column chunk within the file, if it has one.
+    pub fn offset_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.offset_index_offset,
+            self.column_chunk.offset_index_length,
+        )
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
@@ -214,3 +242,9 @@ pub(super) fn column_metadata_byte_range(
     let len = column_metadata.total_compressed_size as u64;
     offset..offset.checked_add(len).unwrap()
 }
+
+fn index_byte_range(offset: Option<i64>, length: Option<i32>) -> Option<core::ops::Range<u64>> {
+    let offset = u64::try_from(offset?).ok()?;
+    let length = u64::try_from(length?).ok()?;
+    Some(offset..offset.checked_add(length)?)
+}
@@ -10,7 +10,7 @@ pub use column_chunk_metadata::ColumnChunkMetadata;
 pub use column_descriptor::{ColumnDescriptor, Descriptor};
 pub use column_order::ColumnOrder;
 pub use file_metadata::{FileMetadata, KeyValue};
-pub use row_metadata::RowGroupMetadata;
+pub use row_metadata::{RowGroupMetadata, SortingColumn};
 pub use schema_descriptor::SchemaDescriptor;
 pub use sort::*;
 
@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use hashbrown::hash_map::RawEntryMut;
-use polars_parquet_format::{RowGroup, SortingColumn};
+use polars_parquet_format::RowGroup;
+pub use polars_parquet_format::SortingColumn;
 use polars_utils::aliases::{InitHashMaps, PlHashMap};
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::pl_str::PlSmallStr;
@@ -0,0 +1,68 @@
+//! Reading of the [page index](https://github.com/apache/parquet-format/blob/master/PageIndex.md)
+//! of column chunks.
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::schema::types::PrimitiveType;
+use crate::parquet::statistics::{ParquetStatistics, Statistics};
+
+/// Deserializes the [`ColumnIndex`] of a column chunk from the bytes at
+/// [`crate::parquet::metadata::ColumnChunkMetadata::column_index_byte_range`].
+pub fn deserialize_column_index(mut data: &[u8]) -> ParquetResult<ColumnIndex> {
+    let max_size = max_size(data);
+    let mut prot = TCompactInputProtocol::new(&mut data, max_size);
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes the [`OffsetIndex`] of a column chunk from the bytes at
+/// [`crate::parquet::metadata::ColumnChunkMetadata::offset_index_byte_range`].
+pub fn deserialize_offset_index(mut data: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = max_size(data);
+    let mut prot = TCompactInputProtocol::new(&mut data, max_size);
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
+
+// The indexes mostly consist of lists, of which every element takes at least one byte but is
+// accounted for as a `usize` by the protocol.
+fn max_size(data: &[u8]) -> usize {
+    data.len() * (size_of::<usize>() + 1) + 1024
+}
+
+/// Decodes the statistics of every data page in a [`ColumnIndex`].
+///
+/// Pages that only contain nulls have no min or max value.
+pub fn deserialize_page_statistics(
+    index: &ColumnIndex,
+    primitive_type: &PrimitiveType,
+) -> ParquetResult<Vec<Statistics>> {
+    let num_pages = index.null_pages.len();
+    if index.min_values.len() != num_pages
+        || index.max_values.len() != num_pages
+        || index
+            .null_counts
+            .as_ref()
+            .is_some_and(|nc| nc.len() != num_pages)
+    {
+        return Err(ParquetError::oos(
+            "The column index has a different number of values per page",
+        ));
+    }
+
+    (0..num_pages)
+        .map(|i| {
+            let is_null_page = index.null_pages[i];
+            let statistics = ParquetStatistics {
+                max: None,
+                min: None,
+                null_count: index.null_counts.as_ref().map(|nc| nc[i]),
+                distinct_count: None,
+                max_value: (!is_null_page).then(|| index.max_values[i].clone()),
+                min_value: (!is_null_page).then(|| index.min_values[i].clone()),
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+            Statistics::deserialize(&statistics, primitive_type.clone())
+        })
+        .collect()
+}
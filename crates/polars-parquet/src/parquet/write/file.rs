@@ -9,7 +9,7 @@ use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
-use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
+use crate::parquet::metadata::{SchemaDescriptor, SortingColumn, ThriftFileMetadata};
 use crate::parquet::write::State;
 use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
 
@@ -173,6 +173,61 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes a row group to the file, followed by the bloom filters of its columns.
+    ///
+    /// `bloom_filters` holds the bitset of the bloom filter of each column of the row group, if
+    /// it has one.
+    ///
+    /// This call is IO-bounded
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: &[Option<Vec<u8>>],
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write(row_group)?;
+
+        let group = self.row_groups.last_mut().unwrap();
+        if bloom_filters.len() != group.columns.len() {
+            return Err(ParquetError::InvalidParameter(format!(
+                "The number of bloom filters ({}) must equal the number of columns ({})",
+                bloom_filters.len(),
+                group.columns.len()
+            )));
+        }
+
+        for (column, bitset) in group.columns.iter_mut().zip(bloom_filters) {
+            let Some(bitset) = bitset else {
+                continue;
+            };
+            let offset = self.offset;
+            self.offset += crate::parquet::bloom_filter::write(&mut self.writer, bitset)?;
+
+            let metadata = column.meta_data.as_mut().unwrap();
+            metadata.bloom_filter_offset = Some(offset as i64);
+            metadata.bloom_filter_length = Some((self.offset - offset) as i32);
+        }
+        Ok(())
+    }
+
+    /// Sets the columns that the rows of the last written row group are sorted by.
+    pub fn set_sorting_columns(
+        &mut self,
+        sorting_columns: Vec<SortingColumn>,
+    ) -> ParquetResult<()> {
+        let Some(group) = self.row_groups.last_mut() else {
+            return Err(ParquetError::InvalidParameter(
+                "Sorting columns can only be set after writing a row group".to_string(),
+            ));
+        };
+        group.sorting_columns = (!sorting_columns.is_empty()).then_some(sorting_columns);
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> ParquetResult<u64> {
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
@@ -82,6 +84,21 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    pub fn scan_avro(
+        sources: ScanSources,
+        options: AvroScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Avro { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
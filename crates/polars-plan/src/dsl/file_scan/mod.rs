@@ -3,6 +3,8 @@ use std::sync::Mutex;
 
 use deletion::DeletionFilesList;
 use polars_core::utils::get_numeric_upcast_supertype_lossless;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
@@ -50,6 +52,9 @@ pub enum FileScanDsl {
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -88,6 +93,9 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -311,6 +319,11 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "avro")]
+        Avro {
+            options: &'a polars_io::avro::AvroScanOptions,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -348,6 +361,9 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { options } => FileScanEqHashWrap::Avro { options },
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
@@ -503,6 +519,18 @@ impl CastColumnsPolicy {
             return self.should_cast_column(column_name, target_inner, incoming_inner);
         }
 
+        #[cfg(feature = "dtype-map")]
+        if let DataType::Map(target_key, target_value) = target_dtype {
+            let DataType::Map(incoming_key, incoming_value) = incoming_dtype else {
+                return mismatch_err("");
+            };
+
+            let should_cast_key = self.should_cast_column(column_name, target_key, incoming_key)?;
+            let should_cast_value =
+                self.should_cast_column(column_name, target_value, incoming_value)?;
+            return Ok(should_cast_key || should_cast_value);
+        }
+
         // Eq here should be cheap as we have intercepted all nested types above.
 
         debug_assert!(!target_dtype.is_nested());
@@ -0,0 +1,36 @@
+use std::fmt;
+
+use super::FunctionExpr;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum MapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    Entries,
+    FromLists,
+}
+
+impl fmt::Display for MapFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        use MapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            Entries => "entries",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<MapFunction> for FunctionExpr {
+    fn from(value: MapFunction) -> Self {
+        Self::MapExpr(value)
+    }
+}
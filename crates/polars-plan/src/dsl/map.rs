@@ -0,0 +1,40 @@
+use crate::dsl::function_expr::MapFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Map`].
+pub struct MapNameSpace(pub Expr);
+
+impl MapNameSpace {
+    /// Get the value belonging to `key` in every map, or null if the key is not present.
+    pub fn get(self, key: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::MapExpr(MapFunction::Get), key)
+    }
+
+    /// Check whether every map contains `key`.
+    pub fn contains_key(self, key: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::MapExpr(MapFunction::ContainsKey), key)
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::Keys))
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0.map_unary(FunctionExpr::MapExpr(MapFunction::Values))
+    }
+
+    /// Get the entries of every map as a list of `{key, value}` structs.
+    pub fn entries(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::MapExpr(MapFunction::Entries))
+    }
+}
+
+/// Create a map column by zipping a list column of keys with a list column of values.
+pub fn map_from_lists(keys: Expr, values: Expr) -> Expr {
+    keys.map_binary(FunctionExpr::MapExpr(MapFunction::FromLists), values)
+}
@@ -26,6 +26,8 @@ mod from;
 pub mod function_expr;
 pub mod functions;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod match_to_schema;
 #[cfg(feature = "meta")]
 mod meta;
@@ -56,6 +58,8 @@ pub use expr::*;
 pub use function_expr::*;
 pub use functions::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 pub use match_to_schema::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
@@ -919,6 +923,23 @@ impl Expr {
         self.map_unary(FunctionExpr::ApproxNUnique)
     }
 
+    /// Get an approximation of the `quantile` with a t-digest.
+    ///
+    /// The `accuracy` is the targeted error in rank, e.g. `0.01` for an
+    /// estimate within 1% of the exact rank. Binary input is interpreted as
+    /// sketches created by [`Expr::approx_quantile_sketch`], which are merged.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: f64, accuracy: f64) -> Self {
+        self.map_unary(FunctionExpr::ApproxQuantile { quantile, accuracy })
+    }
+
+    /// Get a serialized t-digest of the values, to be combined later with
+    /// [`Expr::approx_quantile`].
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile_sketch(self, accuracy: f64) -> Self {
+        self.map_unary(FunctionExpr::ApproxQuantileSketch { accuracy })
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1634,6 +1655,12 @@ impl Expr {
         array::ArrayNameSpace(self)
     }
 
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
     /// Get the [`CategoricalNameSpace`].
     #[cfg(feature = "dtype-categorical")]
     pub fn cat(self) -> cat::CategoricalNameSpace {
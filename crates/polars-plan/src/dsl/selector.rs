@@ -385,7 +385,7 @@ impl DataTypeSelector {
             Self::Integer => dtype.is_integer(),
             Self::UnsignedInteger => dtype.is_unsigned_integer(),
             Self::SignedInteger => dtype.is_signed_integer(),
-            Self::Float => dtype.is_float(),
+            Self::Float => dtype.is_float() || dtype.is_float16(),
             Self::Enum => dtype.is_enum(),
             Self::Categorical => dtype.is_categorical(),
             Self::Nested => dtype.is_nested(),
@@ -455,7 +455,9 @@ impl DataTypeSelector {
             Self::SignedInteger => {
                 dtype_selector(schema, ignored_columns, |dtype| dtype.is_signed_integer())
             },
-            Self::Float => dtype_selector(schema, ignored_columns, |dtype| dtype.is_float()),
+            Self::Float => dtype_selector(schema, ignored_columns, |dtype| {
+                dtype.is_float() || dtype.is_float16()
+            }),
             Self::Enum => dtype_selector(schema, ignored_columns, |dtype| dtype.is_enum()),
             Self::Categorical => {
                 dtype_selector(schema, ignored_columns, |dtype| dtype.is_categorical())
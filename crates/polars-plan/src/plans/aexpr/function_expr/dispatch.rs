@@ -13,6 +13,18 @@ pub(super) fn approx_n_unique(s: &Column) -> PolarsResult<Column> {
         .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
 }
 
+#[cfg(feature = "approx_quantile")]
+pub(super) fn approx_quantile(s: &Column, quantile: f64, accuracy: f64) -> PolarsResult<Column> {
+    let s = s.as_materialized_series();
+    polars_ops::series::approx_quantile(s, quantile, accuracy).map(Column::from)
+}
+
+#[cfg(feature = "approx_quantile")]
+pub(super) fn approx_quantile_sketch(s: &Column, accuracy: f64) -> PolarsResult<Column> {
+    let s = s.as_materialized_series();
+    polars_ops::series::approx_quantile_sketch(s, accuracy).map(Column::from)
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &[Column], null_behavior: NullBehavior) -> PolarsResult<Column> {
     let s1 = s[0].as_materialized_series();
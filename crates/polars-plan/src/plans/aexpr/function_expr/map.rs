@@ -0,0 +1,135 @@
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRMapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    Entries,
+    FromLists,
+}
+
+impl IRMapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRMapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| Ok(map_key_value_dtypes(dt)?.1.clone())),
+            ContainsKey => mapper.with_dtype(DataType::Boolean),
+            Keys => mapper.try_map_dtype(|dt| {
+                Ok(DataType::List(Box::new(
+                    map_key_value_dtypes(dt)?.0.clone(),
+                )))
+            }),
+            Values => mapper.try_map_dtype(|dt| {
+                Ok(DataType::List(Box::new(
+                    map_key_value_dtypes(dt)?.1.clone(),
+                )))
+            }),
+            Entries => mapper.try_map_dtype(|dt| {
+                let (key, value) = map_key_value_dtypes(dt)?;
+                Ok(DataType::List(Box::new(map_entries_dtype(
+                    key.clone(),
+                    value.clone(),
+                ))))
+            }),
+            FromLists => {
+                let args = mapper.args();
+                let key = args[0].dtype().inner_dtype().ok_or_else(|| {
+                    polars_err!(
+                        op = "map.from_lists",
+                        got = args[0].dtype(),
+                        expected = "List"
+                    )
+                })?;
+                let value = args[1].dtype().inner_dtype().ok_or_else(|| {
+                    polars_err!(
+                        op = "map.from_lists",
+                        got = args[1].dtype(),
+                        expected = "List"
+                    )
+                })?;
+                Ok(Field::new(
+                    args[0].name().clone(),
+                    DataType::Map(Box::new(key.clone()), Box::new(value.clone())),
+                ))
+            },
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRMapFunction as M;
+        match self {
+            M::Get | M::ContainsKey | M::Keys | M::Values | M::Entries | M::FromLists => {
+                FunctionOptions::elementwise()
+            },
+        }
+    }
+}
+
+fn map_key_value_dtypes(dtype: &DataType) -> PolarsResult<(&DataType, &DataType)> {
+    match dtype {
+        DataType::Map(key, value) => Ok((key, value)),
+        dt => polars_bail!(op = "map", got = dt, expected = "Map"),
+    }
+}
+
+impl Display for IRMapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRMapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            Entries => "entries",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<IRMapFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRMapFunction) -> Self {
+        use IRMapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            ContainsKey => map_as_slice!(contains_key),
+            Keys => map!(keys),
+            Values => map!(values),
+            Entries => map!(entries),
+            FromLists => map_as_slice!(from_lists),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    ca.map_get(s[1].as_materialized_series()).map(Column::from)
+}
+
+pub(super) fn contains_key(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    ca.map_contains_key(s[1].as_materialized_series())
+        .map(|ca| ca.into_column())
+}
+
+pub(super) fn keys(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_keys().map(|ca| ca.into_column())
+}
+
+pub(super) fn values(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_values().map(|ca| ca.into_column())
+}
+
+pub(super) fn entries(s: &Column) -> PolarsResult<Column> {
+    Ok(s.map()?.map_entries().into_column())
+}
+
+pub(super) fn from_lists(s: &[Column]) -> PolarsResult<Column> {
+    let keys = s[0].list()?;
+    let values = s[1].list()?;
+    polars_ops::chunked_array::map_from_lists(keys, values).map(|ca| ca.into_column())
+}
@@ -40,6 +40,8 @@ mod index_of;
 mod list;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod nan;
 #[cfg(feature = "peaks")]
 mod peaks;
@@ -86,6 +88,8 @@ pub use correlation::IRCorrelationMethod;
 #[cfg(feature = "fused")]
 pub use fused::FusedOperator;
 pub use list::IRListFunction;
+#[cfg(feature = "dtype-map")]
+pub use map::IRMapFunction;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -130,6 +134,8 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(IRCategoricalFunction),
     ListExpr(IRListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(IRMapFunction),
     #[cfg(feature = "strings")]
     StringExpr(IRStringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -263,6 +269,15 @@ pub enum IRFunctionExpr {
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile {
+        quantile: f64,
+        accuracy: f64,
+    },
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantileSketch {
+        accuracy: f64,
+    },
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
@@ -427,6 +442,8 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -604,6 +621,13 @@ impl Hash for IRFunctionExpr {
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => {},
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { quantile, accuracy } => {
+                quantile.to_bits().hash(state);
+                accuracy.to_bits().hash(state);
+            },
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileSketch { accuracy } => accuracy.to_bits().hash(state),
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
@@ -720,6 +744,8 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -814,6 +840,10 @@ impl Display for IRFunctionExpr {
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => "approx_quantile",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileSketch { .. } => "approx_quantile_sketch",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
@@ -1005,6 +1035,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             ListExpr(func) => func.into(),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
             #[cfg(feature = "dtype-struct")]
@@ -1197,6 +1229,12 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { quantile, accuracy } => {
+                map!(dispatch::approx_quantile, quantile, accuracy)
+            },
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileSketch { accuracy } => map!(dispatch::approx_quantile_sketch, accuracy),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
@@ -1395,6 +1433,8 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             F::Categorical(e) => e.function_options(),
             F::ListExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-map")]
+            F::MapExpr(e) => e.function_options(),
             #[cfg(feature = "strings")]
             F::StringExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-struct")]
@@ -1492,6 +1532,10 @@ impl IRFunctionExpr {
             F::UniqueCounts => FunctionOptions::groupwise(),
             #[cfg(feature = "approx_unique")]
             F::ApproxNUnique => FunctionOptions::aggregation(),
+            #[cfg(feature = "approx_quantile")]
+            F::ApproxQuantile { .. } | F::ApproxQuantileSketch { .. } => {
+                FunctionOptions::aggregation()
+            },
             F::Coalesce => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION)
                 .with_supertyping(Default::default()),
@@ -20,6 +20,8 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -165,6 +167,10 @@ impl IRFunctionExpr {
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile { .. } => mapper.map_dtype(polars_ops::series::approx_quantile_dtype),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantileSketch { .. } => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -500,6 +506,7 @@ impl<'a> FieldsMapper<'a> {
             dt @ DataType::Duration(_) => dt.clone(),
             #[cfg(feature = "dtype-time")]
             dt @ DataType::Time => dt.clone(),
+            DataType::Float16 => DataType::Float16,
             DataType::Float32 => DataType::Float32,
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(..) => DataType::Float64,
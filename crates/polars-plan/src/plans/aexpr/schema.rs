@@ -718,6 +718,8 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
         },
         (Float32, Float64) => Float64,
         (Float32, _) => Float32,
+        (Float16, r @ (Float32 | Float64)) => r.clone(),
+        (Float16, _) => Float16,
         #[cfg(feature = "dtype-decimal")]
         (Decimal(_, Some(scale_left)), Decimal(_, _)) => {
             let scale = _get_decimal_scale_div(*scale_left);
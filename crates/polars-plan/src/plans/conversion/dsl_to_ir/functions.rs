@@ -195,6 +195,18 @@ pub(super) fn convert_functions(
                 L::ToStruct(list_to_struct_args) => IL::ToStruct(list_to_struct_args),
             })
         },
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(map_function) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            I::MapExpr(match map_function {
+                M::Get => IM::Get,
+                M::ContainsKey => IM::ContainsKey,
+                M::Keys => IM::Keys,
+                M::Values => IM::Values,
+                M::Entries => IM::Entries,
+                M::FromLists => IM::FromLists,
+            })
+        },
         #[cfg(feature = "strings")]
         F::StringExpr(string_function) => {
             use {IRStringFunction as IS, StringFunction as S};
@@ -789,6 +801,16 @@ pub(super) fn convert_functions(
         F::UniqueCounts => I::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => I::ApproxNUnique,
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile { quantile, accuracy } => {
+            polars_ops::series::validate_approx_quantile_args(Some(quantile), accuracy)?;
+            I::ApproxQuantile { quantile, accuracy }
+        },
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantileSketch { accuracy } => {
+            polars_ops::series::validate_approx_quantile_args(None, accuracy)?;
+            I::ApproxQuantileSketch { accuracy }
+        },
         F::Coalesce => I::Coalesce,
         F::ShrinkType => I::ShrinkType,
         #[cfg(feature = "diff")]
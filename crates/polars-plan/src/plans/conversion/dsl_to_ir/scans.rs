@@ -49,6 +49,10 @@ pub(super) fn dsl_to_ir(
             FileScanDsl::Ipc { .. } => {
                 sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
             #[cfg(feature = "json")]
@@ -189,7 +193,7 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -315,6 +319,45 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.as_paths().unwrap()[0].to_str())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let (metadata, _) = polars_io::avro::read_metadata_from_bytes(&memslice)?;
+    let reader_schema = Arc::new(arrow::io::avro::read::infer_schema(&metadata.record)?);
+
+    Ok(FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(reader_schema.as_ref()), row_index)?,
+        Some(Either::Left(reader_schema)),
+        (None, 0),
+    ))
+}
+
 #[cfg(feature = "csv")]
 pub fn csv_file_info(
     sources: &ScanSources,
@@ -327,6 +370,7 @@ pub fn csv_file_info(
     use polars_core::error::feature_gated;
     use polars_core::{POOL, config};
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
+    use polars_io::csv::read::{leading_lines, maybe_transcode_prefix};
     use polars_io::utils::get_reader_bytes;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
@@ -362,7 +406,23 @@ pub fn csv_file_info(
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let transcoded = &mut vec![];
+        let parse_options = &csv_options.parse_options;
+        // Only the lines that are used for schema inference are transcoded.
+        let mut reader = std::io::Cursor::new(maybe_transcode_prefix(
+            maybe_decompress_bytes(&memslice, owned)?,
+            parse_options.encoding,
+            parse_options.quote_char,
+            parse_options.eol_char,
+            leading_lines(
+                csv_options.skip_lines,
+                csv_options.skip_rows,
+                csv_options.has_header,
+                csv_options.skip_rows_after_header,
+                csv_options.infer_schema_length,
+            ),
+            transcoded,
+        )?);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -578,6 +638,12 @@ impl SourcesToFileInfo {
                     },
                 )
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options } => (
+                scans::avro_file_info(sources, unified_scan_args.row_index.as_ref(), cloud_options)
+                    .map_err(|e| e.context(failed_here!(avro scan)))?,
+                FileScanIR::Avro { options },
+            ),
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
                 // TODO: This is a hack. We conditionally set `allow_missing_columns` to
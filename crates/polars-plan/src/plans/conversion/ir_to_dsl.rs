@@ -403,6 +403,18 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::ToStruct(list_to_struct_args) => L::ToStruct(list_to_struct_args),
             })
         },
+        #[cfg(feature = "dtype-map")]
+        IF::MapExpr(f) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            F::MapExpr(match f {
+                IM::Get => M::Get,
+                IM::ContainsKey => M::ContainsKey,
+                IM::Keys => M::Keys,
+                IM::Values => M::Values,
+                IM::Entries => M::Entries,
+                IM::FromLists => M::FromLists,
+            })
+        },
         #[cfg(feature = "strings")]
         IF::StringExpr(f) => {
             use {IRStringFunction as IB, StringFunction as B};
@@ -914,6 +926,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::UniqueCounts => F::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         IF::ApproxNUnique => F::ApproxNUnique,
+        #[cfg(feature = "approx_quantile")]
+        IF::ApproxQuantile { quantile, accuracy } => F::ApproxQuantile { quantile, accuracy },
+        #[cfg(feature = "approx_quantile")]
+        IF::ApproxQuantileSketch { accuracy } => F::ApproxQuantileSketch { accuracy },
         IF::Coalesce => F::Coalesce,
         IF::ShrinkType => F::ShrinkType,
         #[cfg(feature = "diff")]
@@ -79,6 +79,10 @@ impl IR {
                     } => FileScanDsl::Ipc {
                         options: options.clone(),
                     },
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { options } => FileScanDsl::Avro {
+                        options: options.clone(),
+                    },
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
                         dataset_object,
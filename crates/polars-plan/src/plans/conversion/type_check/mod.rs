@@ -49,9 +49,60 @@ impl OptimizationRule for TypeCheckRule {
             #[cfg(feature = "parquet")]
             IR::Sink { input: _, payload } => {
                 use polars_io::prelude::{
-                    ChildFieldOverwrites, ParquetFieldOverwrites, ParquetWriteOptions,
+                    ChildFieldOverwrites, ParquetEncoding, ParquetFieldOverwrites,
+                    ParquetWriteOptions,
                 };
 
+                /// Checks that the encoding of `o` can be written for a column of `dtype`. The
+                /// `dtype` is `None` for nested fields, which are always written with the default
+                /// encodings.
+                fn type_check_parquet_encoding(
+                    o: &ParquetFieldOverwrites,
+                    dtype: Option<&DataType>,
+                ) -> PolarsResult<()> {
+                    let Some(encoding) = o.encoding else {
+                        return Ok(());
+                    };
+                    if matches!(
+                        encoding,
+                        ParquetEncoding::Plain
+                            | ParquetEncoding::Dictionary
+                            | ParquetEncoding::Adaptive
+                    ) {
+                        return Ok(());
+                    }
+
+                    let Some(dtype) = dtype else {
+                        polars_bail!(InvalidOperation: "parquet encoding {encoding:?} can only be given for top-level columns");
+                    };
+
+                    use DataType as D;
+                    let is_integer = matches!(
+                        dtype,
+                        D::Int8
+                            | D::Int16
+                            | D::Int32
+                            | D::Int64
+                            | D::UInt8
+                            | D::UInt16
+                            | D::UInt32
+                            | D::UInt64
+                    ) || dtype.is_temporal();
+                    let is_valid = match encoding {
+                        ParquetEncoding::DeltaBinaryPacked => is_integer,
+                        ParquetEncoding::ByteStreamSplit => is_integer || dtype.is_float(),
+                        ParquetEncoding::DeltaLengthByteArray | ParquetEncoding::DeltaByteArray => {
+                            matches!(dtype, D::String | D::Binary)
+                        },
+                        _ => true,
+                    };
+                    polars_ensure!(
+                        is_valid,
+                        InvalidOperation: "parquet encoding {encoding:?} is not supported for columns of type {dtype}"
+                    );
+                    Ok(())
+                }
+
                 fn type_check_parquet_field_overwrites(
                     field_overwrites: &[ParquetFieldOverwrites],
                     schema: &Schema,
@@ -104,6 +155,7 @@ impl OptimizationRule for TypeCheckRule {
                             polars_bail!(InvalidOperation: "duplicate parquet field overwrite for struct field `{name}`");
                         }
 
+                        type_check_parquet_encoding(o, (!dtype.is_nested()).then_some(dtype))?;
                         push_children(&mut stack, &o.children, dtype)?;
                     }
 
@@ -113,6 +165,7 @@ impl OptimizationRule for TypeCheckRule {
                                 if o.name.is_some() {
                                     polars_bail!(InvalidOperation: "parquet field overwrite list child cannot have name");
                                 };
+                                type_check_parquet_encoding(o, None)?;
                                 push_children(&mut stack, &o.children, dt)?;
                             },
                             Item::Struct(fields, os) => {
@@ -134,6 +187,7 @@ impl OptimizationRule for TypeCheckRule {
                                         polars_bail!(InvalidOperation: "duplicate parquet field overwrite for struct field `{name}`");
                                     }
 
+                                    type_check_parquet_encoding(o, None)?;
                                     push_children(&mut stack, &o.children, field.dtype())?;
                                 }
                             },
@@ -4,12 +4,13 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "avro"
 ))]
 use polars_core::error::feature_gated;
 #[cfg(any(feature = "json", feature = "parquet"))]
 use polars_io::SerReader;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(feature = "parquet", feature = "json", feature = "avro"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetReader;
@@ -30,7 +31,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     )))]
     {
         unreachable!()
@@ -40,7 +42,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
@@ -57,6 +60,8 @@ pub fn count_rows(
             ),
             #[cfg(feature = "json")]
             FileScanIR::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            #[cfg(feature = "avro")]
+            FileScanIR::Avro { .. } => count_rows_avro(sources, cloud_options),
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset { .. } => unreachable!(),
             FileScanIR::Anonymous { .. } => {
@@ -88,6 +93,7 @@ fn count_all_rows_csv(
                 parse_options.quote_char,
                 parse_options.comment_prefix.as_ref(),
                 parse_options.eol_char,
+                parse_options.encoding,
                 options.has_header,
                 options.skip_lines,
                 options.skip_rows,
@@ -96,12 +102,13 @@ fn count_all_rows_csv(
             _ => {
                 let memslice = source.to_memslice()?;
 
-                polars_io::csv::read::count_rows_from_slice_par(
+                polars_io::csv::read::count_rows_from_slice_transcoded(
                     &memslice[..],
                     parse_options.separator,
                     parse_options.quote_char,
                     parse_options.comment_prefix.as_ref(),
                     parse_options.eol_char,
+                    parse_options.encoding,
                     options.has_header,
                     options.skip_lines,
                     options.skip_rows,
@@ -254,3 +261,46 @@ pub(super) fn count_rows_ndjson(
         })
         .sum()
 }
+
+#[cfg(feature = "avro")]
+pub(super) fn count_rows_avro(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            polars_io::avro::count_rows(&memslice)
+        })
+        .sum()
+}
@@ -158,6 +158,9 @@ impl OptimizationRule for ExpandDatasets {
                                     metadata: None,
                                 },
 
+                                #[cfg(feature = "avro")]
+                                FileScanDsl::Avro { options } => FileScanIR::Avro { options },
+
                                 #[cfg(feature = "parquet")]
                                 FileScanDsl::Parquet { options } => FileScanIR::Parquet {
                                     options,
@@ -230,6 +230,9 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScanIR::Ipc { .. } => true,
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { .. } => true,
+
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
 
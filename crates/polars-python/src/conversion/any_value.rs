@@ -73,6 +73,7 @@ pub(crate) fn any_value_into_py_object<'py>(
         AnyValue::Int32(v) => v.into_bound_py_any(py),
         AnyValue::Int64(v) => v.into_bound_py_any(py),
         AnyValue::Int128(v) => v.into_bound_py_any(py),
+        AnyValue::Float16(v) => v.to_f32().into_bound_py_any(py),
         AnyValue::Float32(v) => v.into_bound_py_any(py),
         AnyValue::Float64(v) => v.into_bound_py_any(py),
         AnyValue::Null => py.None().into_bound_py_any(py),
@@ -16,6 +16,8 @@ use polars::frame::row::Row;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars::io::utils::compression::ExternalCompression;
 use polars::prelude::deletion::DeletionFilesList;
 use polars::series::ops::NullBehavior;
 use polars_core::utils::arrow::array::Array;
@@ -230,6 +232,10 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Int128"))?;
                 class.call0()
             },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16"))?;
+                class.call0()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32"))?;
                 class.call0()
@@ -266,6 +272,20 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let inner = Wrap(*inner.clone());
                 class.call1((&inner,))
             },
+            DataType::Map(key, value) => {
+                let class = pl.getattr(intern!(py, "Map"))?;
+                let key = Wrap(*key.clone());
+                let value = Wrap(*value.clone());
+                class.call1((&key, &value))
+            },
+            DataType::FixedSizeBinary(size) => {
+                let class = pl.getattr(intern!(py, "FixedSizeBinary"))?;
+                class.call1((*size,))
+            },
+            DataType::Uuid => {
+                let class = pl.getattr(intern!(py, "Uuid"))?;
+                class.call0()
+            },
             DataType::Date => {
                 let class = pl.getattr(intern!(py, "Date"))?;
                 class.call0()
@@ -368,6 +388,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
@@ -382,6 +403,9 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Decimal" => DataType::Decimal(None, None), // "none" scale => "infer"
                     "List" => DataType::List(Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
+                    "Map" => DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null)),
+                    "FixedSizeBinary" => DataType::FixedSizeBinary(0),
+                    "Uuid" => DataType::Uuid,
                     "Struct" => DataType::Struct(vec![]),
                     "Null" => DataType::Null,
                     #[cfg(feature = "object")]
@@ -403,6 +427,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
@@ -458,6 +483,19 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let size = size.extract::<usize>()?;
                 DataType::Array(Box::new(inner.0), size)
             },
+            "Map" => {
+                let key = ob.getattr(intern!(py, "key")).unwrap();
+                let value = ob.getattr(intern!(py, "value")).unwrap();
+                let key = key.extract::<Wrap<DataType>>()?;
+                let value = value.extract::<Wrap<DataType>>()?;
+                DataType::Map(Box::new(key.0), Box::new(value.0))
+            },
+            "FixedSizeBinary" => {
+                let size = ob.getattr(intern!(py, "size")).unwrap();
+                let size = size.extract::<usize>()?;
+                DataType::FixedSizeBinary(size)
+            },
+            "Uuid" => DataType::Uuid,
             "Struct" => {
                 let fields = ob.getattr(intern!(py, "fields"))?;
                 let fields = fields
@@ -880,9 +918,13 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "windows-1252" => CsvEncoding::Windows1252,
+            "utf16-le" => CsvEncoding::Utf16Le,
+            "utf16-be" => CsvEncoding::Utf16Be,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be'}}, got {v}",
                 )));
             },
         };
@@ -1417,6 +1459,27 @@ pub(crate) fn parse_fill_null_strategy(
     Ok(parsed)
 }
 
+#[cfg(feature = "parquet")]
+impl<'py> FromPyObject<'py> for Wrap<ParquetEncoding> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "plain" => ParquetEncoding::Plain,
+            "dictionary" => ParquetEncoding::Dictionary,
+            "delta_binary_packed" => ParquetEncoding::DeltaBinaryPacked,
+            "delta_length_byte_array" => ParquetEncoding::DeltaLengthByteArray,
+            "delta_byte_array" => ParquetEncoding::DeltaByteArray,
+            "byte_stream_split" => ParquetEncoding::ByteStreamSplit,
+            "adaptive" => ParquetEncoding::Adaptive,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "parquet `encoding` must be one of {{'plain', 'dictionary', 'delta_binary_packed', 'delta_length_byte_array', 'delta_byte_array', 'byte_stream_split', 'adaptive'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "parquet")]
 pub(crate) fn parse_parquet_compression(
     compression: &str,
@@ -1459,6 +1522,34 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+pub(crate) fn parse_external_compression(
+    compression: &str,
+    compression_level: Option<i32>,
+) -> PyResult<ExternalCompression> {
+    let parsed = match compression {
+        "uncompressed" => ExternalCompression::Uncompressed,
+        "gzip" => ExternalCompression::Gzip {
+            level: compression_level
+                .map(|lvl| {
+                    u32::try_from(lvl).map_err(|_| {
+                        PyValueError::new_err(format!("invalid gzip compression level {lvl}"))
+                    })
+                })
+                .transpose()?,
+        },
+        "zstd" => ExternalCompression::Zstd {
+            level: compression_level,
+        },
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`compression` must be one of {{'uncompressed', 'gzip', 'zstd'}}, got {e}",
+            )));
+        },
+    };
+    Ok(parsed)
+}
+
 pub(crate) fn strings_to_pl_smallstr<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
@@ -429,6 +429,19 @@ impl PyExpr {
         self.inner.clone().approx_n_unique().into()
     }
 
+    #[cfg(feature = "approx_quantile")]
+    fn approx_quantile(&self, quantile: f64, accuracy: f64) -> Self {
+        self.inner
+            .clone()
+            .approx_quantile(quantile, accuracy)
+            .into()
+    }
+
+    #[cfg(feature = "approx_quantile")]
+    fn approx_quantile_sketch(&self, accuracy: f64) -> Self {
+        self.inner.clone().approx_quantile_sketch(accuracy).into()
+    }
+
     fn is_first_distinct(&self) -> Self {
         self.inner.clone().is_first_distinct().into()
     }
@@ -199,6 +199,10 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
         UInt16 => numeric_series_to_numpy::<UInt16Type, f32>(py, s),
         UInt32 => numeric_series_to_numpy::<UInt32Type, f64>(py, s),
         UInt64 => numeric_series_to_numpy::<UInt64Type, f64>(py, s),
+        Float16 => {
+            let s = s.cast(&DataType::Float32).unwrap();
+            numeric_series_to_numpy::<Float32Type, f32>(py, &s)
+        },
         Float32 => numeric_series_to_numpy::<Float32Type, f32>(py, s),
         Float64 => numeric_series_to_numpy::<Float64Type, f64>(py, s),
         Boolean => boolean_series_to_numpy(py, s),
@@ -246,6 +250,14 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
             let values = ca.iter().map(|s| s.into_py_any(py).unwrap());
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        FixedSizeBinary(_) => {
+            let s = s.cast(&DataType::Binary).unwrap();
+            series_to_numpy_with_copy(py, &s, writable)
+        },
+        Uuid => {
+            let s = s.cast(&DataType::String).unwrap();
+            series_to_numpy_with_copy(py, &s, writable)
+        },
         Categorical(_, _) | Enum(_, _) => {
             with_match_categorical_physical_type!(s.dtype().cat_physical().unwrap(), |$C| {
                 let ca = s.cat::<$C>().unwrap();
@@ -261,6 +273,7 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
         List(_) => list_series_to_numpy(py, s, writable),
+        Map(_, _) => list_series_to_numpy(py, &s.to_physical_repr(), writable),
         Array(_, _) => array_series_to_numpy(py, s, writable),
         Struct(_) => {
             let ca = s.struct_().unwrap();
@@ -197,12 +197,12 @@ impl PyLazyFrame {
 
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.and_then(|s| s.as_bytes().first()).copied();
-        let separator = separator
-            .as_bytes()
-            .first()
-            .ok_or_else(|| polars_err!(InvalidOperation: "`separator` cannot be empty"))
-            .copied()
-            .map_err(PyPolarsErr::from)?;
+        if separator.is_empty() {
+            return Err(PyPolarsErr::from(
+                polars_err!(InvalidOperation: "`separator` cannot be empty"),
+            )
+            .into());
+        }
         let eol_char = eol_char
             .as_bytes()
             .first()
@@ -248,7 +248,7 @@ impl PyLazyFrame {
 
         let mut r = r
             .with_infer_schema_length(infer_schema_length)
-            .with_separator(separator)
+            .with_multi_byte_separator(separator)
             .with_has_header(has_header)
             .with_ignore_errors(ignore_errors)
             .with_skip_rows(skip_rows)
@@ -839,7 +839,8 @@ impl PyLazyFrame {
     #[pyo3(signature = (
         target, include_bom, include_header, separator, line_terminator, quote_char, batch_size,
         datetime_format, date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style, cloud_options, credential_provider, retries, sink_options
+        quote_style, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
     ))]
     fn sink_csv(
         &self,
@@ -859,6 +860,8 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
@@ -886,6 +889,7 @@ impl PyLazyFrame {
             include_header,
             batch_size,
             serialize_options,
+            compression: parse_external_compression(compression, compression_level)?,
         };
 
         #[cfg(feature = "cloud")]
@@ -931,17 +935,24 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "json")]
-    #[pyo3(signature = (target, cloud_options, credential_provider, retries, sink_options))]
+    #[pyo3(signature = (
+        target, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
+    ))]
     fn sink_json(
         &self,
         py: Python<'_>,
         target: SinkTarget,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions {
+            compression: parse_external_compression(compression, compression_level)?,
+        };
 
         let cloud_options = match target.base_path() {
             None => None,
@@ -1554,7 +1565,9 @@ impl PyLazyFrame {
 #[cfg(feature = "parquet")]
 impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOverwrites> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
-        use polars_io::parquet::write::ParquetFieldOverwrites;
+        use polars_io::parquet::write::{
+            ParquetBloomFilterOptions, ParquetEncoding, ParquetFieldOverwrites,
+        };
 
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
@@ -1596,12 +1609,28 @@ impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOver
             .map(|v| v.extract::<bool>())
             .transpose()?;
 
+        let bloom_filter = PyDictMethods::get_item(&parsed, "bloom_filter")?
+            .map(|v| {
+                let (fpp, ndv) = v.extract::<(Option<f64>, Option<u64>)>()?;
+                let fpp = fpp.unwrap_or(ParquetBloomFilterOptions::default().fpp());
+                PyResult::Ok(
+                    ParquetBloomFilterOptions::try_new(fpp, ndv).map_err(PyPolarsErr::from)?,
+                )
+            })
+            .transpose()?;
+
+        let encoding = PyDictMethods::get_item(&parsed, "encoding")?
+            .map(|v| PyResult::Ok(v.extract::<Wrap<ParquetEncoding>>()?.0))
+            .transpose()?;
+
         Ok(Wrap(ParquetFieldOverwrites {
             name,
             children,
             field_id,
             metadata,
             required,
+            bloom_filter,
+            encoding,
         }))
     }
 }
@@ -759,6 +759,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"));
                 },
+                IRFunctionExpr::MapExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("map expr"));
+                },
                 IRFunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
@@ -1230,6 +1233,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 } => ("value_counts", sort, parallel, name.as_str(), normalize).into_py_any(py),
                 IRFunctionExpr::UniqueCounts => ("unique_counts",).into_py_any(py),
                 IRFunctionExpr::ApproxNUnique => ("approx_n_unique",).into_py_any(py),
+                #[cfg(feature = "approx_quantile")]
+                IRFunctionExpr::ApproxQuantile { quantile, accuracy } => {
+                    ("approx_quantile", quantile, accuracy).into_py_any(py)
+                },
+                #[cfg(feature = "approx_quantile")]
+                IRFunctionExpr::ApproxQuantileSketch { accuracy } => {
+                    ("approx_quantile_sketch", accuracy).into_py_any(py)
+                },
                 IRFunctionExpr::Coalesce => ("coalesce",).into_py_any(py),
                 IRFunctionExpr::ShrinkType => ("shrink_dtype",).into_py_any(py),
                 IRFunctionExpr::Diff(null_behaviour) => (
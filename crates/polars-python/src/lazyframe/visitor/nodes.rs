@@ -40,6 +40,8 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro { .. } => Err(PyNotImplementedError::new_err("avro scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
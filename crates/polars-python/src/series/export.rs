@@ -2,7 +2,7 @@ use polars_core::prelude::*;
 use polars_ffi::version_0::SeriesExport;
 use pyo3::IntoPyObjectExt;
 use pyo3::prelude::*;
-use pyo3::types::{PyCapsule, PyList};
+use pyo3::types::{PyCapsule, PyDict, PyList};
 
 use super::PySeries;
 use crate::error::PyPolarsErr;
@@ -29,6 +29,10 @@ impl PySeries {
                 DataType::Int32 => PyList::new(py, series.i32().map_err(PyPolarsErr::from)?)?,
                 DataType::Int64 => PyList::new(py, series.i64().map_err(PyPolarsErr::from)?)?,
                 DataType::Int128 => PyList::new(py, series.i128().map_err(PyPolarsErr::from)?)?,
+                DataType::Float16 => {
+                    let s = series.cast(&DataType::Float32).map_err(PyPolarsErr::from)?;
+                    PyList::new(py, s.f32().map_err(PyPolarsErr::from)?)?
+                },
                 DataType::Float32 => PyList::new(py, series.f32().map_err(PyPolarsErr::from)?)?,
                 DataType::Float64 => PyList::new(py, series.f64().map_err(PyPolarsErr::from)?)?,
                 DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -77,6 +81,29 @@ impl PySeries {
                     }
                     v
                 },
+                DataType::Map(_, _) => {
+                    let v = PyList::empty(py);
+                    let ca = series.map().map_err(PyPolarsErr::from)?;
+                    for opt_s in ca.physical().amortized_iter() {
+                        match opt_s {
+                            None => {
+                                v.append(py.None())?;
+                            },
+                            Some(s) => {
+                                let entries = s.as_ref().struct_().map_err(PyPolarsErr::from)?;
+                                let fields = entries.fields_as_series();
+                                let keys = to_list_recursive(py, &fields[0])?;
+                                let values = to_list_recursive(py, &fields[1])?;
+                                let dict = PyDict::new(py);
+                                for (key, value) in keys.try_iter()?.zip(values.try_iter()?) {
+                                    dict.set_item(key?, value?)?;
+                                }
+                                v.append(dict)?;
+                            },
+                        }
+                    }
+                    v
+                },
                 DataType::Date => {
                     let ca = series.date().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
@@ -109,6 +136,16 @@ impl PySeries {
                     let ca = series.binary().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::FixedSizeBinary(_) => {
+                    let s = series.cast(&DataType::Binary).map_err(PyPolarsErr::from)?;
+                    let ca = s.binary().map_err(PyPolarsErr::from)?;
+                    return Wrap(ca).into_bound_py_any(py);
+                },
+                DataType::Uuid => {
+                    let s = series.cast(&DataType::String).map_err(PyPolarsErr::from)?;
+                    let ca = s.str().map_err(PyPolarsErr::from)?;
+                    return Wrap(ca).into_bound_py_any(py);
+                },
                 DataType::Null => {
                     let null: Option<u8> = None;
                     let n = series.len();
@@ -3,7 +3,7 @@ use arrow::bitmap::{Bitmap, BitmapBuilder};
 use arrow::buffer::Buffer;
 use arrow::datatypes::ArrowDataType;
 use arrow::offset::OffsetsBuffer;
-use arrow::types::NativeType;
+use arrow::types::{NativeType, f16};
 use polars_dtype::categorical::CatNative;
 
 use self::encode::fixed_size;
@@ -11,7 +11,7 @@ use self::row::{RowEncodingCategoricalContext, RowEncodingOptions};
 use self::variable::utf8::decode_str;
 use super::*;
 use crate::fixed::numeric::{FixedLengthEncoding, FromSlice};
-use crate::fixed::{boolean, decimal, numeric};
+use crate::fixed::{binary as fixed_binary, boolean, decimal, numeric};
 use crate::variable::{binary, no_order, utf8};
 
 /// Decode `rows` into a arrow format
@@ -116,7 +116,6 @@ fn dtype_and_data_to_encoded_item_len(
             1 + item_len
         },
 
-        D::FixedSizeBinary(_) => todo!(),
         D::FixedSizeList(fsl_field, width) => {
             let mut data = &data[1..];
             let mut item_len = 1; // validity byte
@@ -227,6 +226,12 @@ unsafe fn decode(
         };
     }
 
+    if let Some(RowEncodingContext::FixedSizeBinary) = dict {
+        if fixed_binary::is_fixed_size_list_of_bytes(dtype) {
+            return fixed_binary::decode_fixed_size_list(rows, dtype, opt).to_boxed();
+        }
+    }
+
     match dtype {
         D::Null => NullArray::new(D::Null, rows.len()).to_boxed(),
         D::Boolean => boolean::decode_bool(rows, opt).to_boxed(),
@@ -243,6 +248,9 @@ unsafe fn decode(
         },
         D::Binary | D::LargeBinary | D::BinaryView => binary::decode_binview(rows, opt).to_boxed(),
         D::Utf8 | D::LargeUtf8 | D::Utf8View => decode_str(rows, opt).boxed(),
+        D::FixedSizeBinary(_) => {
+            fixed_binary::decode_fixed_size_binary(rows, dtype, opt).to_boxed()
+        },
 
         D::Struct(fields) => {
             let validity = decode_validity(rows, opt);
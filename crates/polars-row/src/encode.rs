@@ -2,16 +2,17 @@
 use std::mem::MaybeUninit;
 
 use arrow::array::{
-    Array, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeListArray, ListArray,
-    PrimitiveArray, StructArray, UInt8Array, UInt16Array, UInt32Array, Utf8Array, Utf8ViewArray,
+    Array, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeBinaryArray, FixedSizeListArray,
+    ListArray, PrimitiveArray, StructArray, UInt8Array, UInt16Array, UInt32Array, Utf8Array,
+    Utf8ViewArray,
 };
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowDataType;
-use arrow::types::{NativeType, Offset};
+use arrow::types::{NativeType, Offset, f16};
 use polars_dtype::categorical::CatNative;
 
 use crate::fixed::numeric::FixedLengthEncoding;
-use crate::fixed::{boolean, decimal, numeric};
+use crate::fixed::{binary as fixed_binary, boolean, decimal, numeric};
 use crate::row::{RowEncodingOptions, RowsEncoded};
 use crate::variable::{binary, no_order, utf8};
 use crate::widths::RowWidths;
@@ -262,6 +263,12 @@ fn get_encoder(
     if let Some(size) = fixed_size(dtype, opt, dict) {
         row_widths.push_constant(size);
         let state = match dtype {
+            D::FixedSizeList(_, _)
+                if matches!(dict, Some(RowEncodingContext::FixedSizeBinary))
+                    && fixed_binary::is_fixed_size_list_of_bytes(dtype) =>
+            {
+                None
+            },
             D::FixedSizeList(_, width) => {
                 let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
 
@@ -638,6 +645,14 @@ unsafe fn encode_flat_array(
         return;
     }
 
+    if let Some(RowEncodingContext::FixedSizeBinary) = dict {
+        if fixed_binary::is_fixed_size_list_of_bytes(array.dtype()) {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            fixed_binary::encode_fixed_size_list(buffer, array, opt, offsets);
+            return;
+        }
+    }
+
     match array.dtype() {
         D::Null => {},
         D::Boolean => {
@@ -645,7 +660,7 @@ unsafe fn encode_flat_array(
             boolean::encode_bool(buffer, array.iter(), opt, offsets);
         },
 
-        dt if dt.is_numeric() => {
+        dt if dt.is_numeric() || *dt == D::Float16 => {
             if matches!(dt, D::Int128) {
                 if let Some(RowEncodingContext::Decimal(precision)) = dict {
                     decimal::encode(
@@ -696,7 +711,13 @@ unsafe fn encode_flat_array(
         // Lexical ordered Categorical are cast to PrimitiveArray above.
         D::Dictionary(_, _, _) => todo!(),
 
-        D::FixedSizeBinary(_) => todo!(),
+        D::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            fixed_binary::encode_fixed_size_binary(buffer, array, opt, offsets);
+        },
         D::Decimal(_, _) => todo!(),
         D::Decimal32(_, _) => todo!(),
         D::Decimal64(_, _) => todo!(),
@@ -958,8 +979,16 @@ pub fn fixed_size(
             _ => unreachable!(),
         },
 
+        D::Float16 => f16::ENCODED_LEN,
         D::Float32 => f32::ENCODED_LEN,
         D::Float64 => f64::ENCODED_LEN,
+        D::FixedSizeBinary(width) => fixed_binary::len_from_width(*width),
+        D::FixedSizeList(_, width)
+            if matches!(dict, Some(RowEncodingContext::FixedSizeBinary))
+                && fixed_binary::is_fixed_size_list_of_bytes(dtype) =>
+        {
+            fixed_binary::len_from_width(*width)
+        },
         D::FixedSizeList(f, width) => 1 + width * fixed_size(f.dtype(), opt, dict)?,
         D::Struct(fs) => match dict {
             None => {
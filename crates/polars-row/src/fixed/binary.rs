@@ -0,0 +1,156 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+//! Row encoding for fixed-size binary data
+//!
+//! Every value of `width` bytes is encoded as a validity byte followed by `width` bytes. Valid
+//! values write their bytes as-is, which keeps the lexical order of the bytes. For a descending
+//! order all bytes are inverted. Nulls are encoded as the null sentinel followed by `width` zeros.
+
+use std::mem::MaybeUninit;
+
+use arrow::array::{FixedSizeBinaryArray, FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+
+use crate::row::RowEncodingOptions;
+use crate::utils::decode_opt_nulls;
+
+pub(crate) fn len_from_width(width: usize) -> usize {
+    1 + width
+}
+
+/// Whether `dtype` holds fixed-size binary data as a `FixedSizeList` of `UInt8`.
+pub(crate) fn is_fixed_size_list_of_bytes(dtype: &ArrowDataType) -> bool {
+    matches!(dtype, ArrowDataType::FixedSizeList(f, _) if f.dtype() == &ArrowDataType::UInt8)
+}
+
+pub(crate) unsafe fn encode_fixed_size_list(
+    buffer: &mut [MaybeUninit<u8>],
+    array: &FixedSizeListArray,
+    opt: RowEncodingOptions,
+    offsets: &mut [usize],
+) {
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<u8>>()
+        .unwrap();
+    encode(
+        buffer,
+        values.values(),
+        array.size(),
+        array.validity(),
+        opt,
+        offsets,
+    );
+}
+
+pub(crate) unsafe fn encode_fixed_size_binary(
+    buffer: &mut [MaybeUninit<u8>],
+    array: &FixedSizeBinaryArray,
+    opt: RowEncodingOptions,
+    offsets: &mut [usize],
+) {
+    encode(
+        buffer,
+        array.values(),
+        array.size(),
+        array.validity(),
+        opt,
+        offsets,
+    );
+}
+
+unsafe fn encode(
+    buffer: &mut [MaybeUninit<u8>],
+    values: &[u8],
+    width: usize,
+    validity: Option<&Bitmap>,
+    opt: RowEncodingOptions,
+    offsets: &mut [usize],
+) {
+    let null_sentinel = opt.null_sentinel();
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+
+    for (i, offset) in offsets.iter_mut().enumerate() {
+        let dst = buffer.get_unchecked_mut(*offset..*offset + len_from_width(width));
+        *offset += len_from_width(width);
+
+        if validity.is_some_and(|v| !v.get_bit_unchecked(i)) {
+            dst[0] = MaybeUninit::new(null_sentinel);
+            dst[1..].fill(MaybeUninit::new(0));
+            continue;
+        }
+
+        dst[0] = MaybeUninit::new(1);
+        let src = values.get_unchecked(i * width..(i + 1) * width);
+        if descending {
+            for (d, s) in dst[1..].iter_mut().zip(src) {
+                *d = MaybeUninit::new(!s);
+            }
+        } else {
+            for (d, s) in dst[1..].iter_mut().zip(src) {
+                *d = MaybeUninit::new(*s);
+            }
+        }
+    }
+}
+
+/// Decode the values and validity of `width` bytes per row.
+unsafe fn decode(
+    rows: &mut [&[u8]],
+    width: usize,
+    opt: RowEncodingOptions,
+) -> (Vec<u8>, Option<Bitmap>) {
+    let validity = decode_opt_nulls(rows, opt.null_sentinel());
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+
+    let mut values = Vec::with_capacity(rows.len() * width);
+    for row in rows.iter_mut() {
+        let src = row.get_unchecked(1..len_from_width(width));
+        if descending {
+            values.extend(src.iter().map(|b| !b));
+        } else {
+            values.extend_from_slice(src);
+        }
+        *row = row.get_unchecked(len_from_width(width)..);
+    }
+
+    // Nulls are encoded as zeros, which are inverted for a descending order.
+    if descending {
+        if let Some(validity) = &validity {
+            for (i, is_valid) in validity.iter().enumerate() {
+                if !is_valid {
+                    values[i * width..(i + 1) * width].fill(0);
+                }
+            }
+        }
+    }
+
+    (values, validity)
+}
+
+pub(crate) unsafe fn decode_fixed_size_list(
+    rows: &mut [&[u8]],
+    dtype: &ArrowDataType,
+    opt: RowEncodingOptions,
+) -> FixedSizeListArray {
+    let ArrowDataType::FixedSizeList(_, width) = dtype else {
+        unreachable!()
+    };
+    let num_rows = rows.len();
+    let (values, validity) = decode(rows, *width, opt);
+    let values = PrimitiveArray::new(ArrowDataType::UInt8, values.into(), None);
+    FixedSizeListArray::new(dtype.clone(), num_rows, values.boxed(), validity)
+}
+
+pub(crate) unsafe fn decode_fixed_size_binary(
+    rows: &mut [&[u8]],
+    dtype: &ArrowDataType,
+    opt: RowEncodingOptions,
+) -> FixedSizeBinaryArray {
+    let ArrowDataType::FixedSizeBinary(width) = dtype else {
+        unreachable!()
+    };
+    let (values, validity) = decode(rows, *width, opt);
+    FixedSizeBinaryArray::new(dtype.clone(), values.into(), validity)
+}
@@ -13,6 +13,7 @@ macro_rules! with_arms {
     };
 }
 
+pub mod binary;
 pub mod boolean;
 pub mod decimal;
 pub mod numeric;
@@ -5,7 +5,7 @@ use std::mem::MaybeUninit;
 use arrow::array::{Array, PrimitiveArray};
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowDataType;
-use arrow::types::NativeType;
+use arrow::types::{NativeType, canonical_f16, f16};
 use polars_utils::slice::*;
 use polars_utils::total_ord::{canonical_f32, canonical_f64};
 
@@ -96,6 +96,22 @@ encode_signed!(4, i32);
 encode_signed!(8, i64);
 encode_signed!(16, i128);
 
+impl FixedLengthEncoding for f16 {
+    type Encoded = [u8; 2];
+
+    fn encode(self) -> [u8; 2] {
+        let s = canonical_f16(self).to_bits() as i16;
+        let val = s ^ (((s >> 15) as u16) >> 1) as i16;
+        val.encode()
+    }
+
+    fn decode(encoded: Self::Encoded) -> Self {
+        let bits = i16::decode(encoded);
+        let val = bits ^ (((bits >> 15) as u16) >> 1) as i16;
+        Self::from_bits(val as u16)
+    }
+}
+
 impl FixedLengthEncoding for f32 {
     type Encoded = [u8; 4];
 
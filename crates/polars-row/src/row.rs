@@ -22,6 +22,8 @@ pub enum RowEncodingContext {
     Categorical(RowEncodingCategoricalContext),
     /// Decimal with given precision
     Decimal(usize),
+    /// Fixed-size binary stored as a `FixedSizeList` of `UInt8`
+    FixedSizeBinary,
 }
 
 #[derive(Debug, Clone)]
@@ -9,21 +9,38 @@ use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    Assignment, AssignmentTarget, BinaryOperator, CreateTable, Cte, Delete, Distinct,
+    ExcludeSelectItem, Expr as SQLExpr, ExprWithAlias, FromTable, FunctionArg, FunctionArgExpr,
+    FunctionArguments, GroupByExpr, GroupByWithModifier, Ident, Insert, Join, JoinConstraint,
+    JoinOperator, MergeAction, MergeClauseKind, MergeInsertKind, ObjectName, ObjectType, Offset,
+    OrderBy, PivotValueSource, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator,
+    SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions,
 };
 use sqlparser::dialect::GenericDialect;
-use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::parser::{Parser, ParserError, ParserOptions};
+use sqlparser::tokenizer::{Token, TokenWithSpan, Tokenizer};
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::prepared::{PreparedQuery, has_placeholders};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
+use crate::subquery::{SUBQUERY_PREFIX, contains_subquery, split_conjunction, visit_sql_exprs_mut};
 use crate::table_functions::PolarsTableFunctions;
 
+const MAX_CUBE_ELEMENTS: usize = 12;
+const GROUPING_ID_NAME: &str = "__POLARS_GROUPING_ID";
+const GROUPING_INDICATOR_PREFIX: &str = "__POLARS_GROUPING_";
+const JOIN_LEFT_INDEX: &str = "__POLARS_JOIN_LEFT";
+const JOIN_RIGHT_INDEX: &str = "__POLARS_JOIN_RIGHT";
+const JOIN_MATCHED: &str = "__POLARS_JOIN_MATCHED";
+const MERGE_TARGET_INDEX: &str = "__POLARS_MERGE_TARGET";
+const MERGE_SOURCE_INDEX: &str = "__POLARS_MERGE_SOURCE";
+const MERGE_ACTION: &str = "__POLARS_MERGE_ACTION";
+const QUALIFY_NAME: &str = "__POLARS_QUALIFY";
+const TABLESAMPLE: &str = "TABLESAMPLE";
+
 #[derive(Clone)]
 pub struct TableInfo {
     pub(crate) frame: LazyFrame,
@@ -59,10 +76,13 @@ pub struct SQLContext {
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
+    pub(crate) recursion_limit: usize,
 
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    // placeholders seen while preparing a query (None if not preparing one)
+    pub(crate) placeholders: RefCell<Option<PlIndexSet<String>>>,
 }
 
 impl Default for SQLContext {
@@ -73,8 +93,10 @@ impl Default for SQLContext {
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            placeholders: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            recursion_limit: 1000,
         }
     }
 }
@@ -141,33 +163,85 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
+        let stmt = self.parse_statement(query)?;
+        let res = self.execute_statement(&stmt)?;
+
+        // Ensure the result uses the proper arenas.
+        // This will instantiate new arenas with a new version.
+        let lp_arena = std::mem::take(&mut self.lp_arena);
+        let expr_arena = std::mem::take(&mut self.expr_arena);
+        res.set_cached_arena(lp_arena, expr_arena);
+
+        // Every execution should clear the statement-level maps.
+        self.clear_statement_maps();
+
+        Ok(res)
+    }
+
+    /// Parse and plan a SQL query once, returning a [`PreparedQuery`] that can be executed
+    /// repeatedly with values bound to its `$1`, `?` or `:name` placeholders.
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLParams};
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let query = ctx.prepare("SELECT a FROM df WHERE a > $1").unwrap();
+    /// let sql_df = query.execute(vec![Scalar::from(1i32)]).unwrap().collect().unwrap();
+    /// assert_eq!(sql_df.height(), 2);
+    /// # }
+    ///```
+    pub fn prepare(&mut self, query: &str) -> PolarsResult<PreparedQuery> {
+        let stmt = self.parse_statement(query)?;
+        polars_ensure!(
+            matches!(stmt, Statement::Query(_)),
+            SQLInterface: "only queries (not DDL/DML statements) can be prepared"
+        );
+
+        *self.placeholders.borrow_mut() = Some(PlIndexSet::new());
+        let res = self.execute_statement(&stmt);
+        let placeholders = self.placeholders.take().unwrap_or_default();
+
+        // The plan is rebuilt (with the bound values) on execution.
+        self.lp_arena = Default::default();
+        self.expr_arena = Default::default();
+        self.clear_statement_maps();
+
+        PreparedQuery::new(res?, placeholders)
+    }
+
+    fn parse_statement(&self, query: &str) -> PolarsResult<Statement> {
         let mut parser = Parser::new(&GenericDialect);
         parser = parser.with_options(ParserOptions {
             trailing_commas: true,
             ..Default::default()
         });
 
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
+        let tokens = Tokenizer::new(&GenericDialect, query)
+            .with_unescape(true)
+            .tokenize_with_location()
+            .map_err(|err| to_sql_interface_err(ParserError::from(err)))?;
+        let tokens = number_anonymous_placeholders(rewrite_table_samples(tokens)?)?;
+        let mut ast = parser
+            .with_tokens_with_locations(tokens)
             .parse_statements()
             .map_err(to_sql_interface_err)?;
 
         polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
-
-        // Ensure the result uses the proper arenas.
-        // This will instantiate new arenas with a new version.
-        let lp_arena = std::mem::take(&mut self.lp_arena);
-        let expr_arena = std::mem::take(&mut self.expr_arena);
-        res.set_cached_arena(lp_arena, expr_arena);
+        Ok(ast.pop().unwrap())
+    }
 
-        // Every execution should clear the statement-level maps.
+    fn clear_statement_maps(&mut self) {
         self.cte_map.borrow_mut().clear();
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
-
-        Ok(res)
     }
 
     /// add a function registry to the SQLContext
@@ -177,6 +251,13 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations used to evaluate a recursive CTE (`WITH RECURSIVE`).
+    /// Queries that do not reach a fixpoint within this limit raise an error. Defaults to 1000.
+    pub fn with_recursion_limit(mut self, recursion_limit: usize) -> Self {
+        self.recursion_limit = recursion_limit;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -202,6 +283,9 @@ impl SQLContext {
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert_into_table(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update_table(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge_into_table(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -222,15 +306,28 @@ impl SQLContext {
         frame.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)
     }
 
+    /// Collect a frame while planning the query, as some constructs (eg: recursive CTEs) require.
+    pub(crate) fn collect_eager(&self, lf: LazyFrame, construct: &str) -> PolarsResult<DataFrame> {
+        // the values of the placeholders are only bound to the plan of a prepared query
+        // when it is executed, at which point this frame has already been collected
+        polars_ensure!(
+            self.placeholders.borrow().is_none() || !has_placeholders(&lf.logical_plan),
+            SQLInterface: "placeholders are not supported in {} of a prepared query", construct
+        );
+        lf.collect()
+    }
+
     pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
         let table = self.table_map.get(name).cloned();
         table
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.table_aliases.borrow().get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .cloned()
+                        .or_else(|| self.cte_map.borrow().get(alias).cloned())
+                })
             })
     }
 
@@ -588,27 +685,472 @@ impl SQLContext {
         }
     }
 
+    // INSERT INTO <tbl> [(<cols>)] {VALUES ... | SELECT ...}
+    fn execute_insert_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            or,
+            ignore,
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            on,
+            returning,
+            replace_into,
+            ..
+        }) = stmt
+        {
+            if or.is_some()
+                || *ignore
+                || *replace_into
+                || on.is_some()
+                || partitioned.is_some()
+                || returning.is_some()
+            {
+                let error_message = match () {
+                    _ if partitioned.is_some() => "INSERT does not support the PARTITION clause",
+                    _ if returning.is_some() => "INSERT does not support the RETURNING clause",
+                    _ => "INSERT does not support conflict resolution clauses",
+                };
+                polars_bail!(SQLInterface: error_message);
+            }
+            let tbl_name = table_name.0.first().unwrap().value.as_str();
+            let Some(mut target) = self.table_map.get(tbl_name).cloned() else {
+                polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+            };
+            let Some(source) = source else {
+                polars_bail!(SQLInterface: "INSERT expects a VALUES clause or a SELECT query");
+            };
+            let target_schema = self.get_frame_schema(&mut target)?;
+            let target_columns = if columns.is_empty() {
+                target_schema.iter_names().cloned().collect()
+            } else {
+                dml_target_columns(columns.iter().map(|c| c.value.as_str()), &target_schema)?
+            };
+
+            let mut rows = self.execute_query(source)?;
+            let rows_schema = self.get_frame_schema(&mut rows)?;
+            polars_ensure!(
+                rows_schema.len() == target_columns.len(),
+                SQLSyntax: "INSERT has {} target columns but {} values", target_columns.len(), rows_schema.len()
+            );
+
+            // match the inserted columns to the table by position, using NULL for the rest
+            let rows = rows.select(
+                target_schema
+                    .iter()
+                    .map(|(name, dtype)| {
+                        match target_columns.iter().position(|c| c == name) {
+                            Some(idx) => col(rows_schema.get_at_index(idx).unwrap().0.clone()),
+                            None => lit(NULL),
+                        }
+                        .strict_cast(dtype.clone())
+                        .alias(name.clone())
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            let lf = if *overwrite {
+                rows
+            } else {
+                polars_lazy::dsl::concat(vec![target, rows], UnionArgs::default())?
+            };
+            self.table_map.insert(tbl_name.to_string(), lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        }
+    }
+
+    // UPDATE <tbl> SET <col> = <expr>, ... [WHERE ...]
+    fn execute_update_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+            or,
+        } = stmt
+        {
+            if !table.joins.is_empty() || from.is_some() || returning.is_some() || or.is_some() {
+                let error_message = match () {
+                    _ if !table.joins.is_empty() => "UPDATE does not support table JOINs",
+                    _ if from.is_some() => "UPDATE does not support the FROM clause",
+                    _ if returning.is_some() => "UPDATE does not support the RETURNING clause",
+                    _ => "UPDATE does not support conflict resolution clauses",
+                };
+                polars_bail!(SQLInterface: error_message);
+            }
+            let tbl_name = match &table.relation {
+                TableFactor::Table {
+                    name, args: None, ..
+                } => name.0.first().unwrap().value.clone(),
+                relation => {
+                    polars_bail!(SQLInterface: "UPDATE expects a table name; found {}", relation)
+                },
+            };
+            polars_ensure!(
+                self.table_map.contains_key(&tbl_name),
+                SQLInterface: "table '{}' does not exist", tbl_name
+            );
+            let (_, mut lf) = self.get_table(&table.relation)?;
+            let schema = self.get_frame_schema(&mut lf)?;
+            let (targets, mut values) = dml_assignments(assignments, &schema)?;
+
+            // decorrelate any subqueries (as joins against the table)
+            let mut selection = selection.clone();
+            let mut hidden = vec![];
+            for expr in values.iter_mut().chain(selection.iter_mut()) {
+                if contains_subquery(expr) {
+                    let columns;
+                    (lf, columns) = self.decorrelate_subqueries(lf, expr)?;
+                    hidden.extend(columns.into_iter().map(|(name, _)| name));
+                }
+            }
+            let joined_schema = self.get_frame_schema(&mut lf)?;
+            let mut values = values
+                .iter()
+                .map(|expr| parse_sql_expr(expr, self, Some(&joined_schema)))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let mut predicate = selection
+                .as_ref()
+                .map(|expr| parse_sql_expr(expr, self, Some(&joined_schema)))
+                .transpose()?;
+            lf = self.process_subqueries(lf, values.iter_mut().chain(predicate.as_mut()).collect());
+
+            // assignments only apply to the rows matching the WHERE clause
+            lf = lf.with_columns(
+                targets
+                    .into_iter()
+                    .zip(values)
+                    .map(|(name, value)| {
+                        let value = value.strict_cast(schema.get(&name).unwrap().clone());
+                        match &predicate {
+                            Some(predicate) => when(predicate.clone())
+                                .then(value)
+                                .otherwise(col(name.clone())),
+                            None => value,
+                        }
+                        .alias(name)
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            if !hidden.is_empty() {
+                lf = lf.drop(by_name(hidden, true));
+            }
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected UPDATE")
+        }
+    }
+
+    // MERGE INTO <tbl> USING <source> ON <constraint> WHEN [NOT] MATCHED [AND ...] THEN ...
+    //
+    // The table and source are full-joined on the (equi-join) constraint; each joined row
+    // takes the action of the first clause that applies to it. As the SQL standard requires,
+    // it is an error for a table row to match several source rows.
+    fn execute_merge_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        {
+            let tbl_name = match table {
+                TableFactor::Table {
+                    name, args: None, ..
+                } => name.0.first().unwrap().value.clone(),
+                relation => {
+                    polars_bail!(SQLInterface: "MERGE expects a table name; found {}", relation)
+                },
+            };
+            polars_ensure!(
+                self.table_map.contains_key(&tbl_name),
+                SQLInterface: "table '{}' does not exist", tbl_name
+            );
+            let (t_name, mut target) = self.get_table(table)?;
+            let (s_name, source) = self.get_table(source)?;
+            if s_name.is_empty() {
+                polars_bail!(SQLInterface: "cannot merge from an unnamed relation; please provide an alias")
+            }
+            let target_schema = self.get_frame_schema(&mut target)?;
+
+            // the row indexes double as markers for the matched/unmatched rows
+            let mut target = target.with_row_index(MERGE_TARGET_INDEX, None);
+            let mut source = source.with_row_index(MERGE_SOURCE_INDEX, None);
+            let left_schema = self.get_frame_schema(&mut target)?;
+            let right_schema = self.get_frame_schema(&mut source)?;
+            let mut lf = self.process_join(
+                &TableInfo {
+                    frame: target,
+                    name: (&t_name).into(),
+                    schema: left_schema.clone(),
+                },
+                &TableInfo {
+                    frame: source,
+                    name: (&s_name).into(),
+                    schema: right_schema.clone(),
+                },
+                &JoinConstraint::On((**on).clone()),
+                JoinType::Full,
+            )?;
+            let joined_schema = self.get_frame_schema(&mut lf)?;
+            self.register_joined_aliases(&s_name, &left_schema, &right_schema, &joined_schema);
+
+            // a target row may be matched by at most one source row; there is no way to raise
+            // this error lazily, so the join is collected here (only the target row index is
+            // selected, so projection pushdown limits this to the join keys)
+            let target_index = col(MERGE_TARGET_INDEX);
+            let multiple_matches = lf
+                .clone()
+                .select([target_index
+                    .clone()
+                    .count()
+                    .neq(target_index.drop_nulls().n_unique())])
+                .collect()?;
+            polars_ensure!(
+                multiple_matches[0].bool()?.get(0) != Some(true),
+                SQLInterface: "MERGE matched a row of '{}' with more than one source row", tbl_name
+            );
+
+            let in_target = col(MERGE_TARGET_INDEX).is_not_null();
+            let in_source = col(MERGE_SOURCE_INDEX).is_not_null();
+            let is_action = |idx: usize| col(MERGE_ACTION).eq(lit(idx as u32));
+
+            // determine the (first) applicable clause for each row; rows to which no clause
+            // applies are left as they are
+            let mut action = lit(clauses.len() as u32);
+            let mut values = vec![];
+            for (idx, clause) in clauses.iter().enumerate().rev() {
+                let mut condition = match (&clause.clause_kind, &clause.action) {
+                    (
+                        MergeClauseKind::Matched,
+                        MergeAction::Update { .. } | MergeAction::Delete,
+                    ) => in_target.clone().and(in_source.clone()),
+                    (
+                        MergeClauseKind::NotMatchedBySource,
+                        MergeAction::Update { .. } | MergeAction::Delete,
+                    ) => in_source.clone().not(),
+                    (
+                        MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget,
+                        MergeAction::Insert(_),
+                    ) => in_target.clone().not(),
+                    (kind, action) => {
+                        polars_bail!(SQLSyntax: "WHEN {} clause cannot {}", kind, action)
+                    },
+                };
+                if let Some(predicate) = &clause.predicate {
+                    condition =
+                        condition.and(parse_sql_expr(predicate, self, Some(&joined_schema))?);
+                }
+                action = when(condition).then(lit(idx as u32)).otherwise(action);
+
+                let (targets, exprs) = match &clause.action {
+                    MergeAction::Update { assignments } => {
+                        dml_assignments(assignments, &target_schema)?
+                    },
+                    MergeAction::Insert(insert) => {
+                        let row = match &insert.kind {
+                            MergeInsertKind::Values(Values { rows, .. }) if rows.len() == 1 => {
+                                rows[0].clone()
+                            },
+                            _ => {
+                                polars_bail!(SQLInterface: "MERGE INSERT expects a single VALUES row")
+                            },
+                        };
+                        let targets = if insert.columns.is_empty() {
+                            target_schema.iter_names().cloned().collect()
+                        } else {
+                            dml_target_columns(
+                                insert.columns.iter().map(|c| c.value.as_str()),
+                                &target_schema,
+                            )?
+                        };
+                        polars_ensure!(
+                            row.len() == targets.len(),
+                            SQLSyntax: "MERGE INSERT has {} target columns but {} values", targets.len(), row.len()
+                        );
+                        (targets, row)
+                    },
+                    MergeAction::Delete => (vec![], vec![]),
+                };
+                let exprs = exprs
+                    .iter()
+                    .map(|expr| parse_sql_expr(expr, self, Some(&joined_schema)))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                values.push((
+                    idx,
+                    targets.into_iter().zip(exprs).collect::<PlHashMap<_, _>>(),
+                ));
+            }
+
+            // keep the (possibly updated) table rows that were not deleted, and the inserted rows
+            let any_action = |f: fn(&MergeAction) -> bool| {
+                clauses
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, clause)| f(&clause.action))
+                    .map(|(idx, _)| is_action(idx))
+                    .reduce(|a, b| a.or(b))
+                    .unwrap_or(lit(false))
+            };
+            let deleted = any_action(|a| matches!(a, MergeAction::Delete));
+            let inserted = any_action(|a| matches!(a, MergeAction::Insert(_)));
+
+            let lf = lf
+                .with_column(action.alias(MERGE_ACTION))
+                .filter(in_target.and(deleted.not()).or(inserted))
+                .sort(
+                    [MERGE_TARGET_INDEX, MERGE_SOURCE_INDEX],
+                    SortMultipleOptions::default().with_nulls_last(true),
+                )
+                .select(
+                    target_schema
+                        .iter()
+                        .map(|(name, dtype)| {
+                            values
+                                .iter()
+                                .filter_map(|(idx, exprs)| Some((*idx, exprs.get(name)?.clone())))
+                                .fold(col(name.clone()), |value, (idx, expr)| {
+                                    when(is_action(idx)).then(expr).otherwise(value)
+                                })
+                                .strict_cast(dtype.clone())
+                                .alias(name.clone())
+                        })
+                        .collect::<Vec<_>>(),
+                );
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected MERGE")
+        }
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = if with.recursive && set_expr_references_table(&cte.query.body, &cte_name)
+                {
+                    self.execute_recursive_cte(cte)?
+                } else {
+                    let lf = self.execute_query(&cte.query)?;
+                    self.rename_columns_from_table_alias(lf, &cte.alias)?
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE of the form `<anchor> UNION [ALL] <recursive term>`.
+    ///
+    /// The CTE is materialized as a fixpoint: the recursive term is evaluated against the rows
+    /// produced by the previous iteration until it no longer produces (new) rows. With `UNION`,
+    /// duplicate rows are discarded and only rows that were not seen before are fed back.
+    fn execute_recursive_cte(&mut self, cte: &Cte) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let query = &cte.query;
+        let (anchor, recursive_term, quantifier) = match query.body.as_ref() {
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                set_quantifier,
+                left,
+                right,
+            } => (left, right, set_quantifier),
+            _ => polars_bail!(
+                SQLInterface: "recursive CTE '{}' must be of the form '<anchor> UNION [ALL] <recursive term>'", cte_name
+            ),
+        };
+        polars_ensure!(
+            !set_expr_references_table(anchor, cte_name),
+            SQLInterface: "recursive CTE '{}' cannot reference itself in its non-recursive term", cte_name
+        );
+        polars_ensure!(
+            query.order_by.is_none(),
+            SQLInterface: "ORDER BY is not supported in recursive CTE '{}'", cte_name
+        );
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'", quantifier, cte_name
+            ),
+        };
+        self.register_ctes(query)?;
+
+        let unique = |lf: LazyFrame| lf.unique_stable(None, UniqueKeepStrategy::First);
+        let mut lf = self.process_query(anchor, query)?;
+        lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+        if distinct {
+            lf = unique(lf);
+        }
+        let mut result = self.collect_eager(lf, "recursive CTEs")?;
+        let schema = result.schema().clone();
+
+        let mut working = result.clone();
+        let mut n_iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                n_iterations < self.recursion_limit,
+                SQLInterface: "recursive CTE '{}' did not terminate within {} iterations (see `with_recursion_limit`)",
+                cte_name, self.recursion_limit
+            );
+            n_iterations += 1;
+
+            self.register_cte(cte_name, working.lazy());
+            let mut lf = self.process_query(recursive_term, query)?;
+
+            // The recursive term takes the column names and types of the anchor, by position.
+            let lf_schema = self.get_frame_schema(&mut lf)?;
+            polars_ensure!(
+                lf_schema.len() == schema.len(),
+                SQLInterface: "recursive CTE '{}' requires equal number of columns in its non-recursive ({}) and recursive ({}) terms",
+                cte_name, schema.len(), lf_schema.len()
+            );
+            let exprs = lf_schema
+                .iter_names()
+                .zip(schema.iter())
+                .map(|(name, (anchor_name, dtype))| {
+                    col(name.clone())
+                        .strict_cast(dtype.clone())
+                        .alias(anchor_name.clone())
+                })
+                .collect::<Vec<_>>();
+            let lf = lf.select(exprs);
+
+            let lf = if distinct {
+                // The result is already unique, so the rows behind it are new.
+                let n_seen = result.height() as i64;
+                unique(polars_lazy::dsl::concat(
+                    vec![result.clone().lazy(), lf],
+                    UnionArgs::default(),
+                )?)
+                .slice(n_seen, IdxSize::MAX)
+            } else {
+                lf
+            };
+            working = self.collect_eager(lf, "recursive CTEs")?;
+            result.vstack_mut(&working)?;
+        }
+        result.as_single_chunk_par();
+
+        self.process_limit_offset(result.lazy(), &query.limit, &query.offset)
+    }
+
     /// execute the 'FROM' part of the query
-    fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
+    pub(crate) fn execute_from_statement(
+        &mut self,
+        tbl_expr: &TableWithJoins,
+    ) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
         if !tbl_expr.joins.is_empty() {
             for join in &tbl_expr.joins {
@@ -681,47 +1223,105 @@ impl SQLContext {
 
                 // track join-aliased columns so we can resolve them later
                 let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{name}:{r_name}");
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.register_joined_aliases(&r_name, &left_schema, &right_schema, &joined_schema);
             }
         };
         Ok(lf)
     }
 
+    fn register_joined_aliases(
+        &mut self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        joined_schema: &Schema,
+    ) {
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{name}:{r_name}");
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
+        if let Some(qualify) = &select_stmt.qualify {
+            return self.process_qualify(select_stmt, qualify, query);
+        }
         let mut lf = if select_stmt.from.is_empty() {
             DataFrame::empty().lazy()
         } else {
-            // Note: implicit joins need more work to support properly,
-            // explicit joins are preferred for now (ref: #16662)
-            let from = select_stmt.clone().from;
-            if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
+            // Implicit (comma) joins are cross joins; the optimizer turns suitable WHERE
+            // predicates into equi (or inequality) join conditions
+            let mut from = select_stmt.from.clone();
+            let mut tbl_expr = from.remove(0);
+            for tbl in from {
+                if tbl.joins.iter().any(|join| {
+                    matches!(
+                        join.join_operator,
+                        JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+                    )
+                }) {
+                    polars_bail!(SQLInterface: "RIGHT and FULL joins cannot be combined with implicit joins; use explicit JOIN syntax instead")
+                }
+                tbl_expr.joins.push(Join {
+                    relation: tbl.relation,
+                    global: false,
+                    join_operator: JoinOperator::CrossJoin,
+                });
+                tbl_expr.joins.extend(tbl.joins);
             }
-            self.execute_from_statement(from.first().unwrap())?
+            self.execute_from_statement(&tbl_expr)?
         };
 
         // Filter expression (WHERE clause)
-        let schema = self.get_frame_schema(&mut lf)?;
+        let mut schema = self.get_frame_schema(&mut lf)?;
         lf = self.process_where(lf, &select_stmt.selection, false)?;
 
+        // Subqueries in the SELECT list are evaluated as joins; their results become
+        // hidden columns of the frame
+        let decorrelated_stmt;
+        let select_stmt = if select_stmt.projection.iter().any(|item| {
+            matches!(item, SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. } if contains_subquery(e))
+        }) {
+            let mut stmt = select_stmt.clone();
+            for item in stmt.projection.iter_mut() {
+                match item {
+                    SelectItem::UnnamedExpr(expr) => {
+                        let is_subquery = matches!(expr, SQLExpr::Subquery(_));
+                        let columns;
+                        (lf, columns) = self.decorrelate_subqueries(lf, expr)?;
+                        if is_subquery {
+                            // retain the name of the column returned by the subquery
+                            *item = SelectItem::ExprWithAlias {
+                                expr: expr.clone(),
+                                alias: Ident::new(columns[0].1.as_str()),
+                            };
+                        }
+                    },
+                    SelectItem::ExprWithAlias { expr, .. } => {
+                        (lf, _) = self.decorrelate_subqueries(lf, expr)?;
+                    },
+                    _ => {},
+                }
+            }
+            schema = self.get_frame_schema(&mut lf)?;
+            decorrelated_stmt = stmt;
+            &decorrelated_stmt
+        } else {
+            select_stmt
+        };
+
         // 'SELECT *' modifiers
         let mut select_modifiers = SelectModifiers {
             ilike: None,
@@ -734,31 +1334,97 @@ impl SQLContext {
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets: Option<Vec<Vec<Expr>>> = None;
         match &select_stmt.group_by {
-            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
+            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values), optionally
+            // with "ROLLUP", "CUBE" or "GROUPING SETS" elements (or a "WITH ROLLUP|CUBE" modifier)
             GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
+                let mut to_keys = |exprs: &[SQLExpr]| {
+                    exprs
+                        .iter()
+                        .map(|e| {
+                            self.expr_or_ordinal(
+                                e,
+                                &projections,
+                                None,
+                                Some(schema.deref()),
+                                "GROUP BY",
+                            )
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()
+                };
+
+                // Each element of the GROUP BY clause contributes one or more alternative
+                // key groups; the grouping sets are the cross product of those alternatives.
+                let mut sets: Vec<Vec<Expr>> = vec![vec![]];
+                let mut has_grouping_sets = false;
+                for e in group_by_exprs {
+                    let alternatives = match e {
+                        SQLExpr::Rollup(groups)
+                        | SQLExpr::Cube(groups)
+                        | SQLExpr::GroupingSets(groups) => {
+                            has_grouping_sets = true;
+                            let groups = groups
+                                .iter()
+                                .map(|g| to_keys(g))
+                                .collect::<PolarsResult<Vec<_>>>()?;
+                            match e {
+                                SQLExpr::Rollup(_) => rollup_sets(groups),
+                                SQLExpr::Cube(_) => cube_sets(groups)?,
+                                _ => groups,
+                            }
+                        },
+                        _ => vec![to_keys(std::slice::from_ref(e))?],
+                    };
+                    sets = sets
+                        .iter()
+                        .flat_map(|set| {
+                            alternatives.iter().map(move |alt| {
+                                let mut set = set.clone();
+                                for e in alt {
+                                    if !set.contains(e) {
+                                        set.push(e.clone());
+                                    }
+                                }
+                                set
+                            })
+                        })
+                        .collect();
+                }
+
+                match modifiers.as_slice() {
+                    [] => {},
+                    [modifier @ (GroupByWithModifier::Rollup | GroupByWithModifier::Cube)]
+                        if !has_grouping_sets =>
+                    {
+                        let groups = sets.pop().unwrap().into_iter().map(|e| vec![e]).collect();
+                        sets = match modifier {
+                            GroupByWithModifier::Rollup => rollup_sets(groups),
+                            _ => cube_sets(groups)?,
+                        };
+                        has_grouping_sets = true;
+                    },
+                    _ => {
+                        let modifiers = modifiers.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+                        polars_bail!(SQLInterface: "GROUP BY does not support the '{}' modifier here", modifiers.join(" "))
+                    },
+                }
+
+                // Note: the group key is the union of the keys over all grouping sets
+                for e in sets.iter().flatten() {
+                    if !group_by_keys.contains(e) {
+                        group_by_keys.push(e.clone());
+                    }
+                }
+                if has_grouping_sets {
+                    grouping_sets = Some(sets);
                 }
-                // translate the group expressions, allowing ordinal values
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| {
-                        self.expr_or_ordinal(
-                            e,
-                            &projections,
-                            None,
-                            Some(schema.deref()),
-                            "GROUP BY",
-                        )
-                    })
-                    .collect::<PolarsResult<_>>()?
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
             GroupByExpr::All(modifiers) => {
                 if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
+                    polars_bail!(SQLInterface: "GROUP BY ALL does not support CUBE, ROLLUP, or TOTALS modifiers")
                 }
                 projections.iter().for_each(|expr| match expr {
                     // immediately match the most common cases (col|agg|len|lit, optionally aliased).
@@ -785,7 +1451,7 @@ impl SQLContext {
             },
         };
 
-        lf = if group_by_keys.is_empty() {
+        lf = if group_by_keys.is_empty() && grouping_sets.is_none() {
             // The 'having' clause is only valid inside 'group by'
             if select_stmt.having.is_some() {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
@@ -891,7 +1557,7 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf = self.process_group_by(lf, &group_by_keys, grouping_sets, &projections)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
@@ -903,7 +1569,60 @@ impl SQLContext {
         };
 
         // Apply optional DISTINCT clause.
-        lf = match &select_stmt.distinct {
+        self.process_distinct(lf, &select_stmt.distinct, query)
+    }
+
+    /// Evaluate the QUALIFY predicate as a hidden column of the projection, so that it
+    /// can reference window functions (and projection aliases), then filter on it.
+    fn process_qualify(
+        &mut self,
+        select_stmt: &Select,
+        qualify: &SQLExpr,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
+        let aliases: PlHashMap<&str, &SQLExpr> = select_stmt
+            .projection
+            .iter()
+            .filter_map(|item| match item {
+                SelectItem::ExprWithAlias { expr, alias } => Some((alias.value.as_str(), expr)),
+                _ => None,
+            })
+            .collect();
+
+        let mut predicate = qualify.clone();
+        visit_sql_exprs_mut(&mut predicate, &mut |e| {
+            if let SQLExpr::Identifier(ident) = e {
+                if let Some(expr) = aliases.get(ident.value.as_str()) {
+                    *e = SQLExpr::Nested(Box::new((*expr).clone()));
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        })?;
+
+        // DISTINCT applies to the filtered rows (and does not see the hidden column)
+        let mut stmt = select_stmt.clone();
+        stmt.qualify = None;
+        stmt.distinct = None;
+        stmt.projection.push(SelectItem::ExprWithAlias {
+            expr: predicate,
+            alias: Ident::new(QUALIFY_NAME),
+        });
+        let lf = self
+            .execute_select(&stmt, query)?
+            .filter(col(QUALIFY_NAME))
+            .drop(by_name([QUALIFY_NAME], true));
+
+        self.process_distinct(lf, &select_stmt.distinct, query)
+    }
+
+    fn process_distinct(
+        &mut self,
+        mut lf: LazyFrame,
+        distinct: &Option<Distinct>,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
+        Ok(match distinct {
             Some(Distinct::Distinct) => lf.unique_stable(None, UniqueKeepStrategy::Any),
             Some(Distinct::On(exprs)) => {
                 // TODO: support exprs in `unique` see https://github.com/pola-rs/polars/issues/5760
@@ -922,17 +1641,16 @@ impl SQLContext {
 
                 // DISTINCT ON has to apply the ORDER BY before the operation.
                 lf = self.process_order_by(lf, &query.order_by, None)?;
-                return Ok(lf.unique_stable(
+                lf.unique_stable(
                     Some(Selector::ByName {
                         names: cols.into(),
                         strict: true,
                     }),
                     UniqueKeepStrategy::First,
-                ));
+                )
             },
             None => lf,
-        };
-        Ok(lf)
+        })
     }
 
     fn column_projections(
@@ -962,6 +1680,7 @@ impl SQLContext {
                 SelectItem::Wildcard(wildcard_options) => {
                     let cols = schema
                         .iter_names()
+                        .filter(|name| !name.starts_with(SUBQUERY_PREFIX))
                         .map(|name| col(name.clone()))
                         .collect::<Vec<_>>();
 
@@ -1011,7 +1730,22 @@ impl SQLContext {
                 return Ok(DataFrame::empty_with_schema(schema.as_ref()).lazy());
             }
 
-            // ...otherwise parse and apply the filter as normal
+            // ...otherwise decorrelate any subqueries (as joins against the frame)
+            let mut hidden = vec![];
+            let decorrelated;
+            let (expr, schema) = if contains_subquery(expr) {
+                let predicate;
+                (lf, predicate, hidden) = self.decorrelate_predicate(lf, expr, !invert_filter)?;
+                match predicate {
+                    Some(predicate) => decorrelated = predicate,
+                    None => return Ok(lf),
+                }
+                (&decorrelated, self.get_frame_schema(&mut lf)?)
+            } else {
+                (expr, schema)
+            };
+
+            // ...then parse and apply the filter as normal
             let mut filter_expression = parse_sql_expr(expr, self, Some(schema).as_deref())?;
             if filter_expression.clone().meta().has_multiple_outputs() {
                 filter_expression = all_horizontal([filter_expression])?;
@@ -1022,6 +1756,9 @@ impl SQLContext {
             } else {
                 lf.filter(filter_expression)
             };
+            if !hidden.is_empty() {
+                lf = lf.drop(by_name(hidden, true));
+            }
         }
         Ok(lf)
     }
@@ -1033,6 +1770,11 @@ impl SQLContext {
         constraint: &JoinConstraint,
         join_type: JoinType,
     ) -> PolarsResult<LazyFrame> {
+        if let JoinConstraint::On(expr) = constraint {
+            if !is_equi_join_constraint(expr) {
+                return self.process_join_where(tbl_left, tbl_right, expr, join_type);
+            }
+        }
         let (left_on, right_on) = process_join_constraint(constraint, tbl_left, tbl_right)?;
 
         let joined = tbl_left
@@ -1050,6 +1792,182 @@ impl SQLContext {
         Ok(joined)
     }
 
+    /// Join on an arbitrary (non-equi) constraint.
+    ///
+    /// The equalities between the columns of either side (among the AND-ed terms of the
+    /// constraint) become the keys of an equi-join that is filtered by the remaining terms;
+    /// without any such equalities, the join is done with `join_where`. Either only produces
+    /// the matching rows; for outer (and semi/anti) joins the rows of each side are identified
+    /// by their index so that the unmatched rows can be added back (or filtered) afterwards.
+    fn process_join_where(
+        &mut self,
+        tbl_left: &TableInfo,
+        tbl_right: &TableInfo,
+        constraint: &SQLExpr,
+        join_type: JoinType,
+    ) -> PolarsResult<LazyFrame> {
+        // unqualified columns must belong to exactly one side of the join
+        visit_sql_exprs_mut(&mut constraint.clone(), &mut |e| {
+            if let SQLExpr::Identifier(ident) = e {
+                polars_ensure!(
+                    !(tbl_left.schema.contains(&ident.value) && tbl_right.schema.contains(&ident.value)),
+                    SQLInterface: "ambiguous column '{}' in join constraint; qualify it with a table name or alias", ident.value
+                );
+            }
+            Ok(true)
+        })?;
+
+        // resolve the constraint against the joined schema
+        let suffix = format_pl_smallstr!(":{}", tbl_right.name);
+        let mut joined = tbl_left
+            .frame
+            .clone()
+            .cross_join(tbl_right.frame.clone(), Some(suffix.clone()));
+        let joined_schema = self.get_frame_schema(&mut joined)?;
+        self.register_joined_aliases(
+            &tbl_right.name,
+            &tbl_left.schema,
+            &tbl_right.schema,
+            &joined_schema,
+        );
+        let (left_on, right_on, predicate) = split_equi_join_keys(constraint, tbl_left, tbl_right);
+        let predicate = predicate
+            .map(|predicate| parse_sql_expr(&predicate, self, Some(&joined_schema)))
+            .transpose()?
+            .map(|predicate| {
+                predicate.map_expr(|e| {
+                    // (qualified references to suffixed columns are aliased to their original name)
+                    match e {
+                        Expr::Alias(e, _) => Arc::unwrap_or_clone(e),
+                        e => e,
+                    }
+                })
+            });
+        let join_where = |left: LazyFrame, right: LazyFrame| {
+            let builder = left
+                .join_builder()
+                .with(right)
+                .how(JoinType::Inner)
+                .suffix(suffix.clone());
+            if left_on.is_empty() {
+                // (without any keys, the constraint has other terms)
+                return builder.join_where(vec![predicate.clone().unwrap()]);
+            }
+            let joined = builder
+                .left_on(left_on.clone())
+                .right_on(right_on.clone())
+                .coalesce(JoinCoalesce::KeepColumns)
+                .finish();
+            match &predicate {
+                Some(predicate) => joined.filter(predicate.clone()),
+                None => joined,
+            }
+        };
+        if join_type == JoinType::Inner {
+            return Ok(join_where(tbl_left.frame.clone(), tbl_right.frame.clone()));
+        }
+
+        let left = tbl_left.frame.clone().with_row_index(JOIN_LEFT_INDEX, None);
+        let right = tbl_right
+            .frame
+            .clone()
+            .with_row_index(JOIN_RIGHT_INDEX, None);
+        let matched = join_where(left.clone(), right.clone());
+        let with_match = |lf: LazyFrame, index: &str| {
+            let matches = matched
+                .clone()
+                .select([col(index)])
+                .unique(None, UniqueKeepStrategy::Any)
+                .with_column(lit(true).alias(JOIN_MATCHED));
+            lf.join(
+                matches,
+                [col(index)],
+                [col(index)],
+                JoinArgs::new(JoinType::Left),
+            )
+        };
+
+        // rows without a match have nulls for the columns of the other side
+        let n_left = tbl_left.schema.len();
+        let unmatched = |lf: LazyFrame, from_left: bool| {
+            let mut exprs = joined_schema
+                .iter()
+                .enumerate()
+                .map(|(idx, (name, dtype))| match (idx < n_left, from_left) {
+                    (true, true) => col(name.clone()),
+                    (false, false) => col(tbl_right
+                        .schema
+                        .get_at_index(idx - n_left)
+                        .unwrap()
+                        .0
+                        .clone())
+                    .alias(name.clone()),
+                    _ => lit(NULL).cast(dtype.clone()).alias(name.clone()),
+                })
+                .collect::<Vec<_>>();
+            for (index, from_side) in [(JOIN_LEFT_INDEX, from_left), (JOIN_RIGHT_INDEX, !from_left)]
+            {
+                exprs.push(match from_side {
+                    true => col(index),
+                    false => lit(NULL).cast(IDX_DTYPE).alias(index),
+                });
+            }
+            with_match(
+                lf,
+                if from_left {
+                    JOIN_LEFT_INDEX
+                } else {
+                    JOIN_RIGHT_INDEX
+                },
+            )
+            .filter(col(JOIN_MATCHED).is_null())
+            .select(exprs)
+        };
+
+        let (mut parts, order) = match join_type {
+            JoinType::Left => (
+                vec![matched.clone(), unmatched(left, true)],
+                [JOIN_LEFT_INDEX, JOIN_RIGHT_INDEX],
+            ),
+            JoinType::Right => (
+                vec![matched.clone(), unmatched(right, false)],
+                [JOIN_RIGHT_INDEX, JOIN_LEFT_INDEX],
+            ),
+            JoinType::Full => (
+                vec![
+                    matched.clone(),
+                    unmatched(left, true),
+                    unmatched(right, false),
+                ],
+                [JOIN_LEFT_INDEX, JOIN_RIGHT_INDEX],
+            ),
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => {
+                let is_matched = col(JOIN_MATCHED).is_not_null();
+                return Ok(with_match(left, JOIN_LEFT_INDEX)
+                    .filter(match join_type {
+                        JoinType::Semi => is_matched,
+                        _ => is_matched.not(),
+                    })
+                    .drop(by_name([JOIN_LEFT_INDEX, JOIN_MATCHED], true)));
+            },
+            join_type => {
+                polars_bail!(SQLInterface: "join type '{:?}' not currently supported with a non-equi join constraint", join_type)
+            },
+        };
+        // align the column order of the matched rows with the unmatched rows
+        let columns = joined_schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .chain([col(JOIN_LEFT_INDEX), col(JOIN_RIGHT_INDEX)])
+            .collect::<Vec<_>>();
+        parts[0] = parts[0].clone().select(columns);
+
+        Ok(polars_lazy::dsl::concat(parts, UnionArgs::default())?
+            .sort(order, SortMultipleOptions::default().with_nulls_last(true))
+            .drop(by_name([JOIN_LEFT_INDEX, JOIN_RIGHT_INDEX], true)))
+    }
+
     fn process_subqueries(&self, lf: LazyFrame, exprs: Vec<&mut Expr>) -> LazyFrame {
         let mut contexts = vec![];
         for expr in exprs {
@@ -1107,13 +2025,18 @@ impl SQLContext {
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
-                name, alias, args, ..
+                name,
+                alias,
+                args,
+                with_hints,
+                ..
             } => {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
                 let tbl_name = name.0.first().unwrap().value.as_str();
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                    let lf = self.process_table_sample(lf, with_hints)?;
                     match alias {
                         Some(alias) => {
                             self.table_aliases
@@ -1215,11 +2138,257 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (_, lf) = self.get_table(table)?;
+                let lf = self.execute_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                self.register_table_operator_alias(lf, alias)
+            },
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let (_, mut lf) = self.get_table(table)?;
+                let schema = self.get_frame_schema(&mut lf)?;
+                let on: Vec<PlSmallStr> = columns.iter().map(|c| c.value.as_str().into()).collect();
+                let index: Vec<PlSmallStr> = schema
+                    .iter_names()
+                    .filter(|nm| !on.contains(nm))
+                    .cloned()
+                    .collect();
+
+                // UNPIVOT excludes rows with NULL values
+                let lf = lf
+                    .unpivot(UnpivotArgsDSL {
+                        on: by_name(on, true),
+                        index: by_name(index, true),
+                        variable_name: Some(name.value.as_str().into()),
+                        value_name: Some(value.value.as_str().into()),
+                    })
+                    .filter(col(value.value.as_str()).is_not_null());
+                self.register_table_operator_alias(lf, alias)
+            },
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    /// Register the result of a PIVOT/UNPIVOT operator under its (optional) alias.
+    fn register_table_operator_alias(
+        &mut self,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok(("".to_string(), lf)),
+        }
+    }
+
+    /// Rotate the values of the pivot column into columns, evaluating each aggregate on
+    /// the rows matching the value (grouped by the remaining columns of the table).
+    fn execute_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let pivot_col = match value_column {
+            [.., ident] => PlSmallStr::from_str(ident.value.as_str()),
+            [] => polars_bail!(SQLSyntax: "PIVOT requires a column to pivot on"),
+        };
+        polars_ensure!(
+            schema.contains(&pivot_col),
+            ColumnNotFound: "PIVOT column '{}' not found", pivot_col
+        );
+
+        // Pivot values (and the names of the columns they become)
+        let values: Vec<(Expr, String)> = match value_source {
+            PivotValueSource::List(values) => values
+                .iter()
+                .map(|v| {
+                    let value = parse_sql_expr(&v.expr, self, Some(&schema))?;
+                    let name = match (&v.alias, &v.expr) {
+                        (Some(alias), _) => alias.value.clone(),
+                        (None, SQLExpr::Value(SQLValue::SingleQuotedString(s))) => s.clone(),
+                        (None, expr) => expr.to_string(),
+                    };
+                    Ok((value, name))
+                })
+                .collect::<PolarsResult<_>>()?,
+            PivotValueSource::Any(order_by) => {
+                let descending = match order_by.as_slice() {
+                    [] => false,
+                    [ob] if matches!(&ob.expr, SQLExpr::Identifier(ident) if ident.value == pivot_col.as_str()) => {
+                        ob.asc == Some(false)
+                    },
+                    _ => {
+                        polars_bail!(SQLInterface: "PIVOT ... IN (ANY ORDER BY ...) only supports ordering by the pivot column")
+                    },
+                };
+                let values = lf
+                    .clone()
+                    .select([col(pivot_col.clone())])
+                    .unique(None, UniqueKeepStrategy::Any)
+                    .sort(
+                        [pivot_col.as_str()],
+                        SortMultipleOptions::default().with_order_descending(descending),
+                    );
+                pivot_values(self.collect_eager(values, "PIVOT")?)?
+            },
+            PivotValueSource::Subquery(subquery) => {
+                let mut values = self.execute_query_no_ctes(subquery)?;
+                let first = self.get_frame_schema(&mut values)?;
+                let Some(name) = first.get_at_index(0).map(|(name, _)| name.clone()) else {
+                    polars_bail!(SQLSyntax: "PIVOT subquery must return a column")
+                };
+                let values = values
+                    .select([col(name).alias(pivot_col.clone())])
+                    .unique_stable(None, UniqueKeepStrategy::First);
+                pivot_values(self.collect_eager(values, "PIVOT")?)?
+            },
+        };
+
+        // Aggregates; the remaining columns of the table form the group keys
+        let mut aggregates = Vec::with_capacity(aggregate_functions.len());
+        let mut agg_columns = PlHashSet::new();
+        for agg in aggregate_functions {
+            let expr = parse_sql_expr(&agg.expr, self, Some(&schema))?;
+            agg_columns.extend(expr_to_leaf_column_names_iter(&expr));
+            let suffix = match &agg.alias {
+                Some(alias) => alias.value.clone(),
+                None => agg.expr.to_string(),
+            };
+            aggregates.push((expr, suffix));
+        }
+        let default_value = default_on_null
+            .map(|e| parse_sql_expr(e, self, Some(&schema)))
+            .transpose()?;
+
+        let index: Vec<Expr> = schema
+            .iter_names()
+            .filter(|nm| **nm != pivot_col && !agg_columns.contains(*nm))
+            .map(|nm| col(nm.clone()))
+            .collect();
+
+        let mut exprs = Vec::with_capacity(values.len() * aggregates.len());
+        for (value, value_name) in &values {
+            let is_value = col(pivot_col.clone()).eq(value.clone());
+            for (agg, suffix) in &aggregates {
+                let is_count = matches!(agg, Expr::Len | Expr::Agg(AggExpr::Count(..)));
+                let mut expr = agg.clone().map_expr(|e| match e {
+                    Expr::Column(name) => col(name).filter(is_value.clone()),
+                    Expr::Len => col(pivot_col.clone()).filter(is_value.clone()).len(),
+                    e => e,
+                });
+                // Aggregates of groups without matching rows are NULL (and counts are zero)
+                if !is_count {
+                    expr = when(is_value.clone().any(true))
+                        .then(expr)
+                        .otherwise(lit(NULL));
+                }
+                if let Some(default_value) = &default_value {
+                    expr = expr.fill_null(default_value.clone());
+                }
+                let name = if aggregates.len() == 1 {
+                    value_name.clone()
+                } else {
+                    format!("{value_name}_{suffix}")
+                };
+                exprs.push(expr.alias(name));
+            }
+        }
+        Ok(if index.is_empty() {
+            lf.select(exprs)
+        } else {
+            lf.group_by_stable(index).agg(exprs)
+        })
+    }
+
+    /// Apply a `TABLESAMPLE` clause (rewritten as a table hint before parsing).
+    fn process_table_sample(
+        &mut self,
+        lf: LazyFrame,
+        hints: &[SQLExpr],
+    ) -> PolarsResult<LazyFrame> {
+        let Some(args) = hints.iter().find_map(|hint| match hint {
+            SQLExpr::Function(func) if func.name.to_string() == TABLESAMPLE => Some(&func.args),
+            _ => None,
+        }) else {
+            return Ok(lf);
+        };
+        let args: Vec<&SQLValue> = match args {
+            FunctionArguments::List(list) => list
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Value(v))) => Some(v),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+        let (size, unit, seed) = match args.as_slice() {
+            [
+                SQLValue::Number(size, _),
+                SQLValue::SingleQuotedString(unit),
+                seed,
+            ] => {
+                let seed = match seed {
+                    SQLValue::Number(seed, _) => seed.parse::<u64>().map_err(
+                        |_| polars_err!(SQLSyntax: "invalid TABLESAMPLE seed: {}", seed),
+                    )?,
+                    // without REPEATABLE, draw a seed (shared by all of the sampled columns)
+                    SQLValue::Null => rand::random::<u64>(),
+                    _ => polars_bail!(SQLSyntax: "invalid TABLESAMPLE seed: {}", seed),
+                };
+                (size, unit.as_str(), seed)
+            },
+            _ => polars_bail!(SQLSyntax: "invalid TABLESAMPLE clause"),
+        };
+        let sample = if unit == "ROWS" {
+            let n = size.parse::<IdxSize>().map_err(
+                |_| polars_err!(SQLSyntax: "TABLESAMPLE row count must be a non-negative integer, found {}", size),
+            )?;
+            let n = when(len().gt(lit(n))).then(lit(n)).otherwise(len());
+            all().as_expr().sample_n(n, false, false, Some(seed))
+        } else {
+            let pct = size
+                .parse::<f64>()
+                .map_err(|_| polars_err!(SQLSyntax: "invalid TABLESAMPLE percentage: {}", size))?;
+            polars_ensure!(
+                (0.0..=100.0).contains(&pct),
+                SQLSyntax: "TABLESAMPLE percentage must be between 0 and 100, found {}", size
+            );
+            all()
+                .as_expr()
+                .sample_frac(lit(pct / 100.0), false, false, Some(seed))
+        };
+        Ok(lf.select([sample]))
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -1299,19 +2468,53 @@ impl SQLContext {
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<Vec<Vec<Expr>>>,
         projections: &[Expr],
     ) -> PolarsResult<LazyFrame> {
-        let schema_before = self.get_frame_schema(&mut lf)?;
+        let mut schema_before = self.get_frame_schema(&mut lf)?;
         let group_by_keys_schema =
             expressions_to_schema(group_by_keys, &schema_before, Context::Default)?;
 
+        // "GROUPING(...)" refers to hidden per-key indicator columns that are derived from
+        // the grouping id after the aggregation (and dropped by the final projection).
+        let grouping_indicators = group_by_keys_schema
+            .iter_names()
+            .map(|name| grouping_indicator_name(name))
+            .collect::<Vec<_>>();
+        let is_grouping_expr = |e: &Expr| {
+            expr_to_leaf_column_names_iter(e)
+                .any(|name| name.starts_with(GROUPING_INDICATOR_PREFIX))
+        };
+        let uses_grouping = projections.iter().any(is_grouping_expr);
+        if uses_grouping {
+            for name in projections.iter().flat_map(expr_to_leaf_column_names_iter) {
+                if let Some(key) = name.strip_prefix(GROUPING_INDICATOR_PREFIX) {
+                    polars_ensure!(
+                        group_by_keys_schema.contains(key),
+                        SQLSyntax: "GROUPING argument '{}' is not part of the GROUP BY clause", key
+                    );
+                }
+            }
+            let mut schema = (*schema_before).clone();
+            for name in &grouping_indicators {
+                schema.with_column(name.clone(), DataType::UInt32);
+            }
+            schema_before = Arc::new(schema);
+        }
+
         // Remove the group_by keys as polars adds those implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
         let mut projection_overrides = PlHashMap::with_capacity(projections.len());
         let mut projection_aliases = PlHashSet::new();
         let mut group_key_aliases = PlHashSet::new();
+        let mut grouping_projections = PlHashSet::new();
 
         for mut e in projections {
+            if uses_grouping && is_grouping_expr(e) {
+                let field = e.to_field(&schema_before, Context::Default)?;
+                grouping_projections.insert(field.name);
+                continue;
+            }
             // `Len` represents COUNT(*) so we treat as an aggregation here.
             let is_agg_or_window = has_expr(e, |e| {
                 matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. })
@@ -1371,7 +2574,27 @@ impl SQLContext {
                 polars_bail!(SQLSyntax: "Unsupported operation in the GROUP BY clause: {}", e);
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let aggregated =
+            match grouping_sets.or_else(|| uses_grouping.then(|| vec![group_by_keys.to_vec()])) {
+                Some(sets) => {
+                    // The grouping id has one bit per key (the first key being the most significant).
+                    let n_keys = grouping_indicators.len();
+                    let indicators = grouping_indicators
+                        .iter()
+                        .enumerate()
+                        .map(|(i, name)| {
+                            (col(GROUPING_ID_NAME).floor_div(typed_lit(1u32 << (n_keys - 1 - i)))
+                                % typed_lit(2u32))
+                            .alias(name.clone())
+                        })
+                        .collect::<Vec<_>>();
+                    lf.group_by_grouping_sets(sets)
+                        .with_grouping_id_name(GROUPING_ID_NAME)
+                        .agg(&aggregation_projection)?
+                        .with_columns(indicators)
+                },
+                None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+            };
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
 
@@ -1385,6 +2608,7 @@ impl SQLContext {
                 } else if group_by_keys_schema.get(name).is_some()
                     || projection_aliases.contains(name.as_str())
                     || group_key_aliases.contains(name.as_str())
+                    || grouping_projections.contains(name)
                 {
                     projection_expr.clone()
                 } else {
@@ -1567,6 +2791,231 @@ impl SQLContext {
     }
 }
 
+/// Check whether a query references the given table in one of its `FROM` or `JOIN` relations,
+/// including in derived tables, nested joins and set operations.
+fn set_expr_references_table(expr: &SetExpr, name: &str) -> bool {
+    fn factor_references_table(factor: &TableFactor, name: &str) -> bool {
+        match factor {
+            TableFactor::Table { name: tbl, .. } => {
+                tbl.0.first().is_some_and(|ident| ident.value == name)
+            },
+            TableFactor::Derived { subquery, .. } => {
+                set_expr_references_table(&subquery.body, name)
+            },
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => tables_reference_table(std::slice::from_ref(table_with_joins.as_ref()), name),
+            _ => false,
+        }
+    }
+    fn tables_reference_table(tables: &[TableWithJoins], name: &str) -> bool {
+        tables.iter().any(|tbl| {
+            factor_references_table(&tbl.relation, name)
+                || tbl
+                    .joins
+                    .iter()
+                    .any(|join| factor_references_table(&join.relation, name))
+        })
+    }
+
+    match expr {
+        SetExpr::Select(select) => tables_reference_table(&select.from, name),
+        SetExpr::Query(query) => set_expr_references_table(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            set_expr_references_table(left, name) || set_expr_references_table(right, name)
+        },
+        SetExpr::Table(tbl) => tbl.table_name.as_deref() == Some(name),
+        _ => false,
+    }
+}
+
+/// Expand the key groups of a `ROLLUP` into its grouping sets; for `ROLLUP(a, b)` these
+/// are `(a, b)`, `(a)` and `()`.
+fn rollup_sets(groups: Vec<Vec<Expr>>) -> Vec<Vec<Expr>> {
+    (0..=groups.len())
+        .rev()
+        .map(|n| groups[..n].concat())
+        .collect()
+}
+
+/// Expand the key groups of a `CUBE` into its grouping sets (every combination of the groups).
+fn cube_sets(groups: Vec<Vec<Expr>>) -> PolarsResult<Vec<Vec<Expr>>> {
+    let n = groups.len();
+    polars_ensure!(
+        n <= MAX_CUBE_ELEMENTS,
+        SQLInterface: "CUBE supports at most {} elements (found {})", MAX_CUBE_ELEMENTS, n
+    );
+    Ok((0..1usize << n)
+        .map(|mask| {
+            groups
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << (n - 1 - i)) == 0)
+                .flat_map(|(_, g)| g.iter().cloned())
+                .collect()
+        })
+        .collect())
+}
+
+/// Name of the hidden column indicating whether the given group key was aggregated over
+/// (1) or not (0); these columns back the `GROUPING(...)` function.
+pub(crate) fn grouping_indicator_name(key: &str) -> PlSmallStr {
+    format_pl_smallstr!("{}{}", GROUPING_INDICATOR_PREFIX, key)
+}
+
+/// Resolve the columns targeted by an INSERT, checking that they exist and are unique.
+fn dml_target_columns<'a>(
+    columns: impl Iterator<Item = &'a str>,
+    schema: &Schema,
+) -> PolarsResult<Vec<PlSmallStr>> {
+    let mut targets: Vec<PlSmallStr> = vec![];
+    for name in columns {
+        polars_ensure!(
+            schema.contains(name),
+            ColumnNotFound: "column '{}' does not exist in the target table", name
+        );
+        polars_ensure!(
+            !targets.iter().any(|c| c == name),
+            SQLSyntax: "column '{}' is specified more than once", name
+        );
+        targets.push(name.into());
+    }
+    Ok(targets)
+}
+
+/// Split UPDATE assignments (`SET <col> = <expr>, ...`) into their target columns and values.
+fn dml_assignments(
+    assignments: &[Assignment],
+    schema: &Schema,
+) -> PolarsResult<(Vec<PlSmallStr>, Vec<SQLExpr>)> {
+    let names = assignments
+        .iter()
+        .map(|assignment| match &assignment.target {
+            AssignmentTarget::ColumnName(name) => Ok(name.0.last().unwrap().value.as_str()),
+            AssignmentTarget::Tuple(_) => {
+                polars_bail!(SQLInterface: "tuple assignments are not supported; found {}", assignment)
+            },
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let targets = dml_target_columns(names.into_iter(), schema)?;
+    let values = assignments.iter().map(|a| a.value.clone()).collect();
+    Ok((targets, values))
+}
+
+/// The (non-null) values of a single-column frame, as literals along with the names of the
+/// PIVOT columns they become.
+fn pivot_values(df: DataFrame) -> PolarsResult<Vec<(Expr, String)>> {
+    let df = df.drop_nulls::<String>(None)?;
+    let values = df.get_columns()[0].as_materialized_series();
+    let names = values.cast(&DataType::String)?;
+    let names = names.str()?;
+    (0..values.len())
+        .map(|i| {
+            let value = Scalar::new(values.dtype().clone(), values.get(i)?.into_static());
+            Ok((lit(value), names.get(i).unwrap().to_string()))
+        })
+        .collect()
+}
+
+/// Number anonymous `?` placeholders by their position (as `$1`, `$2`, ...).
+fn number_anonymous_placeholders(tokens: Vec<TokenWithSpan>) -> PolarsResult<Vec<TokenWithSpan>> {
+    let mut n_anonymous = 0;
+    let mut n_numbered = 0;
+    let tokens = tokens
+        .into_iter()
+        .map(|mut t| {
+            if let Token::Placeholder(name) = &mut t.token {
+                if name == "?" {
+                    n_anonymous += 1;
+                    *name = format!("${n_anonymous}");
+                } else if name.starts_with('$') {
+                    n_numbered += 1;
+                }
+            }
+            t
+        })
+        .collect();
+    polars_ensure!(
+        n_anonymous == 0 || n_numbered == 0,
+        SQLSyntax: "cannot mix anonymous ('?') and numbered ('$n') placeholders"
+    );
+    Ok(tokens)
+}
+
+/// Rewrite `TABLESAMPLE [method] (n [PERCENT | ROWS]) [REPEATABLE | SEED (seed)]` clauses
+/// (which the parser does not support) as an equivalent table hint, of the form
+/// `WITH (TABLESAMPLE(n, 'PERCENT' | 'ROWS', seed | NULL))`.
+fn rewrite_table_samples(tokens: Vec<TokenWithSpan>) -> PolarsResult<Vec<TokenWithSpan>> {
+    fn is_word(token: Option<&TokenWithSpan>, words: &[&str]) -> bool {
+        matches!(token.map(|t| &t.token), Some(Token::Word(w))
+            if w.quote_style.is_none() && words.iter().any(|kw| w.value.eq_ignore_ascii_case(kw)))
+    }
+    if !tokens.iter().any(|t| is_word(Some(t), &[TABLESAMPLE])) {
+        return Ok(tokens);
+    }
+    let mut tokens = tokens
+        .into_iter()
+        .filter(|t| !matches!(t.token, Token::Whitespace(_)))
+        .peekable();
+
+    let mut rewritten = vec![];
+    while let Some(token) = tokens.next() {
+        if !is_word(Some(&token), &[TABLESAMPLE]) {
+            rewritten.push(token);
+            continue;
+        }
+        let parenthesized_number = |tokens: &mut std::iter::Peekable<_>| {
+            let mut next = || tokens.next().map(|t: TokenWithSpan| t.token);
+            match (next(), next()) {
+                (Some(Token::LParen), Some(Token::Number(n, _))) => Ok((n, next())),
+                _ => Err(
+                    polars_err!(SQLSyntax: "invalid TABLESAMPLE clause; expected a parenthesised sample size"),
+                ),
+            }
+        };
+        if is_word(tokens.peek(), &["BERNOULLI", "SYSTEM", "ROW", "BLOCK"]) {
+            tokens.next();
+        }
+        let (size, mut next) = parenthesized_number(&mut tokens)?;
+        let mut unit = "PERCENT";
+        if let Some(Token::Word(w)) = &next {
+            if w.value.eq_ignore_ascii_case("ROWS") {
+                unit = "ROWS";
+            } else if !w.value.eq_ignore_ascii_case("PERCENT") {
+                polars_bail!(SQLSyntax: "invalid TABLESAMPLE unit: {}", w.value);
+            }
+            next = tokens.next().map(|t| t.token);
+        }
+        polars_ensure!(next == Some(Token::RParen), SQLSyntax: "invalid TABLESAMPLE clause; expected ')' after the sample size");
+
+        let mut seed = Token::make_keyword("NULL");
+        if is_word(tokens.peek(), &["REPEATABLE", "SEED"]) {
+            tokens.next();
+            let (n, next) = parenthesized_number(&mut tokens)?;
+            polars_ensure!(next == Some(Token::RParen), SQLSyntax: "invalid TABLESAMPLE clause; expected ')' after the seed");
+            seed = Token::Number(n, false);
+        }
+        rewritten.extend(
+            [
+                Token::make_keyword("WITH"),
+                Token::LParen,
+                Token::make_word(TABLESAMPLE, None),
+                Token::LParen,
+                Token::Number(size, false),
+                Token::Comma,
+                Token::SingleQuotedString(unit.to_string()),
+                Token::Comma,
+                seed,
+                Token::RParen,
+                Token::RParen,
+            ]
+            .into_iter()
+            .map(TokenWithSpan::wrap),
+        );
+    }
+    Ok(rewritten)
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -1612,6 +3061,80 @@ fn is_regex_colname(nm: &str) -> bool {
     nm.starts_with('^') && nm.ends_with('$')
 }
 
+/// Check if a join constraint only consists of (AND-combined) equalities between columns.
+fn is_equi_join_constraint(expr: &SQLExpr) -> bool {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => is_equi_join_constraint(left) && is_equi_join_constraint(right),
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => matches!(
+            (left.as_ref(), right.as_ref()),
+            (
+                SQLExpr::CompoundIdentifier(_),
+                SQLExpr::CompoundIdentifier(_)
+            )
+        ),
+        SQLExpr::Nested(expr) => is_equi_join_constraint(expr),
+        _ => false,
+    }
+}
+
+/// Split the AND-ed terms of a join constraint into the equalities between a column of the
+/// left and a column of the right table (as the left and right join keys), and the others.
+fn split_equi_join_keys(
+    constraint: &SQLExpr,
+    tbl_left: &TableInfo,
+    tbl_right: &TableInfo,
+) -> (Vec<Expr>, Vec<Expr>, Option<SQLExpr>) {
+    let (mut left_on, mut right_on, mut others) = (vec![], vec![], vec![]);
+    for term in split_conjunction(constraint) {
+        if let SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } = &term
+        {
+            if let (SQLExpr::CompoundIdentifier(a), SQLExpr::CompoundIdentifier(b)) =
+                (left.as_ref(), right.as_ref())
+            {
+                if let ([tbl_a, col_a], [tbl_b, col_b]) = (a.as_slice(), b.as_slice()) {
+                    let side = |tbl: &Ident| match tbl.value.as_str() {
+                        name if name == tbl_left.name.as_str() => Some(true),
+                        name if name == tbl_right.name.as_str() => Some(false),
+                        _ => None,
+                    };
+                    match (side(tbl_a), side(tbl_b)) {
+                        (Some(true), Some(false)) => {
+                            left_on.push(col(col_a.value.as_str()));
+                            right_on.push(col(col_b.value.as_str()));
+                            continue;
+                        },
+                        (Some(false), Some(true)) => {
+                            left_on.push(col(col_b.value.as_str()));
+                            right_on.push(col(col_a.value.as_str()));
+                            continue;
+                        },
+                        _ => {},
+                    }
+                }
+            }
+        }
+        others.push(term);
+    }
+    let others = others.into_iter().reduce(|left, right| SQLExpr::BinaryOp {
+        left: Box::new(left),
+        op: BinaryOperator::And,
+        right: Box::new(right),
+    });
+    (left_on, right_on, others)
+}
+
 fn process_join_on(
     expression: &sqlparser::ast::Expr,
     tbl_left: &TableInfo,
@@ -1,26 +1,37 @@
 use std::ops::Sub;
 
-use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
+use polars_core::chunked_array::ops::SortMultipleOptions;
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, FillNullStrategy, IDX_DTYPE, PolarsResult, QuantileMethod, RollingFnParams,
+    RollingOptionsFixedWindow, RollingVarParams, Schema, SortOptions, TimeUnit, polars_bail,
+    polars_ensure, polars_err,
 };
-use polars_lazy::dsl::Expr;
+use polars_lazy::dsl::{AggExpr, Expr, WindowMapping};
 use polars_ops::chunked_array::UnicodeForm;
 use polars_ops::series::RoundMode;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
-use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
+use polars_plan::dsl::{
+    coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, repeat, when,
+};
+use polars_plan::plans::{DynLiteralValue, LiteralValue, NULL, typed_lit};
 use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
+use polars_plan::utils::expr_output_name;
+use polars_time::chunkedarray::RollingOptionsDynamicWindow;
+use polars_time::{ClosedWindow, Duration};
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::context::grouping_indicator_name;
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -549,6 +560,14 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT COVAR_SAMP(column_1, column_2) FROM df;
     /// ```
     CovarSamp,
+    /// SQL 'grouping' function.
+    /// Indicates whether the given GROUP BY keys were aggregated over (when using
+    /// ROLLUP, CUBE or GROUPING SETS); each argument contributes one bit, which is
+    /// set if that key is not part of the grouping set that produced the row.
+    /// ```sql
+    /// SELECT a, b, SUM(c), GROUPING(a, b) FROM df GROUP BY ROLLUP(a, b);
+    /// ```
+    Grouping,
     /// SQL 'first' function.
     /// Returns the first element of the grouping.
     /// ```sql
@@ -753,6 +772,7 @@ impl PolarsSQLFunctions {
             "first",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
@@ -923,6 +943,7 @@ impl PolarsSQLFunctions {
             "covar_pop" => Self::CovarPop,
             "covar" | "covar_samp" => Self::CovarSamp,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -1434,6 +1455,15 @@ impl SQLFunctionVisitor<'_> {
             CovarPop => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 0)),
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
             First => self.visit_unary(Expr::first),
+            Grouping => self.try_visit_variadic(|exprs: &[Expr]| {
+                polars_ensure!(!exprs.is_empty(), SQLSyntax: "GROUPING expects at least 1 argument");
+                let mut grouping = typed_lit(0u32);
+                for e in exprs {
+                    let name = expr_output_name(e)?;
+                    grouping = grouping * typed_lit(2u32) + col(grouping_indicator_name(&name));
+                }
+                Ok(grouping.alias("grouping"))
+            }),
             Last => self.visit_unary(Expr::last),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
@@ -1655,7 +1685,7 @@ impl SQLFunctionVisitor<'_> {
         cumulative_f: impl Fn(Expr, bool) -> Expr,
     ) -> PolarsResult<Expr> {
         match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
+            Some(WindowType::WindowSpec(spec)) if spec.window_frame.is_none() => {
                 self.apply_cumulative_window(f, cumulative_f, spec)
             },
             Some(WindowType::NamedWindow(named_window)) => polars_bail!(
@@ -1870,6 +1900,9 @@ impl SQLFunctionVisitor<'_> {
     ) -> PolarsResult<Expr> {
         Ok(match &window_type {
             Some(WindowType::WindowSpec(window_spec)) => {
+                if let Some(frame) = &window_spec.window_frame {
+                    return self.apply_window_frame(expr, window_spec, frame);
+                }
                 if window_spec.partition_by.is_empty() {
                     let exprs = window_spec
                         .order_by
@@ -1900,6 +1933,120 @@ impl SQLFunctionVisitor<'_> {
         })
     }
 
+    /// Translate an aggregate with an explicit window frame (eg: `ROWS BETWEEN 6 PRECEDING
+    /// AND CURRENT ROW`) onto the equivalent rolling (or cumulative) expression, evaluated
+    /// over the window partitions in the window order.
+    fn apply_window_frame(
+        &mut self,
+        expr: Expr,
+        spec: &WindowSpec,
+        frame: &WindowFrame,
+    ) -> PolarsResult<Expr> {
+        let (agg, input) = FrameAgg::try_from_expr(&expr)?;
+        let end_bound = frame
+            .end_bound
+            .as_ref()
+            .unwrap_or(&WindowFrameBound::CurrentRow);
+
+        let mut partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if partition_by.is_empty() {
+            partition_by.push(lit(1));
+        }
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        let mut sort_options: Option<SortOptions> = None;
+        for o in &spec.order_by {
+            let descending = !o.asc.unwrap_or(true);
+            let options = SortOptions::default()
+                .with_order_descending(descending)
+                .with_nulls_last(!o.nulls_first.unwrap_or(descending));
+            match sort_options {
+                Some(opts) if opts != options => {
+                    polars_bail!(SQLInterface: "window frames do not support mixed sort orders in ORDER BY; found '{}'", spec)
+                },
+                _ => sort_options = Some(options),
+            }
+            order_by.push(parse_sql_expr(&o.expr, self.ctx, self.active_schema)?);
+        }
+
+        let framed = match (&frame.start_bound, end_bound) {
+            // the frame covers the whole partition; this is a plain windowed aggregate
+            (WindowFrameBound::Preceding(None), WindowFrameBound::Following(None)) => {
+                return Ok(expr.over(partition_by));
+            },
+            (WindowFrameBound::Preceding(None), WindowFrameBound::CurrentRow)
+                if frame.units == WindowFrameUnits::Range =>
+            {
+                if order_by.is_empty() {
+                    // without ORDER BY all rows are peers; the frame is the whole partition
+                    return Ok(expr.over(partition_by));
+                }
+                // Peers (rows with equal ORDER BY values) share the frame that ends at the
+                // last peer. The partition is sorted here, so take the cumulative value at
+                // the next row that is the last of its peers (by its index in the partition).
+                let row_idx = int_range(lit(0), len(), 1, IDX_DTYPE);
+                let is_last_row = row_idx.clone().eq(len() - lit(1));
+                let is_last_peer = order_by.iter().fold(is_last_row, |acc, by| {
+                    acc.or(by.clone().neq_missing(by.clone().shift(lit(-1))))
+                });
+                let last_peer_idx = when(is_last_peer)
+                    .then(row_idx)
+                    .otherwise(lit(NULL))
+                    .fill_null_with_strategy(FillNullStrategy::Backward(None));
+                agg.cumulative(input, false)?.gather(last_peer_idx)
+            },
+            (WindowFrameBound::Preceding(None), WindowFrameBound::CurrentRow) => {
+                agg.cumulative(input, false)?
+            },
+            (WindowFrameBound::CurrentRow, WindowFrameBound::Following(None))
+                if frame.units == WindowFrameUnits::Rows =>
+            {
+                agg.cumulative(input, true)?
+            },
+            (start, end) => match frame.units {
+                WindowFrameUnits::Rows => {
+                    let (start, end) = (frame_row_offset(start)?, frame_row_offset(end)?);
+                    match (start, end) {
+                        (Some(start), Some(end)) if start <= end => {
+                            agg.rolling_rows(input, start, end)
+                        },
+                        _ => polars_bail!(SQLInterface: "unsupported window frame in '{}'", spec),
+                    }
+                },
+                WindowFrameUnits::Range => {
+                    let (WindowFrameBound::Preceding(Some(offset)), WindowFrameBound::CurrentRow) =
+                        (start, end)
+                    else {
+                        polars_bail!(SQLInterface: "RANGE window frames must be of the form '<offset> PRECEDING AND CURRENT ROW'; found '{}'", spec)
+                    };
+                    let (order_by, descending) = match (order_by.as_slice(), sort_options) {
+                        ([by], Some(opts)) => (by.clone(), opts.descending),
+                        _ => {
+                            polars_bail!(SQLInterface: "RANGE window frames require exactly one ORDER BY expression; found '{}'", spec)
+                        },
+                    };
+                    polars_ensure!(
+                        !descending,
+                        SQLInterface: "RANGE window frames require an ascending ORDER BY; found '{}'", spec
+                    );
+                    // Note: the rolling kernel sorts by `order_by` itself.
+                    let window_size = frame_range_offset(offset)?;
+                    return Ok(agg
+                        .rolling_range(input, order_by, window_size)?
+                        .over(partition_by));
+                },
+                WindowFrameUnits::Groups => {
+                    polars_bail!(SQLInterface: "GROUPS window frames are not supported; found '{}'", spec)
+                },
+            },
+        };
+        let order_by = sort_options.map(|opts| (order_by, opts));
+        framed.over_with_options(Some(partition_by), order_by, WindowMapping::GroupsToRows)
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
@@ -1909,6 +2056,186 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Aggregates that support an explicit window frame.
+#[derive(Clone, Copy)]
+enum FrameAgg {
+    Count,
+    Max,
+    Mean,
+    Median,
+    Min,
+    Std(u8),
+    Sum,
+    Var(u8),
+}
+
+impl FrameAgg {
+    /// Split a (SQL) aggregate expression into the aggregate and its input.
+    fn try_from_expr(expr: &Expr) -> PolarsResult<(Self, Expr)> {
+        Ok(match expr {
+            Expr::Agg(agg) => match agg {
+                // `COUNT(x)` counts the non-null values in the frame
+                AggExpr::Count(e, false) => (
+                    Self::Count,
+                    e.as_ref().clone().is_not_null().cast(IDX_DTYPE),
+                ),
+                AggExpr::Max { input, .. } => (Self::Max, input.as_ref().clone()),
+                AggExpr::Mean(e) => (Self::Mean, e.as_ref().clone()),
+                AggExpr::Median(e) => (Self::Median, e.as_ref().clone()),
+                AggExpr::Min { input, .. } => (Self::Min, input.as_ref().clone()),
+                AggExpr::Std(e, ddof) => (Self::Std(*ddof), e.as_ref().clone()),
+                AggExpr::Sum(e) => (Self::Sum, e.as_ref().clone()),
+                AggExpr::Var(e, ddof) => (Self::Var(*ddof), e.as_ref().clone()),
+                _ => polars_bail!(SQLInterface: "window frames are not supported for '{}'", expr),
+            },
+            // `COUNT(*)` counts the rows in the frame
+            Expr::Len => (Self::Count, repeat(lit(1), len()).cast(IDX_DTYPE)),
+            _ => {
+                polars_bail!(SQLInterface: "window frames are only supported for aggregate functions; found '{}'", expr)
+            },
+        })
+    }
+
+    /// The aggregate over all rows up to (and including) the current row, or from the
+    /// current row onwards if `reverse`.
+    fn cumulative(self, input: Expr, reverse: bool) -> PolarsResult<Expr> {
+        // Note: cumulative functions are null where the input is null; carry the last
+        // value forward instead, as the frame still contains the preceding values.
+        let fill = |e: Expr| {
+            e.fill_null_with_strategy(if reverse {
+                FillNullStrategy::Backward(None)
+            } else {
+                FillNullStrategy::Forward(None)
+            })
+        };
+        Ok(match self {
+            Self::Count => input.cum_sum(reverse),
+            Self::Max => fill(input.cum_max(reverse)),
+            Self::Mean => fill(input.clone().cum_sum(reverse)) / input.cum_count(reverse),
+            Self::Min => fill(input.cum_min(reverse)),
+            Self::Sum => fill(input.cum_sum(reverse)),
+            Self::Median | Self::Std(_) | Self::Var(_) => {
+                polars_bail!(SQLInterface: "unbounded window frames are not supported for {}", self.name())
+            },
+        })
+    }
+
+    /// The aggregate over the rows from `start` to `end` (relative to the current row).
+    fn rolling_rows(self, input: Expr, start: i64, end: i64) -> Expr {
+        let options = RollingOptionsFixedWindow {
+            window_size: (end - start + 1) as usize,
+            min_periods: 1,
+            fn_params: self.fn_params(),
+            ..Default::default()
+        };
+        // The rolling kernels compute the aggregate over a window that ends at each row;
+        // pad the input with nulls (which the kernels skip) for frames that extend past
+        // the current row and shift the result into place.
+        let (input, n_pad) = if end > 0 {
+            let padding = repeat(lit(NULL), lit(end));
+            (input.append(padding, true), end)
+        } else {
+            (input, 0)
+        };
+        let rolled = match self {
+            Self::Count | Self::Sum => input.rolling_sum(options),
+            Self::Max => input.rolling_max(options),
+            Self::Mean => input.rolling_mean(options),
+            Self::Median => input.rolling_median(options),
+            Self::Min => input.rolling_min(options),
+            Self::Std(_) => input.rolling_std(options),
+            Self::Var(_) => input.rolling_var(options),
+        };
+        let rolled = if n_pad > 0 {
+            rolled.slice(lit(n_pad), len())
+        } else if end < 0 {
+            rolled.shift(lit(-end))
+        } else {
+            rolled
+        };
+        match self {
+            Self::Count => rolled.fill_null(lit(0).cast(IDX_DTYPE)),
+            _ => rolled,
+        }
+    }
+
+    /// The aggregate over the rows whose `by` value lies within `window_size` before (and
+    /// including) the value of the current row.
+    fn rolling_range(self, input: Expr, by: Expr, window_size: Duration) -> PolarsResult<Expr> {
+        let options = RollingOptionsDynamicWindow {
+            window_size,
+            min_periods: 1,
+            closed_window: ClosedWindow::Both,
+            fn_params: self.fn_params(),
+        };
+        Ok(match self {
+            Self::Count | Self::Sum => input.rolling_sum_by(by, options),
+            Self::Max => input.rolling_max_by(by, options),
+            Self::Mean => input.rolling_mean_by(by, options),
+            Self::Median => input.rolling_median_by(by, options),
+            Self::Min => input.rolling_min_by(by, options),
+            Self::Std(_) => input.rolling_std_by(by, options),
+            Self::Var(_) => input.rolling_var_by(by, options),
+        })
+    }
+
+    fn fn_params(self) -> Option<RollingFnParams> {
+        match self {
+            Self::Std(ddof) | Self::Var(ddof) => {
+                Some(RollingFnParams::Var(RollingVarParams { ddof }))
+            },
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Count => "COUNT",
+            Self::Max => "MAX",
+            Self::Mean => "AVG",
+            Self::Median => "MEDIAN",
+            Self::Min => "MIN",
+            Self::Std(_) => "STDDEV",
+            Self::Sum => "SUM",
+            Self::Var(_) => "VARIANCE",
+        }
+    }
+}
+
+/// The offset of a `ROWS` frame bound relative to the current row (`None` if unbounded).
+fn frame_row_offset(bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+    let parse_offset = |e: &SQLExpr| -> PolarsResult<i64> {
+        if let SQLExpr::Value(SQLValue::Number(n, _)) = e {
+            if let Some(n) = n.parse::<i64>().ok().filter(|n| *n >= 0) {
+                return Ok(n);
+            }
+        }
+        polars_bail!(SQLSyntax: "ROWS frame offset must be a non-negative integer; found {}", e)
+    };
+    Ok(match bound {
+        WindowFrameBound::CurrentRow => Some(0),
+        WindowFrameBound::Preceding(Some(e)) => Some(-parse_offset(e)?),
+        WindowFrameBound::Following(Some(e)) => Some(parse_offset(e)?),
+        WindowFrameBound::Preceding(None) | WindowFrameBound::Following(None) => None,
+    })
+}
+
+/// The window size of a `RANGE` frame offset; either a non-negative integer or an interval.
+fn frame_range_offset(offset: &SQLExpr) -> PolarsResult<Duration> {
+    match offset {
+        SQLExpr::Value(SQLValue::Number(n, _)) => match n.parse::<i64>() {
+            Ok(n) if n >= 0 => Ok(Duration::parse(&format!("{n}i"))),
+            _ => {
+                polars_bail!(SQLSyntax: "RANGE frame offset must be a non-negative integer or an interval; found {}", n)
+            },
+        },
+        SQLExpr::Interval(interval) => interval_to_duration(interval, true),
+        _ => {
+            polars_bail!(SQLSyntax: "RANGE frame offset must be a non-negative integer or an interval; found {}", offset)
+        },
+    }
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
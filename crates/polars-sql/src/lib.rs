@@ -5,9 +5,12 @@ mod context;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+mod prepared;
 mod sql_expr;
+mod subquery;
 mod table_functions;
 mod types;
 
 pub use context::SQLContext;
+pub use prepared::{PreparedQuery, SQLParams};
 pub use sql_expr::sql_expr;
@@ -0,0 +1,321 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_plan::plans::{DslFunction, StatsFunction};
+use polars_plan::utils::has_expr;
+
+/// Values to bind to the placeholders of a [`PreparedQuery`].
+#[derive(Clone, Debug)]
+pub enum SQLParams {
+    /// Values of the positional placeholders (`$1`, `$2`, ... or `?`), in order.
+    Positional(Vec<Scalar>),
+    /// Values of the named placeholders (`:name`), by name.
+    Named(PlHashMap<String, Scalar>),
+}
+
+impl From<Vec<Scalar>> for SQLParams {
+    fn from(values: Vec<Scalar>) -> Self {
+        Self::Positional(values)
+    }
+}
+
+impl From<PlHashMap<String, Scalar>> for SQLParams {
+    fn from(values: PlHashMap<String, Scalar>) -> Self {
+        Self::Named(values)
+    }
+}
+
+/// A SQL query that has been parsed and planned once (see [`crate::SQLContext::prepare`]).
+///
+/// Executing it binds the given values to the placeholders of the query, substituting
+/// them as literals in the cached plan.
+#[derive(Clone)]
+pub struct PreparedQuery {
+    plan: LazyFrame,
+    placeholders: Vec<String>,
+    positional: bool,
+}
+
+impl PreparedQuery {
+    pub(crate) fn new(plan: LazyFrame, placeholders: PlIndexSet<String>) -> PolarsResult<Self> {
+        let mut placeholders: Vec<String> = placeholders.into_iter().collect();
+        let positional = placeholders.iter().all(|p| p.starts_with('$'));
+        if positional {
+            placeholders.sort_by_key(|p| positional_index(p));
+            for (i, p) in placeholders.iter().enumerate() {
+                polars_ensure!(
+                    positional_index(p) == Some(i),
+                    SQLSyntax: "positional placeholders must be numbered consecutively from $1; found '{}'", p
+                );
+            }
+        } else {
+            placeholders.sort();
+            if let Some(p) = placeholders.iter().find(|p| !p.starts_with(':')) {
+                polars_bail!(SQLSyntax: "placeholder '{}' is not supported (or mixed with named placeholders); use '$1', '?' or ':name'", p)
+            }
+        }
+        Ok(Self {
+            plan,
+            placeholders,
+            positional,
+        })
+    }
+
+    /// The placeholders of the query, as written (eg: `$1`, `:name`); anonymous `?`
+    /// placeholders are numbered by their position.
+    pub fn placeholders(&self) -> &[String] {
+        &self.placeholders
+    }
+
+    /// Bind the given values to the placeholders, returning the resulting [`LazyFrame`].
+    pub fn execute(&self, params: impl Into<SQLParams>) -> PolarsResult<LazyFrame> {
+        let values: PlHashMap<&str, Scalar> = match params.into() {
+            SQLParams::Positional(values) => {
+                polars_ensure!(
+                    self.positional,
+                    SQLInterface: "the query has named placeholders; bind them with `SQLParams::Named`"
+                );
+                polars_ensure!(
+                    values.len() == self.placeholders.len(),
+                    SQLInterface: "the query has {} placeholder(s), but {} value(s) were given",
+                    self.placeholders.len(), values.len()
+                );
+                self.placeholders
+                    .iter()
+                    .map(String::as_str)
+                    .zip(values)
+                    .collect()
+            },
+            SQLParams::Named(mut values) => {
+                polars_ensure!(
+                    !self.positional || self.placeholders.is_empty(),
+                    SQLInterface: "the query has positional placeholders; bind them with `SQLParams::Positional`"
+                );
+                let bound = self
+                    .placeholders
+                    .iter()
+                    .map(|p| {
+                        let value = values.remove(&p[1..]).ok_or_else(
+                            || polars_err!(SQLInterface: "no value given for placeholder '{}'", p),
+                        )?;
+                        Ok((p.as_str(), value))
+                    })
+                    .collect::<PolarsResult<_>>()?;
+                if let Some(name) = values.keys().next() {
+                    polars_bail!(SQLInterface: "the query has no placeholder ':{}'", name)
+                }
+                bound
+            },
+        };
+
+        let plan = bind_plan(self.plan.logical_plan.clone(), &values);
+        Ok(LazyFrame::from(plan).with_optimizations(self.plan.get_current_optimizations()))
+    }
+}
+
+/// The stand-in for a placeholder in the plan of a prepared query: a literal with an
+/// unknown type (which is never produced otherwise), holding the placeholder name.
+pub(crate) fn placeholder_expr(name: &str) -> Expr {
+    Expr::Literal(LiteralValue::Scalar(Scalar::new(
+        DataType::Unknown(UnknownKind::Any),
+        AnyValue::StringOwned(name.into()),
+    )))
+}
+
+fn placeholder_name(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Literal(LiteralValue::Scalar(sc))
+            if matches!(sc.dtype(), DataType::Unknown(UnknownKind::Any)) =>
+        {
+            match sc.value() {
+                AnyValue::StringOwned(name) => Some(name.as_str()),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+fn positional_index(placeholder: &str) -> Option<usize> {
+    placeholder
+        .strip_prefix('$')?
+        .parse::<usize>()
+        .ok()?
+        .checked_sub(1)
+}
+
+fn bind_expr(expr: Expr, values: &PlHashMap<&str, Scalar>) -> Expr {
+    expr.map_expr(|e| match placeholder_name(&e) {
+        Some(name) => lit(values[name].clone()),
+        None => e,
+    })
+}
+
+/// Substitute the bound values for the placeholders in the expressions of the plan.
+fn bind_plan(plan: DslPlan, values: &PlHashMap<&str, Scalar>) -> DslPlan {
+    map_plan_exprs(plan, &mut |e| bind_expr(e, values))
+}
+
+/// Whether the plan has placeholders; these can't be bound once the plan has been collected.
+pub(crate) fn has_placeholders(plan: &DslPlan) -> bool {
+    let mut found = false;
+    map_plan_exprs(plan.clone(), &mut |e| {
+        found |= has_expr(&e, |e| placeholder_name(e).is_some());
+        e
+    });
+    found
+}
+
+fn map_input(input: Arc<DslPlan>, f: &mut dyn FnMut(Expr) -> Expr) -> Arc<DslPlan> {
+    Arc::new(map_plan_exprs(Arc::unwrap_or_clone(input), f))
+}
+
+fn map_inputs(inputs: Vec<DslPlan>, f: &mut dyn FnMut(Expr) -> Expr) -> Vec<DslPlan> {
+    inputs.into_iter().map(|p| map_plan_exprs(p, f)).collect()
+}
+
+fn map_exprs(exprs: Vec<Expr>, f: &mut dyn FnMut(Expr) -> Expr) -> Vec<Expr> {
+    exprs.into_iter().map(f).collect()
+}
+
+/// Apply `f` to the expressions of the plan and of its inputs.
+///
+/// Every variant is matched explicitly, so that new variants aren't silently passed through;
+/// the exceptions are those behind features that this crate doesn't enable, which are never
+/// produced by SQL (so they only come from registered frames, which have no placeholders).
+fn map_plan_exprs(plan: DslPlan, f: &mut dyn FnMut(Expr) -> Expr) -> DslPlan {
+    match plan {
+        DslPlan::Filter { input, predicate } => DslPlan::Filter {
+            input: map_input(input, f),
+            predicate: f(predicate),
+        },
+        DslPlan::Cache { input } => DslPlan::Cache {
+            input: map_input(input, f),
+        },
+        DslPlan::Select {
+            expr,
+            input,
+            options,
+        } => DslPlan::Select {
+            expr: map_exprs(expr, f),
+            input: map_input(input, f),
+            options,
+        },
+        DslPlan::GroupBy {
+            input,
+            keys,
+            aggs,
+            maintain_order,
+            options,
+            apply,
+        } => DslPlan::GroupBy {
+            input: map_input(input, f),
+            keys: map_exprs(keys, f),
+            aggs: map_exprs(aggs, f),
+            maintain_order,
+            options,
+            apply,
+        },
+        DslPlan::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            predicates,
+            options,
+        } => DslPlan::Join {
+            input_left: map_input(input_left, f),
+            input_right: map_input(input_right, f),
+            left_on: map_exprs(left_on, f),
+            right_on: map_exprs(right_on, f),
+            predicates: map_exprs(predicates, f),
+            options,
+        },
+        DslPlan::HStack {
+            input,
+            exprs,
+            options,
+        } => DslPlan::HStack {
+            input: map_input(input, f),
+            exprs: map_exprs(exprs, f),
+            options,
+        },
+        DslPlan::MatchToSchema {
+            input,
+            match_schema,
+            per_column,
+            extra_columns,
+        } => DslPlan::MatchToSchema {
+            input: map_input(input, f),
+            match_schema,
+            per_column,
+            extra_columns,
+        },
+        DslPlan::Distinct { input, options } => DslPlan::Distinct {
+            input: map_input(input, f),
+            options,
+        },
+        DslPlan::Sort {
+            input,
+            by_column,
+            slice,
+            sort_options,
+        } => DslPlan::Sort {
+            input: map_input(input, f),
+            by_column: map_exprs(by_column, f),
+            slice,
+            sort_options,
+        },
+        DslPlan::Slice { input, offset, len } => DslPlan::Slice {
+            input: map_input(input, f),
+            offset,
+            len,
+        },
+        DslPlan::MapFunction { input, function } => DslPlan::MapFunction {
+            input: map_input(input, f),
+            function: match function {
+                DslFunction::FillNan(e) => DslFunction::FillNan(f(e)),
+                DslFunction::Stats(StatsFunction::Quantile { quantile, method }) => {
+                    DslFunction::Stats(StatsFunction::Quantile {
+                        quantile: f(quantile),
+                        method,
+                    })
+                },
+                function @ (DslFunction::RowIndex { .. }
+                | DslFunction::Explode { .. }
+                | DslFunction::Unpivot { .. }
+                | DslFunction::Rename { .. }
+                | DslFunction::Unnest(_)
+                | DslFunction::Stats(_)
+                | DslFunction::FunctionIR(_)) => function,
+                #[allow(unreachable_patterns)]
+                function => function,
+            },
+        },
+        DslPlan::Union { inputs, args } => DslPlan::Union {
+            inputs: map_inputs(inputs, f),
+            args,
+        },
+        DslPlan::HConcat { inputs, options } => DslPlan::HConcat {
+            inputs: map_inputs(inputs, f),
+            options,
+        },
+        DslPlan::ExtContext { input, contexts } => DslPlan::ExtContext {
+            input: map_input(input, f),
+            contexts: map_inputs(contexts, f),
+        },
+        DslPlan::Sink { input, payload } => DslPlan::Sink {
+            input: map_input(input, f),
+            payload,
+        },
+        DslPlan::SinkMultiple { inputs } => DslPlan::SinkMultiple {
+            inputs: map_inputs(inputs, f),
+        },
+        // Plans that were converted (eg: to resolve their schema) are rebuilt from the DSL.
+        DslPlan::IR { dsl, .. } => map_plan_exprs(Arc::unwrap_or_clone(dsl), f),
+        plan @ (DslPlan::Scan { .. } | DslPlan::DataFrameScan { .. }) => plan,
+        #[allow(unreachable_patterns)]
+        plan => plan,
+    }
+}
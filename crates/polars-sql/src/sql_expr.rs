@@ -28,6 +28,7 @@ use sqlparser::parser::{Parser, ParserOptions};
 
 use crate::SQLContext;
 use crate::functions::SQLFunctionVisitor;
+use crate::prepared::placeholder_expr;
 use crate::types::{
     bitstring_to_bytes_literal, is_iso_date, is_iso_datetime, is_iso_time, map_sql_dtype_to_polars,
 };
@@ -122,8 +123,20 @@ impl SQLExprVisitor<'_> {
                 negated,
             } => {
                 let expr = self.visit_expr(expr)?;
-                let elems = self.visit_array_expr(list, true, Some(&expr))?;
-                let is_in = expr.is_in(elems, false);
+                let is_in = if list
+                    .iter()
+                    .any(|e| matches!(e, SQLExpr::Value(SQLValue::Placeholder(_))))
+                {
+                    // placeholder values are only bound after planning; compare with each element
+                    let mut is_in = lit(false);
+                    for e in list {
+                        is_in = is_in.or(expr.clone().eq(self.visit_expr(e)?));
+                    }
+                    is_in
+                } else {
+                    let elems = self.visit_array_expr(list, true, Some(&expr))?;
+                    expr.is_in(elems, false)
+                };
                 Ok(if *negated { is_in.not() } else { is_in })
             },
             SQLExpr::InSubquery {
@@ -192,7 +205,9 @@ impl SQLExprVisitor<'_> {
                 Ok(if *negated { matches.not() } else { matches })
             },
             SQLExpr::Subscript { expr, subscript } => self.visit_subscript(expr, subscript),
-            SQLExpr::Subquery(_) => polars_bail!(SQLInterface: "unexpected subquery"),
+            SQLExpr::Subquery(_) => {
+                polars_bail!(SQLInterface: "subqueries are only supported in the SELECT list and WHERE clause")
+            },
             SQLExpr::Trim {
                 expr,
                 trim_where,
@@ -819,6 +834,14 @@ impl SQLExprVisitor<'_> {
                 bitstring_to_bytes_literal(b)?
             },
             SQLValue::SingleQuotedString(s) => lit(s.clone()),
+            SQLValue::Placeholder(name) => {
+                let mut placeholders = self.ctx.placeholders.borrow_mut();
+                let Some(placeholders) = placeholders.as_mut() else {
+                    polars_bail!(SQLInterface: "placeholder '{}' can only be used in a prepared query (see `SQLContext::prepare`)", name)
+                };
+                placeholders.insert(name.clone());
+                placeholder_expr(name)
+            },
             other => {
                 polars_bail!(SQLInterface: "value {:?} is not a supported literal type", other)
             },
@@ -0,0 +1,844 @@
+//! Decorrelation of subqueries in the WHERE clause and SELECT list.
+//!
+//! Subqueries are evaluated as joins against the frame of the enclosing query. Equality predicates
+//! in the WHERE clause of a subquery that relate its rows to those of the outer query (such as
+//! `inner.k = outer.k`) become the join keys, and the remaining predicates are evaluated by the
+//! (now uncorrelated) subquery itself. Top-level `[NOT] EXISTS` and `[NOT] IN` predicates of a
+//! WHERE clause become semi/anti joins; every other subquery becomes a left join that attaches the
+//! subquery result to the outer frame as a hidden column.
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_lazy::prelude::*;
+use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
+use polars_plan::prelude::*;
+use polars_utils::format_pl_smallstr;
+use sqlparser::ast::{
+    BinaryOperator, Expr as SQLExpr, FunctionArg, FunctionArgExpr, FunctionArguments, GroupByExpr,
+    Ident, Query, SelectItem, SetExpr, Subscript, TableFactor, TableWithJoins, UnaryOperator,
+    WindowType,
+};
+
+use crate::SQLContext;
+use crate::sql_expr::parse_sql_expr;
+
+/// Prefix of the hidden columns that hold the results of decorrelated subqueries.
+pub(crate) const SUBQUERY_PREFIX: &str = "__POLARS_SUBQUERY_";
+const SUBQUERY_KEY_PREFIX: &str = "__POLARS_SUBQUERY_KEY_";
+
+#[derive(Clone, Copy)]
+enum SubqueryKind<'a> {
+    /// `[NOT] EXISTS (subquery)`
+    Exists,
+    /// `expr [NOT] IN (subquery)`
+    In(&'a SQLExpr),
+    /// A subquery that returns a single value.
+    Scalar,
+}
+
+/// A subquery that has been split into an uncorrelated frame and the keys that relate its rows
+/// to those of the outer query.
+struct DecorrelatedSubquery {
+    frame: LazyFrame,
+    /// Whether the subquery references the outer query.
+    correlated: bool,
+    /// Key expressions, evaluated on the outer frame.
+    outer_keys: Vec<Expr>,
+    /// Key expressions, evaluated on `frame`.
+    inner_keys: Vec<Expr>,
+    /// Name of the column of `frame` that holds the subquery result.
+    value: PlSmallStr,
+    /// Whether the value is an aggregate (in which case there is one row per key).
+    is_aggregate: bool,
+    /// Whether the value is a `COUNT`, which is zero (not null) if there are no matching rows.
+    is_count: bool,
+}
+
+/// The tables and columns that are visible inside a subquery.
+struct SubqueryScope<'a> {
+    tables: PlHashSet<String>,
+    schema: &'a Schema,
+    outer_schema: &'a Schema,
+}
+
+impl SubqueryScope<'_> {
+    /// Whether the expression references columns of the subquery and/or of the outer query.
+    ///
+    /// Qualified references are resolved by their table name, unqualified references resolve to
+    /// the subquery if it has a column of that name (as in standard SQL).
+    fn references(&self, expr: &SQLExpr) -> PolarsResult<(bool, bool)> {
+        let (mut inner, mut outer) = (false, false);
+        visit_sql_exprs_mut(&mut expr.clone(), &mut |e| {
+            match e {
+                SQLExpr::Identifier(ident) => {
+                    if self.schema.contains(&ident.value)
+                        || !self.outer_schema.contains(&ident.value)
+                    {
+                        inner = true
+                    } else {
+                        outer = true
+                    }
+                },
+                SQLExpr::CompoundIdentifier(idents) => {
+                    let root = &idents[0].value;
+                    if self.tables.contains(root) || self.schema.contains(root) {
+                        inner = true
+                    } else {
+                        outer = true
+                    }
+                },
+                _ => {},
+            }
+            Ok(true)
+        })?;
+        Ok((inner, outer))
+    }
+}
+
+impl SQLContext {
+    /// Decorrelate the subqueries of a WHERE clause predicate.
+    ///
+    /// Returns the frame (with any semi/anti joins applied), the remaining predicate (if any), and
+    /// the names of the hidden columns that the remaining predicate references. Semi/anti joins
+    /// are only used if `semi_anti` is set; otherwise every subquery becomes a hidden column.
+    pub(crate) fn decorrelate_predicate(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &SQLExpr,
+        semi_anti: bool,
+    ) -> PolarsResult<(LazyFrame, Option<SQLExpr>, Vec<PlSmallStr>)> {
+        #[cfg(feature = "semi_anti_join")]
+        let outer_schema = self.get_frame_schema(&mut lf)?;
+        let mut predicates = vec![];
+        let mut hidden = vec![];
+        for mut predicate in split_conjunction(expr) {
+            #[cfg(feature = "semi_anti_join")]
+            if semi_anti {
+                let subquery = match &predicate {
+                    SQLExpr::Exists { subquery, negated } => {
+                        Some((subquery, SubqueryKind::Exists, *negated))
+                    },
+                    SQLExpr::InSubquery {
+                        expr,
+                        subquery,
+                        negated,
+                    } => Some((subquery, SubqueryKind::In(expr), *negated)),
+                    _ => None,
+                };
+                if let Some((subquery, kind, negated)) = subquery {
+                    let sq = self.decorrelate_subquery(subquery, kind, &outer_schema)?;
+                    if sq.correlated {
+                        let not_in = (negated && matches!(kind, SubqueryKind::In(_))).then(|| {
+                            (
+                                sq.frame.clone(),
+                                sq.outer_keys.clone(),
+                                sq.inner_keys.clone(),
+                            )
+                        });
+                        lf = lf
+                            .join_builder()
+                            .with(sq.frame)
+                            .left_on(sq.outer_keys)
+                            .right_on(sq.inner_keys)
+                            .how(if negated {
+                                JoinType::Anti
+                            } else {
+                                JoinType::Semi
+                            })
+                            .finish();
+                        if let Some((frame, outer_keys, inner_keys)) = not_in {
+                            // `x NOT IN (...)` is null (so the row is filtered out) if either `x`
+                            // or a value returned by the subquery is null, unless the subquery
+                            // returns no rows at all.
+                            let n = outer_keys.len() - 1;
+                            let nulls = format_pl_smallstr!("{}NULLS", SUBQUERY_PREFIX);
+                            let (value, inner_value) = (&outer_keys[n], &inner_keys[n]);
+                            lf = join_null_indicator(
+                                lf,
+                                frame,
+                                &outer_keys[..n],
+                                &inner_keys[..n],
+                                inner_value.clone(),
+                                nulls.clone(),
+                            )
+                            .filter(
+                                col(nulls.clone())
+                                    .is_null()
+                                    .or(col(nulls.clone()).not().and(value.clone().is_not_null())),
+                            )
+                            .drop(by_name([nulls], true));
+                        }
+                        continue;
+                    }
+                }
+            }
+            #[cfg(not(feature = "semi_anti_join"))]
+            let _ = semi_anti;
+
+            let columns;
+            (lf, columns) = self.decorrelate_subqueries(lf, &mut predicate)?;
+            hidden.extend(columns.into_iter().map(|(name, _)| name));
+            predicates.push(predicate);
+        }
+        let predicate = predicates
+            .into_iter()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            });
+        Ok((lf, predicate, hidden))
+    }
+
+    /// Replace the subqueries in `expr` with references to hidden columns that hold their result.
+    ///
+    /// Returns the frame with the hidden columns attached, and the name of each hidden column
+    /// along with the name of the (single) column returned by its subquery. Uncorrelated `IN`
+    /// subqueries are left in place.
+    pub(crate) fn decorrelate_subqueries(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &mut SQLExpr,
+    ) -> PolarsResult<(LazyFrame, Vec<(PlSmallStr, PlSmallStr)>)> {
+        let outer_schema = self.get_frame_schema(&mut lf)?;
+        let n_hidden = outer_schema
+            .iter_names()
+            .filter(|name| name.starts_with(SUBQUERY_PREFIX))
+            .count();
+        let mut columns = vec![];
+        visit_sql_exprs_mut(expr, &mut |e| {
+            let (sq, kind, negated) = match e {
+                SQLExpr::Exists { subquery, negated } => (
+                    self.decorrelate_subquery(subquery, SubqueryKind::Exists, &outer_schema)?,
+                    SubqueryKind::Exists,
+                    *negated,
+                ),
+                SQLExpr::InSubquery {
+                    expr,
+                    subquery,
+                    negated,
+                } => {
+                    let kind = SubqueryKind::In(expr);
+                    let sq = self.decorrelate_subquery(subquery, kind, &outer_schema)?;
+                    if !sq.correlated {
+                        return Ok(true);
+                    }
+                    (sq, kind, *negated)
+                },
+                SQLExpr::Subquery(subquery) => (
+                    self.decorrelate_subquery(subquery, SubqueryKind::Scalar, &outer_schema)?,
+                    SubqueryKind::Scalar,
+                    false,
+                ),
+                _ => return Ok(true),
+            };
+            let name = format_pl_smallstr!("{}{}", SUBQUERY_PREFIX, n_hidden + columns.len());
+            let output_name = sq.value.clone();
+            lf = join_subquery_column(self, lf.clone(), sq, kind, name.clone())?;
+
+            let column = SQLExpr::Identifier(Ident::new(name.as_str()));
+            *e = if negated {
+                SQLExpr::UnaryOp {
+                    op: UnaryOperator::Not,
+                    expr: Box::new(column),
+                }
+            } else {
+                column
+            };
+            columns.push((name, output_name));
+            Ok(false)
+        })?;
+        Ok((lf, columns))
+    }
+
+    /// Split a subquery into an uncorrelated frame and the keys that join it to the outer frame.
+    fn decorrelate_subquery(
+        &mut self,
+        query: &Query,
+        kind: SubqueryKind,
+        outer_schema: &Schema,
+    ) -> PolarsResult<DecorrelatedSubquery> {
+        if query.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        let select = match query.body.as_ref() {
+            SetExpr::Select(select) if select.from.len() == 1 => select,
+            _ => return self.uncorrelated_subquery(query, kind, outer_schema),
+        };
+        let mut inner = self.execute_from_statement(&select.from[0])?;
+        let inner_schema = self.get_frame_schema(&mut inner)?;
+        let scope = SubqueryScope {
+            tables: relation_names(&select.from[0]),
+            schema: &inner_schema,
+            outer_schema,
+        };
+
+        // Correlated references are only supported in (equality) predicates of the WHERE clause.
+        let mut other_exprs = select
+            .having
+            .iter()
+            .chain(match &select.group_by {
+                GroupByExpr::Expressions(exprs, _) => exprs.as_slice(),
+                GroupByExpr::All(_) => &[],
+            })
+            .collect::<Vec<_>>();
+        if !matches!(kind, SubqueryKind::Exists) {
+            other_exprs.extend(select.projection.iter().filter_map(|item| match item {
+                SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. } => Some(e),
+                _ => None,
+            }));
+        }
+        for e in other_exprs {
+            if scope.references(e)?.1 {
+                polars_bail!(SQLInterface: "correlated subqueries can only reference the outer query in their WHERE clause; found '{}'", e)
+            }
+        }
+
+        let mut predicates = vec![];
+        let mut outer_keys = vec![];
+        let mut inner_key_exprs = vec![];
+        for predicate in select.selection.iter().flat_map(split_conjunction) {
+            if !scope.references(&predicate)?.1 {
+                predicates.push(predicate);
+                continue;
+            }
+            let (inner_expr, outer_expr) = match &predicate {
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } => match (scope.references(left)?, scope.references(right)?) {
+                    ((_, false), (false, true)) => (left, right),
+                    ((false, true), (_, false)) => (right, left),
+                    _ => {
+                        polars_bail!(SQLInterface: "correlated subquery predicates must compare a subquery expression to an outer query expression; found '{}'", predicate)
+                    },
+                },
+                _ => {
+                    polars_bail!(SQLInterface: "correlated subquery predicates must be equality comparisons; found '{}'", predicate)
+                },
+            };
+            outer_keys.push(parse_sql_expr(outer_expr, self, Some(outer_schema))?);
+            inner_key_exprs.push(inner_expr.as_ref().clone());
+        }
+        if outer_keys.is_empty() {
+            return self.uncorrelated_subquery(query, kind, outer_schema);
+        }
+        polars_ensure!(
+            query.limit.is_none() && query.offset.is_none() && query.fetch.is_none(),
+            SQLInterface: "LIMIT, OFFSET and FETCH are not supported in correlated subqueries"
+        );
+
+        // Rewrite the subquery to return its correlation keys (grouping by them if the subquery
+        // aggregates), followed by its value.
+        let mut select = select.clone();
+        select.selection = predicates
+            .into_iter()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            });
+        let mut projection = inner_key_exprs
+            .iter()
+            .enumerate()
+            .map(|(i, e)| SelectItem::ExprWithAlias {
+                expr: e.clone(),
+                alias: Ident::new(format!("{SUBQUERY_KEY_PREFIX}{i}")),
+            })
+            .collect::<Vec<_>>();
+        let (mut is_aggregate, mut is_count) = (false, false);
+        if !matches!(kind, SubqueryKind::Exists) {
+            let value_expr = match select.projection.as_slice() {
+                [SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. }] => e,
+                _ => polars_bail!(SQLSyntax: "SQL subquery returns more than one column"),
+            };
+            let value = parse_sql_expr(value_expr, self, Some(&inner_schema))?;
+            is_aggregate = has_expr(&value, |e| matches!(e, Expr::Agg(_) | Expr::Len));
+            let mut value = &value;
+            while let Expr::Alias(e, _) = value {
+                value = e;
+            }
+            is_count = matches!(value, Expr::Len | Expr::Agg(AggExpr::Count(..)));
+            projection.push(select.projection[0].clone());
+        }
+        select.projection = projection;
+        match &mut select.group_by {
+            GroupByExpr::Expressions(exprs, _) if !exprs.is_empty() => {
+                // Grouping by other expressions as well, there may be several rows per key (or
+                // none, in which case `COUNT` is null too).
+                exprs.splice(0..0, inner_key_exprs);
+                (is_aggregate, is_count) = (false, false);
+            },
+            GroupByExpr::Expressions(exprs, _) if is_aggregate => *exprs = inner_key_exprs,
+            _ => {},
+        }
+        let query = Query {
+            body: Box::new(SetExpr::Select(select)),
+            order_by: None,
+            ..query.clone()
+        };
+
+        let mut frame = self.execute_query_no_ctes(&query)?;
+        let schema = self.get_frame_schema(&mut frame)?;
+        let n_keys = outer_keys.len();
+        let mut inner_keys = (0..n_keys)
+            .map(|i| col(format_pl_smallstr!("{}{}", SUBQUERY_KEY_PREFIX, i)))
+            .collect::<Vec<_>>();
+        let value = match kind {
+            SubqueryKind::Exists => PlSmallStr::from_static("exists"),
+            _ => schema.get_at_index(n_keys).unwrap().0.clone(),
+        };
+        if let SubqueryKind::In(expr) = kind {
+            outer_keys.push(parse_sql_expr(expr, self, Some(outer_schema))?);
+            inner_keys.push(col(value.clone()));
+        }
+        let (outer_keys, inner_keys) =
+            align_key_dtypes(outer_keys, inner_keys, outer_schema, &schema)?;
+
+        Ok(DecorrelatedSubquery {
+            frame,
+            correlated: true,
+            outer_keys,
+            inner_keys,
+            value,
+            is_aggregate,
+            is_count,
+        })
+    }
+
+    fn uncorrelated_subquery(
+        &mut self,
+        query: &Query,
+        kind: SubqueryKind,
+        outer_schema: &Schema,
+    ) -> PolarsResult<DecorrelatedSubquery> {
+        let mut frame = self.execute_query_no_ctes(query)?;
+        let schema = self.get_frame_schema(&mut frame)?;
+        let value = match kind {
+            SubqueryKind::Exists => PlSmallStr::from_static("exists"),
+            _ => {
+                if schema.len() != 1 {
+                    polars_bail!(SQLSyntax: "SQL subquery returns more than one column");
+                }
+                schema.get_at_index(0).unwrap().0.clone()
+            },
+        };
+        let (outer_keys, inner_keys) = match kind {
+            SubqueryKind::In(expr) => align_key_dtypes(
+                vec![parse_sql_expr(expr, self, Some(outer_schema))?],
+                vec![col(value.clone())],
+                outer_schema,
+                &schema,
+            )?,
+            _ => (vec![], vec![]),
+        };
+        Ok(DecorrelatedSubquery {
+            frame,
+            correlated: false,
+            outer_keys,
+            inner_keys,
+            value,
+            is_aggregate: false,
+            is_count: false,
+        })
+    }
+}
+
+/// Attach the result of a subquery to the outer frame as the column `name`; for `EXISTS` and
+/// `IN` subqueries, this is a boolean column that indicates whether there is a matching row.
+fn join_subquery_column(
+    ctx: &SQLContext,
+    lf: LazyFrame,
+    sq: DecorrelatedSubquery,
+    kind: SubqueryKind,
+    name: PlSmallStr,
+) -> PolarsResult<LazyFrame> {
+    if sq.outer_keys.is_empty() {
+        // Uncorrelated; the subquery result is a single value (null if there are no rows).
+        let value = match kind {
+            SubqueryKind::Exists => len().gt(lit(0)),
+            _ => col(sq.value).first(),
+        };
+        return Ok(lf.cross_join(sq.frame.select([value.alias(name)]), None));
+    }
+
+    let in_keys = matches!(kind, SubqueryKind::In(_)).then(|| {
+        (
+            sq.frame.clone(),
+            sq.outer_keys.clone(),
+            sq.inner_keys.clone(),
+        )
+    });
+    let key_names = (0..sq.inner_keys.len())
+        .map(|i| format_pl_smallstr!("{}{}", SUBQUERY_KEY_PREFIX, i))
+        .collect::<Vec<_>>();
+    let keys = sq
+        .inner_keys
+        .into_iter()
+        .zip(&key_names)
+        .map(|(e, key)| e.alias(key.clone()));
+    let right = match kind {
+        SubqueryKind::Scalar if sq.is_aggregate => sq.frame.select(
+            keys.chain([col(sq.value).alias(name.clone())])
+                .collect::<Vec<_>>(),
+        ),
+        SubqueryKind::Scalar => {
+            // A scalar subquery may return at most a single row for every row of the outer query;
+            // as for MERGE, there is no way to raise this error lazily, so the number of rows per
+            // key is collected here.
+            let n_rows = format_pl_smallstr!("{}_LEN", name);
+            let grouped = sq.frame.group_by(keys.collect::<Vec<_>>()).agg([
+                col(sq.value).first().alias(name.clone()),
+                len().alias(n_rows.clone()),
+            ]);
+            let multiple_rows = ctx.collect_eager(
+                grouped
+                    .clone()
+                    .select([col(n_rows.clone()).max().gt(lit(1))]),
+                "correlated scalar subqueries",
+            )?;
+            polars_ensure!(
+                multiple_rows[0].bool()?.get(0) != Some(true),
+                SQLInterface: "correlated scalar subquery returned more than one row for a row of the outer query"
+            );
+            grouped.drop(by_name([n_rows], true))
+        },
+        _ => sq
+            .frame
+            .select(
+                keys.chain([lit(true).alias(name.clone())])
+                    .collect::<Vec<_>>(),
+            )
+            .unique(None, UniqueKeepStrategy::Any),
+    };
+    let joined = lf
+        .join_builder()
+        .with(right)
+        .left_on(sq.outer_keys)
+        .right_on(
+            key_names
+                .iter()
+                .map(|key| col(key.clone()))
+                .collect::<Vec<_>>(),
+        )
+        .how(JoinType::Left)
+        .coalesce(JoinCoalesce::KeepColumns)
+        .maintain_order(MaintainOrderJoin::Left)
+        .finish()
+        .drop(by_name(key_names, true));
+
+    // Note: outer rows without a matching row get a null; the exceptions are `COUNT` (which is
+    // zero for an empty set of rows), and the `EXISTS`/`IN` indicator.
+    Ok(match kind {
+        SubqueryKind::Scalar if sq.is_count => {
+            joined.with_column(col(name.clone()).fill_null(lit(0).cast(IDX_DTYPE)))
+        },
+        SubqueryKind::Scalar => joined,
+        SubqueryKind::Exists => joined.with_column(col(name.clone()).fill_null(lit(false))),
+        SubqueryKind::In(_) => {
+            // As in standard SQL, `x IN (...)` is null (not false) without a match if either `x`
+            // or a value returned by the subquery is null, unless the subquery returns no rows.
+            let (frame, outer_keys, inner_keys) = in_keys.unwrap();
+            let n = outer_keys.len() - 1;
+            let nulls = format_pl_smallstr!("{}_NULLS", name);
+            let (value, inner_value) = (&outer_keys[n], &inner_keys[n]);
+            join_null_indicator(
+                joined,
+                frame,
+                &outer_keys[..n],
+                &inner_keys[..n],
+                inner_value.clone(),
+                nulls.clone(),
+            )
+            .with_column(
+                when(col(name.clone()).is_not_null())
+                    .then(lit(true))
+                    .when(col(nulls.clone()).is_null())
+                    .then(lit(false))
+                    .when(col(nulls.clone()).or(value.clone().is_null()))
+                    .then(lit(NULL).cast(DataType::Boolean))
+                    .otherwise(lit(false))
+                    .alias(name),
+            )
+            .drop(by_name([nulls], true))
+        },
+    })
+}
+
+/// Left join the outer frame with whether the values returned by a correlated `IN` subquery
+/// contain a null, per correlation key, as the column `name`. The column is null for outer rows
+/// for which the subquery returns no rows.
+fn join_null_indicator(
+    lf: LazyFrame,
+    frame: LazyFrame,
+    outer_keys: &[Expr],
+    inner_keys: &[Expr],
+    value: Expr,
+    name: PlSmallStr,
+) -> LazyFrame {
+    let key_names = (0..inner_keys.len())
+        .map(|i| format_pl_smallstr!("{}{}", SUBQUERY_KEY_PREFIX, i))
+        .collect::<Vec<_>>();
+    let keys = inner_keys
+        .iter()
+        .zip(&key_names)
+        .map(|(e, key)| e.clone().alias(key.clone()))
+        .collect::<Vec<_>>();
+    let right = frame
+        .group_by(keys)
+        .agg([value.is_null().any(false).alias(name)]);
+    lf.join_builder()
+        .with(right)
+        .left_on(outer_keys)
+        .right_on(
+            key_names
+                .iter()
+                .map(|key| col(key.clone()))
+                .collect::<Vec<_>>(),
+        )
+        .how(JoinType::Left)
+        .coalesce(JoinCoalesce::KeepColumns)
+        .maintain_order(MaintainOrderJoin::Left)
+        .finish()
+        .drop(by_name(key_names, true))
+}
+
+/// Cast the join keys to their supertype where the dtypes of the outer and inner keys differ.
+fn align_key_dtypes(
+    outer_keys: Vec<Expr>,
+    inner_keys: Vec<Expr>,
+    outer_schema: &Schema,
+    inner_schema: &Schema,
+) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+    outer_keys
+        .into_iter()
+        .zip(inner_keys)
+        .map(|(outer, inner)| {
+            let outer_dtype = outer.to_field(outer_schema, Context::Default)?.dtype;
+            let inner_dtype = inner.to_field(inner_schema, Context::Default)?.dtype;
+            if outer_dtype == inner_dtype {
+                Ok((outer, inner))
+            } else {
+                let dtype = try_get_supertype(&outer_dtype, &inner_dtype)?;
+                Ok((outer.cast(dtype.clone()), inner.cast(dtype)))
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+        .map(|keys| keys.into_iter().unzip())
+}
+
+/// The names (or aliases) of the relations in a FROM clause item.
+fn relation_names(tbl_expr: &TableWithJoins) -> PlHashSet<String> {
+    std::iter::once(&tbl_expr.relation)
+        .chain(tbl_expr.joins.iter().map(|join| &join.relation))
+        .filter_map(|relation| match relation {
+            TableFactor::Table { name, alias, .. } => Some(match alias {
+                Some(alias) => alias.name.value.clone(),
+                None => name.0.first()?.value.clone(),
+            }),
+            TableFactor::Derived { alias, .. } | TableFactor::UNNEST { alias, .. } => {
+                alias.as_ref().map(|alias| alias.name.value.clone())
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Split a predicate into its `AND`-ed components.
+pub(crate) fn split_conjunction(expr: &SQLExpr) -> Vec<SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut predicates = split_conjunction(left);
+            predicates.extend(split_conjunction(right));
+            predicates
+        },
+        SQLExpr::Nested(expr) => split_conjunction(expr),
+        _ => vec![expr.clone()],
+    }
+}
+
+/// Whether the expression contains a subquery (not counting subqueries of subqueries).
+pub(crate) fn contains_subquery(expr: &SQLExpr) -> bool {
+    let mut found = false;
+    let _ = visit_sql_exprs_mut(&mut expr.clone(), &mut |e| {
+        found |= matches!(
+            e,
+            SQLExpr::Exists { .. } | SQLExpr::InSubquery { .. } | SQLExpr::Subquery(_)
+        );
+        Ok(!found)
+    });
+    found
+}
+
+/// Visit an expression and its sub-expressions (in pre-order), only descending into the
+/// sub-expressions of an expression if `f` returns true.
+///
+/// Note: this does not descend into subqueries.
+pub(crate) fn visit_sql_exprs_mut<F>(expr: &mut SQLExpr, f: &mut F) -> PolarsResult<()>
+where
+    F: FnMut(&mut SQLExpr) -> PolarsResult<bool>,
+{
+    if !f(expr)? {
+        return Ok(());
+    }
+    let mut children: Vec<&mut SQLExpr> = vec![];
+    match expr {
+        SQLExpr::IsFalse(e)
+        | SQLExpr::IsNotFalse(e)
+        | SQLExpr::IsTrue(e)
+        | SQLExpr::IsNotTrue(e)
+        | SQLExpr::IsNull(e)
+        | SQLExpr::IsNotNull(e)
+        | SQLExpr::IsUnknown(e)
+        | SQLExpr::IsNotUnknown(e)
+        | SQLExpr::Nested(e)
+        | SQLExpr::UnaryOp { expr: e, .. }
+        | SQLExpr::Cast { expr: e, .. }
+        | SQLExpr::Ceil { expr: e, .. }
+        | SQLExpr::Floor { expr: e, .. }
+        | SQLExpr::Extract { expr: e, .. }
+        | SQLExpr::Collate { expr: e, .. }
+        | SQLExpr::Named { expr: e, .. }
+        | SQLExpr::CompositeAccess { expr: e, .. }
+        | SQLExpr::JsonAccess { value: e, .. }
+        | SQLExpr::InSubquery { expr: e, .. } => children.push(e),
+        SQLExpr::IsDistinctFrom(a, b)
+        | SQLExpr::IsNotDistinctFrom(a, b)
+        | SQLExpr::BinaryOp {
+            left: a, right: b, ..
+        }
+        | SQLExpr::AnyOp {
+            left: a, right: b, ..
+        }
+        | SQLExpr::AllOp {
+            left: a, right: b, ..
+        }
+        | SQLExpr::Position { expr: a, r#in: b }
+        | SQLExpr::Like {
+            expr: a,
+            pattern: b,
+            ..
+        }
+        | SQLExpr::ILike {
+            expr: a,
+            pattern: b,
+            ..
+        }
+        | SQLExpr::SimilarTo {
+            expr: a,
+            pattern: b,
+            ..
+        }
+        | SQLExpr::RLike {
+            expr: a,
+            pattern: b,
+            ..
+        }
+        | SQLExpr::AtTimeZone {
+            timestamp: a,
+            time_zone: b,
+        }
+        | SQLExpr::InUnnest {
+            expr: a,
+            array_expr: b,
+            ..
+        } => children.extend([&mut **a, &mut **b]),
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => children.extend([&mut **expr, &mut **low, &mut **high]),
+        SQLExpr::InList { expr, list, .. } => {
+            children.push(expr);
+            children.extend(list);
+        },
+        SQLExpr::Substring {
+            expr,
+            substring_from,
+            substring_for,
+            ..
+        } => {
+            children.push(expr);
+            children.extend(substring_from.as_deref_mut());
+            children.extend(substring_for.as_deref_mut());
+        },
+        SQLExpr::Trim {
+            expr,
+            trim_what,
+            trim_characters,
+            ..
+        } => {
+            children.push(expr);
+            children.extend(trim_what.as_deref_mut());
+            children.extend(trim_characters.iter_mut().flatten());
+        },
+        SQLExpr::Overlay {
+            expr,
+            overlay_what,
+            overlay_from,
+            overlay_for,
+        } => {
+            children.extend([&mut **expr, &mut **overlay_what, &mut **overlay_from]);
+            children.extend(overlay_for.as_deref_mut());
+        },
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            children.extend(operand.as_deref_mut());
+            children.extend(conditions);
+            children.extend(results);
+            children.extend(else_result.as_deref_mut());
+        },
+        SQLExpr::Tuple(exprs) | SQLExpr::Struct { values: exprs, .. } => children.extend(exprs),
+        SQLExpr::Array(arr) => children.extend(&mut arr.elem),
+        SQLExpr::Subscript { expr, subscript } => {
+            children.push(expr);
+            match subscript.as_mut() {
+                Subscript::Index { index } => children.push(index),
+                Subscript::Slice {
+                    lower_bound,
+                    upper_bound,
+                    stride,
+                } => children.extend(
+                    [lower_bound, upper_bound, stride]
+                        .into_iter()
+                        .filter_map(Option::as_mut),
+                ),
+            }
+        },
+        SQLExpr::GroupingSets(groups) | SQLExpr::Cube(groups) | SQLExpr::Rollup(groups) => {
+            children.extend(groups.iter_mut().flatten())
+        },
+        SQLExpr::Function(func) => {
+            if let FunctionArguments::List(list) = &mut func.args {
+                children.extend(list.args.iter_mut().filter_map(|arg| match arg {
+                    FunctionArg::Named {
+                        arg: FunctionArgExpr::Expr(e),
+                        ..
+                    }
+                    | FunctionArg::ExprNamed {
+                        arg: FunctionArgExpr::Expr(e),
+                        ..
+                    }
+                    | FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => Some(e),
+                    _ => None,
+                }));
+            }
+            children.extend(func.filter.as_deref_mut());
+            if let Some(WindowType::WindowSpec(spec)) = &mut func.over {
+                children.extend(&mut spec.partition_by);
+                children.extend(spec.order_by.iter_mut().map(|o| &mut o.expr));
+            }
+        },
+        _ => {},
+    }
+    for child in children {
+        visit_sql_exprs_mut(child, f)?;
+    }
+    Ok(())
+}
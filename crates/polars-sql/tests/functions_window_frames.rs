@@ -0,0 +1,186 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "k" => ["a", "a", "a", "a", "b", "b", "b"],
+      "t" => [3, 1, 2, 4, 2, 1, 3],
+      "x" => [Some(30i64), Some(10), None, Some(40), Some(200), Some(100), Some(300)],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+/// Evaluate the given window expressions, returning the results ordered by (k, t).
+fn eval_window(ctx: &mut SQLContext, exprs: &[&str]) -> DataFrame {
+    let projections = exprs
+        .iter()
+        .enumerate()
+        .map(|(i, e)| format!("{e} AS w{i}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!("SELECT k, t, {projections} FROM df ORDER BY k, t");
+    ctx.execute(&sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_window_frame_rows() {
+    let mut ctx = create_ctx();
+    let out = eval_window(
+        &mut ctx,
+        &[
+            "SUM(x) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)",
+            "SUM(x) OVER (PARTITION BY k ORDER BY t ROWS 1 PRECEDING)",
+            "AVG(x) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING)",
+            "COUNT(x) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN CURRENT ROW AND 2 FOLLOWING)",
+            "COUNT(*) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN CURRENT ROW AND 2 FOLLOWING)",
+            "MAX(x) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING)",
+            "COUNT(*) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING)",
+        ],
+    );
+    let expected = df! {
+      "k" => ["a", "a", "a", "a", "b", "b", "b"],
+      "t" => [1, 2, 3, 4, 1, 2, 3],
+      "w0" => [10i64, 10, 30, 70, 100, 300, 500],
+      "w1" => [10i64, 10, 30, 70, 100, 300, 500],
+      "w2" => [10.0, 20.0, 35.0, 35.0, 150.0, 200.0, 250.0],
+      "w3" => [2 as IdxSize, 2, 2, 1, 3, 2, 1],
+      "w4" => [3 as IdxSize, 3, 2, 1, 3, 2, 1],
+      "w5" => [None, Some(10i64), Some(10), Some(30), None, Some(100), Some(200)],
+      "w6" => [0 as IdxSize, 1, 2, 2, 0, 1, 2],
+    }
+    .unwrap();
+    assert!(
+        out.equals_missing(&expected) && out.schema() == expected.schema(),
+        "expected = {expected:?}\nactual={out:?}"
+    );
+}
+
+#[test]
+fn test_window_frame_unbounded() {
+    let mut ctx = create_ctx();
+    let out = eval_window(
+        &mut ctx,
+        &[
+            "SUM(x) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+            "SUM(x) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING)",
+            "SUM(x) OVER (PARTITION BY k ORDER BY t ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING)",
+            "MIN(x) OVER (ORDER BY t, k ROWS UNBOUNDED PRECEDING)",
+        ],
+    );
+    let expected = df! {
+      "k" => ["a", "a", "a", "a", "b", "b", "b"],
+      "t" => [1, 2, 3, 4, 1, 2, 3],
+      "w0" => [10i64, 10, 40, 80, 100, 300, 600],
+      "w1" => [80i64, 70, 70, 40, 600, 500, 300],
+      "w2" => [80i64, 80, 80, 80, 600, 600, 600],
+      "w3" => [10i64, 10, 10, 10, 10, 10, 10],
+    }
+    .unwrap();
+    assert!(
+        out.equals_missing(&expected),
+        "expected = {expected:?}\nactual={out:?}"
+    );
+}
+
+#[test]
+fn test_window_frame_range() {
+    let df = df! {
+      "d" => ["2024-01-01", "2024-01-09", "2024-01-05", "2024-01-02", "2024-01-08"],
+      "n" => [1, 9, 5, 5, 8],
+      "v" => [1i64, 5, 3, 2, 4],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("d").cast(DataType::Date));
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+
+    let sql = r#"
+      SELECT
+        d,
+        SUM(v) OVER (ORDER BY d RANGE BETWEEN INTERVAL '3 days' PRECEDING AND CURRENT ROW) AS s,
+        -- Rows with the same ordering value (peers) are always in the same frame.
+        COUNT(*) OVER (ORDER BY n RANGE BETWEEN 3 PRECEDING AND CURRENT ROW) AS c
+      FROM df
+      ORDER BY d"#;
+    let out = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(
+        out.column("s").unwrap().i64().unwrap().to_vec(),
+        [Some(1), Some(3), Some(5), Some(7), Some(9)]
+    );
+    assert_eq!(
+        out.column("c")
+            .unwrap()
+            .cast(&DataType::Int64)
+            .unwrap()
+            .i64()
+            .unwrap()
+            .to_vec(),
+        [Some(1), Some(2), Some(2), Some(3), Some(2)]
+    );
+}
+
+#[test]
+fn test_window_frame_range_unbounded_peers() {
+    let df = df! {
+      "k" => ["a", "b", "a", "a", "b", "a", "b"],
+      "t" => [2, 1, 3, 1, 2, 2, 1],
+      "x" => [-5i64, 100, 40, 10, 300, 20, 200],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+
+    // Rows with the same ordering value (peers) share the frame that ends at the last peer.
+    let out = eval_window(
+        &mut ctx,
+        &[
+            "SUM(x) OVER (PARTITION BY k ORDER BY t RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+            "SUM(x) OVER (PARTITION BY k ORDER BY t DESC RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+            "MAX(x) OVER (PARTITION BY k ORDER BY t RANGE UNBOUNDED PRECEDING)",
+            "SUM(x) OVER (PARTITION BY k RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+        ],
+    );
+    let expected = df! {
+      "k" => ["a", "a", "a", "a", "b", "b", "b"],
+      "t" => [1, 2, 2, 3, 1, 1, 2],
+      "w0" => [10i64, 25, 25, 65, 300, 300, 600],
+      "w1" => [65i64, 55, 55, 40, 600, 600, 300],
+      "w2" => [10i64, 20, 20, 40, 200, 200, 300],
+      "w3" => [65i64, 65, 65, 65, 600, 600, 600],
+    }
+    .unwrap();
+    assert!(
+        out.equals_missing(&expected),
+        "expected = {expected:?}\nactual={out:?}"
+    );
+}
+
+#[test]
+fn test_window_frame_invalid() {
+    let mut ctx = create_ctx();
+    for sql in [
+        // GROUPS frames are not supported.
+        "SELECT SUM(x) OVER (ORDER BY t GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        // RANGE frames can only look back.
+        "SELECT SUM(x) OVER (ORDER BY t RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM df",
+        // Start of the frame after its end.
+        "SELECT SUM(x) OVER (ORDER BY t ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) FROM df",
+        // Non-integer offset.
+        "SELECT SUM(x) OVER (ORDER BY t ROWS BETWEEN 1.5 PRECEDING AND CURRENT ROW) FROM df",
+        // Unbounded frames that have no cumulative equivalent.
+        "SELECT STDDEV(x) OVER (ORDER BY t ROWS UNBOUNDED PRECEDING) FROM df",
+        // Mixed sort orders.
+        "SELECT SUM(x) OVER (ORDER BY t, k DESC ROWS 1 PRECEDING) FROM df",
+        // Not an aggregate.
+        "SELECT UPPER(k) OVER (ORDER BY t ROWS 1 PRECEDING) FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
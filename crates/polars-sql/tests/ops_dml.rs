@@ -0,0 +1,192 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn stock_context() -> SQLContext {
+    let stock = df! {
+      "id" => [1, 2, 3],
+      "item" => ["apple", "pear", "plum"],
+      "qty" => [10i64, 0, 5],
+    }
+    .unwrap()
+    .lazy();
+    let updates = df! {
+      "id" => [2, 3, 4],
+      "item" => ["pear", "plum", "kiwi"],
+      "qty" => [7i64, 0, 3],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("stock", stock);
+    ctx.register("updates", updates);
+    ctx
+}
+
+fn assert_table_eq(ctx: &mut SQLContext, table: &str, expected: DataFrame) {
+    let actual = ctx
+        .execute(&format!("SELECT * FROM {table}"))
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(
+        actual.schema() == expected.schema() && actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_insert_into() {
+    let mut ctx = stock_context();
+    let _ = ctx
+        .execute("INSERT INTO stock VALUES (4, 'kiwi', 3), (5, 'fig', 1)")
+        .unwrap();
+    let _ = ctx
+        .execute("INSERT INTO stock (item, id) VALUES ('lime', 6)")
+        .unwrap();
+    let _ = ctx
+        .execute("INSERT INTO stock SELECT id * 10, item, qty FROM updates WHERE qty = 0")
+        .unwrap();
+    let expected = df! {
+      "id" => [1, 2, 3, 4, 5, 6, 30],
+      "item" => ["apple", "pear", "plum", "kiwi", "fig", "lime", "plum"],
+      "qty" => [Some(10i64), Some(0), Some(5), Some(3), Some(1), None, Some(0)],
+    }
+    .unwrap();
+    assert_table_eq(&mut ctx, "stock", expected);
+
+    for sql in [
+        "INSERT INTO stock VALUES (7, 'lime')",
+        "INSERT INTO stock (id, id) VALUES (7, 8)",
+        "INSERT INTO stock (id, price) VALUES (7, 1.5)",
+        "INSERT INTO missing VALUES (1)",
+        "INSERT INTO stock VALUES (7, 'lime', 1) RETURNING id",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
+
+#[test]
+fn test_update() {
+    let mut ctx = stock_context();
+    let _ = ctx
+        .execute("UPDATE stock SET qty = qty + 1, item = UPPER(item) WHERE qty < 10")
+        .unwrap();
+    let _ = ctx
+        .execute(
+            r#"
+          UPDATE stock AS s
+          SET qty = (SELECT u.qty FROM updates u WHERE u.id = s.id)
+          WHERE EXISTS (SELECT 1 FROM updates u WHERE u.id = s.id AND u.qty > 0)"#,
+        )
+        .unwrap();
+    let expected = df! {
+      "id" => [1, 2, 3],
+      "item" => ["apple", "PEAR", "PLUM"],
+      "qty" => [10i64, 7, 6],
+    }
+    .unwrap();
+    assert_table_eq(&mut ctx, "stock", expected);
+
+    // Without a WHERE clause all of the rows are updated.
+    let _ = ctx.execute("UPDATE stock SET qty = 0").unwrap();
+    let expected = df! {
+      "id" => [1, 2, 3],
+      "item" => ["apple", "PEAR", "PLUM"],
+      "qty" => [0i64, 0, 0],
+    }
+    .unwrap();
+    assert_table_eq(&mut ctx, "stock", expected);
+
+    for sql in [
+        "UPDATE stock SET price = 1",
+        "UPDATE stock SET qty = 1, qty = 2",
+        "UPDATE stock SET qty = u.qty FROM updates u WHERE u.id = stock.id",
+        "UPDATE missing SET qty = 1",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
+
+#[test]
+fn test_merge_into() {
+    let mut ctx = stock_context();
+    let _ = ctx
+        .execute(
+            r#"
+          MERGE INTO stock AS t
+          USING updates AS s
+          ON t.id = s.id
+          WHEN MATCHED AND s.qty = 0 THEN DELETE
+          WHEN MATCHED THEN UPDATE SET qty = t.qty + s.qty
+          WHEN NOT MATCHED THEN INSERT (id, item, qty) VALUES (s.id, s.item, s.qty)"#,
+        )
+        .unwrap();
+    let expected = df! {
+      "id" => [1, 2, 4],
+      "item" => ["apple", "pear", "kiwi"],
+      "qty" => [10i64, 7, 3],
+    }
+    .unwrap();
+    assert_table_eq(&mut ctx, "stock", expected);
+
+    // Subquery source, with rows not matched by the source.
+    let _ = ctx
+        .execute(
+            r#"
+          MERGE INTO stock
+          USING (SELECT id, qty FROM updates WHERE id > 2) AS s
+          ON stock.id = s.id
+          WHEN NOT MATCHED BY SOURCE AND stock.qty > 8 THEN UPDATE SET qty = 0
+          WHEN NOT MATCHED THEN INSERT (id, qty) VALUES (s.id, s.qty)"#,
+        )
+        .unwrap();
+    let expected = df! {
+      "id" => [1, 2, 4, 3],
+      "item" => [Some("apple"), Some("pear"), Some("kiwi"), None],
+      "qty" => [0i64, 7, 3, 0],
+    }
+    .unwrap();
+    assert_table_eq(&mut ctx, "stock", expected);
+
+    for sql in [
+        // Insert into rows that already exist.
+        "MERGE INTO stock t USING updates s ON t.id = s.id WHEN MATCHED THEN INSERT VALUES (s.id, s.item, s.qty)",
+        // Insert with the wrong number of values.
+        "MERGE INTO stock t USING updates s ON t.id = s.id WHEN NOT MATCHED THEN INSERT (id) VALUES (s.id, s.qty)",
+        // Unknown target column.
+        "MERGE INTO stock t USING updates s ON t.id = s.id WHEN MATCHED THEN UPDATE SET price = 1",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
+
+#[test]
+fn test_merge_into_multiple_matches() {
+    let mut ctx = stock_context();
+    let updates = df! {
+      "id" => [2, 2, 4],
+      "qty" => [1i64, 2, 3],
+    }
+    .unwrap()
+    .lazy();
+    ctx.register("updates", updates);
+
+    // Target row 2 is matched by two source rows.
+    let Err(err) = ctx.execute(
+        "MERGE INTO stock t USING updates s ON t.id = s.id WHEN MATCHED THEN UPDATE SET qty = s.qty",
+    ) else {
+        panic!("expected MERGE to fail");
+    };
+    assert!(matches!(err, PolarsError::SQLInterface(_)), "{err}");
+
+    // The table is left unchanged.
+    let expected = df! {
+      "id" => [1, 2, 3],
+      "item" => ["apple", "pear", "plum"],
+      "qty" => [10i64, 0, 5],
+    }
+    .unwrap();
+    assert_table_eq(&mut ctx, "stock", expected);
+}
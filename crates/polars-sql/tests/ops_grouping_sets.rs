@@ -0,0 +1,158 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn sales_context() -> SQLContext {
+    let sales = df! {
+      "region" => ["eu", "eu", "eu", "us", "us"],
+      "product" => ["a", "b", "b", "a", "c"],
+      "amount" => [1, 2, 3, 4, 5],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales);
+    ctx
+}
+
+fn assert_sql_eq(ctx: &mut SQLContext, sql: &str, expected: DataFrame) {
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.schema() == expected.schema() && actual.equals_missing(&expected),
+        "sql = {sql}\nexpected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_group_by_rollup() {
+    let expected = df! {
+      "region" => [Some("eu"), Some("eu"), Some("us"), Some("us"), Some("eu"), Some("us"), None],
+      "product" => [Some("a"), Some("b"), Some("a"), Some("c"), None, None, None],
+      "total" => [1, 5, 4, 5, 6, 9, 15],
+      "g" => [0u32, 0, 0, 0, 1, 1, 3],
+    }
+    .unwrap();
+
+    let mut ctx = sales_context();
+    for sql in [
+        r#"
+          SELECT region, product, SUM(amount) AS total, GROUPING(region, product) AS g
+          FROM sales
+          GROUP BY ROLLUP(region, product)
+          ORDER BY g, region, product"#,
+        r#"
+          SELECT region, product, SUM(amount) AS total, GROUPING(region, product) AS g
+          FROM sales
+          GROUP BY region, product WITH ROLLUP
+          ORDER BY g, region, product"#,
+        r#"
+          SELECT region, product, SUM(amount) AS total, GROUPING(region, product) AS g
+          FROM sales
+          GROUP BY GROUPING SETS ((region, product), (region), ())
+          ORDER BY g, region, product"#,
+    ] {
+        assert_sql_eq(&mut ctx, sql, expected.clone());
+    }
+}
+
+#[test]
+fn test_group_by_cube() {
+    let mut ctx = sales_context();
+    let sql = r#"
+      SELECT region, product, COUNT(*) AS n, GROUPING(region) AS g_region, GROUPING(product) AS g_product
+      FROM sales
+      GROUP BY CUBE(region, product)
+      ORDER BY g_region, g_product, region, product"#;
+    let expected = df! {
+      "region" => [Some("eu"), Some("eu"), Some("us"), Some("us"), Some("eu"), Some("us"), None, None, None, None],
+      "product" => [Some("a"), Some("b"), Some("a"), Some("c"), None, None, Some("a"), Some("b"), Some("c"), None],
+      "n" => [1u32, 2, 1, 1, 3, 2, 2, 2, 1, 5],
+      "g_region" => [0u32, 0, 0, 0, 0, 0, 1, 1, 1, 1],
+      "g_product" => [0u32, 0, 0, 0, 1, 1, 0, 0, 0, 1],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+}
+
+#[test]
+fn test_group_by_partial_rollup() {
+    // Plain keys are combined with every grouping set of the ROLLUP.
+    let mut ctx = sales_context();
+    let sql = r#"
+      SELECT region, product, MAX(amount) AS max_amount
+      FROM sales
+      GROUP BY region, ROLLUP(product)
+      ORDER BY region, product NULLS LAST"#;
+    let expected = df! {
+      "region" => ["eu", "eu", "eu", "us", "us", "us"],
+      "product" => [Some("a"), Some("b"), None, Some("a"), Some("c"), None],
+      "max_amount" => [1, 3, 3, 4, 5, 5],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+}
+
+#[test]
+fn test_grouping_distinguishes_null_keys() {
+    let df = df! {
+      "k" => [Some("x"), None, None],
+      "v" => [1, 2, 3],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+
+    let sql = r#"
+      SELECT k, SUM(v) AS v, GROUPING(k) AS is_total
+      FROM df
+      GROUP BY ROLLUP(k)
+      ORDER BY is_total, k NULLS FIRST"#;
+    let expected = df! {
+      "k" => [None, Some("x"), None],
+      "v" => [5, 1, 6],
+      "is_total" => [0u32, 0, 1],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    // GROUPING is also valid (and always zero) with a plain GROUP BY.
+    let sql = "SELECT k, GROUPING(k) AS g FROM df GROUP BY k ORDER BY k NULLS FIRST";
+    let expected = df! {
+      "k" => [None, Some("x")],
+      "g" => [0u32, 0],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+}
+
+#[test]
+fn test_grouping_sets_empty_input() {
+    // As in standard SQL, the grand total row is produced even if there is no data.
+    let mut ctx = sales_context();
+    let sql = r#"
+      SELECT region, COUNT(*) AS n
+      FROM sales
+      WHERE amount > 100
+      GROUP BY ROLLUP(region)"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.height(), 1);
+    assert_eq!(actual.column("region").unwrap().null_count(), 1);
+    assert_eq!(actual.column("n").unwrap().u32().unwrap().get(0), Some(0));
+}
+
+#[test]
+fn test_grouping_sets_invalid() {
+    let mut ctx = sales_context();
+    for sql in [
+        // GROUPING argument that is not a group key.
+        "SELECT region, GROUPING(amount) FROM sales GROUP BY ROLLUP(region)",
+        // Unsupported modifier.
+        "SELECT region, SUM(amount) FROM sales GROUP BY region WITH TOTALS",
+        // Modifier combined with an explicit ROLLUP.
+        "SELECT region, SUM(amount) FROM sales GROUP BY ROLLUP(region) WITH CUBE",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,202 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let events = df! {
+      "id" => [1, 2, 3],
+      "k" => ["x", "x", "y"],
+      "t" => [1, 5, 10],
+    }
+    .unwrap()
+    .lazy();
+    let windows = df! {
+      "w" => ["a", "b", "c"],
+      "k" => ["x", "y", "y"],
+      "lo" => [0, 4, 20],
+      "hi" => [5, 8, 30],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("events", events);
+    ctx.register("windows", windows);
+    ctx
+}
+
+fn assert_sql_eq(ctx: &mut SQLContext, sql: &str, expected: DataFrame) {
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.schema() == expected.schema() && actual.equals_missing(&expected),
+        "sql = {sql}\nexpected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_non_equi_join_inner() {
+    let mut ctx = create_ctx();
+    for (sql, ids, windows) in [
+        (
+            "SELECT e.id, w.w FROM events e JOIN windows w ON e.t >= w.lo AND e.t < w.hi ORDER BY e.id",
+            vec![1, 2],
+            vec!["a", "b"],
+        ),
+        // Mixed equality and inequality conditions.
+        (
+            "SELECT e.id, w.w FROM events e INNER JOIN windows w ON e.k = w.k AND e.t < w.hi ORDER BY e.id",
+            vec![1, 3],
+            vec!["a", "c"],
+        ),
+        // OR-combined conditions.
+        (
+            "SELECT e.id, w.w FROM events e JOIN windows w ON (e.t = w.hi OR e.t * 3 = w.hi) ORDER BY e.id",
+            vec![2, 3],
+            vec!["a", "c"],
+        ),
+    ] {
+        assert_sql_eq(&mut ctx, sql, df! { "id" => ids, "w" => windows }.unwrap());
+    }
+
+    // The equalities become join keys (rather than filtering a cross join).
+    let sql = "SELECT e.id, w.w FROM events e JOIN windows w ON e.k = w.k AND e.t < w.hi";
+    let plan = ctx.execute(sql).unwrap().describe_optimized_plan().unwrap();
+    assert!(!plan.contains("CROSS JOIN"), "{plan}");
+
+    // Self-join, resolving the (suffixed) columns of the right table.
+    let sql =
+        "SELECT a.id, b.id AS id2 FROM events a JOIN events b ON a.t < b.t ORDER BY a.id, id2";
+    let expected = df! { "id" => [1, 1, 2], "id2" => [2, 3, 3] }.unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+}
+
+#[test]
+fn test_non_equi_join_outer() {
+    let mut ctx = create_ctx();
+    let on = "ON e.t >= w.lo AND e.t < w.hi";
+    for (join, ids, windows) in [
+        (
+            "LEFT JOIN",
+            vec![Some(1), Some(2), Some(3)],
+            vec![Some("a"), Some("b"), None],
+        ),
+        (
+            "RIGHT JOIN",
+            vec![Some(1), Some(2), None],
+            vec![Some("a"), Some("b"), Some("c")],
+        ),
+        (
+            "FULL JOIN",
+            vec![Some(1), Some(2), Some(3), None],
+            vec![Some("a"), Some("b"), None, Some("c")],
+        ),
+    ] {
+        // Rows without a match are kept, in the order of the preserved table(s).
+        let sql = format!("SELECT e.id, w.w FROM events e {join} windows w {on}");
+        assert_sql_eq(&mut ctx, &sql, df! { "id" => ids, "w" => windows }.unwrap());
+    }
+
+    // Mixed equality and inequality conditions.
+    let sql = "SELECT e.id, w.w FROM events e LEFT JOIN windows w ON e.k = w.k AND e.t < w.hi";
+    let expected = df! {
+      "id" => [1, 2, 3],
+      "w" => [Some("a"), None, Some("c")],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    let sql = format!("SELECT * FROM events e LEFT JOIN windows w {on}");
+    let expected = df! {
+      "id" => [1, 2, 3],
+      "k" => ["x", "x", "y"],
+      "t" => [1, 5, 10],
+      "w" => [Some("a"), Some("b"), None],
+      "k:w" => [Some("x"), Some("y"), None],
+      "lo" => [Some(0), Some(4), None],
+      "hi" => [Some(5), Some(8), None],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, &sql, expected);
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_non_equi_join_semi_anti() {
+    let mut ctx = create_ctx();
+    for (join, ids) in [("SEMI", vec![1, 2]), ("ANTI", vec![3])] {
+        let sql = format!(
+            "SELECT * FROM events e LEFT {join} JOIN windows w ON e.t >= w.lo AND e.t < w.hi ORDER BY id"
+        );
+        let expected = df! {
+          "id" => ids.clone(),
+          "k" => ids.iter().map(|id| if *id < 3 { "x" } else { "y" }).collect::<Vec<_>>(),
+          "t" => ids.iter().map(|id| [1, 5, 10][*id as usize - 1]).collect::<Vec<_>>(),
+        }
+        .unwrap();
+        assert_sql_eq(&mut ctx, &sql, expected);
+    }
+}
+
+#[test]
+fn test_implicit_joins() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT e.id, w.w
+      FROM events e, windows w
+      WHERE e.k = w.k AND e.t < w.hi
+      ORDER BY e.id"#;
+    assert_sql_eq(
+        &mut ctx,
+        sql,
+        df! { "id" => [1, 3], "w" => ["a", "c"] }.unwrap(),
+    );
+
+    // The WHERE predicates become join conditions (rather than a filtered cross join).
+    let plan = ctx.execute(sql).unwrap().describe_optimized_plan().unwrap();
+    assert!(!plan.contains("CROSS JOIN"), "{plan}");
+
+    let sql = r#"
+      SELECT e.id, w.w, e2.id AS id2
+      FROM events e, windows w, events e2
+      WHERE e.t >= w.lo AND e.t < w.hi AND e2.k = w.k
+      ORDER BY e.id, id2"#;
+    let expected = df! {
+      "id" => [1, 1, 2],
+      "w" => ["a", "a", "b"],
+      "id2" => [1, 2, 3],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    let sql = "SELECT COUNT(*) AS n FROM events, windows";
+    assert_sql_eq(&mut ctx, sql, df! { "n" => [9 as IdxSize] }.unwrap());
+
+    let sql = "SELECT * FROM events e, windows w RIGHT JOIN events e2 ON e2.k = w.k";
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_non_equi_join_ambiguous_columns() {
+    let mut ctx = create_ctx();
+
+    // Unqualified columns that only exist on one side of the join are fine.
+    let sql = "SELECT e.id, w.w FROM events e JOIN windows w ON e.k = w.k AND t < hi ORDER BY e.id";
+    assert_sql_eq(
+        &mut ctx,
+        sql,
+        df! { "id" => [1, 3], "w" => ["a", "c"] }.unwrap(),
+    );
+
+    for sql in [
+        "SELECT * FROM events e JOIN windows w ON e.t < w.hi AND k = k",
+        "SELECT * FROM events e LEFT JOIN windows w ON k != w.k",
+    ] {
+        let Err(err) = ctx.execute(sql) else {
+            panic!("expected an error for {sql}");
+        };
+        assert!(
+            err.to_string().contains("ambiguous column 'k'"),
+            "{sql}: {err}"
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn sales_context() -> SQLContext {
+    let sales = df! {
+      "region" => ["north", "north", "south", "south", "south", "west"],
+      "quarter" => ["q1", "q2", "q1", "q1", "q3", "q2"],
+      "amount" => [10, 20, 30, 5, 40, 7],
+    }
+    .unwrap()
+    .lazy();
+    let wide = df! {
+      "region" => ["north", "south"],
+      "q1" => [Some(10), None],
+      "q2" => [Some(20), Some(35)],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales);
+    ctx.register("wide", wide);
+    ctx
+}
+
+fn assert_sql_eq(ctx: &mut SQLContext, sql: &str, expected: DataFrame) {
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.schema() == expected.schema() && actual.equals_missing(&expected),
+        "sql = {sql}\nexpected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_pivot() {
+    let mut ctx = sales_context();
+    let sql = r#"
+      SELECT * FROM sales
+      PIVOT (SUM(amount) FOR quarter IN ('q1', 'q2', 'q3' AS third))
+      ORDER BY region"#;
+    let expected = df! {
+      "region" => ["north", "south", "west"],
+      "q1" => [Some(10), Some(35), None],
+      "q2" => [Some(20), None, Some(7)],
+      "third" => [None, Some(40), None],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    // Pivot values taken from the data, with multiple aggregates and a default value.
+    let sql = r#"
+      SELECT * FROM (SELECT quarter, amount FROM sales) AS s
+      PIVOT (
+        MAX(amount) AS hi, COUNT(*) AS n
+        FOR quarter IN (ANY ORDER BY quarter DESC)
+        DEFAULT ON NULL (0)
+      ) AS p"#;
+    let expected = df! {
+      "q3_hi" => [40],
+      "q3_n" => [1 as IdxSize],
+      "q2_hi" => [20],
+      "q2_n" => [2 as IdxSize],
+      "q1_hi" => [30],
+      "q1_n" => [3 as IdxSize],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    // The pivoted table can be aliased (renaming its columns) and referenced.
+    let sql = r#"
+      SELECT p.r, p.a FROM sales
+      PIVOT (SUM(amount) FOR quarter IN (SELECT 'q1')) AS p (r, a)
+      WHERE p.a > 10"#;
+    assert_sql_eq(
+        &mut ctx,
+        sql,
+        df! { "r" => ["south"], "a" => [35] }.unwrap(),
+    );
+
+    let sql = "SELECT * FROM sales PIVOT (SUM(amount) FOR missing IN ('q1'))";
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_unpivot() {
+    let mut ctx = sales_context();
+    let sql = r#"
+      SELECT * FROM wide
+      UNPIVOT (amount FOR quarter IN (q1, q2))
+      ORDER BY region, quarter"#;
+    let expected = df! {
+      "region" => ["north", "north", "south"],
+      "quarter" => ["q1", "q2", "q2"],
+      "amount" => [10, 20, 35],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    // Round-trip through PIVOT.
+    let sql = r#"
+      SELECT u.region, u.q2 FROM (
+        SELECT * FROM wide UNPIVOT (amount FOR quarter IN (q1, q2))
+      ) AS w PIVOT (SUM(amount) FOR quarter IN ('q2')) AS u
+      ORDER BY u.region"#;
+    let expected = df! {
+      "region" => ["north", "south"],
+      "q2" => [20, 35],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+}
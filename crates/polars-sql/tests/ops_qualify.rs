@@ -0,0 +1,57 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "grp" => ["a", "a", "a", "b", "b"],
+      "id" => [1, 2, 3, 4, 5],
+      "value" => [10, 30, 20, 50, 40],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+fn assert_sql_eq(ctx: &mut SQLContext, sql: &str, expected: DataFrame) {
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.schema() == expected.schema() && actual.equals_missing(&expected),
+        "sql = {sql}\nexpected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_qualify() {
+    let mut ctx = create_ctx();
+    let expected = df! { "grp" => ["a", "b"], "id" => [2, 4] }.unwrap();
+
+    // Window function in the QUALIFY clause.
+    let sql = r#"
+      SELECT grp, id FROM df
+      QUALIFY value = MAX(value) OVER (PARTITION BY grp)
+      ORDER BY grp"#;
+    assert_sql_eq(&mut ctx, sql, expected.clone());
+
+    // Window function referenced by its alias in the projection.
+    let sql = r#"
+      SELECT grp, id, MAX(value) OVER (PARTITION BY grp) AS top FROM df
+      WHERE id > 1
+      QUALIFY value = top
+      ORDER BY grp"#;
+    let expected_top = df! { "grp" => ["a", "b"], "id" => [2, 4], "top" => [30, 50] }.unwrap();
+    assert_sql_eq(&mut ctx, sql, expected_top);
+
+    // DISTINCT applies to the qualified rows.
+    let sql = r#"
+      SELECT DISTINCT grp FROM df
+      QUALIFY value > AVG(value) OVER (PARTITION BY grp)
+      ORDER BY grp"#;
+    assert_sql_eq(&mut ctx, sql, df! { "grp" => ["a", "b"] }.unwrap());
+
+    let sql = "SELECT grp, id FROM df QUALIFY missing = 1";
+    assert!(ctx.execute(sql).is_err());
+}
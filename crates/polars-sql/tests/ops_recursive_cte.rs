@@ -0,0 +1,131 @@
+use polars_core::df;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn org_context() -> SQLContext {
+    let employees = df! {
+      "id" => [1, 2, 3, 4, 5, 6],
+      "name" => ["Ada", "Bo", "Cy", "Di", "Ed", "Fay"],
+      "manager_id" => [None, Some(1), Some(1), Some(2), Some(4), None],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("employees", employees);
+    ctx
+}
+
+#[test]
+fn test_recursive_cte_counter() {
+    let mut ctx = SQLContext::new();
+    let sql = r#"
+      WITH RECURSIVE t(n) AS (
+          SELECT 1
+          UNION ALL
+          SELECT n + 1 FROM t WHERE n < 10
+      )
+      SELECT SUM(n) AS total, COUNT(*) AS len FROM t"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(
+        actual.column("total").unwrap().i32().unwrap().get(0),
+        Some(55)
+    );
+    assert_eq!(
+        actual.column("len").unwrap().u32().unwrap().get(0),
+        Some(10)
+    );
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() {
+    let mut ctx = org_context();
+    let sql = r#"
+      WITH RECURSIVE reports AS (
+          SELECT id, name, 0 AS depth FROM employees WHERE manager_id IS NULL
+          UNION ALL
+          SELECT e.id, e.name, r.depth + 1
+          FROM employees e
+          INNER JOIN reports r ON e.manager_id = r.id
+      )
+      SELECT name, depth FROM reports ORDER BY depth, name"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "name" => ["Ada", "Fay", "Bo", "Cy", "Di", "Ed"],
+      "depth" => [0, 0, 1, 1, 2, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_union_distinct_terminates_on_cycle() {
+    let edges = df! {
+      "src" => [1, 2, 3, 3],
+      "dst" => [2, 3, 1, 4],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("edges", edges);
+
+    // The graph has a cycle, so only UNION (which discards rows that were seen before)
+    // reaches a fixpoint.
+    let sql = r#"
+      WITH RECURSIVE reachable(node) AS (
+          SELECT 1
+          UNION
+          SELECT edges.dst FROM edges INNER JOIN reachable ON edges.src = reachable.node
+      )
+      SELECT node FROM reachable ORDER BY node"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "node" => [1, 2, 3, 4] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    let sql = sql.replace("UNION\n", "UNION ALL\n");
+    let mut ctx = ctx.with_recursion_limit(50);
+    let err = ctx.execute(&sql).err().unwrap();
+    assert!(
+        err.to_string()
+            .contains("did not terminate within 50 iterations")
+    );
+}
+
+#[test]
+fn test_recursive_cte_mixed_with_plain_cte() {
+    let mut ctx = org_context();
+    let sql = r#"
+      WITH RECURSIVE
+        roots AS (SELECT id FROM employees WHERE manager_id IS NULL),
+        chain(id, lvl) AS (
+            SELECT id, 1 FROM roots
+            UNION ALL
+            SELECT e.id, c.lvl + 1 FROM employees e JOIN chain c ON e.manager_id = c.id
+        )
+      SELECT MAX(lvl) AS max_lvl FROM chain"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(
+        actual.column("max_lvl").unwrap().i32().unwrap().get(0),
+        Some(4)
+    );
+}
+
+#[test]
+fn test_recursive_cte_invalid() {
+    let mut ctx = org_context();
+    for sql in [
+        // Self-reference in the anchor.
+        "WITH RECURSIVE t AS (SELECT id FROM t UNION ALL SELECT id FROM employees) SELECT * FROM t",
+        // Not a union.
+        "WITH RECURSIVE t AS (SELECT id FROM t) SELECT * FROM t",
+        // Mismatched number of columns.
+        "WITH RECURSIVE t AS (SELECT 1 AS a UNION ALL SELECT a, a FROM t WHERE a < 3) SELECT * FROM t",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,245 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn shop_context() -> SQLContext {
+    let customers = df! {
+      "id" => [1, 2, 3, 4],
+      "name" => ["a", "b", "c", "d"],
+      "region" => [Some("eu"), Some("eu"), Some("us"), None],
+    }
+    .unwrap()
+    .lazy();
+    let orders = df! {
+      "order_id" => [10, 11, 12, 13, 14],
+      "customer_id" => [Some(1), Some(1), Some(2), Some(3), None],
+      "amount" => [5i64, 15, 20, 7, 100],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers);
+    ctx.register("orders", orders);
+    ctx
+}
+
+fn assert_sql_eq(ctx: &mut SQLContext, sql: &str, expected: DataFrame) {
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(
+        actual.schema() == expected.schema() && actual.equals_missing(&expected),
+        "sql = {sql}\nexpected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_exists_subquery() {
+    let mut ctx = shop_context();
+    for (sql, names) in [
+        (
+            r#"
+              SELECT name FROM customers c
+              WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.amount > 10)
+              ORDER BY name"#,
+            ["a", "b"],
+        ),
+        (
+            r#"
+              SELECT name FROM customers c
+              WHERE NOT EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.amount > 10)
+              ORDER BY name"#,
+            ["c", "d"],
+        ),
+        // Unqualified reference to the outer query.
+        (
+            r#"
+              SELECT name FROM customers
+              WHERE EXISTS (SELECT * FROM orders WHERE customer_id = id AND amount < 10)
+              ORDER BY name"#,
+            ["a", "c"],
+        ),
+        // Nested inside another expression (evaluated as a left join).
+        (
+            r#"
+              SELECT name FROM customers c
+              WHERE region = 'us' OR NOT EXISTS (SELECT * FROM orders o WHERE o.customer_id = c.id)
+              ORDER BY name"#,
+            ["c", "d"],
+        ),
+    ] {
+        assert_sql_eq(&mut ctx, sql, df! { "name" => names }.unwrap());
+    }
+
+    let sql = r#"
+      SELECT name, EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id) AS has_orders
+      FROM customers c
+      ORDER BY name"#;
+    let expected = df! {
+      "name" => ["a", "b", "c", "d"],
+      "has_orders" => [true, true, true, false],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+}
+
+#[test]
+fn test_correlated_in_subquery() {
+    let mut ctx = shop_context();
+    let sql = r#"
+      SELECT order_id FROM orders o
+      WHERE amount IN (SELECT MAX(amount) FROM orders i WHERE i.customer_id = o.customer_id)
+      ORDER BY order_id"#;
+    assert_sql_eq(&mut ctx, sql, df! { "order_id" => [11, 12, 13] }.unwrap());
+
+    let sql = sql.replace("amount IN", "amount NOT IN");
+    assert_sql_eq(&mut ctx, &sql, df! { "order_id" => [10, 14] }.unwrap());
+}
+
+#[test]
+fn test_correlated_in_subquery_nulls() {
+    let mut ctx = SQLContext::new();
+    let t = df! {
+      "id" => [1, 2, 3, 4, 5],
+      "k" => [1, 1, 2, 2, 3],
+      "x" => [Some(1), None, Some(1), Some(5), Some(1)],
+    }
+    .unwrap();
+    let s = df! {
+      "k" => [1, 1, 2, 2],
+      "y" => [Some(1), Some(2), Some(3), None],
+    }
+    .unwrap();
+    ctx.register("t", t.lazy());
+    ctx.register("s", s.lazy());
+
+    // `IN` is null without a match if either side is null (unless the subquery has no rows).
+    let sql = r#"
+      SELECT
+        id,
+        x IN (SELECT y FROM s WHERE s.k = t.k) AS is_in,
+        NOT (x IN (SELECT y FROM s WHERE s.k = t.k)) AS not_in
+      FROM t
+      ORDER BY id"#;
+    let expected = df! {
+      "id" => [1, 2, 3, 4, 5],
+      "is_in" => [Some(true), None, None, None, Some(false)],
+      "not_in" => [Some(false), None, None, None, Some(true)],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    // Rows for which `NOT IN` is null are filtered out.
+    let sql = "SELECT id FROM t WHERE x NOT IN (SELECT y FROM s WHERE s.k = t.k) ORDER BY id";
+    assert_sql_eq(&mut ctx, sql, df! { "id" => [5] }.unwrap());
+    let sql = "SELECT id FROM t WHERE x IN (SELECT y FROM s WHERE s.k = t.k) ORDER BY id";
+    assert_sql_eq(&mut ctx, sql, df! { "id" => [1] }.unwrap());
+}
+
+#[test]
+fn test_correlated_scalar_subquery() {
+    let mut ctx = shop_context();
+    let sql = r#"
+      SELECT order_id FROM orders o
+      WHERE amount > (SELECT AVG(amount) FROM orders i WHERE i.customer_id = o.customer_id)
+      ORDER BY order_id"#;
+    assert_sql_eq(&mut ctx, sql, df! { "order_id" => [11] }.unwrap());
+
+    // COUNT is zero (rather than null) for rows without a match.
+    let sql = r#"
+      SELECT
+        name,
+        (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id) AS n_orders,
+        (SELECT SUM(amount) FROM orders o WHERE o.customer_id = c.id) AS total
+      FROM customers c
+      ORDER BY name"#;
+    let expected = df! {
+      "name" => ["a", "b", "c", "d"],
+      "n_orders" => [2 as IdxSize, 1, 1, 0],
+      "total" => [Some(20i64), Some(20), Some(7), None],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    // An unnamed subquery keeps the name of the column it returns.
+    let sql = r#"
+      SELECT *, (SELECT MAX(amount) FROM orders WHERE orders.customer_id = customers.id)
+      FROM customers
+      WHERE id < 3"#;
+    let expected = df! {
+      "id" => [1, 2],
+      "name" => ["a", "b"],
+      "region" => ["eu", "eu"],
+      "amount" => [15i64, 20],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+
+    // A non-aggregate subquery that returns at most one row per outer row.
+    let sql = r#"
+      SELECT name, (SELECT amount FROM orders o WHERE o.customer_id = c.id AND amount > 10) AS big
+      FROM customers c
+      ORDER BY name"#;
+    let expected = df! {
+      "name" => ["a", "b", "c", "d"],
+      "big" => [Some(15i64), Some(20), None, None],
+    }
+    .unwrap();
+    assert_sql_eq(&mut ctx, sql, expected);
+}
+
+#[test]
+fn test_uncorrelated_subquery() {
+    let mut ctx = shop_context();
+    let sql = "SELECT order_id FROM orders WHERE amount > (SELECT AVG(amount) FROM orders)";
+    assert_sql_eq(&mut ctx, sql, df! { "order_id" => [14] }.unwrap());
+
+    for (threshold, expected) in [(50, 4), (500, 0)] {
+        let sql = format!(
+            "SELECT COUNT(*) AS n FROM customers WHERE EXISTS (SELECT 1 FROM orders WHERE amount > {threshold})"
+        );
+        assert_sql_eq(
+            &mut ctx,
+            &sql,
+            df! { "n" => [expected as IdxSize] }.unwrap(),
+        );
+    }
+}
+
+#[test]
+fn test_delete_with_correlated_subquery() {
+    let mut ctx = shop_context();
+    let sql = r#"
+      DELETE FROM customers
+      WHERE NOT EXISTS (SELECT 1 FROM orders WHERE orders.customer_id = customers.id)"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "id" => [1, 2, 3],
+      "name" => ["a", "b", "c"],
+      "region" => ["eu", "eu", "us"],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_correlated_subquery_invalid() {
+    let mut ctx = shop_context();
+    for sql in [
+        // Non-equality correlation.
+        "SELECT name FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.amount > c.id)",
+        // Correlated reference outside of the WHERE clause.
+        "SELECT name, (SELECT MAX(o.amount + c.id) FROM orders o WHERE o.customer_id = c.id) FROM customers c",
+        // LIMIT in a correlated subquery.
+        "SELECT name FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id LIMIT 1)",
+        // Scalar subquery that returns more than one row for an outer row.
+        "SELECT name, (SELECT amount FROM orders o WHERE o.customer_id = c.id) FROM customers c",
+        "SELECT name, (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id GROUP BY amount) FROM customers c",
+        // Scalar subquery that returns more than one column.
+        "SELECT name FROM customers c WHERE id = (SELECT customer_id, amount FROM orders o WHERE o.customer_id = c.id)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
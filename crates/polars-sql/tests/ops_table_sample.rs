@@ -0,0 +1,59 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = DataFrame::new(vec![
+        Column::new("a".into(), (0..100).collect::<Vec<i32>>()),
+        Column::new("b".into(), (0..100).map(|i| i * 2).collect::<Vec<i32>>()),
+    ])
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+#[test]
+fn test_table_sample() {
+    let mut ctx = create_ctx();
+    for (sql, height) in [
+        ("SELECT * FROM df TABLESAMPLE (10)", 10),
+        ("SELECT * FROM df TABLESAMPLE BERNOULLI (25 PERCENT)", 25),
+        (
+            "SELECT * FROM df AS t TABLESAMPLE SYSTEM (7 ROWS) WHERE t.a >= 0",
+            7,
+        ),
+        ("SELECT * FROM df TABLESAMPLE (500 ROWS)", 100),
+        ("SELECT a FROM df TABLESAMPLE (0)", 0),
+    ] {
+        let df = ctx.execute(sql).unwrap().collect().unwrap();
+        assert_eq!(df.height(), height, "{sql}");
+
+        // All of the columns are sampled from the same rows.
+        if df.width() == 2 {
+            let a = df.column("a").unwrap().i32().unwrap();
+            let b = df.column("b").unwrap().i32().unwrap();
+            assert!(
+                a.into_iter()
+                    .zip(b)
+                    .all(|(a, b)| a.unwrap() * 2 == b.unwrap())
+            );
+        }
+    }
+
+    // A seed makes the sample repeatable.
+    let sql = "SELECT a FROM df TABLESAMPLE BERNOULLI (20) REPEATABLE (42)";
+    let df1 = ctx.execute(sql).unwrap().collect().unwrap();
+    let df2 = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(df1.equals(&df2));
+
+    for sql in [
+        "SELECT * FROM df TABLESAMPLE (150)",
+        "SELECT * FROM df TABLESAMPLE (1.5 ROWS)",
+        "SELECT * FROM df TABLESAMPLE (10 PERCENT",
+        "SELECT * FROM df TABLESAMPLE (10 BYTES)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,168 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "id" => [1, 2, 3, 4],
+      "name" => ["a", "b", "c", "d"],
+      "value" => [1.5, 2.5, 3.5, 4.5],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+fn assert_frame_eq(actual: LazyFrame, expected: DataFrame) {
+    let actual = actual.collect().unwrap();
+    assert!(
+        actual.schema() == expected.schema() && actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_prepared_positional() {
+    let mut ctx = create_ctx();
+    let query = ctx
+        .prepare("SELECT id, value * $2 AS value FROM df WHERE id > $1 ORDER BY id")
+        .unwrap();
+    assert_eq!(query.placeholders(), ["$1", "$2"]);
+
+    // The same (cached) plan is executed with different values.
+    for (min_id, factor, ids, values) in [
+        (2, 2.0, vec![3, 4], vec![7.0, 9.0]),
+        (3, 10.0, vec![4], vec![45.0]),
+    ] {
+        let lf = query
+            .execute(vec![Scalar::from(min_id), Scalar::from(factor)])
+            .unwrap();
+        assert_frame_eq(lf, df! { "id" => ids, "value" => values }.unwrap());
+    }
+
+    // Anonymous placeholders are numbered by position.
+    let query = ctx
+        .prepare("SELECT id FROM df WHERE name IN (?, ?) OR id = ?")
+        .unwrap();
+    assert_eq!(query.placeholders(), ["$1", "$2", "$3"]);
+    let lf = query
+        .execute(vec![
+            Scalar::from(PlSmallStr::from("a")),
+            Scalar::from(PlSmallStr::from("c")),
+            Scalar::from(4),
+        ])
+        .unwrap();
+    assert_frame_eq(lf, df! { "id" => [1, 3, 4] }.unwrap());
+
+    // Placeholders in CTEs, projections and HAVING clauses.
+    let query = ctx
+        .prepare(
+            r#"
+          WITH t AS (SELECT id % 2 AS odd, * FROM df WHERE id <= $1)
+          SELECT odd, COUNT(*) AS n, $2 AS tag FROM t
+          GROUP BY odd HAVING n >= $3"#,
+        )
+        .unwrap();
+    let lf = query
+        .execute(vec![
+            Scalar::from(3),
+            Scalar::from(PlSmallStr::from("x")),
+            Scalar::from(2),
+        ])
+        .unwrap();
+    let expected = df! {
+      "odd" => [1],
+      "n" => [2 as IdxSize],
+      "tag" => ["x"],
+    }
+    .unwrap();
+    assert_frame_eq(lf, expected);
+
+    // Missing (or extra) values, and values for named placeholders.
+    assert!(query.execute(vec![Scalar::from(1)]).is_err());
+    assert!(query.execute(PlHashMap::<String, Scalar>::new()).is_err());
+}
+
+#[test]
+fn test_prepared_named() {
+    let mut ctx = create_ctx();
+    let query = ctx
+        .prepare("SELECT name FROM df WHERE id BETWEEN :lo AND :hi AND value > :lo")
+        .unwrap();
+    assert_eq!(query.placeholders(), [":hi", ":lo"]);
+
+    let params = PlHashMap::from_iter([
+        ("lo".to_string(), Scalar::from(2)),
+        ("hi".to_string(), Scalar::from(3)),
+    ]);
+    let lf = query.execute(params.clone()).unwrap();
+    assert_frame_eq(lf, df! { "name" => ["b", "c"] }.unwrap());
+
+    // Registering new data does not affect the prepared plan.
+    ctx.unregister("df");
+    let lf = query.execute(SQLParams::Named(params.clone())).unwrap();
+    assert_eq!(lf.collect().unwrap().height(), 2);
+
+    let mut extra = params;
+    extra.insert("other".to_string(), Scalar::from(1));
+    assert!(query.execute(extra).is_err());
+    assert!(
+        query
+            .execute(vec![Scalar::from(1), Scalar::from(2)])
+            .is_err()
+    );
+}
+
+#[test]
+fn test_prepared_eager_constructs() {
+    let mut ctx = create_ctx();
+    // Recursive CTEs are collected while planning, so their placeholders can't be bound later.
+    for sql in [
+        "WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < $1) SELECT * FROM t",
+        "WITH RECURSIVE t(n) AS (SELECT $1 UNION ALL SELECT n + 1 FROM t WHERE n < 3) SELECT * FROM t",
+        "SELECT id, (SELECT value FROM df i WHERE i.id = o.id AND i.value > $1) AS v FROM df o",
+    ] {
+        let Err(err) = ctx.prepare(sql) else {
+            panic!("{sql}")
+        };
+        assert!(
+            err.to_string()
+                .contains("placeholders are not supported in"),
+            "{sql}: {err}"
+        );
+    }
+
+    // Placeholders outside of the collected part of the query are fine.
+    let query = ctx
+        .prepare(
+            r#"
+          WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 4)
+          SELECT n FROM t WHERE n > $1"#,
+        )
+        .unwrap();
+    let lf = query.execute(vec![Scalar::from(2)]).unwrap();
+    assert_frame_eq(lf, df! { "n" => [3, 4] }.unwrap());
+}
+
+#[test]
+fn test_prepared_invalid() {
+    let mut ctx = create_ctx();
+    // Placeholders require a prepared query.
+    let sql = "SELECT * FROM df WHERE id = $1";
+    assert!(ctx.execute(sql).is_err());
+
+    for sql in [
+        "SELECT * FROM df WHERE id = $1 OR id = ?",
+        "SELECT * FROM df WHERE id = $2",
+        "SELECT * FROM df WHERE id = $1 OR name = :name",
+        "DROP TABLE df",
+    ] {
+        assert!(ctx.prepare(sql).is_err(), "{sql}");
+    }
+    // The context still rejects placeholders outside of prepared queries.
+    assert_eq!(ctx.get_tables(), ["df"]);
+    assert!(ctx.execute(sql).is_err());
+}
@@ -8,12 +8,12 @@ use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::sparse_init_vec::SparseInitVec;
+use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
 use super::compute_node_prelude::*;
@@ -22,6 +22,9 @@ use crate::async_primitives::connector::Receiver;
 use crate::expression::StreamExpr;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    MemoryReservation, MemoryTracker, SpillWriter, SpilledFrames, spill_memory_limit,
+};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
@@ -47,6 +50,17 @@ struct LocalGroupBySinkState {
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
     pre_agg_idxs_offsets_per_p: Vec<usize>,
+
+    // The memory reserved for the in-memory cold rows, released when dropped.
+    reservation: MemoryReservation,
+
+    // Once the memory budget is exhausted the cold rows (keys and values) are
+    // spilled to disk per partition instead, along with the sequence id of
+    // each spilled frame. The pre-aggregates are always kept in memory.
+    spilling: bool,
+    spill_writers_per_p: Vec<Option<SpillWriter>>,
+    spilled_seqs_per_p: Vec<Vec<u64>>,
+    spill_idxs_per_p: Vec<Vec<IdxSize>>,
 }
 
 impl LocalGroupBySinkState {
@@ -55,6 +69,7 @@ impl LocalGroupBySinkState {
         reductions: Vec<Box<dyn GroupedReduction>>,
         hot_table_size: usize,
         num_partitions: usize,
+        reservation: MemoryReservation,
     ) -> Self {
         let hot_grouper = new_hash_hot_grouper(key_schema, hot_table_size);
         Self {
@@ -70,6 +85,12 @@ impl LocalGroupBySinkState {
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
+
+            reservation,
+            spilling: false,
+            spill_writers_per_p: (0..num_partitions).map(|_| None).collect(),
+            spilled_seqs_per_p: vec![Vec::new(); num_partitions],
+            spill_idxs_per_p: vec![Vec::new(); num_partitions],
         }
     }
 
@@ -99,6 +120,130 @@ impl LocalGroupBySinkState {
             .extend(self.pre_agg_idxs_values_per_p.iter().map(|vp| vp.len()));
         self.pre_aggs.push((hash_keys, reductions));
     }
+
+    /// Writes the given cold rows to the spill files of their partitions.
+    fn spill_cold(
+        &mut self,
+        seq: u64,
+        cold_keys: &HashKeys,
+        cold_key_df: DataFrame,
+        cold_df: DataFrame,
+        partitioner: &HashPartitioner,
+    ) -> PolarsResult<()> {
+        for idxs in &mut self.spill_idxs_per_p {
+            idxs.clear();
+        }
+        cold_keys.gen_idxs_per_partition(
+            partitioner,
+            &mut self.spill_idxs_per_p,
+            &mut self.sketch_per_p,
+            true,
+        );
+
+        let mut columns = cold_key_df
+            .take_columns()
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| c.with_name(spilled_key_name(i)))
+            .collect_vec();
+        columns.extend(cold_df.take_columns());
+        let spill_df = unsafe { DataFrame::new_no_checks(cold_keys.len(), columns) };
+
+        for (p, idxs) in self.spill_idxs_per_p.iter().enumerate() {
+            if idxs.is_empty() {
+                continue;
+            }
+            let p_df = unsafe { spill_df.take_slice_unchecked_impl(idxs, false) };
+            let writer = match &mut self.spill_writers_per_p[p] {
+                Some(writer) => writer,
+                slot => slot.insert(SpillWriter::new(p_df.schema())?),
+            };
+            writer.write(&p_df)?;
+            self.spilled_seqs_per_p[p].push(seq);
+        }
+        Ok(())
+    }
+
+    /// Inserts the rows belonging to partition p of this local's cold morsels.
+    ///
+    /// # Safety
+    /// The morsels must be the cold morsels of this local, and the reductions
+    /// must belong to the grouper.
+    unsafe fn combine_cold_morsels(
+        &self,
+        morsels: &[(u64, HashKeys, DataFrame)],
+        p: usize,
+        grouped_reduction_cols: &[PlSmallStr],
+        grouper: &mut dyn Grouper,
+        reductions: &mut [Box<dyn GroupedReduction>],
+        group_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        let num_partitions = self.sketch_per_p.len();
+        for (i, morsel) in morsels.iter().enumerate() {
+            let (seq_id, keys, cols) = morsel;
+            unsafe {
+                let p_morsel_idxs_start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let p_morsel_idxs_stop =
+                    self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_morsel_idxs =
+                    &self.morsel_idxs_values_per_p[p][p_morsel_idxs_start..p_morsel_idxs_stop];
+
+                group_idxs.clear();
+                grouper.insert_keys_subset(keys, p_morsel_idxs, Some(group_idxs));
+                for (c, r) in grouped_reduction_cols.iter().zip(reductions.iter_mut()) {
+                    let values = cols.column(c.as_str()).unwrap();
+                    r.resize(grouper.num_groups());
+                    r.update_groups_subset(values, p_morsel_idxs, group_idxs, *seq_id)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Inserts the groups belonging to partition p of this local's pre-aggregates.
+    ///
+    /// # Safety
+    /// The pre-aggregates must be those of this local, and the reductions
+    /// must belong to the grouper.
+    unsafe fn combine_pre_aggs(
+        &self,
+        pre_aggs: &[(HashKeys, Vec<Box<dyn GroupedReduction>>)],
+        p: usize,
+        grouper: &mut dyn Grouper,
+        reductions: &mut [Box<dyn GroupedReduction>],
+        group_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        let num_partitions = self.sketch_per_p.len();
+        for (i, key_pre_aggs) in pre_aggs.iter().enumerate() {
+            let (keys, pre_aggs) = key_pre_aggs;
+            unsafe {
+                let p_pre_agg_idxs_start = self.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
+                let p_pre_agg_idxs_stop =
+                    self.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_pre_agg_idxs =
+                    &self.pre_agg_idxs_values_per_p[p][p_pre_agg_idxs_start..p_pre_agg_idxs_stop];
+
+                group_idxs.clear();
+                grouper.insert_keys_subset(keys, p_pre_agg_idxs, Some(group_idxs));
+                for (pre_agg, r) in pre_aggs.iter().zip(reductions.iter_mut()) {
+                    r.resize(grouper.num_groups());
+                    r.combine_subset(&**pre_agg, p_pre_agg_idxs, group_idxs)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn spilled_key_name(i: usize) -> PlSmallStr {
+    format_pl_smallstr!("__POLARS_GB_SPILLED_KEY{i}")
+}
+
+/// The cold rows of a single partition spilled by a local sink, with the
+/// sequence id of each spilled frame.
+struct SpilledPartition {
+    frames: SpilledFrames,
+    seqs: Vec<u64>,
 }
 
 struct GroupBySinkState {
@@ -110,6 +255,10 @@ struct GroupBySinkState {
     locals: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
     partitioner: HashPartitioner,
+    // Reductions which need the values of a group in row order can't have
+    // those values split between hot and cold rows of the same morsel, so for
+    // those we send all rows down the cold path.
+    use_hot_table: bool,
 }
 
 impl GroupBySinkState {
@@ -126,6 +275,7 @@ impl GroupBySinkState {
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
+            let use_hot_table = self.use_hot_table;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -145,12 +295,16 @@ impl GroupBySinkState {
                     hot_idxs.clear();
                     hot_group_idxs.clear();
                     cold_idxs.clear();
-                    local.hot_grouper.insert_keys(
-                        &hash_keys,
-                        &mut hot_idxs,
-                        &mut hot_group_idxs,
-                        &mut cold_idxs,
-                    );
+                    if use_hot_table {
+                        local.hot_grouper.insert_keys(
+                            &hash_keys,
+                            &mut hot_idxs,
+                            &mut hot_group_idxs,
+                            &mut cold_idxs,
+                        );
+                    } else {
+                        cold_idxs.extend(0..df.height() as IdxSize);
+                    }
 
                     // Drop columns not used for reductions (key-only columns).
                     if uniq_grouped_reduction_cols.len() < grouped_reduction_cols.len() {
@@ -182,16 +336,34 @@ impl GroupBySinkState {
                             let cold_keys = hash_keys.gather_unchecked(&cold_idxs);
                             let cold_df = df.take_slice_unchecked_impl(&cold_idxs, false);
 
-                            cold_keys.gen_idxs_per_partition(
-                                &partitioner,
-                                &mut local.morsel_idxs_values_per_p,
-                                &mut local.sketch_per_p,
-                                true,
-                            );
-                            local
-                                .morsel_idxs_offsets_per_p
-                                .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
-                            local.cold_morsels.push((seq, cold_keys, cold_df));
+                            if !local.spilling {
+                                let key_size =
+                                    keys.estimated_size() * cold_idxs.len() / keys.height();
+                                local.spilling = !local
+                                    .reservation
+                                    .try_grow(cold_df.estimated_size() + key_size);
+                            }
+                            if local.spilling {
+                                let cold_key_df = keys.take_slice_unchecked_impl(&cold_idxs, false);
+                                local.spill_cold(
+                                    seq,
+                                    &cold_keys,
+                                    cold_key_df,
+                                    cold_df,
+                                    &partitioner,
+                                )?;
+                            } else {
+                                cold_keys.gen_idxs_per_partition(
+                                    &partitioner,
+                                    &mut local.morsel_idxs_values_per_p,
+                                    &mut local.sketch_per_p,
+                                    true,
+                                );
+                                local.morsel_idxs_offsets_per_p.extend(
+                                    local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()),
+                                );
+                                local.cold_morsels.push((seq, cold_keys, cold_df));
+                            }
                         }
                     }
 
@@ -205,8 +377,7 @@ impl GroupBySinkState {
         }
     }
 
-    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
-        // Finalize pre-aggregations.
+    fn finalize_pre_aggs(&mut self) {
         POOL.install(|| {
             self.locals
                 .as_mut_slice()
@@ -221,6 +392,14 @@ impl GroupBySinkState {
                     l.add_pre_agg(hot_keys, hot_reductions, &self.partitioner);
                 });
         });
+    }
+
+    fn has_spilled(&self) -> bool {
+        self.locals.iter().any(|l| l.spilling)
+    }
+
+    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
+        self.finalize_pre_aggs();
 
         // To reduce maximum memory usage we want to drop the morsels
         // as soon as they're processed, so we move into Arcs. The drops might
@@ -294,33 +473,15 @@ impl GroupBySinkState {
                             drop(drop_q_recv.try_recv());
                         }
 
-                        for (i, morsel) in l_morsels.iter().enumerate() {
-                            let (seq_id, keys, cols) = morsel;
-                            unsafe {
-                                let p_morsel_idxs_start =
-                                    l.morsel_idxs_offsets_per_p[i * num_partitions + p];
-                                let p_morsel_idxs_stop =
-                                    l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
-                                let p_morsel_idxs = &l.morsel_idxs_values_per_p[p]
-                                    [p_morsel_idxs_start..p_morsel_idxs_stop];
-
-                                group_idxs.clear();
-                                p_grouper.insert_keys_subset(
-                                    keys,
-                                    p_morsel_idxs,
-                                    Some(&mut group_idxs),
-                                );
-                                for (c, r) in grouped_reduction_cols.iter().zip(&mut p_reductions) {
-                                    let values = cols.column(c.as_str()).unwrap();
-                                    r.resize(p_grouper.num_groups());
-                                    r.update_groups_subset(
-                                        values,
-                                        p_morsel_idxs,
-                                        &group_idxs,
-                                        *seq_id,
-                                    )?;
-                                }
-                            }
+                        unsafe {
+                            l.combine_cold_morsels(
+                                &l_morsels,
+                                p,
+                                grouped_reduction_cols,
+                                &mut *p_grouper,
+                                &mut p_reductions,
+                                &mut group_idxs,
+                            )?;
                         }
 
                         if let Some(l) = Arc::into_inner(l_morsels) {
@@ -341,27 +502,14 @@ impl GroupBySinkState {
                             drop(drop_q_recv.try_recv());
                         }
 
-                        for (i, key_pre_aggs) in l_pre_aggs.iter().enumerate() {
-                            let (keys, pre_aggs) = key_pre_aggs;
-                            unsafe {
-                                let p_pre_agg_idxs_start =
-                                    l.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
-                                let p_pre_agg_idxs_stop =
-                                    l.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
-                                let p_pre_agg_idxs = &l.pre_agg_idxs_values_per_p[p]
-                                    [p_pre_agg_idxs_start..p_pre_agg_idxs_stop];
-
-                                group_idxs.clear();
-                                p_grouper.insert_keys_subset(
-                                    keys,
-                                    p_pre_agg_idxs,
-                                    Some(&mut group_idxs),
-                                );
-                                for (pre_agg, r) in pre_aggs.iter().zip(&mut p_reductions) {
-                                    r.resize(p_grouper.num_groups());
-                                    r.combine_subset(&**pre_agg, p_pre_agg_idxs, &group_idxs)?;
-                                }
-                            }
+                        unsafe {
+                            l.combine_pre_aggs(
+                                &l_pre_aggs,
+                                p,
+                                &mut *p_grouper,
+                                &mut p_reductions,
+                                &mut group_idxs,
+                            )?;
                         }
 
                         if let Some(l) = Arc::into_inner(l_pre_aggs) {
@@ -423,6 +571,114 @@ impl GroupBySinkState {
 
         Ok(output_per_partition.try_assume_init().ok().unwrap())
     }
+
+    /// Finishes the spill files of all locals, returning the spilled cold rows
+    /// per partition.
+    fn finish_spilling(&mut self) -> PolarsResult<Vec<Vec<SpilledPartition>>> {
+        let num_partitions = self.partitioner.num_partitions();
+        let mut spilled_per_p: Vec<Vec<SpilledPartition>> =
+            (0..num_partitions).map(|_| Vec::new()).collect();
+        for l in &mut self.locals {
+            let writers = core::mem::take(&mut l.spill_writers_per_p);
+            let seqs = core::mem::take(&mut l.spilled_seqs_per_p);
+            for (p, (writer, seqs)) in writers.into_iter().zip(seqs).enumerate() {
+                if let Some(writer) = writer {
+                    spilled_per_p[p].push(SpilledPartition {
+                        frames: writer.finish()?,
+                        seqs,
+                    });
+                }
+            }
+        }
+        Ok(spilled_per_p)
+    }
+
+    /// Combines all in-memory and spilled state of a single partition.
+    fn combine_partition(
+        &self,
+        p: usize,
+        spilled: Vec<SpilledPartition>,
+    ) -> PolarsResult<GroupByPartition> {
+        let mut sketch = CardinalitySketch::new();
+        for l in &self.locals {
+            sketch.combine(&l.sketch_per_p[p]);
+        }
+
+        let est_num_groups = sketch.estimate() * 5 / 4;
+        let mut p_grouper = self.grouper.new_empty();
+        let mut p_reductions = self
+            .grouped_reductions
+            .iter()
+            .map(|gr| gr.new_empty())
+            .collect_vec();
+        p_grouper.reserve(est_num_groups);
+        for r in &mut p_reductions {
+            r.reserve(est_num_groups);
+        }
+
+        let mut group_idxs = Vec::new();
+        for l in &self.locals {
+            unsafe {
+                l.combine_cold_morsels(
+                    &l.cold_morsels,
+                    p,
+                    &self.grouped_reduction_cols,
+                    &mut *p_grouper,
+                    &mut p_reductions,
+                    &mut group_idxs,
+                )?;
+                l.combine_pre_aggs(
+                    &l.pre_aggs,
+                    p,
+                    &mut *p_grouper,
+                    &mut p_reductions,
+                    &mut group_idxs,
+                )?;
+            }
+        }
+
+        let num_keys = self.key_selectors.len();
+        let mut all_idxs = Vec::new();
+        for spilled_partition in spilled {
+            let mut reader = spilled_partition.frames.reader()?;
+            for seq in spilled_partition.seqs {
+                let df = reader.next_frame()?.unwrap();
+                let height = df.height();
+                let mut columns = df.take_columns();
+                let values = columns.split_off(num_keys);
+                let keys = DataFrame::new_with_height(height, columns)?;
+                let values = DataFrame::new_with_height(height, values)?;
+                let hash_keys = HashKeys::from_df(&keys, self.random_state, true, false);
+
+                all_idxs.clear();
+                all_idxs.extend(0..height as IdxSize);
+                group_idxs.clear();
+                unsafe {
+                    p_grouper.insert_keys_subset(&hash_keys, &all_idxs, Some(&mut group_idxs));
+                    for (c, r) in self.grouped_reduction_cols.iter().zip(&mut p_reductions) {
+                        let values = values.column(c.as_str()).unwrap();
+                        r.resize(p_grouper.num_groups());
+                        r.update_groups_subset(values, &all_idxs, &group_idxs, seq)?;
+                    }
+                }
+            }
+        }
+
+        Ok(GroupByPartition {
+            grouper: p_grouper,
+            grouped_reductions: p_reductions,
+        })
+    }
+}
+
+/// A group-by which spilled part of its state, finalized and emitted one
+/// partition at a time.
+struct SpilledGroupBySource {
+    sink: GroupBySinkState,
+    spilled_per_p: Vec<Vec<SpilledPartition>>,
+    next_partition: usize,
+    source: Option<InMemorySourceNode>,
+    seq_offset: MorselSeq,
 }
 
 struct GroupByPartition {
@@ -446,6 +702,7 @@ impl GroupByPartition {
 enum GroupByState {
     Sink(GroupBySinkState),
     Source(InMemorySourceNode),
+    SpilledSource(Box<SpilledGroupBySource>),
     Done,
 }
 
@@ -477,6 +734,7 @@ impl GroupByNode {
             .collect::<PlHashSet<_>>()
             .into_iter()
             .collect_vec();
+        let memory = Arc::new(MemoryTracker::new(spill_memory_limit()));
         let locals = (0..num_pipelines)
             .map(|_| {
                 let reductions = grouped_reductions.iter().map(|gr| gr.new_empty()).collect();
@@ -485,10 +743,12 @@ impl GroupByNode {
                     reductions,
                     hot_table_size,
                     num_partitions,
+                    memory.reservation(),
                 )
             })
             .collect();
         let partitioner = HashPartitioner::new(num_partitions, 0);
+        let use_hot_table = !grouped_reductions.iter().any(|gr| gr.needs_row_order());
         Self {
             state: GroupByState::Sink(GroupBySinkState {
                 key_selectors,
@@ -499,6 +759,7 @@ impl GroupByNode {
                 grouped_reduction_cols,
                 locals,
                 partitioner,
+                use_hot_table,
             }),
             key_schema,
             output_schema,
@@ -532,6 +793,19 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
+                if sink.has_spilled() {
+                    sink.finalize_pre_aggs();
+                    let spilled_per_p = sink.finish_spilling()?;
+                    self.state = GroupByState::SpilledSource(Box::new(SpilledGroupBySource {
+                        sink,
+                        spilled_per_p,
+                        next_partition: 0,
+                        source: None,
+                        seq_offset: MorselSeq::new(0),
+                    }));
+                    return self.update_state(recv, send, state);
+                }
+
                 let partitions = sink.combine_locals()?;
                 let dfs = POOL.install(|| {
                     partitions
@@ -551,6 +825,33 @@ impl ComputeNode for GroupByNode {
                     self.state = GroupByState::Done;
                 }
             },
+            // Emit the partitions one by one, only combining a partition once
+            // the previous one has been sent.
+            GroupByState::SpilledSource(src) => loop {
+                if let Some(source) = &mut src.source {
+                    source.update_state(&mut [], send, state)?;
+                    if send[0] != PortState::Done {
+                        break;
+                    }
+                    src.source = None;
+                    send[0] = PortState::Ready;
+                }
+
+                let p = src.next_partition;
+                if p == src.spilled_per_p.len() {
+                    self.state = GroupByState::Done;
+                    break;
+                }
+                src.next_partition += 1;
+                let spilled = core::mem::take(&mut src.spilled_per_p[p]);
+                let df = src
+                    .sink
+                    .combine_partition(p, spilled)?
+                    .into_df(&self.key_schema, &self.output_schema)?;
+                let seq_offset = src.seq_offset;
+                src.seq_offset = seq_offset.offset_by_u64(df.height() as u64 + 1);
+                src.source = Some(InMemorySourceNode::new(Arc::new(df), seq_offset));
+            },
             // Nothing to change.
             GroupByState::Done | GroupByState::Sink(_) => {},
         }
@@ -561,7 +862,7 @@ impl ComputeNode for GroupByNode {
                 send[0] = PortState::Blocked;
                 recv[0] = PortState::Ready;
             },
-            GroupByState::Source(..) => {
+            GroupByState::Source(..) | GroupByState::SpilledSource(..) => {
                 recv[0] = PortState::Done;
                 send[0] = PortState::Ready;
             },
@@ -596,6 +897,11 @@ impl ComputeNode for GroupByNode {
                 assert!(recv_ports[0].is_none());
                 source.spawn(scope, &mut [], send_ports, state, join_handles);
             },
+            GroupByState::SpilledSource(src) => {
+                assert!(recv_ports[0].is_none());
+                let source = src.source.as_mut().unwrap();
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
             GroupByState::Done => unreachable!(),
         }
     }
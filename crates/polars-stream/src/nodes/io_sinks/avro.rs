@@ -0,0 +1,152 @@
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_io::avro::{AvroWriterOptions, Record, schema_to_record, write_blocks, write_header};
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{SinkOptions, SinkTarget};
+use polars_utils::priority::Priority;
+
+use super::{SinkInputPort, SinkNode};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::Receiver;
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sinks::parallelize_receive_task;
+use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::{JoinHandle, TaskPriority};
+
+pub struct AvroSinkNode {
+    target: SinkTarget,
+    write_options: AvroWriterOptions,
+    sink_options: SinkOptions,
+    cloud_options: Option<CloudOptions>,
+
+    /// The Avro schema of a row, shared by the header and all blocks.
+    record: Arc<Record>,
+}
+
+impl AvroSinkNode {
+    pub fn new(
+        input_schema: SchemaRef,
+        target: SinkTarget,
+        sink_options: SinkOptions,
+        write_options: AvroWriterOptions,
+        cloud_options: Option<CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let record = Arc::new(schema_to_record(&input_schema, &write_options.name)?);
+
+        Ok(Self {
+            target,
+            write_options,
+            sink_options,
+            cloud_options,
+            record,
+        })
+    }
+}
+
+impl SinkNode for AvroSinkNode {
+    fn name(&self) -> &str {
+        "avro-sink"
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        true
+    }
+    fn do_maintain_order(&self) -> bool {
+        self.sink_options.maintain_order
+    }
+
+    fn spawn_sink(
+        &mut self,
+        recv_port_rx: Receiver<(PhaseOutcome, SinkInputPort)>,
+        state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let (pass_rxs, mut io_rx) = parallelize_receive_task(
+            join_handles,
+            recv_port_rx,
+            state.num_pipelines,
+            self.sink_options.maintain_order,
+        );
+
+        // 16MB
+        const DEFAULT_ALLOCATION_SIZE: usize = 1 << 24;
+
+        // Encode task.
+        //
+        // Task serializes (and compresses) each morsel into a self-contained Avro block. All blocks
+        // share the sync marker of the header, so they can be encoded independently.
+        join_handles.extend(pass_rxs.into_iter().map(|mut pass_rx| {
+            let record = self.record.clone();
+            let compression = self.write_options.compression;
+
+            spawn(TaskPriority::High, async move {
+                // Amortize the allocations over time. If we see that we need to do way larger
+                // allocations, we adjust to that over time.
+                let mut allocation_size = DEFAULT_ALLOCATION_SIZE;
+
+                while let Ok((mut rx, mut lin_tx)) = pass_rx.recv().await {
+                    while let Ok(morsel) = rx.recv().await {
+                        let (mut df, seq, _, consume_token) = morsel.into_inner();
+                        if df.height() == 0 {
+                            continue;
+                        }
+
+                        let mut buffer = Vec::with_capacity(allocation_size);
+                        df.align_chunks();
+                        write_blocks(&mut buffer, &df, &record, compression, false)?;
+
+                        allocation_size = allocation_size.max(buffer.len());
+                        if lin_tx.insert(Priority(Reverse(seq), buffer)).await.is_err() {
+                            return Ok(());
+                        }
+                        drop(consume_token); // Keep the consume_token until here to increase the
+                        // backpressure.
+                    }
+                }
+
+                PolarsResult::Ok(())
+            })
+        }));
+
+        // IO task.
+        //
+        // Task that will actually do write to the target file.
+        let target = self.target.clone();
+        let sink_options = self.sink_options.clone();
+        let cloud_options = self.cloud_options.clone();
+        let record = self.record.clone();
+        let compression = self.write_options.compression;
+        let io_task = polars_io::pl_async::get_runtime().spawn(async move {
+            use tokio::io::AsyncWriteExt;
+
+            let mut file = target
+                .open_into_writeable_async(&sink_options, cloud_options.as_ref())
+                .await?
+                .try_into_async_writeable()?;
+
+            // Write the header
+            let mut header = Vec::new();
+            write_header(&mut header, &record, compression)?;
+            file.write_all(&header).await?;
+
+            while let Ok(mut lin_rx) = io_rx.recv().await {
+                while let Some(Priority(_, buffer)) = lin_rx.get().await {
+                    file.write_all(&buffer).await?;
+                }
+            }
+
+            file.sync_on_close(sink_options.sync_on_close).await?;
+            file.close().await?;
+
+            PolarsResult::Ok(())
+        });
+        join_handles.push(spawn(TaskPriority::Low, async move {
+            io_task
+                .await
+                .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))
+        }));
+    }
+}
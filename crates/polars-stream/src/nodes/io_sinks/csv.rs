@@ -13,8 +13,8 @@ use super::{SinkInputPort, SinkNode};
 use crate::async_executor::spawn;
 use crate::async_primitives::connector::Receiver;
 use crate::execute::StreamingExecutionState;
-use crate::nodes::io_sinks::parallelize_receive_task;
 use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::io_sinks::{FrameCompressor, parallelize_receive_task};
 use crate::nodes::{JoinHandle, TaskPriority};
 
 pub struct CsvSinkNode {
@@ -125,16 +125,18 @@ impl SinkNode for CsvSinkNode {
         let schema = self.schema.clone();
         let options = self.write_options.clone();
         let cloud_options = self.cloud_options.clone();
+        let num_pipelines = state.num_pipelines;
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
-            use tokio::io::AsyncWriteExt;
-
             let mut file = target
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
-                .await?;
+                .await?
+                .try_into_async_writeable()?;
+            let mut compressor = FrameCompressor::new(options.compression, num_pipelines);
 
             // Write the header
             if options.include_header || options.include_bom {
-                let mut writer = CsvWriter::new(&mut *file)
+                let mut header = Vec::new();
+                let mut writer = CsvWriter::new(&mut header)
                     .include_bom(options.include_bom)
                     .include_header(options.include_header)
                     .with_separator(options.serialize_options.separator)
@@ -151,15 +153,16 @@ impl SinkNode for CsvSinkNode {
                     .n_threads(1) // Disable rayon parallelism
                     .batched(&schema)?;
                 writer.write_batch(&DataFrame::empty_with_schema(&schema))?;
-            }
 
-            let mut file = file.try_into_async_writeable()?;
+                compressor.write(&mut *file, &header).await?;
+            }
 
             while let Ok(mut lin_rx) = io_rx.recv().await {
                 while let Some(Priority(_, buffer)) = lin_rx.get().await {
-                    file.write_all(&buffer).await?;
+                    compressor.write(&mut *file, &buffer).await?;
                 }
             }
+            compressor.finish(&mut *file).await?;
 
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
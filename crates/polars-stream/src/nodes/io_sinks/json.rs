@@ -2,7 +2,7 @@ use std::cmp::Reverse;
 
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::json::BatchedWriter;
+use polars_io::json::{BatchedWriter, JsonWriterOptions};
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -10,24 +10,27 @@ use super::{SinkInputPort, SinkNode};
 use crate::async_executor::spawn;
 use crate::async_primitives::connector::Receiver;
 use crate::execute::StreamingExecutionState;
-use crate::nodes::io_sinks::parallelize_receive_task;
 use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::io_sinks::{FrameCompressor, parallelize_receive_task};
 use crate::nodes::{JoinHandle, TaskPriority};
 
 pub struct NDJsonSinkNode {
     target: SinkTarget,
     sink_options: SinkOptions,
+    write_options: JsonWriterOptions,
     cloud_options: Option<CloudOptions>,
 }
 impl NDJsonSinkNode {
     pub fn new(
         target: SinkTarget,
         sink_options: SinkOptions,
+        write_options: JsonWriterOptions,
         cloud_options: Option<CloudOptions>,
     ) -> Self {
         Self {
             target,
             sink_options,
+            write_options,
             cloud_options,
         }
     }
@@ -98,19 +101,21 @@ impl SinkNode for NDJsonSinkNode {
         // Task that will actually do write to the target file.
         let sink_options = self.sink_options.clone();
         let target = self.target.clone();
+        let compression = self.write_options.compression;
+        let num_pipelines = state.num_pipelines;
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
-            use tokio::io::AsyncWriteExt;
-
             let mut file = target
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?
                 .try_into_async_writeable()?;
 
+            let mut compressor = FrameCompressor::new(compression, num_pipelines);
             while let Ok(mut lin_rx) = io_rx.recv().await {
                 while let Some(Priority(_, buffer)) = lin_rx.get().await {
-                    file.write_all(&buffer).await?;
+                    compressor.write(&mut *file, &buffer).await?;
                 }
             }
+            compressor.finish(&mut *file).await?;
 
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "csv", feature = "json"))]
+use std::collections::VecDeque;
 use std::sync::{Arc, LazyLock, Mutex};
 
 use futures::StreamExt;
@@ -7,6 +9,8 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::Column;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars_io::utils::compression::ExternalCompression;
 
 use self::metrics::WriteMetrics;
 use super::{ComputeNode, JoinHandle, Morsel, PortState, RecvPort, SendPort, TaskScope};
@@ -22,6 +26,8 @@ mod metrics;
 mod phase;
 use phase::PhaseOutcome;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 #[cfg(feature = "csv")]
 pub mod csv;
 #[cfg(feature = "ipc")]
@@ -175,6 +181,99 @@ pub fn parallelize_receive_task<T: Ord + Send + Sync + 'static>(
     (pass_rxs, io_rx)
 }
 
+/// Compresses the (linearized) output of the CSV and NDJSON sinks into frames of at least
+/// [`FrameCompressor::MIN_FRAME_SIZE`] bytes, as compressing every morsel into its own (small)
+/// frame hurts the compression ratio. Frames are compressed on the blocking thread pool, with up
+/// to `max_in_flight` of them in flight at once.
+#[cfg(any(feature = "csv", feature = "json"))]
+struct FrameCompressor {
+    compression: ExternalCompression,
+    buffer: Vec<u8>,
+    in_flight: VecDeque<tokio::task::JoinHandle<PolarsResult<Vec<u8>>>>,
+    max_in_flight: usize,
+    has_frames: bool,
+}
+
+#[cfg(any(feature = "csv", feature = "json"))]
+impl FrameCompressor {
+    const MIN_FRAME_SIZE: usize = 1 << 22;
+
+    fn new(compression: ExternalCompression, max_in_flight: usize) -> Self {
+        Self {
+            compression,
+            buffer: Vec::new(),
+            in_flight: VecDeque::new(),
+            max_in_flight: max_in_flight.max(1),
+            has_frames: false,
+        }
+    }
+
+    /// Write `bytes` to `file`; if compressed, they are buffered until a frame is full.
+    async fn write<W>(&mut self, file: &mut W, bytes: &[u8]) -> PolarsResult<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        if self.compression.is_uncompressed() {
+            return Ok(file.write_all(bytes).await?);
+        }
+        self.buffer.extend_from_slice(bytes);
+        if self.buffer.len() >= Self::MIN_FRAME_SIZE {
+            self.compress_buffer();
+        }
+        while self.in_flight.len() > self.max_in_flight {
+            self.write_frame(file).await?;
+        }
+        Ok(())
+    }
+
+    /// Compress and write the remaining bytes.
+    async fn finish<W>(mut self, file: &mut W) -> PolarsResult<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+    {
+        if self.compression.is_uncompressed() {
+            return Ok(());
+        }
+        // An empty file is not a valid compressed stream, so there is at least one frame.
+        if !self.buffer.is_empty() || !self.has_frames {
+            self.compress_buffer();
+        }
+        while !self.in_flight.is_empty() {
+            self.write_frame(file).await?;
+        }
+        Ok(())
+    }
+
+    fn compress_buffer(&mut self) {
+        let compression = self.compression;
+        let bytes = std::mem::take(&mut self.buffer);
+        self.in_flight
+            .push_back(polars_io::pl_async::get_runtime().spawn_blocking(move || {
+                let mut out = Vec::with_capacity(bytes.len() / 2);
+                compression.compress_frame(&bytes, &mut out)?;
+                Ok(out)
+            }));
+        self.has_frames = true;
+    }
+
+    async fn write_frame<W>(&mut self, file: &mut W) -> PolarsResult<()>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let frame = self
+            .in_flight
+            .pop_front()
+            .unwrap()
+            .await
+            .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))?;
+        Ok(file.write_all(&frame).await?)
+    }
+}
+
 pub trait SinkNode {
     fn name(&self) -> &str;
 
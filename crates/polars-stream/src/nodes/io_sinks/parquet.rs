@@ -8,13 +8,13 @@ use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::write::BatchedWriter;
-use polars_io::prelude::{ParquetWriteOptions, get_column_write_options};
+use polars_io::prelude::{ParquetWriteOptions, get_column_write_options, get_sorting_column};
 use polars_io::schema_to_arrow_checked;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, Version,
-    WriteOptions, array_to_columns, to_parquet_schema,
+    ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, SortingColumn,
+    Version, WriteOptions, array_to_bloom_filters, array_to_columns, to_parquet_schema,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
@@ -32,6 +32,14 @@ use crate::execute::StreamingExecutionState;
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::{JoinHandle, TaskPriority};
 
+/// The compressed pages and the bloom filter bitsets of the Parquet columns of a column, and its
+/// sort order.
+type EncodedColumn = (
+    Vec<Vec<CompressedPage>>,
+    Vec<Option<Vec<u8>>>,
+    Option<SortingColumn>,
+);
+
 pub struct ParquetSinkNode {
     target: SinkTarget,
 
@@ -112,7 +120,11 @@ impl SinkNode for ParquetSinkNode {
         let (mut lin_rx, lin_txs) =
             Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
-        let (mut io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (mut io_tx, mut io_rx) = connector::<(
+            Vec<Vec<CompressedPage>>,
+            Vec<Option<Vec<u8>>>,
+            Vec<SortingColumn>,
+        )>();
 
         let write_options = &self.write_options;
 
@@ -150,6 +162,8 @@ impl SinkNode for ParquetSinkNode {
                             let type_ = &parquet_schema.fields()[col_idx];
                             let column_options = &column_options[col_idx];
 
+                            let sorting_column = get_sorting_column(&column, &parquet_schema);
+
                             let array = column.as_materialized_series().rechunk();
                             let array = array.to_arrow(0, CompatLevel::newest());
 
@@ -159,6 +173,9 @@ impl SinkNode for ParquetSinkNode {
                             // @NOTE: Since one Polars column might contain multiple Parquet columns (when
                             // it has a struct datatype), we return a Vec<Vec<CompressedPage>>.
 
+                            let bloom_filters =
+                                array_to_bloom_filters(&array, type_.clone(), column_options);
+
                             // Array -> Parquet pages.
                             let encoded_columns =
                                 array_to_columns(array, type_.clone(), column_options, options)?;
@@ -183,7 +200,10 @@ impl SinkNode for ParquetSinkNode {
                                 .collect::<ParquetResult<Vec<_>>>()?;
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, (compressed_pages, bloom_filters, sorting_column)),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -205,7 +225,7 @@ impl SinkNode for ParquetSinkNode {
             struct Current {
                 seq: usize,
                 num_columns_seen: usize,
-                columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                columns: Vec<Option<EncodedColumn>>,
             }
 
             let mut current = Current {
@@ -215,14 +235,14 @@ impl SinkNode for ParquetSinkNode {
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, encoded_column))) = lin_rx.get().await {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
 
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
-                current.columns[i] = Some(compressed_pages);
+                current.columns[i] = Some(encoded_column);
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -230,11 +250,25 @@ impl SinkNode for ParquetSinkNode {
                     // them.
                     let mut current_row_group: Vec<Vec<CompressedPage>> =
                         Vec::with_capacity(num_parquet_columns);
+                    let mut current_bloom_filters = Vec::with_capacity(num_parquet_columns);
+                    let mut current_sorting_columns = Vec::new();
                     for column in current.columns.iter_mut() {
-                        current_row_group.extend(column.take().unwrap());
+                        let (compressed_pages, bloom_filters, sorting_column) =
+                            column.take().unwrap();
+                        current_row_group.extend(compressed_pages);
+                        current_bloom_filters.extend(bloom_filters);
+                        current_sorting_columns.extend(sorting_column);
                     }
 
-                    if io_tx.send(current_row_group).await.is_err() {
+                    if io_tx
+                        .send((
+                            current_row_group,
+                            current_bloom_filters,
+                            current_sorting_columns,
+                        ))
+                        .await
+                        .is_err()
+                    {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
@@ -284,11 +318,11 @@ impl SinkNode for ParquetSinkNode {
             );
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
-            while let Ok(current_row_group) = io_rx.recv().await {
+            while let Ok((current_row_group, bloom_filters, sorting_columns)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 assert_eq!(current_row_group.len(), num_parquet_columns);
-                writer.write_row_group(&current_row_group)?;
+                writer.write_row_group(&current_row_group, &bloom_filters, sorting_columns)?;
             }
 
             let file_size = writer.finish()?;
@@ -1,3 +1,4 @@
+use std::io::Cursor;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -13,7 +14,8 @@ use polars_io::prelude::_csv_read_internal::{
 };
 use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice,
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, TranscodedChunks,
+    count_rows_from_slice, leading_lines,
 };
 use polars_io::utils::compression::maybe_decompress_bytes;
 use polars_io::utils::slice::SplitSlicePosition;
@@ -90,8 +92,7 @@ const NO_SLICE: (usize, usize) = (0, usize::MAX);
 const SLICE_ENDED: (usize, usize) = (usize::MAX, 0);
 
 struct LineBatch {
-    // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-    bytes: &'static [u8],
+    bytes: MemSlice,
     n_lines: usize,
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
@@ -174,6 +175,29 @@ impl FileReader for CsvFileReader {
             self.options.infer_schema_length
         };
 
+        // Data that is not utf8 is transcoded in chunks of whole lines. The first chunk holds the
+        // rows that are skipped and those that are used for schema inference.
+        let parse_options = self.options.parse_options.as_ref();
+        let (memslice, transcoded_chunks) = if parse_options.encoding.needs_transcoding() {
+            let mut chunks = TranscodedChunks::new(
+                Cursor::new(memslice),
+                parse_options.encoding,
+                parse_options.quote_char,
+                parse_options.eol_char,
+            );
+            let n_lines = leading_lines(
+                self.options.skip_lines,
+                self.options.skip_rows,
+                self.options.has_header,
+                self.options.skip_rows_after_header,
+                infer_schema_length,
+            );
+            let first = chunks.next_chunk(n_lines)?.unwrap_or_default();
+            (MemSlice::from_vec(first), Some(chunks))
+        } else {
+            (memslice, None)
+        };
+
         let (mut inferred_schema, ..) = polars_io::csv::read::infer_file_schema(
             &polars_io::mmap::ReaderBytes::Owned(memslice.clone()),
             &self.options.parse_options,
@@ -268,7 +292,8 @@ impl FileReader for CsvFileReader {
         let line_batch_source_handle = AbortOnDropHandle::new(spawn(
             TaskPriority::Low,
             LineBatchSource {
-                memslice: memslice.clone(),
+                memslice,
+                transcoded_chunks,
                 line_counter: CountLines::new(
                     self.options.parse_options.quote_char,
                     self.options.parse_options.eol_char,
@@ -293,8 +318,6 @@ impl FileReader for CsvFileReader {
             .zip(morsel_senders)
             .enumerate()
             .map(|(worker_idx, (mut line_batch_rx, mut morsel_tx))| {
-                // Hold a ref as we are receiving `&'static [u8]`s pointing to this.
-                let global_memslice = memslice.clone();
                 // Only verbose log from the last worker to avoid flooding output.
                 let verbose = verbose && worker_idx == n_workers - 1;
                 let mut n_rows_processed: usize = 0;
@@ -312,19 +335,13 @@ impl FileReader for CsvFileReader {
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
-                        debug_assert!(bytes.as_ptr() as usize >= global_memslice.as_ptr() as usize);
-                        debug_assert!(
-                            bytes.as_ptr() as usize + bytes.len()
-                                <= global_memslice.as_ptr() as usize + global_memslice.len()
-                        );
-
                         let (offset, len) = match slice {
                             SLICE_ENDED => (0, 1),
                             v => v,
                         };
 
                         let (df, n_rows_in_chunk) =
-                            chunk_reader.read_chunk(bytes, n_lines, (offset, len), row_offset)?;
+                            chunk_reader.read_chunk(&bytes, n_lines, (offset, len), row_offset)?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
 
@@ -359,7 +376,7 @@ impl FileReader for CsvFileReader {
                             assert_eq!(slice, SLICE_ENDED);
 
                             let n_lines = if let Some(v) = alt_count_lines.as_deref() {
-                                v.count_lines(bytes)?
+                                v.count_lines(&bytes)?
                             } else {
                                 n_lines
                             };
@@ -423,6 +440,8 @@ impl CsvFileReader {
 
 struct LineBatchSource {
     memslice: MemSlice,
+    /// The chunks after `memslice` if the data is transcoded in chunks.
+    transcoded_chunks: Option<TranscodedChunks<Cursor<MemSlice>>>,
     line_counter: CountLines,
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     options: Arc<CsvReadOptions>,
@@ -438,6 +457,7 @@ impl LineBatchSource {
     async fn run(self) -> PolarsResult<usize> {
         let LineBatchSource {
             memslice,
+            mut transcoded_chunks,
             line_counter,
             mut line_batch_tx,
             options,
@@ -468,8 +488,7 @@ impl LineBatchSource {
             eprintln!("[CsvSource]: Start line splitting",);
         }
 
-        let global_bytes: &[u8] = memslice.as_ref();
-        let global_bytes: &'static [u8] = unsafe { std::mem::transmute(global_bytes) };
+        let mut block = memslice;
 
         let i = {
             let parse_options = options.parse_options.as_ref();
@@ -484,7 +503,7 @@ impl LineBatchSource {
             let has_header = options.has_header;
 
             find_starting_point(
-                global_bytes,
+                &block,
                 quote_char,
                 eol_char,
                 file_schema_len,
@@ -496,14 +515,14 @@ impl LineBatchSource {
             )?
         };
 
-        let mut bytes = &global_bytes[i..];
+        let mut bytes = &block[i..];
 
-        let mut chunk_size = {
+        let initial_chunk_size = |n_bytes: usize| {
             let max_chunk_size = 16 * 1024 * 1024;
             let chunk_size = if global_slice.is_some() {
                 max_chunk_size
             } else {
-                std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
+                std::cmp::min(n_bytes / (16 * num_pipelines), max_chunk_size)
             };
 
             // Use a small min chunk size to catch failures in tests.
@@ -513,10 +532,22 @@ impl LineBatchSource {
             let min_chunk_size = 1024 * 4;
             std::cmp::max(chunk_size, min_chunk_size)
         };
+        let mut chunk_size = initial_chunk_size(bytes.len());
 
         loop {
             if bytes.is_empty() {
-                break;
+                // Continue with the next block of whole lines if the data is transcoded in chunks.
+                let next_block = match transcoded_chunks.as_mut() {
+                    Some(chunks) => chunks.next_chunk(Some(1))?,
+                    None => None,
+                };
+                let Some(next_block) = next_block else {
+                    break;
+                };
+                block = MemSlice::from_vec(next_block);
+                bytes = &block;
+                chunk_size = initial_chunk_size(bytes.len());
+                continue;
             }
 
             let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
@@ -527,7 +558,7 @@ impl LineBatchSource {
                 (count, pos)
             };
 
-            let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
+            let slice_start = bytes.as_ptr() as usize - block.as_ptr() as usize;
 
             bytes = &bytes[position..];
 
@@ -561,13 +592,11 @@ impl LineBatchSource {
                 NO_SLICE
             };
 
-            let bytes_this_chunk = &global_bytes[slice_start..slice_start + position];
-
             let morsel_seq = *morsel_seq_ref;
             *morsel_seq_ref = morsel_seq.successor();
 
             let batch = LineBatch {
-                bytes: bytes_this_chunk,
+                bytes: block.slice(slice_start..slice_start + position),
                 n_lines: count,
                 slice,
                 row_offset: current_row_offset,
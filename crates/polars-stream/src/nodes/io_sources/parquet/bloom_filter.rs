@@ -0,0 +1,149 @@
+use std::ops::Range;
+
+use arrow::datatypes::ArrowSchema;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::PolarsResult;
+use polars_io::predicates::{ScanIOPredicate, SpecializedColumnPredicate};
+use polars_io::prelude::_internal::{
+    bloom_filter_hashes, bloom_filter_may_contain, deserialize_bloom_filter_header,
+};
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+
+/// The number of bytes fetched to read the header of a bloom filter whose length is not in the
+/// metadata. The header is a handful of bytes and is followed by a bitset of at least 32 bytes.
+const HEADER_SIZE_ESTIMATE: usize = 32;
+
+/// A lookup of the hashes of the values that a column is compared for equality against in the
+/// bloom filter of a column chunk.
+struct Lookup {
+    /// The index of the row group in the row group slice.
+    row_group: usize,
+    hashes: Vec<u64>,
+    offset: usize,
+    length: Option<usize>,
+}
+
+/// Row-group predicate pushdown using the bloom filters of the columns that the predicate
+/// compares for equality against literals. Row groups whose bloom filter doesn't contain any of
+/// the literals of such a column are added to `skip_mask`.
+pub(super) async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    predicate: &ScanIOPredicate,
+    metadata: &FileMetadata,
+    reader_schema: &ArrowSchema,
+    byte_source: &DynByteSource,
+    skip_mask: Option<Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    let columns = predicate
+        .column_predicates
+        .predicates
+        .iter()
+        .filter_map(|(name, (_, specialized))| {
+            let values = match specialized.as_ref()? {
+                SpecializedColumnPredicate::Equal(value) => std::slice::from_ref(value),
+                SpecializedColumnPredicate::EqualOneOf(values) => values.as_ref(),
+                _ => return None,
+            };
+            Some((reader_schema.get(name)?, values))
+        })
+        .collect::<Vec<_>>();
+
+    if columns.is_empty() {
+        return Ok(skip_mask);
+    }
+
+    let mut lookups = Vec::new();
+    for (i, row_group) in metadata.row_groups[row_group_slice.clone()]
+        .iter()
+        .enumerate()
+    {
+        if skip_mask.as_ref().is_some_and(|m| m.get_bit(i)) {
+            continue;
+        }
+
+        for (field, values) in &columns {
+            let Some([idx]) = row_group.columns_idxs_under_root_iter(&field.name) else {
+                continue;
+            };
+            let column = &row_group.parquet_columns()[*idx];
+            let Some(offset) = column
+                .bloom_filter_offset()
+                .and_then(|o| usize::try_from(o).ok())
+            else {
+                continue;
+            };
+            let Some(hashes) = bloom_filter_hashes(column, field, values) else {
+                continue;
+            };
+
+            lookups.push(Lookup {
+                row_group: i,
+                hashes,
+                offset,
+                length: column
+                    .bloom_filter_length()
+                    .and_then(|l| usize::try_from(l).ok()),
+            });
+        }
+    }
+
+    if lookups.is_empty() {
+        return Ok(skip_mask);
+    }
+
+    let mut ranges = lookups
+        .iter()
+        .map(|l| l.offset..l.offset + l.length.unwrap_or(HEADER_SIZE_ESTIMATE))
+        .collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let num_row_groups = row_group_slice.len();
+    let mut skip_mask = match skip_mask {
+        None => MutableBitmap::from_len_zeroed(num_row_groups),
+        Some(skip_mask) => skip_mask.make_mut(),
+    };
+
+    for lookup in &lookups {
+        if skip_mask.get(lookup.row_group) {
+            continue;
+        }
+
+        let data = &bytes_map[&lookup.offset];
+        let Some((header_len, num_bytes)) = deserialize_bloom_filter_header(data)? else {
+            continue;
+        };
+        let bitset_range = header_len..header_len + num_bytes;
+
+        let may_contain = if lookup.length.is_some() {
+            let Some(bitset) = data.get(bitset_range) else {
+                continue;
+            };
+            bloom_filter_may_contain(bitset, &lookup.hashes)
+        } else {
+            let start = lookup.offset + bitset_range.start;
+            let bitset = byte_source
+                .get_range(start..start + bitset_range.len())
+                .await?;
+            bloom_filter_may_contain(&bitset, &lookup.hashes)
+        };
+
+        if !may_contain {
+            skip_mask.set(lookup.row_group, true);
+        }
+    }
+
+    let skip_mask = skip_mask.freeze();
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            reading {} / {} row groups",
+            skip_mask.unset_bits(),
+            num_row_groups,
+        );
+    }
+
+    Ok(Some(skip_mask))
+}
@@ -4,7 +4,9 @@ use std::sync::Arc;
 
 use arrow::datatypes::ArrowDataType;
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{Column, DataType, IDX_DTYPE, IntoColumn};
+use polars_core::prelude::{
+    ArrowSchema, Column, DataType, IDX_DTYPE, IntoColumn, PlIndexSet, PlSmallStr,
+};
 use polars_core::schema::SchemaRef;
 use polars_core::series::Series;
 use polars_core::utils::arrow::bitmap::Bitmap;
@@ -14,8 +16,11 @@ use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::_internal::{PrefilterMaskSetting, collect_statistics_with_live_columns};
 use polars_io::prelude::{FileMetadata, ParallelStrategy};
+use polars_parquet::read::statistics::ArrowColumnStatisticsArrays;
 use polars_utils::{IdxSize, format_pl_smallstr};
 
+use super::bloom_filter::calculate_row_group_bloom_filter_skip_mask;
+use super::page_index::PageIndexPruner;
 use super::row_group_data_fetch::RowGroupDataFetcher;
 use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
@@ -35,7 +40,7 @@ async fn calculate_row_group_pred_pushdown_skip_mask(
     metadata: &Arc<FileMetadata>,
     reader_schema: &ArrowSchemaRef,
     mut row_index: Option<RowIndex>,
-    cast_columns: Option<(CastColumns, SchemaRef)>,
+    cast_columns: Option<Arc<(CastColumns, SchemaRef)>>,
     verbose: bool,
 ) -> PolarsResult<Option<Bitmap>> {
     if !use_statistics {
@@ -73,75 +78,18 @@ async fn calculate_row_group_pred_pushdown_skip_mask(
             row_index.as_ref().map(|ri| (&ri.name, ri.offset)),
         )?;
 
-        let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
-
         let lengths: Vec<IdxSize> = metadata.row_groups[row_group_slice.clone()]
             .iter()
             .map(|rg| rg.num_rows() as IdxSize)
             .collect();
-        columns.push(Column::new("len".into(), lengths));
-        for (c, stat) in live_columns.iter().zip(stats) {
-            let field = reader_schema.get(c).map(Cow::Borrowed).unwrap_or_else(|| {
-                let row_index = row_index.clone().unwrap();
-                assert_eq!(c, &row_index.name);
-
-                Cow::Owned(arrow::datatypes::Field {
-                    name: row_index.name,
-                    dtype: ArrowDataType::IDX_DTYPE,
-                    is_nullable: false,
-                    metadata: None,
-                })
-            });
-
-            let min_name = format_pl_smallstr!("{c}_min");
-            let max_name = format_pl_smallstr!("{c}_max");
-            let nc_name = format_pl_smallstr!("{c}_nc");
-
-            let (min, max, nc) = match stat {
-                None => {
-                    let dtype = DataType::from_arrow_field(field.as_ref());
-
-                    (
-                        Column::full_null(min_name, num_row_groups, &dtype),
-                        Column::full_null(max_name, num_row_groups, &dtype),
-                        Column::full_null(nc_name, num_row_groups, &IDX_DTYPE),
-                    )
-                },
-                Some(stat) => {
-                    let md = field.metadata.as_deref();
-
-                    (
-                        unsafe {
-                            Series::_try_from_arrow_unchecked_with_md(
-                                min_name,
-                                vec![stat.min_value],
-                                field.dtype(),
-                                md,
-                            )
-                        }?
-                        .into_column(),
-                        unsafe {
-                            Series::_try_from_arrow_unchecked_with_md(
-                                max_name,
-                                vec![stat.max_value],
-                                field.dtype(),
-                                md,
-                            )
-                        }?
-                        .into_column(),
-                        Series::from_arrow(nc_name, stat.null_count.boxed())?.into_column(),
-                    )
-                },
-            };
-
-            columns.extend([min, max, nc]);
-        }
-
-        let mut statistics_df = DataFrame::new_with_height(num_row_groups, columns)?;
-
-        if let Some((cast_columns, file_schema)) = cast_columns {
-            cast_columns.apply_cast_to_statistics(&mut statistics_df, &file_schema)?;
-        }
+        let statistics_df = statistics_df(
+            lengths,
+            &live_columns,
+            stats,
+            &reader_schema,
+            row_index.as_ref(),
+            cast_columns.as_deref(),
+        )?;
 
         sbp.evaluate_with_stat_df(&statistics_df)
     })
@@ -159,6 +107,86 @@ async fn calculate_row_group_pred_pushdown_skip_mask(
     Ok(Some(skip_row_group_mask))
 }
 
+/// Builds the DataFrame of statistics that a skip batch predicate is evaluated on, with a row
+/// per batch of `lengths`.
+pub(super) fn statistics_df(
+    lengths: Vec<IdxSize>,
+    live_columns: &PlIndexSet<PlSmallStr>,
+    stats: Vec<Option<ArrowColumnStatisticsArrays>>,
+    reader_schema: &ArrowSchema,
+    row_index: Option<&RowIndex>,
+    cast_columns: Option<&(CastColumns, SchemaRef)>,
+) -> PolarsResult<DataFrame> {
+    let num_batches = lengths.len();
+    let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
+
+    columns.push(Column::new("len".into(), lengths));
+    for (c, stat) in live_columns.iter().zip(stats) {
+        let field = reader_schema.get(c).map(Cow::Borrowed).unwrap_or_else(|| {
+            let row_index = row_index.cloned().unwrap();
+            assert_eq!(c, &row_index.name);
+
+            Cow::Owned(arrow::datatypes::Field {
+                name: row_index.name,
+                dtype: ArrowDataType::IDX_DTYPE,
+                is_nullable: false,
+                metadata: None,
+            })
+        });
+
+        let min_name = format_pl_smallstr!("{c}_min");
+        let max_name = format_pl_smallstr!("{c}_max");
+        let nc_name = format_pl_smallstr!("{c}_nc");
+
+        let (min, max, nc) = match stat {
+            None => {
+                let dtype = DataType::from_arrow_field(field.as_ref());
+
+                (
+                    Column::full_null(min_name, num_batches, &dtype),
+                    Column::full_null(max_name, num_batches, &dtype),
+                    Column::full_null(nc_name, num_batches, &IDX_DTYPE),
+                )
+            },
+            Some(stat) => {
+                let md = field.metadata.as_deref();
+
+                (
+                    unsafe {
+                        Series::_try_from_arrow_unchecked_with_md(
+                            min_name,
+                            vec![stat.min_value],
+                            field.dtype(),
+                            md,
+                        )
+                    }?
+                    .into_column(),
+                    unsafe {
+                        Series::_try_from_arrow_unchecked_with_md(
+                            max_name,
+                            vec![stat.max_value],
+                            field.dtype(),
+                            md,
+                        )
+                    }?
+                    .into_column(),
+                    Series::from_arrow(nc_name, stat.null_count.boxed())?.into_column(),
+                )
+            },
+        };
+
+        columns.extend([min, max, nc]);
+    }
+
+    let mut statistics_df = DataFrame::new_with_height(num_batches, columns)?;
+
+    if let Some((cast_columns, file_schema)) = cast_columns {
+        cast_columns.apply_cast_to_statistics(&mut statistics_df, file_schema)?;
+    }
+
+    Ok(statistics_df)
+}
+
 impl ParquetReadImpl {
     /// Constructs the task that distributes morsels across the engine pipelines.
     #[allow(clippy::type_complexity)]
@@ -205,7 +233,20 @@ impl ParquetReadImpl {
             tokio::sync::mpsc::channel(row_group_prefetch_size);
 
         let row_index = self.row_index.clone();
-        let live_filter_columns_cast = self.live_filter_columns_cast.take();
+        let live_filter_columns_cast = self.live_filter_columns_cast.take().map(Arc::new);
+
+        let page_index_pruner = predicate
+            .as_ref()
+            .filter(|p| use_statistics && p.skip_batch_predicate.is_some())
+            .map(|p| {
+                Arc::new(PageIndexPruner {
+                    predicate: p.clone(),
+                    reader_schema: reader_schema.clone(),
+                    row_index: row_index.clone(),
+                    cast_columns: live_filter_columns_cast.clone(),
+                    verbose,
+                })
+            });
 
         let prefetch_task = AbortOnDropHandle(io_runtime.spawn(async move {
             polars_ensure!(
@@ -274,6 +315,22 @@ impl ParquetReadImpl {
             )
             .await?;
 
+            let row_group_mask = match predicate.as_ref().filter(|_| use_statistics) {
+                Some(predicate) => {
+                    calculate_row_group_bloom_filter_skip_mask(
+                        row_group_slice.clone(),
+                        predicate,
+                        &metadata,
+                        &reader_schema,
+                        &byte_source,
+                        row_group_mask,
+                        verbose,
+                    )
+                    .await?
+                },
+                None => row_group_mask,
+            };
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection,
                 predicate,
@@ -283,6 +340,7 @@ impl ParquetReadImpl {
                 byte_source,
                 row_group_slice,
                 row_group_mask,
+                page_index_pruner,
                 row_offset,
             };
 
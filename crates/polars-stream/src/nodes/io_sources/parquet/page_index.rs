@@ -0,0 +1,306 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchemaRef;
+use polars_core::prelude::{InitHashMaps, PlHashMap};
+use polars_core::schema::SchemaRef;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::PolarsResult;
+use polars_io::RowIndex;
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::_internal::collect_page_statistics_with_live_columns;
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::read::{
+    ColumnChunkMetadata, OffsetIndex, deserialize_column_index, deserialize_offset_index,
+};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+
+use super::init::statistics_df;
+use crate::async_executor;
+use crate::nodes::TaskPriority;
+use crate::nodes::io_sources::multi_file_reader::extra_ops::cast_columns::CastColumns;
+
+/// Page-level predicate pushdown: uses the page indexes (`ColumnIndex` / `OffsetIndex`) of the
+/// live columns to find the rows of a row group that lie in pages that cannot match the
+/// predicate.
+pub(super) struct PageIndexPruner {
+    pub(super) predicate: ScanIOPredicate,
+    pub(super) reader_schema: ArrowSchemaRef,
+    pub(super) row_index: Option<RowIndex>,
+    pub(super) cast_columns: Option<Arc<(CastColumns, SchemaRef)>>,
+    pub(super) verbose: bool,
+}
+
+pub(super) struct PrunedRowGroup {
+    /// The rows of the row group that can match the predicate.
+    pub(super) mask: Bitmap,
+    /// The offset indexes that were read, by the index of their column in the row group.
+    pub(super) offset_indexes: PlHashMap<usize, OffsetIndex>,
+}
+
+impl PageIndexPruner {
+    /// Returns the rows of the row group that can match the predicate, or `None` if the page
+    /// indexes don't allow skipping any rows.
+    ///
+    /// The offset indexes of the columns in `extra_offset_indexes` are read along with the page
+    /// indexes of the live columns.
+    pub(super) async fn prune(
+        &self,
+        metadata: &Arc<FileMetadata>,
+        row_group_idx: usize,
+        row_offset: usize,
+        byte_source: &DynByteSource,
+        extra_offset_indexes: &[usize],
+    ) -> PolarsResult<Option<PrunedRowGroup>> {
+        let row_group = &metadata.row_groups[row_group_idx];
+        let live_columns = self.predicate.live_columns.clone();
+
+        // The column and offset index of every live column that has them.
+        let index_ranges = live_columns
+            .iter()
+            .map(|c| {
+                let idxs = row_group.columns_idxs_under_root_iter(c)?;
+                let [idx] = idxs else {
+                    return None;
+                };
+                let column = &row_group.parquet_columns()[*idx];
+                Some((
+                    *idx,
+                    to_usize_range(column.column_index_byte_range()?),
+                    to_usize_range(column.offset_index_byte_range()?),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        if index_ranges.iter().all(Option::is_none) {
+            return Ok(None);
+        }
+
+        let extra_ranges = extra_offset_indexes
+            .iter()
+            .filter(|idx| !index_ranges.iter().flatten().any(|(i, _, _)| i == *idx))
+            .filter_map(|&idx| {
+                let range = row_group.parquet_columns()[idx].offset_index_byte_range()?;
+                Some((idx, to_usize_range(range)))
+            })
+            .collect::<Vec<_>>();
+
+        let mut ranges = index_ranges
+            .iter()
+            .flatten()
+            .flat_map(|(_, ci, oi)| [ci.clone(), oi.clone()])
+            .chain(extra_ranges.iter().map(|(_, range)| range.clone()))
+            .collect::<Vec<_>>();
+        let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+        let metadata = metadata.clone();
+        let predicate = self.predicate.clone();
+        let reader_schema = self.reader_schema.clone();
+        let row_index = self.row_index.clone().map(|mut ri| {
+            ri.offset = ri
+                .offset
+                .saturating_add(IdxSize::try_from(row_offset).unwrap_or(IdxSize::MAX));
+            ri
+        });
+        let cast_columns = self.cast_columns.clone();
+        let verbose = self.verbose;
+
+        // Note: We are spawning here onto the computational async runtime because the caller is
+        // being run on a tokio async thread.
+        async_executor::spawn(TaskPriority::High, async move {
+            let row_group = &metadata.row_groups[row_group_idx];
+            let num_rows = row_group.num_rows();
+
+            let mut offset_indexes = PlHashMap::with_capacity(index_ranges.len());
+            let page_indexes = index_ranges
+                .iter()
+                .map(|ranges| {
+                    let Some((idx, ci, oi)) = ranges else {
+                        return Ok(None);
+                    };
+                    let column_index = deserialize_column_index(&bytes_map[&ci.start])?;
+                    let offset_index = deserialize_offset_index(&bytes_map[&oi.start])?;
+                    offset_indexes.insert(*idx, offset_index.clone());
+                    PolarsResult::Ok(Some((column_index, offset_index)))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            for (idx, range) in extra_ranges {
+                offset_indexes.insert(idx, deserialize_offset_index(&bytes_map[&range.start])?);
+            }
+
+            let Some(page_stats) = collect_page_statistics_with_live_columns(
+                row_group,
+                reader_schema.as_ref(),
+                &live_columns,
+                row_index.as_ref().map(|ri| (&ri.name, ri.offset)),
+                &page_indexes,
+            )?
+            else {
+                return Ok(None);
+            };
+
+            let num_ranges = page_stats.num_ranges();
+            let lengths = page_stats
+                .row_offsets
+                .windows(2)
+                .map(|w| (w[1] - w[0]) as IdxSize)
+                .collect();
+            let statistics_df = statistics_df(
+                lengths,
+                &live_columns,
+                page_stats.columns,
+                reader_schema.as_ref(),
+                row_index.as_ref(),
+                cast_columns.as_deref(),
+            )?;
+
+            let sbp = predicate.skip_batch_predicate.as_ref().unwrap();
+            let skip_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+            if verbose {
+                eprintln!(
+                    "[ParquetFileReader]: Page index pushdown: \
+                    reading {} / {} page ranges of row group {}",
+                    skip_mask.unset_bits(),
+                    num_ranges,
+                    row_group_idx,
+                );
+            }
+
+            if skip_mask.set_bits() == 0 {
+                return Ok(None);
+            }
+
+            let mut mask = MutableBitmap::with_capacity(num_rows);
+            for (skip, range) in skip_mask.iter().zip(page_stats.row_offsets.windows(2)) {
+                mask.extend_constant(range[1] - range[0], !skip);
+            }
+
+            Ok(Some(PrunedRowGroup {
+                mask: mask.freeze(),
+                offset_indexes,
+            }))
+        })
+        .await
+    }
+}
+
+/// The pages of a column chunk that contain selected rows, to be fetched without the other
+/// pages.
+pub(super) struct SparsePages {
+    /// The byte ranges in the file of the dictionary page (if any) and of the selected pages.
+    pub(super) byte_ranges: Vec<Range<usize>>,
+    /// The rows of the row group in the selected pages.
+    pub(super) rows: Vec<Range<usize>>,
+}
+
+impl SparsePages {
+    /// Selects the pages of a flat column that contain rows selected by `mask`. Returns `None` if
+    /// the column is nested or its offset index doesn't describe its pages.
+    pub(super) fn try_new(
+        column: &ColumnChunkMetadata,
+        offset_index: &OffsetIndex,
+        mask: &Bitmap,
+    ) -> Option<Self> {
+        let descriptor = column.descriptor();
+        if descriptor.path_in_schema.len() != 1 || descriptor.descriptor.max_rep_level != 0 {
+            return None;
+        }
+
+        let num_rows = mask.len();
+        let chunk = to_usize_range(column.byte_range());
+        let pages = offset_index
+            .page_locations
+            .iter()
+            .map(|loc| {
+                let offset = usize::try_from(loc.offset).ok()?;
+                let size = usize::try_from(loc.compressed_page_size).ok()?;
+                let first_row = usize::try_from(loc.first_row_index).ok()?;
+                Some((offset..offset.checked_add(size)?, first_row))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let is_valid = pages
+            .first()
+            .is_some_and(|(bytes, first_row)| *first_row == 0 && bytes.start >= chunk.start)
+            && pages
+                .windows(2)
+                .all(|w| w[0].0.end <= w[1].0.start && w[0].1 < w[1].1)
+            && pages
+                .last()
+                .is_some_and(|(bytes, first_row)| bytes.end <= chunk.end && *first_row < num_rows);
+        if !is_valid {
+            return None;
+        }
+
+        let mut byte_ranges = Vec::with_capacity(pages.len() + 1);
+        let mut rows = Vec::with_capacity(pages.len());
+
+        // The dictionary page precedes the first data page.
+        if pages[0].0.start > chunk.start {
+            byte_ranges.push(chunk.start..pages[0].0.start);
+        }
+
+        for (i, (bytes, first_row)) in pages.iter().enumerate() {
+            let end_row = pages.get(i + 1).map_or(num_rows, |(_, r)| *r);
+            if mask
+                .clone()
+                .sliced(*first_row, end_row - first_row)
+                .set_bits()
+                == 0
+            {
+                continue;
+            }
+
+            push_merged(&mut byte_ranges, bytes.clone());
+            push_merged(&mut rows, *first_row..end_row);
+        }
+
+        Some(Self { byte_ranges, rows })
+    }
+
+    /// Concatenates the fetched pages into the data of a column chunk that only holds them.
+    pub(super) fn assemble(&self, bytes_map: &mut PlHashMap<usize, MemSlice>) -> MemSlice {
+        if let [range] = self.byte_ranges.as_slice() {
+            return bytes_map.remove(&range.start).unwrap();
+        }
+
+        let len = self.byte_ranges.iter().map(|r| r.len()).sum();
+        let mut data = Vec::with_capacity(len);
+        for range in &self.byte_ranges {
+            data.extend_from_slice(&bytes_map.remove(&range.start).unwrap());
+        }
+        MemSlice::from_vec(data)
+    }
+}
+
+/// A column chunk of which only the pages containing selected rows were fetched.
+pub(super) struct SparseColumnChunk {
+    pub(super) data: MemSlice,
+    /// The rows of the row group in the fetched pages.
+    pub(super) rows: Vec<Range<usize>>,
+}
+
+impl SparseColumnChunk {
+    /// Restricts a mask over the rows of the row group to the rows in the fetched pages.
+    pub(super) fn restrict_mask(&self, mask: &Bitmap) -> Bitmap {
+        let mut out = MutableBitmap::with_capacity(self.rows.iter().map(|r| r.len()).sum());
+        for range in &self.rows {
+            out.extend_from_bitmap(&mask.clone().sliced(range.start, range.len()));
+        }
+        out.freeze()
+    }
+}
+
+fn push_merged(ranges: &mut Vec<Range<usize>>, range: Range<usize>) {
+    match ranges.last_mut() {
+        Some(last) if last.end == range.start => last.end = range.end,
+        _ => ranges.push(range),
+    }
+}
+
+fn to_usize_range(range: Range<u64>) -> Range<usize> {
+    range.start as usize..range.end as usize
+}
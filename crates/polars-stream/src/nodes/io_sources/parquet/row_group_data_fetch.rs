@@ -1,10 +1,10 @@
 use std::ops::Range;
 use std::sync::Arc;
 
-use arrow::datatypes::ArrowSchemaRef;
+use arrow::datatypes::{ArrowSchema, ArrowSchemaRef};
 use polars_core::prelude::PlHashMap;
 use polars_core::series::IsSorted;
-use polars_core::utils::arrow::bitmap::Bitmap;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, create_sorting_map};
@@ -13,6 +13,7 @@ use polars_parquet::read::RowGroupMetadata;
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::page_index::{PageIndexPruner, PrunedRowGroup, SparseColumnChunk, SparsePages};
 use crate::utils::task_handles_ext;
 
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
@@ -22,6 +23,12 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: Vec<(usize, IsSorted)>,
+    /// The rows to read, if the page indexes (and the slice) allow skipping some. The `slice` is
+    /// always `None` if this is set.
+    pub(super) page_mask: Option<Bitmap>,
+    /// Column chunks of which only the pages with rows in `page_mask` were fetched, by the start
+    /// of their byte range.
+    pub(super) sparse_column_chunks: PlHashMap<usize, SparseColumnChunk>,
 }
 
 pub(super) struct RowGroupDataFetcher {
@@ -35,6 +42,7 @@ pub(super) struct RowGroupDataFetcher {
 
     pub(super) row_group_slice: Range<usize>,
     pub(super) row_group_mask: Option<Bitmap>,
+    pub(super) page_index_pruner: Option<Arc<PageIndexPruner>>,
 
     pub(super) row_offset: usize,
 }
@@ -80,74 +88,163 @@ impl RowGroupDataFetcher {
             let current_byte_source = self.byte_source.clone();
             let projection = self.projection.clone();
             let memory_prefetch_func = self.memory_prefetch_func;
+            let page_index_pruner = self.page_index_pruner.clone();
             let io_runtime = polars_io::pl_async::get_runtime();
 
             let handle = io_runtime.spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
-                let fetched_bytes =
-                    if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
-                        // Skip byte range calculation for `no_prefetch`.
-                        if memory_prefetch_func as usize
-                            != polars_utils::mem::prefetch::no_prefetch as usize
+                let is_mem_slice =
+                    matches!(current_byte_source.as_ref(), DynByteSource::MemSlice(_));
+
+                let pruned = if let Some(pruner) = page_index_pruner.as_ref() {
+                    // Only the pages that are needed are fetched from other byte sources, for
+                    // which we need the offset indexes of the projected columns.
+                    let sparse_columns = if is_mem_slice {
+                        vec![]
+                    } else {
+                        get_row_group_column_idxs_for_projection(
+                            row_group_metadata,
+                            projection.as_deref(),
+                        )
+                    };
+                    pruner
+                        .prune(
+                            &metadata,
+                            idx,
+                            current_row_offset,
+                            current_byte_source.as_ref(),
+                            &sparse_columns,
+                        )
+                        .await?
+                } else {
+                    None
+                };
+
+                let (slice, page_mask, offset_indexes) = match pruned {
+                    None => (slice, None, PlHashMap::default()),
+                    Some(PrunedRowGroup {
+                        mask,
+                        offset_indexes,
+                    }) => {
+                        let mask = match slice {
+                            None => mask,
+                            Some((offset, len)) => {
+                                let mut slice_mask = MutableBitmap::with_capacity(num_rows);
+                                slice_mask.extend_constant(offset, false);
+                                slice_mask.extend_constant(len, true);
+                                slice_mask.extend_constant(num_rows - offset - len, false);
+                                &mask & &slice_mask.freeze()
+                            },
+                        };
+                        (None, Some(mask), offset_indexes)
+                    },
+                };
+
+                let mut sparse_column_chunks = PlHashMap::default();
+
+                let fetched_bytes = if page_mask.as_ref().is_some_and(|m| m.set_bits() == 0) {
+                    // None of the rows can match the predicate.
+                    FetchedBytes::BytesMap(PlHashMap::default())
+                } else if let Some(page_mask) = page_mask.as_ref().filter(|_| !is_mem_slice) {
+                    let mut ranges = vec![];
+                    let mut sparse_pages = vec![];
+
+                    for i in get_row_group_column_idxs_for_projection(
+                        row_group_metadata,
+                        projection.as_deref(),
+                    ) {
+                        let column = &row_group_metadata.parquet_columns()[i];
+                        let byte_range = column.byte_range();
+                        let byte_range = byte_range.start as usize..byte_range.end as usize;
+
+                        match offset_indexes
+                            .get(&i)
+                            .and_then(|oi| SparsePages::try_new(column, oi, page_mask))
                         {
-                            let slice = mem_slice.0.as_ref();
-
-                            if let Some(columns) = projection.as_ref() {
-                                for range in get_row_group_byte_ranges_for_projection(
-                                    row_group_metadata,
-                                    &mut columns.iter_names(),
-                                ) {
-                                    memory_prefetch_func(unsafe { slice.get_unchecked(range) })
-                                }
-                            } else {
-                                let range = row_group_metadata.full_byte_range();
-                                let range = range.start as usize..range.end as usize;
-
-                                memory_prefetch_func(unsafe { slice.get_unchecked(range) })
-                            };
+                            Some(pages) => {
+                                ranges.extend(pages.byte_ranges.iter().cloned());
+                                sparse_pages.push((byte_range.start, pages));
+                            },
+                            None => ranges.push(byte_range),
                         }
+                    }
 
-                        // We have a mmapped or in-memory slice representing the entire
-                        // file that can be sliced directly, so we can skip the byte-range
-                        // calculations and HashMap allocation.
-                        let mem_slice = mem_slice.0.clone();
-                        FetchedBytes::MemSlice {
-                            offset: 0,
-                            mem_slice,
-                        }
-                    } else if let Some(columns) = projection.as_ref() {
-                        let mut ranges = get_row_group_byte_ranges_for_projection(
-                            row_group_metadata,
-                            &mut columns.iter_names(),
-                        )
-                        .collect::<Vec<_>>();
+                    let n_ranges = ranges.len();
 
-                        let n_ranges = ranges.len();
+                    let mut bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
 
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+                    assert_eq!(bytes_map.len(), n_ranges);
 
-                        assert_eq!(bytes_map.len(), n_ranges);
+                    for (start, pages) in sparse_pages {
+                        let data = pages.assemble(&mut bytes_map);
+                        let rows = pages.rows;
+                        sparse_column_chunks.insert(start, SparseColumnChunk { data, rows });
+                    }
 
-                        FetchedBytes::BytesMap(bytes_map)
-                    } else {
-                        // We still prefer `get_ranges()` over a single `get_range()` for downloading
-                        // the entire row group, as it can have less memory-copying. A single `get_range()`
-                        // would naively concatenate the memory blocks of the entire row group, while
-                        // `get_ranges()` can skip concatenation since the downloaded blocks are
-                        // aligned to the columns.
-                        let mut ranges = row_group_metadata
-                            .byte_ranges_iter()
-                            .map(|x| x.start as usize..x.end as usize)
-                            .collect::<Vec<_>>();
+                    FetchedBytes::BytesMap(bytes_map)
+                } else if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
+                    // Skip byte range calculation for `no_prefetch`.
+                    if memory_prefetch_func as usize
+                        != polars_utils::mem::prefetch::no_prefetch as usize
+                    {
+                        let slice = mem_slice.0.as_ref();
 
-                        let n_ranges = ranges.len();
+                        if let Some(columns) = projection.as_ref() {
+                            for range in get_row_group_byte_ranges_for_projection(
+                                row_group_metadata,
+                                &mut columns.iter_names(),
+                            ) {
+                                memory_prefetch_func(unsafe { slice.get_unchecked(range) })
+                            }
+                        } else {
+                            let range = row_group_metadata.full_byte_range();
+                            let range = range.start as usize..range.end as usize;
+
+                            memory_prefetch_func(unsafe { slice.get_unchecked(range) })
+                        };
+                    }
+
+                    // We have a mmapped or in-memory slice representing the entire
+                    // file that can be sliced directly, so we can skip the byte-range
+                    // calculations and HashMap allocation.
+                    let mem_slice = mem_slice.0.clone();
+                    FetchedBytes::MemSlice {
+                        offset: 0,
+                        mem_slice,
+                    }
+                } else if let Some(columns) = projection.as_ref() {
+                    let mut ranges = get_row_group_byte_ranges_for_projection(
+                        row_group_metadata,
+                        &mut columns.iter_names(),
+                    )
+                    .collect::<Vec<_>>();
+
+                    let n_ranges = ranges.len();
+
+                    let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+
+                    assert_eq!(bytes_map.len(), n_ranges);
+
+                    FetchedBytes::BytesMap(bytes_map)
+                } else {
+                    // We still prefer `get_ranges()` over a single `get_range()` for downloading
+                    // the entire row group, as it can have less memory-copying. A single `get_range()`
+                    // would naively concatenate the memory blocks of the entire row group, while
+                    // `get_ranges()` can skip concatenation since the downloaded blocks are
+                    // aligned to the columns.
+                    let mut ranges = row_group_metadata
+                        .byte_ranges_iter()
+                        .map(|x| x.start as usize..x.end as usize)
+                        .collect::<Vec<_>>();
 
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+                    let n_ranges = ranges.len();
 
-                        assert_eq!(bytes_map.len(), n_ranges);
+                    let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
 
-                        FetchedBytes::BytesMap(bytes_map)
-                    };
+                    assert_eq!(bytes_map.len(), n_ranges);
+
+                    FetchedBytes::BytesMap(bytes_map)
+                };
 
                 PolarsResult::Ok(RowGroupData {
                     fetched_bytes,
@@ -156,6 +253,8 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_mask,
+                    sparse_column_chunks,
                 })
             });
 
@@ -206,3 +305,24 @@ fn get_row_group_byte_ranges_for_projection<'a>(
             })
     })
 }
+
+/// The indices of the parquet columns of the projected fields (or of all fields).
+fn get_row_group_column_idxs_for_projection(
+    row_group_metadata: &RowGroupMetadata,
+    projection: Option<&ArrowSchema>,
+) -> Vec<usize> {
+    let Some(projection) = projection else {
+        return (0..row_group_metadata.n_columns()).collect();
+    };
+
+    projection
+        .iter_names()
+        .flat_map(|col_name| {
+            row_group_metadata
+                .columns_idxs_under_root_iter(col_name)
+                .into_iter()
+                .flatten()
+                .copied()
+        })
+        .collect()
+}
@@ -47,8 +47,19 @@ impl RowGroupDecoder {
             slice.0 == 0 && slice.1 >= row_group_data.row_group_metadata.num_rows()
         });
 
+        // None of the rows can match the predicate according to the page indexes. Empty morsels
+        // are dropped by the morsel distributor.
+        if row_group_data
+            .page_mask
+            .as_ref()
+            .is_some_and(|mask| mask.set_bits() == 0)
+        {
+            return Ok(DataFrame::empty());
+        }
+
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
+            && row_group_data.page_mask.is_none()
             && !self.predicate_arrow_field_indices.is_empty()
         {
             self.row_group_data_to_df_prefiltered(row_group_data).await
@@ -74,19 +85,27 @@ impl RowGroupDecoder {
 
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
+        let filter = match row_group_data.page_mask.as_ref() {
+            // The slice is part of the mask.
+            Some(mask) => Filter::Mask(mask.clone()),
+            None => Filter::Range(slice_range.clone()),
+        };
+        let projection_height = filter.num_rows(row_group_data.row_group_metadata.num_rows());
+
         if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
+            let s = match row_group_data.page_mask.as_ref() {
+                Some(mask) => s.filter(&BooleanChunked::from_bitmap(
+                    PlSmallStr::EMPTY,
+                    mask.clone(),
+                ))?,
+                None => s,
+            };
             out_columns.push(s);
         }
 
         let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
-
-        let projection_height = slice_range.len();
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, Some(filter))
+            .await?;
 
         out_columns.extend(decoded_cols);
 
@@ -242,16 +261,21 @@ fn decode_column(
         ));
     };
 
+    let mut filter = filter;
     let columns_to_deserialize = iter
         .map(|col_md| {
             let byte_range = col_md.byte_range();
+            let byte_range = byte_range.start as usize..byte_range.end as usize;
 
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
+            // Only flat columns are fetched sparsely, so this is the only leaf of the field.
+            if let Some(chunk) = row_group_data.sparse_column_chunks.get(&byte_range.start) {
+                if let Some(Filter::Mask(mask)) = filter.as_mut() {
+                    *mask = chunk.restrict_mask(mask);
+                }
+                return (col_md, chunk.data.clone());
+            }
+
+            (col_md, row_group_data.fetched_bytes.get_range(byte_range))
         })
         .collect::<Vec<_>>();
 
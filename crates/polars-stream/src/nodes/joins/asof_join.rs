@@ -0,0 +1,673 @@
+//! Streaming as-of join.
+//!
+//! Both inputs must be sorted by their `on` key, or only within each group
+//! when joining by `by` groups. Left morsels are joined one at a time, in
+//! order: for each left morsel we first receive right morsels until every
+//! right row that could match one of its rows has been seen, and then join
+//! the morsel with that window of the right input using the in-memory as-of
+//! join kernel. Right rows before the window are dropped, except for the last
+//! one (per `by` group), as that can still be the backward or nearest match of
+//! later left rows.
+//!
+//! With `by` groups the right input is tracked per group, as a group can only
+//! be joined once the right input passed the keys of that group. Depending on
+//! how the groups are interleaved this can buffer a large part of the right
+//! input.
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{polars_bail, polars_ensure, polars_warn};
+use polars_ops::frame::{AsOfOptions, AsofStrategy, DataFrameJoinOps, JoinArgs, JoinType};
+use polars_ops::series::SeriesMethods;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::distributor_channel::{Sender, distributor_channel};
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+
+struct AsOfJoinParams {
+    left_on: PlSmallStr,
+    right_on: PlSmallStr,
+    left_by: Option<Vec<PlSmallStr>>,
+    right_by: Option<Vec<PlSmallStr>>,
+    strategy: AsofStrategy,
+    tolerance: Option<AnyValue<'static>>,
+    check_sortedness: bool,
+    args: JoinArgs,
+    right_input_schema: Arc<Schema>,
+}
+
+impl AsOfJoinParams {
+    fn left_key(&self, df: &DataFrame) -> Series {
+        df.column(&self.left_on)
+            .unwrap()
+            .as_materialized_series()
+            .to_physical_repr()
+            .into_owned()
+    }
+
+    fn right_key(&self, df: &DataFrame) -> Series {
+        df.column(&self.right_on)
+            .unwrap()
+            .as_materialized_series()
+            .to_physical_repr()
+            .into_owned()
+    }
+
+    fn join(&self, left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+        let left_key = left.column(&self.left_on)?.as_materialized_series();
+        let right_key = right.column(&self.right_on)?.as_materialized_series();
+        left._join_impl(
+            right,
+            vec![left_key.clone()],
+            vec![right_key.clone()],
+            self.args.clone(),
+            None,
+            false,
+            false,
+        )
+    }
+}
+
+/// Converts a `tolerance_str` to a tolerance in the unit of the key, the same
+/// way the in-memory engine does.
+fn resolve_tolerance_str(options: &mut AsOfOptions, key_dtype: &DataType) -> PolarsResult<()> {
+    use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+
+    let Some(tolerance_str) = &options.tolerance_str else {
+        return Ok(());
+    };
+    let duration = polars_time::Duration::try_parse(tolerance_str)?;
+    polars_ensure!(
+        duration.months() == 0,
+        ComputeError: "cannot use month offset in timedelta of an asof join; \
+        consider using 4 weeks"
+    );
+    let tolerance = match key_dtype {
+        DataType::Datetime(tu, _) | DataType::Duration(tu) => Scalar::from(match tu {
+            TimeUnit::Nanoseconds => duration.duration_ns(),
+            TimeUnit::Microseconds => duration.duration_us(),
+            TimeUnit::Milliseconds => duration.duration_ms(),
+        }),
+        DataType::Date => Scalar::from((duration.duration_ms() / MILLISECONDS_IN_DAY) as i32),
+        DataType::Time => Scalar::from(duration.duration_ns()),
+        dt => polars_bail!(
+            InvalidOperation: "can only use timedelta string language with Date/Datetime/Duration/Time dtypes, got {dt}"
+        ),
+    };
+    options.tolerance = Some(tolerance);
+    Ok(())
+}
+
+/// Checks that the (null-free) key is sorted, and continues from `prev`.
+fn ensure_sorted(key: &Series, prev: Option<&Series>) -> PolarsResult<()> {
+    let sorted = key.is_sorted(SortOptions::default())?
+        && match prev {
+            Some(prev) if !key.is_empty() => prev.lt_eq(&key.head(Some(1)))?.all(),
+            _ => true,
+        };
+    polars_ensure!(
+        sorted,
+        InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first"
+    );
+    Ok(())
+}
+
+/// The number of elements of the sorted, null-free key smaller than `value`.
+fn num_lt(key: &Series, value: &Series) -> PolarsResult<usize> {
+    Ok(key.lt(value)?.sum().unwrap_or(0) as usize)
+}
+
+/// The number of elements of the sorted, null-free key smaller than or equal
+/// to `value`.
+fn num_lt_eq(key: &Series, value: &Series) -> PolarsResult<usize> {
+    Ok(key.lt_eq(value)?.sum().unwrap_or(0) as usize)
+}
+
+fn encode_groups(df: &DataFrame, by: &[PlSmallStr]) -> PolarsResult<BinaryOffsetChunked> {
+    let columns = by
+        .iter()
+        .map(|name| Ok(df.column(name)?.to_physical_repr()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, &columns)
+}
+
+/// The smallest and largest key of each group, for a null-free key which is
+/// sorted within each group.
+fn key_range_per_group(
+    df: &DataFrame,
+    key: &Series,
+    by: &[PlSmallStr],
+) -> PolarsResult<PlHashMap<Vec<u8>, (AnyValue<'static>, AnyValue<'static>)>> {
+    let groups = encode_groups(df, by)?;
+    let mut ranges = PlHashMap::new();
+    for (i, group) in groups.into_no_null_iter().enumerate() {
+        let value = key.get(i)?.into_static();
+        if let Some((_, max)) = ranges.get_mut(group) {
+            *max = value;
+        } else {
+            ranges.insert(group.to_vec(), (value.clone(), value));
+        }
+    }
+    Ok(ranges)
+}
+
+/// Whether `key` lies further than the tolerance beyond `left_max`, in which
+/// case no right row from `key` onwards can match a left row up to `left_max`.
+fn beyond_tolerance(key: &Series, left_max: &Series, tolerance: &AnyValue) -> bool {
+    let (Ok(key), Ok(left_max)) = (key.get(0), left_max.get(0)) else {
+        return false;
+    };
+    if key.dtype().is_float() {
+        match (
+            key.extract::<f64>(),
+            left_max.extract::<f64>(),
+            tolerance.extract::<f64>(),
+        ) {
+            (Some(k), Some(m), Some(t)) => k - m > t.abs(),
+            _ => false,
+        }
+    } else {
+        match (
+            key.extract::<i128>(),
+            left_max.extract::<i128>(),
+            tolerance.extract::<i128>(),
+        ) {
+            (Some(k), Some(m), Some(t)) => k - m > t.abs(),
+            _ => false,
+        }
+    }
+}
+
+/// The part of the right input that can still be matched by future left rows,
+/// without rows with a null key as those never match.
+#[derive(Default)]
+struct RightBuffer {
+    frames: VecDeque<DataFrame>,
+    /// The key of the last (non-null) row received.
+    last_key: Option<Series>,
+}
+
+impl RightBuffer {
+    /// Buffers a right frame, returns the buffered part (if any).
+    fn push(&mut self, df: DataFrame, params: &AsOfJoinParams) -> PolarsResult<Option<&DataFrame>> {
+        let key = params.right_key(&df);
+        let df = if key.has_nulls() {
+            df.filter(&key.is_not_null())?
+        } else {
+            df
+        };
+        if df.height() == 0 {
+            return Ok(None);
+        }
+
+        let key = params.right_key(&df);
+        if params.check_sortedness {
+            ensure_sorted(&key, self.last_key.as_ref())?;
+        }
+        self.last_key = Some(key.tail(Some(1)));
+        self.frames.push_back(df);
+        Ok(self.frames.back())
+    }
+
+    /// Drops all rows with a key smaller than `lower`, except for the last
+    /// such row, and returns the remaining rows.
+    fn window(&mut self, lower: &Series, params: &AsOfJoinParams) -> PolarsResult<DataFrame> {
+        let mut before = Vec::new();
+        while let Some(df) = self.frames.pop_front() {
+            let num_before = num_lt(&params.right_key(&df), lower)?;
+            if num_before < df.height() {
+                let (head, tail) = df.split_at(num_before as i64);
+                before.push(head);
+                self.frames.push_front(tail);
+                break;
+            }
+            before.push(df);
+        }
+
+        if !before.is_empty() {
+            let before = accumulate_dataframes_vertical_unchecked(before);
+            self.frames.push_front(before.slice(-1, 1));
+        }
+        Ok(self.current(params))
+    }
+
+    /// Drops all rows of the given groups with a key smaller than the lower
+    /// bound of their group, except for the last such row per group, and
+    /// returns the remaining rows.
+    fn window_per_group(
+        &mut self,
+        left_groups: &PlHashMap<Vec<u8>, (AnyValue<'static>, AnyValue<'static>)>,
+        params: &AsOfJoinParams,
+    ) -> PolarsResult<DataFrame> {
+        let df = self.current(params);
+        let groups = encode_groups(&df, params.right_by.as_deref().unwrap())?;
+        let key = params.right_key(&df);
+
+        let mut keep = vec![true; df.height()];
+        let mut last_before = PlHashMap::new();
+        for (i, group) in groups.into_no_null_iter().enumerate() {
+            if let Some((lower, _)) = left_groups.get(group) {
+                if key.get(i)? < *lower {
+                    keep[i] = false;
+                    last_before.insert(group, i);
+                }
+            }
+        }
+        if last_before.is_empty() {
+            return Ok(df);
+        }
+        for i in last_before.into_values() {
+            keep[i] = true;
+        }
+
+        let keep = BooleanChunked::from_slice(PlSmallStr::EMPTY, &keep);
+        let df = df.filter(&keep)?;
+        self.frames = VecDeque::from([df.clone()]);
+        Ok(df)
+    }
+
+    fn current(&self, params: &AsOfJoinParams) -> DataFrame {
+        if self.frames.is_empty() {
+            return DataFrame::empty_with_schema(&params.right_input_schema);
+        }
+        accumulate_dataframes_vertical_unchecked(self.frames.iter().cloned())
+    }
+}
+
+/// Tracks what still has to be received from the right input before a left
+/// morsel can be joined.
+struct RightNeed {
+    /// The smallest and largest key of the left morsel, `None` if all its keys
+    /// are null.
+    left_range: Option<(Series, Series)>,
+    /// Without `by`: whether no right row with a key after the left range was
+    /// seen yet.
+    pending: bool,
+    /// Without `by`: once nothing is pending, the right input must have passed
+    /// this key.
+    bound: Option<Series>,
+    /// With `by`: the smallest and largest key of each (row-encoded) group of
+    /// the left morsel.
+    left_groups: Option<PlHashMap<Vec<u8>, (AnyValue<'static>, AnyValue<'static>)>>,
+    /// With `by`: the key the right input still has to pass per group, and
+    /// whether that is the last key it has to pass.
+    pending_groups: PlHashMap<Vec<u8>, (AnyValue<'static>, bool)>,
+}
+
+impl RightNeed {
+    fn new(left: &DataFrame, params: &AsOfJoinParams) -> PolarsResult<Self> {
+        let key = params.left_key(left);
+        let (min, max) = (key.min_reduce()?, key.max_reduce()?);
+        let mut need = Self {
+            left_range: None,
+            pending: false,
+            bound: None,
+            left_groups: None,
+            pending_groups: PlHashMap::new(),
+        };
+        if max.is_null() {
+            return Ok(need);
+        }
+        let left_min = min.into_series(PlSmallStr::EMPTY);
+        let left_max = max.into_series(PlSmallStr::EMPTY);
+
+        match &params.left_by {
+            None => match params.strategy {
+                // All right rows with a key up to the largest left key.
+                AsofStrategy::Backward => need.bound = Some(left_max.clone()),
+                // The first right row after the largest left key, together
+                // with all rows with an equal key as those can break ties.
+                AsofStrategy::Forward | AsofStrategy::Nearest => need.pending = true,
+            },
+            // The same, but for the largest left key of every group.
+            Some(by) => {
+                let (left, key) = if key.has_nulls() {
+                    let left = left.filter(&key.is_not_null())?;
+                    let key = params.left_key(&left);
+                    (left, key)
+                } else {
+                    (left.clone(), key)
+                };
+                let left_groups = key_range_per_group(&left, &key, by)?;
+                let is_last = params.strategy == AsofStrategy::Backward;
+                need.pending_groups = left_groups
+                    .iter()
+                    .map(|(group, (_, max))| (group.clone(), (max.clone(), is_last)))
+                    .collect();
+                need.left_groups = Some(left_groups);
+            },
+        }
+        need.left_range = Some((left_min, left_max));
+        Ok(need)
+    }
+
+    /// Registers (part of) the right input.
+    fn observe(&mut self, right: &DataFrame, params: &AsOfJoinParams) -> PolarsResult<()> {
+        let Some((_, left_max)) = &self.left_range else {
+            return Ok(());
+        };
+        let key = params.right_key(right);
+
+        let Some(by) = &params.right_by else {
+            if !self.pending {
+                return Ok(());
+            }
+            let offset = num_lt_eq(&key, left_max)?;
+            if offset < right.height() {
+                self.pending = false;
+                self.bound = Some(key.slice(offset as i64, 1));
+            }
+            return Ok(());
+        };
+
+        if self.pending_groups.is_empty() {
+            return Ok(());
+        }
+        let groups = encode_groups(right, by)?;
+        for (i, group) in groups.into_no_null_iter().enumerate() {
+            let Some((bound, is_last)) = self.pending_groups.get_mut(group) else {
+                continue;
+            };
+            let value = key.get(i)?;
+            if value > *bound {
+                if *is_last {
+                    self.pending_groups.remove(group);
+                    if self.pending_groups.is_empty() {
+                        break;
+                    }
+                } else {
+                    *bound = value.into_static();
+                    *is_last = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_satisfied(
+        &self,
+        right: &RightBuffer,
+        right_exhausted: bool,
+        params: &AsOfJoinParams,
+    ) -> PolarsResult<bool> {
+        let Some((_, left_max)) = &self.left_range else {
+            return Ok(true);
+        };
+        if right_exhausted {
+            return Ok(true);
+        }
+        if params.right_by.is_some() {
+            return Ok(self.pending_groups.is_empty());
+        }
+        let Some(last_key) = &right.last_key else {
+            return Ok(false);
+        };
+        if let Some(tolerance) = &params.tolerance {
+            if params.strategy != AsofStrategy::Backward
+                && beyond_tolerance(last_key, left_max, tolerance)
+            {
+                return Ok(true);
+            }
+        }
+        if self.pending {
+            return Ok(false);
+        }
+        match &self.bound {
+            None => Ok(true),
+            Some(bound) => Ok(last_key.gt(bound)?.all()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct AsOfJoinState {
+    /// Left morsels which are received but not yet joined.
+    left_buffer: VecDeque<(DataFrame, MorselSeq)>,
+    /// The key of the last (non-null) left row received.
+    left_last_key: Option<Series>,
+    right: RightBuffer,
+}
+
+impl AsOfJoinState {
+    fn push_left(&mut self, morsel: Morsel, params: &AsOfJoinParams) -> PolarsResult<()> {
+        let (df, seq, _, _) = morsel.into_inner();
+        if params.check_sortedness {
+            let key = params.left_key(&df).drop_nulls();
+            ensure_sorted(&key, self.left_last_key.as_ref())?;
+            if !key.is_empty() {
+                self.left_last_key = Some(key.tail(Some(1)));
+            }
+        }
+        self.left_buffer.push_back((df, seq));
+        Ok(())
+    }
+
+    /// Joins left morsels until the left input is exhausted, or a phase
+    /// transition is requested by the output or one of the inputs.
+    ///
+    /// A missing right receiver means the right input is exhausted.
+    async fn run(
+        &mut self,
+        params: &AsOfJoinParams,
+        mut left: Option<Receiver<Morsel>>,
+        mut right: Option<Receiver<Morsel>>,
+        mut distributor: Sender<(DataFrame, MorselSeq, DataFrame)>,
+        source_token: SourceToken,
+    ) -> PolarsResult<()> {
+        'joining: while !source_token.stop_requested() {
+            if self.left_buffer.is_empty() {
+                let Some(recv) = &mut left else {
+                    break;
+                };
+                let Ok(morsel) = recv.recv().await else {
+                    break;
+                };
+                self.push_left(morsel, params)?;
+            }
+            let (left_df, seq) = self.left_buffer.pop_front().unwrap();
+
+            let mut need = RightNeed::new(&left_df, params)?;
+            for df in &self.right.frames {
+                need.observe(df, params)?;
+            }
+            while !need.is_satisfied(&self.right, right.is_none(), params)? {
+                let Ok(morsel) = right.as_mut().unwrap().recv().await else {
+                    // The right input stopped for this phase, continue in the
+                    // next one.
+                    self.left_buffer.push_front((left_df, seq));
+                    break 'joining;
+                };
+                if let Some(df) = self.right.push(morsel.into_df(), params)? {
+                    need.observe(df, params)?;
+                }
+            }
+
+            let window = match (&need.left_range, &need.left_groups) {
+                (Some(_), Some(left_groups)) => self.right.window_per_group(left_groups, params)?,
+                (Some((left_min, _)), None) => self.right.window(left_min, params)?,
+                (None, _) => self.right.current(params),
+            };
+            if distributor.send((left_df, seq, window)).await.is_err() {
+                return Ok(());
+            }
+        }
+
+        // Stop the inputs and buffer everything they already produced.
+        if let Some(left) = &mut left {
+            if let Ok(morsel) = left.recv().await {
+                morsel.source_token().stop();
+                self.push_left(morsel, params)?;
+                while let Ok(morsel) = left.recv().await {
+                    self.push_left(morsel, params)?;
+                }
+            }
+        }
+        if let Some(right) = &mut right {
+            if let Ok(morsel) = right.recv().await {
+                morsel.source_token().stop();
+                self.right.push(morsel.into_df(), params)?;
+                while let Ok(morsel) = right.recv().await {
+                    self.right.push(morsel.into_df(), params)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct AsOfJoinNode {
+    params: Arc<AsOfJoinParams>,
+    state: AsOfJoinState,
+}
+
+impl AsOfJoinNode {
+    pub fn new(
+        left_input_schema: Arc<Schema>,
+        right_input_schema: Arc<Schema>,
+        left_on: PlSmallStr,
+        right_on: PlSmallStr,
+        mut args: JoinArgs,
+    ) -> PolarsResult<Self> {
+        let JoinType::AsOf(options) = &mut args.how else {
+            unreachable!()
+        };
+        resolve_tolerance_str(options, left_input_schema.try_get(&left_on)?)?;
+        let strategy = options.strategy;
+        let tolerance = options.tolerance.clone().map(|t| t.into_value());
+        let left_by = options.left_by.clone();
+        let right_by = options.right_by.clone();
+        // We check the sortedness across morsels ourselves, and the kernel
+        // only ever sees a single morsel. Like the in-memory engine, we can't
+        // check it within each group.
+        let mut check_sortedness = std::mem::take(&mut options.check_sortedness);
+        if check_sortedness && left_by.is_some() {
+            polars_warn!("Sortedness of columns cannot be checked when 'by' groups provided");
+            check_sortedness = false;
+        }
+        // Slices are applied after this node.
+        args.slice = None;
+
+        Ok(Self {
+            params: Arc::new(AsOfJoinParams {
+                left_on,
+                right_on,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                check_sortedness,
+                args,
+                right_input_schema,
+            }),
+            state: AsOfJoinState::default(),
+        })
+    }
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // Every output row belongs to a left row, so we are done as soon as
+        // the left input is.
+        let left_done = recv[0] == PortState::Done && self.state.left_buffer.is_empty();
+        if send[0] == PortState::Done || left_done {
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            send[0] = PortState::Done;
+            self.state = AsOfJoinState::default();
+            return Ok(());
+        }
+
+        let send_blocked = send[0] == PortState::Blocked;
+        let left_blocked = recv[0] == PortState::Blocked && self.state.left_buffer.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        send[0] = if left_blocked || right_blocked {
+            PortState::Blocked
+        } else {
+            PortState::Ready
+        };
+        if recv[0] != PortState::Done {
+            recv[0] = if send_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        if recv[1] != PortState::Done {
+            recv[1] = if send_blocked || left_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        let senders = send_ports[0].take().unwrap().parallel();
+        let left = recv_ports[0].take().map(|p| p.serial());
+        // The right port is only missing when the right input is done, see
+        // update_state.
+        let right = recv_ports[1].take().map(|p| p.serial());
+
+        let (distributor, dist_recv) =
+            distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        let source_token = SourceToken::new();
+
+        let params = &self.params;
+        let state = &mut self.state;
+        let run_token = source_token.clone();
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            state.run(params, left, right, distributor, run_token).await
+        }));
+
+        // The actual joining happens in parallel.
+        join_handles.extend(
+            dist_recv
+                .into_iter()
+                .zip(senders)
+                .map(|(mut recv, mut send)| {
+                    let params = &self.params;
+                    let source_token = source_token.clone();
+                    scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok((left, seq, right)) = recv.recv().await {
+                            let out = params.join(&left, &right)?;
+                            if send
+                                .send(Morsel::new(out, seq, source_token.clone()))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                        Ok(())
+                    })
+                }),
+        );
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use parking_lot::Mutex;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
@@ -24,12 +25,15 @@ use rayon::prelude::*;
 
 use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
 use crate::async_executor;
-use crate::async_primitives::connector::{Receiver, Sender};
+use crate::async_primitives::connector::{Receiver, Sender, connector};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::expression::StreamExpr;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    MemoryReservation, MemoryTracker, SpillReader, SpillWriter, SpilledFrames, spill_memory_limit,
+};
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -58,6 +62,12 @@ impl EquiJoinParams {
         }
     }
 
+    /// Can we spill the build side to disk? Only joins which don't maintain
+    /// order can be processed partition by partition afterwards.
+    fn can_spill(&self) -> bool {
+        !self.preserve_order_build && !self.preserve_order_probe
+    }
+
     /// Should we emit unmatched rows from the probe side?
     fn emit_unmatched_probe(&self) -> bool {
         if self.left_is_build.unwrap() {
@@ -170,23 +180,30 @@ fn select_schema(schema: &Schema, selector: &[Option<PlSmallStr>]) -> Schema {
         .collect()
 }
 
-async fn select_keys(
+async fn select_key_df(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &EquiJoinParams,
     state: &ExecutionState,
-) -> PolarsResult<HashKeys> {
+) -> PolarsResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
-    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
-    Ok(HashKeys::from_df(
-        &keys,
-        params.random_state,
-        params.args.nulls_equal,
-        false,
-    ))
+    DataFrame::new_with_broadcast_len(key_columns, df.height())
+}
+
+fn hash_key_df(keys: &DataFrame, params: &EquiJoinParams) -> HashKeys {
+    HashKeys::from_df(keys, params.random_state, params.args.nulls_equal, false)
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<HashKeys> {
+    let keys = select_key_df(df, key_selectors, state).await?;
+    Ok(hash_key_df(&keys, params))
 }
 
 fn select_payload(df: DataFrame, selector: &[Option<PlSmallStr>]) -> DataFrame {
@@ -202,6 +219,68 @@ fn select_payload(df: DataFrame, selector: &[Option<PlSmallStr>]) -> DataFrame {
         .collect()
 }
 
+fn spilled_key_name(i: usize) -> PlSmallStr {
+    format_pl_smallstr!("__POLARS_JOIN_SPILLED_KEY{i}")
+}
+
+/// Writes the payload rows together with their keys to the spill writer of
+/// their partition. The keys are stored as the trailing columns.
+#[allow(clippy::too_many_arguments)]
+fn spill_partitioned(
+    writers_per_p: &mut [Option<SpillWriter>],
+    idxs_per_p: &mut [Vec<IdxSize>],
+    sketch_per_p: &mut [CardinalitySketch],
+    hash_keys: &HashKeys,
+    key_df: DataFrame,
+    payload: DataFrame,
+    partitioner: &HashPartitioner,
+    partition_nulls: bool,
+) -> PolarsResult<()> {
+    for idxs in idxs_per_p.iter_mut() {
+        idxs.clear();
+    }
+    hash_keys.gen_idxs_per_partition(partitioner, idxs_per_p, sketch_per_p, partition_nulls);
+
+    let height = payload.height();
+    let mut columns = payload.take_columns();
+    columns.extend(
+        key_df
+            .take_columns()
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| c.with_name(spilled_key_name(i))),
+    );
+    let spill_df = unsafe { DataFrame::new_no_checks(height, columns) };
+
+    for (p, idxs) in idxs_per_p.iter().enumerate() {
+        if idxs.is_empty() {
+            continue;
+        }
+        let p_df = unsafe { spill_df.take_slice_unchecked_impl(idxs, false) };
+        let writer = match &mut writers_per_p[p] {
+            Some(writer) => writer,
+            slot => slot.insert(SpillWriter::new(p_df.schema())?),
+        };
+        writer.write(&p_df)?;
+    }
+    Ok(())
+}
+
+/// Splits a frame written by [`spill_partitioned`] back into its hashed keys
+/// and payload.
+fn split_spilled(
+    df: DataFrame,
+    num_keys: usize,
+    params: &EquiJoinParams,
+) -> PolarsResult<(HashKeys, DataFrame)> {
+    let height = df.height();
+    let mut columns = df.take_columns();
+    let key_columns = columns.split_off(columns.len() - num_keys);
+    let keys = DataFrame::new_with_height(height, key_columns)?;
+    let payload = DataFrame::new_with_height(height, columns)?;
+    Ok((hash_key_df(&keys, params), payload))
+}
+
 fn estimate_cardinality(
     morsels: &[Morsel],
     key_selectors: &[StreamExpr],
@@ -370,6 +449,7 @@ impl SampleState {
             state.num_pipelines,
             state.num_pipelines,
             sampled_probe_morsels,
+            params.can_spill(),
         );
 
         // Simulate the sample build morsels flowing into the build side.
@@ -420,6 +500,15 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // The memory reserved for the morsels above, None if this join can't spill.
+    reservation: Option<MemoryReservation>,
+
+    // Once the memory budget is exhausted all further morsels are spilled to
+    // disk per partition instead.
+    spilling: bool,
+    spill_writers_per_p: Vec<Option<SpillWriter>>,
+    spill_idxs_per_p: Vec<Vec<IdxSize>>,
 }
 
 struct BuildState {
@@ -432,13 +521,19 @@ impl BuildState {
         num_pipelines: usize,
         num_partitions: usize,
         sampled_probe_morsels: BufferedStream,
+        can_spill: bool,
     ) -> Self {
+        let memory = can_spill.then(|| Arc::new(MemoryTracker::new(spill_memory_limit())));
         let local_builders = (0..num_pipelines)
             .map(|_| LocalBuilder {
                 morsels: Vec::new(),
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                reservation: memory.as_ref().map(|m| m.reservation()),
+                spilling: false,
+                spill_writers_per_p: (0..num_partitions).map(|_| None).collect(),
+                spill_idxs_per_p: vec![Vec::new(); num_partitions],
             })
             .collect();
         Self {
@@ -467,16 +562,32 @@ impl BuildState {
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let key_df =
+                select_key_df(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys = hash_key_df(&key_df, params);
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
+            if let Some(reservation) = &mut local.reservation {
+                if !local.spilling {
+                    local.spilling =
+                        !reservation.try_grow(payload.estimated_size() + key_df.estimated_size());
+                }
+            }
+            if local.spilling {
+                spill_partitioned(
+                    &mut local.spill_writers_per_p,
+                    &mut local.spill_idxs_per_p,
+                    &mut local.sketch_per_p,
+                    &hash_keys,
+                    key_df,
+                    payload,
+                    &partitioner,
+                    track_unmatchable,
+                )?;
+                continue;
+            }
+
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
                 &mut local.morsel_idxs_values_per_p,
@@ -492,6 +603,95 @@ impl BuildState {
         Ok(())
     }
 
+    fn has_spilled(&self) -> bool {
+        self.local_builders.iter().any(|l| l.spilling)
+    }
+
+    /// Finishes the spill files of all builders, returning the spilled build
+    /// rows per partition.
+    fn finish_spilling(&mut self) -> PolarsResult<Vec<Vec<SpilledFrames>>> {
+        let num_partitions = self.local_builders[0].sketch_per_p.len();
+        let mut spilled_per_p: Vec<Vec<SpilledFrames>> =
+            (0..num_partitions).map(|_| Vec::new()).collect();
+        for l in &mut self.local_builders {
+            for (p, writer) in core::mem::take(&mut l.spill_writers_per_p)
+                .into_iter()
+                .enumerate()
+            {
+                if let Some(writer) = writer {
+                    spilled_per_p[p].push(writer.finish()?);
+                }
+            }
+        }
+        Ok(spilled_per_p)
+    }
+
+    /// Builds the probe table of a single partition from the in-memory and
+    /// spilled build rows.
+    fn build_spilled_partition(
+        &self,
+        p: usize,
+        spilled: &[SpilledFrames],
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeTable> {
+        let track_unmatchable = params.emit_unmatched_build();
+        let (payload_schema, num_keys) = if params.left_is_build.unwrap() {
+            (&params.left_payload_schema, params.left_key_selectors.len())
+        } else {
+            (
+                &params.right_payload_schema,
+                params.right_key_selectors.len(),
+            )
+        };
+
+        let num_partitions = self.local_builders[0].sketch_per_p.len();
+        let mut sketch = CardinalitySketch::new();
+        let mut payload_rows = spilled.iter().map(|f| f.num_rows()).sum::<usize>();
+        for l in &self.local_builders {
+            sketch.combine(&l.sketch_per_p[p]);
+            let offsets_len = l.morsel_idxs_offsets_per_p.len();
+            payload_rows += l.morsel_idxs_offsets_per_p[offsets_len - num_partitions + p];
+        }
+
+        let mut p_table = table.new_empty();
+        p_table.reserve(sketch.estimate() * 5 / 4);
+        let mut p_payload = DataFrameBuilder::new(payload_schema.clone());
+        p_payload.reserve(payload_rows);
+
+        for l in &self.local_builders {
+            for (i, (_mseq, payload, keys)) in l.morsels.iter().enumerate() {
+                unsafe {
+                    let p_morsel_idxs_start = l.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                    let p_morsel_idxs_stop =
+                        l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                    let p_morsel_idxs =
+                        &l.morsel_idxs_values_per_p[p][p_morsel_idxs_start..p_morsel_idxs_stop];
+                    p_table.insert_keys_subset(keys, p_morsel_idxs, track_unmatchable);
+                    p_payload.gather_extend(payload, p_morsel_idxs, ShareStrategy::Never);
+                }
+            }
+        }
+
+        let mut all_idxs = Vec::new();
+        for frames in spilled {
+            let mut reader = frames.reader()?;
+            while let Some(df) = reader.next_frame()? {
+                let (keys, payload) = split_spilled(df, num_keys, params)?;
+                all_idxs.clear();
+                all_idxs.extend(0..keys.len() as IdxSize);
+                unsafe { p_table.insert_keys_subset(&keys, &all_idxs, track_unmatchable) };
+                p_payload.extend(&payload, ShareStrategy::Never);
+            }
+        }
+
+        Ok(ProbeTable {
+            hash_table: p_table,
+            payload: p_payload.freeze(),
+            seq_ids: Vec::new(),
+        })
+    }
+
     fn finalize_ordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
         let track_unmatchable = params.emit_unmatched_build();
         let payload_schema = if params.left_is_build.unwrap() {
@@ -742,6 +942,10 @@ struct ProbeState {
 
 impl ProbeState {
     /// Returns the max morsel sequence sent.
+    ///
+    /// If `spilled` is set the received morsels were written by
+    /// [`spill_partitioned`] rather than coming from the probe input.
+    #[allow(clippy::too_many_arguments)]
     async fn partition_and_probe(
         mut recv: Receiver<Morsel>,
         mut send: Sender<Morsel>,
@@ -750,6 +954,7 @@ impl ProbeState {
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
+        spilled: bool,
     ) -> PolarsResult<MorselSeq> {
         // TODO: shuffle after partitioning and keep probe tables thread-local.
         let mut partition_idxs = vec![Vec::new(); partitioner.num_partitions()];
@@ -792,9 +997,13 @@ impl ProbeState {
                 continue;
             }
 
-            let hash_keys =
-                select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
-            let mut payload = select_payload(df, payload_selector);
+            let (hash_keys, mut payload) = if spilled {
+                split_spilled(df, key_selectors.len(), params)?
+            } else {
+                let hash_keys =
+                    select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
+                (hash_keys, select_payload(df, payload_selector))
+            };
             let mut payload_rechunked = false; // We don't eagerly rechunk because there might be no matches.
             let mut total_matches = 0;
 
@@ -1152,12 +1361,285 @@ impl EmitUnmatchedState {
     }
 }
 
+/// Spills the probe input to disk per partition, for joins whose build side
+/// was spilled.
+struct LocalProbeSpiller {
+    writers_per_p: Vec<Option<SpillWriter>>,
+    idxs_per_p: Vec<Vec<IdxSize>>,
+}
+
+impl LocalProbeSpiller {
+    async fn spill(
+        &mut self,
+        df: DataFrame,
+        partitioner: &HashPartitioner,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let (key_selectors, payload_selector) = if params.left_is_build.unwrap() {
+            (&params.right_key_selectors, &params.right_payload_select)
+        } else {
+            (&params.left_key_selectors, &params.left_payload_select)
+        };
+        let key_df = select_key_df(&df, key_selectors, &state.in_memory_exec_state).await?;
+        let hash_keys = hash_key_df(&key_df, params);
+        let payload = select_payload(df, payload_selector);
+        spill_partitioned(
+            &mut self.writers_per_p,
+            &mut self.idxs_per_p,
+            &mut [],
+            &hash_keys,
+            key_df,
+            payload,
+            partitioner,
+            params.emit_unmatched_probe(),
+        )
+    }
+
+    async fn sink(
+        &mut self,
+        mut recv: Receiver<Morsel>,
+        partitioner: HashPartitioner,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        while let Ok(morsel) = recv.recv().await {
+            self.spill(morsel.into_df(), &partitioner, params, state)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+struct SpillProbeState {
+    build: BuildState,
+    spilled_build_per_p: Vec<Vec<SpilledFrames>>,
+    spillers: Vec<LocalProbeSpiller>,
+}
+
+impl SpillProbeState {
+    fn new(
+        mut build: BuildState,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<Self> {
+        let spilled_build_per_p = build.finish_spilling()?;
+        let num_partitions = spilled_build_per_p.len();
+        let mut spillers = (0..state.num_pipelines)
+            .map(|_| LocalProbeSpiller {
+                writers_per_p: (0..num_partitions).map(|_| None).collect(),
+                idxs_per_p: vec![Vec::new(); num_partitions],
+            })
+            .collect_vec();
+
+        // Spill the sampled probe morsels right away, the probe input might
+        // already be done.
+        let partitioner = HashPartitioner::new(num_partitions, 0);
+        let sampled_probe_morsels = build.sampled_probe_morsels.take_morsels();
+        get_runtime().block_on(async {
+            for morsel in sampled_probe_morsels {
+                spillers[0]
+                    .spill(morsel.into_df(), &partitioner, params, state)
+                    .await?;
+            }
+            PolarsResult::Ok(())
+        })?;
+
+        if config::verbose() {
+            eprintln!("equi-join build side exceeded the memory limit, spilling probe side");
+        }
+
+        Ok(Self {
+            build,
+            spilled_build_per_p,
+            spillers,
+        })
+    }
+
+    fn finish(self) -> PolarsResult<SpilledJoinState> {
+        let num_partitions = self.spilled_build_per_p.len();
+        let mut spilled_probe_per_p: Vec<Vec<SpilledFrames>> =
+            (0..num_partitions).map(|_| Vec::new()).collect();
+        for spiller in self.spillers {
+            for (p, writer) in spiller.writers_per_p.into_iter().enumerate() {
+                if let Some(writer) = writer {
+                    spilled_probe_per_p[p].push(writer.finish()?);
+                }
+            }
+        }
+
+        Ok(SpilledJoinState {
+            build: self.build,
+            spilled_build_per_p: self.spilled_build_per_p,
+            spilled_probe_per_p,
+            next_partition: 0,
+            active: ActiveSpilledPartition::None,
+            unordered_morsel_seq: AtomicU64::new(0),
+        })
+    }
+}
+
+enum ActiveSpilledPartition {
+    None,
+    Probe {
+        table: ProbeTable,
+        readers: Mutex<Vec<SpillReader>>,
+        // Keeps the spill files alive while we are reading them.
+        _frames: Vec<SpilledFrames>,
+    },
+    EmitUnmatched(EmitUnmatchedState),
+}
+
+/// A join whose build side was spilled is processed one partition at a time:
+/// we build the partition's table, probe it with the spilled probe rows of
+/// that partition and emit its unmatched build rows if needed.
+struct SpilledJoinState {
+    build: BuildState,
+    spilled_build_per_p: Vec<Vec<SpilledFrames>>,
+    spilled_probe_per_p: Vec<Vec<SpilledFrames>>,
+    next_partition: usize,
+    active: ActiveSpilledPartition,
+    unordered_morsel_seq: AtomicU64,
+}
+
+impl SpilledJoinState {
+    /// Moves on to the next step with work left to do, returns false once all
+    /// partitions are done.
+    fn advance(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> PolarsResult<bool> {
+        loop {
+            match &mut self.active {
+                ActiveSpilledPartition::Probe { readers, .. } => {
+                    if !readers.get_mut().is_empty() {
+                        return Ok(true);
+                    }
+                    let ActiveSpilledPartition::Probe { table, .. } =
+                        core::mem::replace(&mut self.active, ActiveSpilledPartition::None)
+                    else {
+                        unreachable!()
+                    };
+                    if params.emit_unmatched_build() {
+                        let seq = self.unordered_morsel_seq.load(Ordering::Relaxed);
+                        self.active = ActiveSpilledPartition::EmitUnmatched(EmitUnmatchedState {
+                            partitions: vec![table],
+                            active_partition_idx: 0,
+                            offset_in_active_p: 0,
+                            morsel_seq: MorselSeq::new(seq),
+                        });
+                    }
+                },
+                ActiveSpilledPartition::EmitUnmatched(emit_state) => {
+                    if emit_state.active_partition_idx < emit_state.partitions.len() {
+                        return Ok(true);
+                    }
+                    // Continue numbering after the emitted morsels, MorselSeq
+                    // stores twice the sequence number.
+                    self.unordered_morsel_seq
+                        .store(emit_state.morsel_seq.to_u64() / 2, Ordering::Relaxed);
+                    self.active = ActiveSpilledPartition::None;
+                },
+                ActiveSpilledPartition::None => {
+                    let p = self.next_partition;
+                    if p == self.spilled_build_per_p.len() {
+                        return Ok(false);
+                    }
+                    self.next_partition += 1;
+
+                    let spilled_build = core::mem::take(&mut self.spilled_build_per_p[p]);
+                    let p_table =
+                        self.build
+                            .build_spilled_partition(p, &spilled_build, params, table)?;
+                    drop(spilled_build);
+
+                    let frames = core::mem::take(&mut self.spilled_probe_per_p[p]);
+                    let readers = frames.iter().map(|f| f.reader()).try_collect_vec()?;
+                    self.active = ActiveSpilledPartition::Probe {
+                        table: p_table,
+                        readers: Mutex::new(readers),
+                        _frames: frames,
+                    };
+                },
+            }
+        }
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        send_port: SendPort<'_>,
+        params: &'env EquiJoinParams,
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        match &mut self.active {
+            ActiveSpilledPartition::Probe { table, readers, .. } => {
+                let source_token = SourceToken::new();
+                for send in send_port.parallel() {
+                    // Feed the spilled probe rows to a regular probe task.
+                    let (mut feed_send, feed_recv) = connector();
+                    let readers = &*readers;
+                    let source_token = source_token.clone();
+                    join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                        let wait_group = WaitGroup::default();
+                        loop {
+                            let reader = readers.lock().pop();
+                            let Some(mut reader) = reader else {
+                                break;
+                            };
+                            while let Some(df) = reader.next_frame()? {
+                                let mut morsel =
+                                    Morsel::new(df, MorselSeq::default(), source_token.clone());
+                                morsel.set_consume_token(wait_group.token());
+                                if feed_send.send(morsel).await.is_err() {
+                                    readers.lock().push(reader);
+                                    return Ok(());
+                                }
+                                wait_group.wait().await;
+                                if source_token.stop_requested() {
+                                    readers.lock().push(reader);
+                                    return Ok(());
+                                }
+                            }
+                        }
+                        Ok(())
+                    }));
+
+                    let table = &*table;
+                    let unordered_morsel_seq = &self.unordered_morsel_seq;
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        ProbeState::partition_and_probe(
+                            feed_recv,
+                            send,
+                            core::slice::from_ref(table),
+                            unordered_morsel_seq,
+                            HashPartitioner::new(1, 0),
+                            params,
+                            state,
+                            true,
+                        )
+                        .await?;
+                        Ok(())
+                    }));
+                }
+            },
+            ActiveSpilledPartition::EmitUnmatched(emit_state) => {
+                join_handles.push(scope.spawn_task(
+                    TaskPriority::Low,
+                    emit_state.emit_unmatched(send_port.serial(), params, state.num_pipelines),
+                ));
+            },
+            ActiveSpilledPartition::None => unreachable!(),
+        }
+    }
+}
+
 enum EquiJoinState {
     Sample(SampleState),
     Build(BuildState),
     Probe(ProbeState),
     EmitUnmatchedBuild(EmitUnmatchedState),
     EmitUnmatchedBuildInOrder(InMemorySourceNode),
+    SpillProbe(SpillProbeState),
+    ProcessSpilled(SpilledJoinState),
     Done,
 }
 
@@ -1220,6 +1702,7 @@ impl EquiJoinNode {
                 num_pipelines,
                 num_pipelines,
                 BufferedStream::default(),
+                !preserve_order_build && !preserve_order_probe,
             ))
         } else {
             EquiJoinState::Sample(SampleState::default())
@@ -1285,14 +1768,43 @@ impl ComputeNode for EquiJoinNode {
         let probe_idx = 1 - build_idx;
 
         // If we are building and the build input is done, transition to probing.
+        // If the build side was spilled we have to spill the probe side as well.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
-                let probe_state = if self.params.preserve_order_build {
-                    build_state.finalize_ordered(&self.params, &*self.table)
+                if build_state.has_spilled() {
+                    let EquiJoinState::Build(build_state) =
+                        core::mem::replace(&mut self.state, EquiJoinState::Done)
+                    else {
+                        unreachable!()
+                    };
+                    let spill_state = SpillProbeState::new(build_state, &self.params, state)?;
+                    self.state = EquiJoinState::SpillProbe(spill_state);
                 } else {
-                    build_state.finalize_unordered(&self.params, &*self.table)
+                    let probe_state = if self.params.preserve_order_build {
+                        build_state.finalize_ordered(&self.params, &*self.table)
+                    } else {
+                        build_state.finalize_unordered(&self.params, &*self.table)
+                    };
+                    self.state = EquiJoinState::Probe(probe_state);
+                }
+            }
+        }
+
+        // If we are spilling the probe side and the probe input is done,
+        // process the spilled partitions.
+        if let EquiJoinState::SpillProbe(_) = &self.state {
+            if recv[probe_idx] == PortState::Done {
+                let EquiJoinState::SpillProbe(spill_state) =
+                    core::mem::replace(&mut self.state, EquiJoinState::Done)
+                else {
+                    unreachable!()
                 };
-                self.state = EquiJoinState::Probe(probe_state);
+                self.state = EquiJoinState::ProcessSpilled(spill_state.finish()?);
+            }
+        }
+        if let EquiJoinState::ProcessSpilled(spilled_state) = &mut self.state {
+            if !spilled_state.advance(&self.params, &*self.table)? {
+                self.state = EquiJoinState::Done;
             }
         }
 
@@ -1375,6 +1887,16 @@ impl ComputeNode for EquiJoinNode {
                 recv[build_idx] = PortState::Done;
                 recv[probe_idx] = PortState::Done;
             },
+            EquiJoinState::SpillProbe(_) => {
+                send[0] = PortState::Blocked;
+                recv[build_idx] = PortState::Done;
+                recv[probe_idx] = PortState::Ready;
+            },
+            EquiJoinState::ProcessSpilled(_) => {
+                send[0] = PortState::Ready;
+                recv[build_idx] = PortState::Done;
+                recv[probe_idx] = PortState::Done;
+            },
             EquiJoinState::EmitUnmatchedBuildInOrder(src_node) => {
                 recv[build_idx] = PortState::Done;
                 recv[probe_idx] = PortState::Done;
@@ -1503,6 +2025,7 @@ impl ComputeNode for EquiJoinNode {
                                 partitioner.clone(),
                                 &self.params,
                                 state,
+                                false,
                             ),
                         )
                     })
@@ -1530,6 +2053,25 @@ impl ComputeNode for EquiJoinNode {
                 assert!(recv_ports[probe_idx].is_none());
                 src_node.spawn(scope, &mut [], send_ports, state, join_handles);
             },
+            EquiJoinState::SpillProbe(spill_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[build_idx].is_none());
+                let receivers = recv_ports[probe_idx].take().unwrap().parallel();
+
+                let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+                for (spiller, recv) in spill_state.spillers.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        spiller.sink(recv, partitioner.clone(), &self.params, state),
+                    ));
+                }
+            },
+            EquiJoinState::ProcessSpilled(spilled_state) => {
+                assert!(recv_ports[build_idx].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                let send = send_ports[0].take().unwrap();
+                spilled_state.spawn(scope, send, &self.params, state, join_handles);
+            },
             EquiJoinState::Done => unreachable!(),
         }
     }
@@ -0,0 +1,342 @@
+//! Streaming inequality join.
+//!
+//! The right input is fully buffered, stripped of rows with null keys, sorted
+//! by the first key and split into blocks of which we track the min/max of
+//! every key. Left morsels are then streamed through in parallel: each morsel
+//! is only joined (using the in-memory IEJoin kernel) with the blocks whose
+//! key bounds can satisfy all inequalities with the bounds of the morsel.
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::state::ExecutionState;
+use polars_ops::frame::{
+    DataFrameJoinOps, IEJoinOptions, InequalityOperator, JoinArgs, JoinTypeOptions,
+};
+use polars_utils::format_pl_smallstr;
+use polars_utils::itertools::Itertools;
+
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::compute_node_prelude::*;
+
+fn key_name(i: usize) -> PlSmallStr {
+    format_pl_smallstr!("__POLARS_IEJOIN_KEY{i}")
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Column>> {
+    let mut keys = Vec::with_capacity(key_selectors.len());
+    for (i, selector) in key_selectors.iter().enumerate() {
+        let key = selector.evaluate(df, state).await?;
+        let key = if key.len() == 1 && df.height() != 1 {
+            key.new_from_index(0, df.height())
+        } else {
+            key
+        };
+        keys.push(key.with_name(key_name(i)));
+    }
+    Ok(keys)
+}
+
+/// The range of (physical) values of a key column.
+enum KeyBounds {
+    /// Only nulls, nothing can match.
+    Empty,
+    /// Contains NaNs, which we don't bound.
+    Unbounded,
+    Range(AnyValue<'static>, AnyValue<'static>),
+}
+
+impl KeyBounds {
+    fn new(key: &Series) -> PolarsResult<Self> {
+        let key = key.to_physical_repr();
+        if key.null_count() == key.len() {
+            return Ok(Self::Empty);
+        }
+        if key.dtype().is_float() && key.is_nan()?.any() {
+            return Ok(Self::Unbounded);
+        }
+        let min = key.min_reduce()?.value().clone();
+        let max = key.max_reduce()?.value().clone();
+        Ok(Self::Range(min, max))
+    }
+
+    /// Whether `left op right` can hold for any pair of values in the bounds.
+    fn may_match(op: InequalityOperator, left: &Self, right: &Self) -> bool {
+        use InequalityOperator as Op;
+        match (left, right) {
+            (Self::Empty, _) | (_, Self::Empty) => false,
+            (Self::Range(l_min, l_max), Self::Range(r_min, r_max)) => match op {
+                Op::Lt => l_min < r_max,
+                Op::LtEq => l_min <= r_max,
+                Op::Gt => l_max > r_min,
+                Op::GtEq => l_max >= r_min,
+            },
+            _ => true,
+        }
+    }
+}
+
+struct BuildBlock {
+    payload: DataFrame,
+    keys: Vec<Series>,
+    bounds: Vec<KeyBounds>,
+}
+
+enum IEJoinState {
+    Build(Mutex<Vec<DataFrame>>),
+    Probe(Vec<BuildBlock>),
+    Done,
+}
+
+pub struct IEJoinNode {
+    right_input_schema: Arc<Schema>,
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    args: JoinArgs,
+    operators: Vec<InequalityOperator>,
+    options: IEJoinOptions,
+    state: IEJoinState,
+}
+
+impl IEJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        mut args: JoinArgs,
+        options: IEJoinOptions,
+    ) -> Self {
+        // Slices are applied by a separate node.
+        args.slice = None;
+        let operators = std::iter::once(options.operator1)
+            .chain(options.operator2)
+            .collect();
+        Self {
+            right_input_schema,
+            left_key_selectors,
+            right_key_selectors,
+            args,
+            operators,
+            options,
+            state: IEJoinState::Build(Mutex::default()),
+        }
+    }
+
+    /// Sorts the buffered right input by the first key and splits it into
+    /// blocks.
+    fn finalize_build(&self, frames: Vec<DataFrame>) -> PolarsResult<Vec<BuildBlock>> {
+        if frames.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(frames);
+
+        // Null keys never match.
+        let key_names = (0..self.right_key_selectors.len())
+            .map(key_name)
+            .collect::<Vec<_>>();
+        let mut mask = BooleanChunked::full(PlSmallStr::EMPTY, true, df.height());
+        for name in &key_names {
+            mask = &mask & &df.column(name)?.as_materialized_series().is_not_null();
+        }
+        if !mask.all() {
+            df = df.filter(&mask)?;
+        }
+        let df = df.sort([key_names[0].clone()], SortMultipleOptions::default())?;
+
+        let num_payload_cols = self.right_input_schema.len();
+        let block_size = get_ideal_morsel_size();
+        let mut blocks = Vec::with_capacity(df.height().div_ceil(block_size));
+        let mut offset = 0;
+        while offset < df.height() {
+            let height = (df.height() - offset).min(block_size);
+            let mut columns = df.slice(offset as i64, height).take_columns();
+            let keys = columns
+                .split_off(num_payload_cols)
+                .into_iter()
+                .map(|c| c.take_materialized_series())
+                .collect::<Vec<_>>();
+            let bounds = keys.iter().map(KeyBounds::new).try_collect_vec()?;
+            let payload = unsafe { DataFrame::new_no_checks(height, columns) };
+            blocks.push(BuildBlock {
+                payload,
+                keys,
+                bounds,
+            });
+            offset += height;
+        }
+        Ok(blocks)
+    }
+
+    fn may_match(&self, left_bounds: &[KeyBounds], block: &BuildBlock) -> bool {
+        self.operators
+            .iter()
+            .zip(left_bounds.iter().zip(&block.bounds))
+            .all(|(op, (l, r))| KeyBounds::may_match(*op, l, r))
+    }
+}
+
+impl ComputeNode for IEJoinNode {
+    fn name(&self) -> &str {
+        "ie-join"
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, IEJoinState::Build(_))
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // Are we done?
+        if send[0] == PortState::Done || recv[0] == PortState::Done {
+            self.state = IEJoinState::Done;
+        }
+
+        // Transition to probe?
+        if recv[1] == PortState::Done {
+            if let IEJoinState::Build(frames) = &mut self.state {
+                let frames = core::mem::take(frames.get_mut());
+                let blocks = self.finalize_build(frames)?;
+                if blocks.is_empty() {
+                    self.state = IEJoinState::Done;
+                } else {
+                    self.state = IEJoinState::Probe(blocks);
+                }
+            }
+        }
+
+        match &self.state {
+            IEJoinState::Build(_) => {
+                recv[0] = PortState::Blocked;
+                recv[1] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            IEJoinState::Probe(_) => {
+                recv[1] = PortState::Done;
+                core::mem::swap(&mut recv[0], &mut send[0]);
+            },
+            IEJoinState::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+        let slf = &*self;
+        match &slf.state {
+            IEJoinState::Build(frames) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[0].is_none());
+                let receivers = recv_ports[1].take().unwrap().parallel();
+
+                for mut recv in receivers {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let mut local_frames = Vec::new();
+                        while let Ok(morsel) = recv.recv().await {
+                            let df = morsel.into_df();
+                            let keys = select_keys(
+                                &df,
+                                &slf.right_key_selectors,
+                                &state.in_memory_exec_state,
+                            )
+                            .await?;
+                            local_frames.push(df.hstack(&keys)?);
+                        }
+
+                        frames.lock().extend(local_frames);
+                        Ok(())
+                    }));
+                }
+            },
+            IEJoinState::Probe(blocks) => {
+                assert!(recv_ports[1].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+                let ideal_morsel_size = get_ideal_morsel_size();
+
+                for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let join_options = JoinTypeOptions::IEJoin(slf.options.clone());
+                        while let Ok(morsel) = recv.recv().await {
+                            let left_df = morsel.df();
+                            if left_df.height() == 0 {
+                                continue;
+                            }
+                            let left_keys = select_keys(
+                                left_df,
+                                &slf.left_key_selectors,
+                                &state.in_memory_exec_state,
+                            )
+                            .await?
+                            .into_iter()
+                            .map(|c| c.take_materialized_series())
+                            .collect::<Vec<_>>();
+                            let left_bounds =
+                                left_keys.iter().map(KeyBounds::new).try_collect_vec()?;
+
+                            let mut out = Vec::new();
+                            let mut out_height = 0;
+                            for (i, block) in blocks.iter().enumerate() {
+                                if slf.may_match(&left_bounds, block) {
+                                    let df = left_df._join_impl(
+                                        &block.payload,
+                                        left_keys.clone(),
+                                        block.keys.clone(),
+                                        slf.args.clone(),
+                                        Some(join_options.clone()),
+                                        false,
+                                        false,
+                                    )?;
+                                    if df.height() > 0 {
+                                        out_height += df.height();
+                                        out.push(df);
+                                    }
+                                }
+
+                                let is_last = i + 1 == blocks.len();
+                                if !out.is_empty() && (out_height >= ideal_morsel_size || is_last) {
+                                    let df = accumulate_dataframes_vertical_unchecked(
+                                        core::mem::take(&mut out),
+                                    );
+                                    out_height = 0;
+                                    let out_morsel = Morsel::new(
+                                        df,
+                                        morsel.seq(),
+                                        morsel.source_token().clone(),
+                                    );
+                                    if send.send(out_morsel).await.is_err() {
+                                        return Ok(());
+                                    }
+                                }
+                            }
+                        }
+                        Ok(())
+                    }));
+                }
+            },
+            IEJoinState::Done => unreachable!(),
+        }
+    }
+}
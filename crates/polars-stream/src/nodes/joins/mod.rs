@@ -12,8 +12,12 @@ use crate::async_primitives::wait_group::WaitGroup;
 use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::pipe::RecvPort;
 
+#[cfg(feature = "asof_join")]
+pub mod asof_join;
 pub mod cross_join;
 pub mod equi_join;
+#[cfg(feature = "iejoin")]
+pub mod iejoin;
 pub mod in_memory;
 #[cfg(feature = "semi_anti_join")]
 pub mod semi_anti_join;
@@ -55,6 +59,11 @@ impl BufferedStream {
         self.morsels.is_empty()
     }
 
+    /// Takes all buffered morsels out of this stream.
+    pub fn take_morsels(&self) -> Vec<Morsel> {
+        core::iter::from_fn(|| self.morsels.pop()).collect()
+    }
+
     #[allow(clippy::needless_lifetimes)]
     pub fn reinsert<'s, 'env>(
         &'s self,
@@ -16,6 +16,7 @@ pub mod ordered_union;
 pub mod reduce;
 pub mod select;
 pub mod simple_projection;
+pub mod sort;
 pub mod streaming_slice;
 pub mod with_row_index;
 pub mod zip;
@@ -0,0 +1,666 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use polars_core::POOL;
+use polars_core::prelude::row_encode::_get_rows_encoded_arr;
+use polars_core::prelude::{Column, IdxCa, IntoColumn, SortMultipleOptions};
+use polars_core::scalar::Scalar;
+use polars_core::schema::Schema;
+use polars_core::series::IsSorted;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets};
+use polars_utils::IdxSize;
+use polars_utils::itertools::Itertools;
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::utils::spill::{
+    MemoryReservation, MemoryTracker, SpillReader, SpilledFrames, spill_memory_limit,
+};
+
+/// Minimum size of a sorted run, we don't want to end up merging thousands of
+/// tiny runs if the memory limit is small.
+const MIN_RUN_SIZE_BYTES: usize = 16 * 1024 * 1024;
+
+/// Which rows of a sorted run can still be part of the (sliced) output.
+#[derive(Clone, Copy)]
+enum RowRetention {
+    All,
+    Head(usize),
+    Tail(usize),
+}
+
+impl RowRetention {
+    fn retain<'a>(&self, idxs: &'a [IdxSize]) -> &'a [IdxSize] {
+        match *self {
+            Self::All => idxs,
+            Self::Head(n) => &idxs[..n.min(idxs.len())],
+            Self::Tail(n) => &idxs[idxs.len().saturating_sub(n)..],
+        }
+    }
+}
+
+/// The columns and order by which rows are sorted, shared between the sink
+/// and source phases.
+struct SortKeys {
+    /// Indices of the key columns, possibly including the columns we add to
+    /// maintain order.
+    key_cols: Vec<usize>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+}
+
+impl SortKeys {
+    /// Encodes the keys of each row such that comparing the encoded rows
+    /// bytewise gives the requested order.
+    fn encode(&self, df: &DataFrame) -> PolarsResult<BinaryArray<i64>> {
+        let columns = df.get_columns();
+        let by = self
+            .key_cols
+            .iter()
+            .map(|i| columns[*i].clone())
+            .collect_vec();
+        _get_rows_encoded_arr(&by, &self.descending, &self.nulls_last)
+    }
+}
+
+/// A sorted run, split into chunks of roughly morsel size.
+enum SortedRun {
+    InMemory {
+        chunks: VecDeque<DataFrame>,
+        reservation: MemoryReservation,
+    },
+    Spilled(SpilledFrames),
+}
+
+impl SortedRun {
+    fn num_rows(&self) -> usize {
+        match self {
+            Self::InMemory { chunks, .. } => chunks.iter().map(|df| df.height()).sum(),
+            Self::Spilled(frames) => frames.num_rows(),
+        }
+    }
+}
+
+struct LocalSortSinkState {
+    buffer: Vec<DataFrame>,
+    buffered_bytes: usize,
+    runs: Vec<SortedRun>,
+}
+
+struct SortSinkState {
+    locals: Vec<LocalSortSinkState>,
+    /// The names of the (seq, idx) columns added to each morsel if we have to
+    /// maintain the input order of equal rows.
+    order_cols: Option<(PlSmallStr, PlSmallStr)>,
+    run_size_bytes: usize,
+    memory: Arc<MemoryTracker>,
+}
+
+impl SortSinkState {
+    /// Sorts the buffered frames into a new run, which is kept in memory if the
+    /// memory limit allows it and spilled to disk otherwise.
+    fn create_run(
+        dfs: Vec<DataFrame>,
+        keys: &SortKeys,
+        retention: RowRetention,
+        memory: &Arc<MemoryTracker>,
+    ) -> PolarsResult<SortedRun> {
+        let df = accumulate_dataframes_vertical_unchecked(dfs);
+        let encoded = keys.encode(&df)?;
+
+        // Sort stably, so that runs with maintained order stay correct.
+        let mut idxs = (0..df.height() as IdxSize).collect_vec();
+        idxs.sort_by(|l, r| encoded.value(*l as usize).cmp(encoded.value(*r as usize)));
+        let idxs = retention.retain(&idxs);
+
+        let morsel_size = get_ideal_morsel_size();
+        let chunks: VecDeque<DataFrame> = idxs
+            .chunks(morsel_size)
+            .map(|chunk_idxs| unsafe { df.take_slice_unchecked_impl(chunk_idxs, false) })
+            .collect();
+
+        let num_bytes = chunks.iter().map(|df| df.estimated_size()).sum();
+        if let Some(reservation) = memory.try_reserve(num_bytes) {
+            Ok(SortedRun::InMemory {
+                chunks,
+                reservation,
+            })
+        } else {
+            Ok(SortedRun::Spilled(SpilledFrames::spill(
+                df.schema(),
+                &chunks,
+            )?))
+        }
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        keys: &'env SortKeys,
+        retention: RowRetention,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let order_cols = &self.order_cols;
+            let memory = &self.memory;
+            let run_size_bytes = self.run_size_bytes;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let seq = morsel.seq().to_u64();
+                    let mut df = morsel.into_df();
+                    if df.height() == 0 {
+                        continue;
+                    }
+
+                    if let Some((seq_col, idx_col)) = order_cols {
+                        let height = df.height();
+                        let idx =
+                            IdxCa::from_vec(idx_col.clone(), (0..height as IdxSize).collect());
+                        unsafe {
+                            df.with_column_unchecked(Column::new_scalar(
+                                seq_col.clone(),
+                                Scalar::from(seq),
+                                height,
+                            ));
+                            df.with_column_unchecked(idx.into_column());
+                        }
+                    }
+
+                    local.buffered_bytes += df.estimated_size();
+                    local.buffer.push(df);
+                    if local.buffered_bytes >= run_size_bytes {
+                        let dfs = core::mem::take(&mut local.buffer);
+                        local.buffered_bytes = 0;
+                        local
+                            .runs
+                            .push(Self::create_run(dfs, keys, retention, memory)?);
+                    }
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    /// Sorts the remaining buffered data and collects all sorted runs.
+    fn into_runs(self, keys: &SortKeys, retention: RowRetention) -> PolarsResult<Vec<SortedRun>> {
+        let memory = &self.memory;
+        let runs_per_local = POOL.install(|| {
+            self.locals
+                .into_par_iter()
+                .with_max_len(1)
+                .map(|mut local| {
+                    if !local.buffer.is_empty() {
+                        let run = Self::create_run(local.buffer, keys, retention, memory)?;
+                        local.runs.push(run);
+                    }
+                    PolarsResult::Ok(local.runs)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(runs_per_local.into_iter().flatten().collect())
+    }
+}
+
+/// The chunks of a sorted run that have not been loaded yet.
+enum RunChunks {
+    InMemory {
+        chunks: VecDeque<DataFrame>,
+        // Released chunk by chunk as the merge loads them.
+        reservation: MemoryReservation,
+    },
+    Spilled {
+        reader: Box<SpillReader>,
+        // Keeps the spill file alive while we are reading it.
+        _frames: SpilledFrames,
+    },
+}
+
+impl RunChunks {
+    fn next_chunk(&mut self) -> PolarsResult<Option<DataFrame>> {
+        match self {
+            Self::InMemory {
+                chunks,
+                reservation,
+            } => {
+                let df = chunks.pop_front();
+                if let Some(df) = &df {
+                    reservation.shrink(df.estimated_size());
+                }
+                Ok(df)
+            },
+            Self::Spilled { reader, .. } => reader.next_frame(),
+        }
+    }
+}
+
+/// The position of the merge within a single sorted run.
+struct RunCursor {
+    chunks: RunChunks,
+    /// The not yet merged remainder of the current chunk and its encoded keys,
+    /// never empty.
+    df: DataFrame,
+    encoded: BinaryArray<i64>,
+}
+
+impl RunCursor {
+    fn new(run: SortedRun, keys: &SortKeys) -> PolarsResult<Option<Self>> {
+        let chunks = match run {
+            SortedRun::InMemory {
+                chunks,
+                reservation,
+            } => RunChunks::InMemory {
+                chunks,
+                reservation,
+            },
+            SortedRun::Spilled(frames) => RunChunks::Spilled {
+                reader: Box::new(frames.reader()?),
+                _frames: frames,
+            },
+        };
+        let mut cursor = Self {
+            chunks,
+            df: DataFrame::empty(),
+            encoded: BinaryArray::new_empty(arrow::datatypes::ArrowDataType::LargeBinary),
+        };
+        Ok(cursor.load_next_chunk(keys)?.then_some(cursor))
+    }
+
+    /// Loads the next non-empty chunk, returns false if the run is exhausted.
+    fn load_next_chunk(&mut self, keys: &SortKeys) -> PolarsResult<bool> {
+        while let Some(df) = self.chunks.next_chunk()? {
+            if df.height() > 0 {
+                self.encoded = keys.encode(&df)?;
+                self.df = df;
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn last_key(&self) -> &[u8] {
+        self.encoded.value(self.encoded.len() - 1)
+    }
+
+    /// The number of rows in the current chunk with a key smaller than or equal
+    /// to `bound`.
+    fn num_rows_up_to(&self, bound: &[u8]) -> usize {
+        let (mut lo, mut hi) = (0, self.encoded.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.encoded.value(mid) <= bound {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Skips `n` rows, returns false if the run is exhausted.
+    fn advance(&mut self, n: usize, keys: &SortKeys) -> PolarsResult<bool> {
+        if n < self.df.height() {
+            self.df = self.df.slice(n as i64, self.df.height() - n);
+            self.encoded.slice(n, self.encoded.len() - n);
+            Ok(true)
+        } else {
+            self.load_next_chunk(keys)
+        }
+    }
+}
+
+struct SortSourceState {
+    cursors: Vec<RunCursor>,
+    output_schema: Arc<Schema>,
+    rows_to_skip: usize,
+    rows_left: usize,
+    pending: VecDeque<DataFrame>,
+    seq: MorselSeq,
+    sent_any: bool,
+    /// The sorted flag to set on the first sort key, if it is part of the
+    /// output.
+    sorted_flag: Option<(usize, IsSorted)>,
+}
+
+impl SortSourceState {
+    /// Merges the next block of rows from all runs.
+    ///
+    /// All rows with a key up to the smallest last key of the current chunks
+    /// of all runs can safely be output, and at least one chunk is fully
+    /// consumed each time.
+    fn next_block(&mut self, keys: &SortKeys) -> PolarsResult<Option<DataFrame>> {
+        let Some(bound) = self.cursors.iter().map(|c| c.last_key()).min() else {
+            return Ok(None);
+        };
+        let bound = bound.to_vec();
+
+        let mut parts = Vec::with_capacity(self.cursors.len());
+        let mut part_keys = Vec::with_capacity(self.cursors.len());
+        let mut exhausted = Vec::new();
+        for (i, cursor) in self.cursors.iter_mut().enumerate() {
+            let n = cursor.num_rows_up_to(&bound);
+            if n == 0 {
+                continue;
+            }
+            parts.push(cursor.df.slice(0, n));
+            part_keys.push(cursor.encoded.clone().sliced(0, n));
+            if !cursor.advance(n, keys)? {
+                exhausted.push(i);
+            }
+        }
+        for i in exhausted.into_iter().rev() {
+            self.cursors.swap_remove(i);
+        }
+
+        let block = if parts.len() == 1 {
+            parts.pop().unwrap()
+        } else {
+            let encoded = part_keys
+                .iter()
+                .flat_map(|arr| arr.values_iter())
+                .collect_vec();
+            let mut idxs = (0..encoded.len() as IdxSize).collect_vec();
+            idxs.sort_by(|l, r| encoded[*l as usize].cmp(encoded[*r as usize]));
+            let df = accumulate_dataframes_vertical_unchecked(parts);
+            unsafe { df.take_slice_unchecked_impl(&idxs, false) }
+        };
+
+        // Drop the columns we added to maintain order.
+        let width = self.output_schema.len();
+        let columns = block.get_columns()[..width].to_vec();
+        Ok(Some(unsafe {
+            DataFrame::new_no_checks(block.height(), columns)
+        }))
+    }
+
+    fn next_morsel_df(&mut self, keys: &SortKeys) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            if let Some(mut df) = self.pending.pop_front() {
+                self.sent_any = true;
+                if let Some((col_idx, sorted)) = self.sorted_flag {
+                    unsafe { df.get_columns_mut()[col_idx].set_sorted_flag(sorted) };
+                }
+                return Ok(Some(df));
+            }
+
+            let block = if self.rows_left > 0 {
+                self.next_block(keys)?
+            } else {
+                None
+            };
+            let Some(mut block) = block else {
+                // Always send at least one morsel, some nodes rely on it.
+                if !self.sent_any {
+                    let df = DataFrame::empty_with_schema(&self.output_schema);
+                    self.pending.push_back(df);
+                    continue;
+                }
+                return Ok(None);
+            };
+
+            if self.rows_to_skip > 0 {
+                let skip = self.rows_to_skip.min(block.height());
+                block = block.slice(skip as i64, block.height() - skip);
+                self.rows_to_skip -= skip;
+            }
+            let len = block.height().min(self.rows_left);
+            self.rows_left -= len;
+            let block = block.slice(0, len);
+
+            let morsel_size = get_ideal_morsel_size();
+            let mut offset = 0;
+            while offset < block.height() {
+                self.pending
+                    .push_back(block.slice(offset as i64, morsel_size));
+                offset += morsel_size;
+            }
+        }
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.sent_any && self.pending.is_empty() && (self.cursors.is_empty() || self.rows_left == 0)
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        send_ports: &mut [Option<SendPort<'_>>],
+        keys: &'env SortKeys,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let mut send = send_ports[0].take().unwrap().serial();
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            let source_token = SourceToken::new();
+            let wait_group = WaitGroup::default();
+            while let Some(df) = self.next_morsel_df(keys)? {
+                let mut morsel = Morsel::new(df, self.seq, source_token.clone());
+                morsel.set_consume_token(wait_group.token());
+                if let Err(morsel) = send.send(morsel).await {
+                    self.pending.push_front(morsel.into_df());
+                    break;
+                }
+                self.seq = self.seq.successor();
+
+                wait_group.wait().await;
+                if source_token.stop_requested() {
+                    break;
+                }
+            }
+            Ok(())
+        }));
+    }
+}
+
+enum SortState {
+    Sink(SortSinkState),
+    Source(SortSourceState),
+    Done,
+}
+
+/// An external merge sort.
+///
+/// Each pipeline collects its morsels into runs of bounded size, which are
+/// sorted on their row-encoded keys and kept in memory or spilled to disk if
+/// the memory limit is exceeded. Once the input is done the runs are k-way
+/// merged into the output.
+pub struct SortNode {
+    state: SortState,
+    keys: SortKeys,
+    slice: Option<(i64, usize)>,
+    retention: RowRetention,
+    output_schema: Arc<Schema>,
+}
+
+impl SortNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        key_cols: Vec<usize>,
+        sort_options: SortMultipleOptions,
+        slice: Option<(i64, usize)>,
+        num_pipelines: usize,
+    ) -> Self {
+        let num_keys = key_cols.len();
+        let broadcast = |v: &[bool]| {
+            if v.len() == 1 {
+                vec![v[0]; num_keys]
+            } else {
+                assert_eq!(v.len(), num_keys);
+                v.to_vec()
+            }
+        };
+        let mut keys = SortKeys {
+            key_cols,
+            descending: broadcast(&sort_options.descending),
+            nulls_last: broadcast(&sort_options.nulls_last),
+        };
+
+        // Without keys we also use the input order, so all rows are ordered.
+        let maintain_order = sort_options.maintain_order || num_keys == 0;
+        let order_cols = maintain_order.then(|| {
+            let seq_col = polars_utils::pl_str::unique_column_name();
+            let idx_col = polars_utils::pl_str::unique_column_name();
+            let width = input_schema.len();
+            keys.key_cols.extend([width, width + 1]);
+            keys.descending.extend([false, false]);
+            keys.nulls_last.extend([false, false]);
+            (seq_col, idx_col)
+        });
+
+        let retention = match slice {
+            None => RowRetention::All,
+            Some((offset, len)) if offset >= 0 => {
+                RowRetention::Head((offset as usize).saturating_add(len))
+            },
+            Some((offset, _)) => RowRetention::Tail(offset.unsigned_abs() as usize),
+        };
+
+        let memory = Arc::new(MemoryTracker::new(spill_memory_limit()));
+        let run_size_bytes = (memory.limit() / (2 * num_pipelines)).max(MIN_RUN_SIZE_BYTES);
+        let locals = (0..num_pipelines)
+            .map(|_| LocalSortSinkState {
+                buffer: Vec::new(),
+                buffered_bytes: 0,
+                runs: Vec::new(),
+            })
+            .collect();
+
+        Self {
+            state: SortState::Sink(SortSinkState {
+                locals,
+                order_cols,
+                run_size_bytes,
+                memory,
+            }),
+            keys,
+            slice,
+            retention,
+            output_schema: input_schema,
+        }
+    }
+
+    fn to_source(&self, sink: SortSinkState) -> PolarsResult<SortSourceState> {
+        let runs = sink.into_runs(&self.keys, self.retention)?;
+        let num_rows = runs.iter().map(|r| r.num_rows()).sum();
+        let (rows_to_skip, rows_left) = match self.slice {
+            None => (0, usize::MAX),
+            Some((offset, len)) => slice_offsets(offset, len, num_rows),
+        };
+
+        let first_key = self.keys.key_cols[0];
+        let sorted_flag = (first_key < self.output_schema.len()).then(|| {
+            let sorted = if self.keys.descending[0] {
+                IsSorted::Descending
+            } else {
+                IsSorted::Ascending
+            };
+            (first_key, sorted)
+        });
+
+        let cursors = runs
+            .into_iter()
+            .map(|run| RunCursor::new(run, &self.keys))
+            .filter_map(Result::transpose)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(SortSourceState {
+            cursors,
+            output_schema: self.output_schema.clone(),
+            rows_to_skip,
+            rows_left,
+            pending: VecDeque::new(),
+            seq: MorselSeq::default(),
+            sent_any: false,
+            sorted_flag,
+        })
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, transition to being a source.
+            SortState::Sink(_) if matches!(recv[0], PortState::Done) => {
+                let SortState::Sink(sink) = core::mem::replace(&mut self.state, SortState::Done)
+                else {
+                    unreachable!()
+                };
+                self.state = SortState::Source(self.to_source(sink)?);
+            },
+            SortState::Source(source) if source.is_exhausted() => {
+                self.state = SortState::Done;
+            },
+            // Nothing to change.
+            SortState::Done | SortState::Sink(_) | SortState::Source(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            SortState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::Source(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            SortState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().parallel(),
+                    &self.keys,
+                    self.retention,
+                    join_handles,
+                )
+            },
+            SortState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, send_ports, &self.keys, join_handles);
+            },
+            SortState::Done => unreachable!(),
+        }
+    }
+}
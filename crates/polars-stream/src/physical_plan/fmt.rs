@@ -36,6 +36,8 @@ impl NodeStyle {
             | K::SemiAntiJoin { .. }
             | K::InMemoryJoin { .. }
             | K::Multiplexer { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "iejoin")]
+            K::IEJoin { .. } => Self::MemoryIntensive,
             #[cfg(feature = "merge_sorted")]
             K::MergeSorted { .. } => Self::MemoryIntensive,
             _ => Self::Generic,
@@ -256,6 +258,8 @@ fn visualize_plan_rec(
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileType::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -280,6 +284,8 @@ fn visualize_plan_rec(
                 FileType::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileType::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -480,6 +486,55 @@ fn visualize_plan_rec(
             input_right,
             args: _,
         } => ("cross-join".to_string(), &[*input_left, *input_right][..]),
+        #[cfg(feature = "asof_join")]
+        PhysNodeKind::AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let mut out = "asof-join".to_string();
+            let mut f = EscapeLabel(&mut out);
+
+            write!(f, "\nleft_on: {left_on}\nright_on: {right_on}").unwrap();
+            if let polars_ops::frame::JoinType::AsOf(options) = &args.how {
+                write!(f, "\nstrategy: {:?}", options.strategy).unwrap();
+                if let (Some(left_by), Some(right_by)) = (&options.left_by, &options.right_by) {
+                    write!(f, "\nleft_by: {left_by:?}\nright_by: {right_by:?}").unwrap();
+                }
+            }
+
+            (out, &[*input_left, *input_right][..])
+        },
+        #[cfg(feature = "iejoin")]
+        PhysNodeKind::IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args: _,
+            options,
+        } => {
+            let mut label = "ie-join".to_string();
+            write!(
+                label,
+                r"\nleft_on:\n{}",
+                fmt_exprs_to_label(left_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\nright_on:\n{}",
+                fmt_exprs_to_label(right_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(label, r"\noperator1: {:?}", options.operator1).unwrap();
+            if let Some(op) = options.operator2 {
+                write!(label, r"\noperator2: {op:?}").unwrap();
+            }
+            (label, &[*input_left, *input_right][..])
+        },
         #[cfg(feature = "merge_sorted")]
         PhysNodeKind::MergeSorted {
             input_left,
@@ -82,6 +82,21 @@ pub(crate) fn is_fake_elementwise_function(expr: &AExpr) -> bool {
     }
 }
 
+/// Whether this function is an aggregation which can be computed with a
+/// GroupedReduction, see `polars_expr::reduce::into_reduction`.
+pub(crate) fn is_reducing_function(function: &IRFunctionExpr) -> bool {
+    match function {
+        #[cfg(feature = "bitwise")]
+        IRFunctionExpr::Bitwise(inner_fn) => matches!(
+            inner_fn,
+            IRBitwiseFunction::And | IRBitwiseFunction::Or | IRBitwiseFunction::Xor
+        ),
+        #[cfg(feature = "approx_quantile")]
+        IRFunctionExpr::ApproxQuantile { .. } | IRFunctionExpr::ApproxQuantileSketch { .. } => true,
+        _ => false,
+    }
+}
+
 pub(crate) fn is_elementwise_rec_cached(
     expr_key: ExprNodeKey,
     arena: &Arena<AExpr>,
@@ -702,16 +717,12 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(left_col_expr);
             },
 
-            #[cfg(feature = "bitwise")]
             AExpr::Function {
                 input: ref mut inner_exprs,
-                function: IRFunctionExpr::Bitwise(inner_fn),
+                ref function,
                 options,
-            } if matches!(
-                inner_fn,
-                IRBitwiseFunction::And | IRBitwiseFunction::Or | IRBitwiseFunction::Xor
-            ) =>
-            {
+            } if is_reducing_function(function) => {
+                let function = function.clone();
                 assert!(inner_exprs.len() == 1);
 
                 let (trans_input, trans_exprs) =
@@ -721,7 +732,7 @@ fn lower_exprs_with_ctx(
                 let out_name = unique_column_name();
                 let trans_fn_expr = ctx.expr_arena.add(AExpr::Function {
                     input: vec![ExprIR::from_node(trans_exprs[0], ctx.expr_arena)],
-                    function: IRFunctionExpr::Bitwise(inner_fn),
+                    function,
                     options,
                 });
                 let expr_ir = ExprIR::new(trans_fn_expr, OutputName::Alias(out_name.clone()));
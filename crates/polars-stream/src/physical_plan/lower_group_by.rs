@@ -1,16 +1,14 @@
 use std::sync::Arc;
 
 use parking_lot::Mutex;
-use polars_core::prelude::{InitHashMaps, PlIndexMap};
+use polars_core::prelude::{DataType, InitHashMaps, PlIndexMap};
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_err};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
-use polars_plan::plans::{
-    AExpr, DataFrameUdf, IR, IRAggExpr, IRFunctionExpr, NaiveExprMerger, write_group_by,
-};
-use polars_plan::prelude::{GroupbyOptions, *};
+use polars_plan::plans::{AExpr, DataFrameUdf, IR, IRAggExpr, NaiveExprMerger, write_group_by};
+use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::unique_column_name;
@@ -20,7 +18,7 @@ use slotmap::SlotMap;
 use super::{ExprCache, PhysNode, PhysNodeKey, PhysNodeKind, PhysStream, StreamingLowerIRContext};
 use crate::physical_plan::lower_expr::{
     build_select_stream, compute_output_schema, is_elementwise_rec_cached,
-    is_fake_elementwise_function, is_input_independent,
+    is_fake_elementwise_function, is_input_independent, is_reducing_function,
 };
 use crate::physical_plan::lower_ir::build_slice_stream;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
@@ -89,6 +87,7 @@ fn try_lower_elementwise_scalar_agg_expr(
     agg_exprs: &mut Vec<ExprIR>,
     uniq_input_exprs: &mut PlIndexMap<u32, PlSmallStr>,
     uniq_agg_exprs: &mut PlIndexMap<u32, PlSmallStr>,
+    row_index_name: &mut Option<PlSmallStr>,
 ) -> Option<Node> {
     // Helper macro to simplify recursive calls.
     macro_rules! lower_rec {
@@ -102,6 +101,7 @@ fn try_lower_elementwise_scalar_agg_expr(
                 agg_exprs,
                 uniq_input_exprs,
                 uniq_agg_exprs,
+                row_index_name,
             )
         };
     }
@@ -169,20 +169,15 @@ fn try_lower_elementwise_scalar_agg_expr(
             }))
         },
 
-        #[cfg(feature = "bitwise")]
         AExpr::Function {
             input: inner_exprs,
-            function: IRFunctionExpr::Bitwise(inner_fn),
+            function,
             options,
-        } if matches!(
-            inner_fn,
-            IRBitwiseFunction::And | IRBitwiseFunction::Or | IRBitwiseFunction::Xor
-        ) =>
-        {
+        } if is_reducing_function(function) => {
             assert!(inner_exprs.len() == 1);
 
             let input = inner_exprs[0].clone().node();
-            let inner_fn = *inner_fn;
+            let function = function.clone();
             let options = *options;
 
             if is_input_independent(input, expr_arena, expr_cache) {
@@ -207,7 +202,7 @@ fn try_lower_elementwise_scalar_agg_expr(
                     let input_col_node = expr_arena.add(AExpr::Column(input_col.clone()));
                     let trans_agg_node = expr_arena.add(AExpr::Function {
                         input: vec![ExprIR::from_node(input_col_node, expr_arena)],
-                        function: IRFunctionExpr::Bitwise(inner_fn),
+                        function,
                         options,
                     });
 
@@ -279,7 +274,19 @@ fn try_lower_elementwise_scalar_agg_expr(
                 | IRAggExpr::Sum(input)
                 | IRAggExpr::Var(input, ..)
                 | IRAggExpr::Std(input, ..)
-                | IRAggExpr::Count(input, ..) => {
+                | IRAggExpr::Count(input, ..)
+                | IRAggExpr::Median(input)
+                | IRAggExpr::NUnique(input)
+                | IRAggExpr::Implode(input)
+                | IRAggExpr::Quantile { expr: input, .. } => {
+                    if let IRAggExpr::Quantile { quantile, .. } = agg {
+                        // The quantile is fixed when the reduction is created.
+                        if !matches!(expr_arena.get(*quantile), AExpr::Literal(lit) if lit.is_scalar())
+                        {
+                            return None;
+                        }
+                    }
+
                     let agg = agg.clone();
                     let input = *input;
                     if is_input_independent(input, expr_arena, expr_cache) {
@@ -320,11 +327,34 @@ fn try_lower_elementwise_scalar_agg_expr(
                     let result_node = expr_arena.add(AExpr::Column(name));
                     Some(result_node)
                 },
-                IRAggExpr::Median(..)
-                | IRAggExpr::NUnique(..)
-                | IRAggExpr::Implode(..)
-                | IRAggExpr::Quantile { .. }
-                | IRAggExpr::AggGroups(..) => None, // TODO: allow all aggregates,
+                IRAggExpr::AggGroups(_) => {
+                    // The row indices of each group are collected from a row
+                    // index column that is added to the input of the group-by.
+                    let agg_id = expr_merger.get_uniq_id(expr).unwrap();
+                    let name = uniq_agg_exprs
+                        .entry(agg_id)
+                        .or_insert_with(|| {
+                            let row_index_col = row_index_name
+                                .get_or_insert_with(unique_column_name)
+                                .clone();
+                            let input_col_node = expr_arena.add(AExpr::Column(row_index_col));
+                            let trans_agg_node =
+                                expr_arena.add(AExpr::Agg(IRAggExpr::AggGroups(input_col_node)));
+
+                            // Add to aggregation expressions and replace with a reference to its output.
+                            let agg_expr = if let Some(name) = outer_name {
+                                ExprIR::new(trans_agg_node, OutputName::Alias(name))
+                            } else {
+                                ExprIR::new(trans_agg_node, OutputName::Alias(unique_column_name()))
+                            };
+                            agg_exprs.push(agg_expr.clone());
+                            agg_expr.output_name().clone()
+                        })
+                        .clone();
+
+                    let result_node = expr_arena.add(AExpr::Column(name));
+                    Some(result_node)
+                },
             }
         },
         AExpr::Len => {
@@ -411,6 +441,7 @@ fn try_build_streaming_group_by(
     }
 
     let mut uniq_agg_exprs = PlIndexMap::new();
+    let mut row_index_name = None;
     for agg in aggs {
         let trans_node = try_lower_elementwise_scalar_agg_expr(
             agg.node(),
@@ -421,6 +452,7 @@ fn try_build_streaming_group_by(
             &mut trans_agg_exprs,
             &mut uniq_input_exprs,
             &mut uniq_agg_exprs,
+            &mut row_index_name,
         )?;
         let output_name = OutputName::Alias(agg.output_name().clone());
         trans_output_exprs.push(ExprIR::new(trans_node, output_name));
@@ -433,6 +465,22 @@ fn try_build_streaming_group_by(
         input_exprs.push(ExprIR::new(node, OutputName::Alias(name.clone())));
     }
 
+    let mut input = input;
+    if let Some(name) = row_index_name {
+        let mut output_schema = (*phys_sm[input.node].output_schema).clone();
+        output_schema
+            .insert_at_index(0, name.clone(), DataType::IDX_DTYPE)
+            .unwrap();
+        let kind = PhysNodeKind::WithRowIndex {
+            input,
+            name: name.clone(),
+            offset: None,
+        };
+        input = PhysStream::first(phys_sm.insert(PhysNode::new(Arc::new(output_schema), kind)));
+        let row_index_node = expr_arena.add(AExpr::Column(name.clone()));
+        input_exprs.push(ExprIR::new(row_index_node, OutputName::Alias(name)));
+    }
+
     let pre_select =
         build_select_stream(input, &input_exprs, expr_arena, phys_sm, expr_cache, ctx).ok()?;
 
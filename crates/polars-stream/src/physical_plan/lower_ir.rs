@@ -35,6 +35,32 @@ use crate::physical_plan::lower_expr::{
 use crate::physical_plan::lower_group_by::build_group_by_stream;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
 
+/// Returns the key columns if an as-of join can use the streaming as-of join
+/// node, which (unlike the in-memory engine) only supports plain column keys.
+#[cfg(feature = "asof_join")]
+fn asof_join_keys(
+    args: &polars_ops::frame::JoinArgs,
+    options: &Option<polars_plan::dsl::JoinTypeOptionsIR>,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> Option<(
+    polars_utils::pl_str::PlSmallStr,
+    polars_utils::pl_str::PlSmallStr,
+)> {
+    if !args.how.is_asof() || options.is_some() || args.validation.needs_checks() {
+        return None;
+    }
+    let column_key = |on: &[ExprIR]| match on {
+        [e] => match expr_arena.get(e.node()) {
+            AExpr::Column(name) if name == e.output_name() => Some(name.clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+    Some((column_key(left_on)?, column_key(right_on)?))
+}
+
 /// Creates a new PhysStream which outputs a slice of the input stream.
 pub fn build_slice_stream(
     input: PhysStream,
@@ -430,11 +456,61 @@ pub fn lower_ir(
             by_column,
             slice,
             sort_options,
-        } => PhysNodeKind::Sort {
-            by_column: by_column.clone(),
-            slice: *slice,
-            sort_options: sort_options.clone(),
-            input: lower_ir!(*input)?,
+        } => {
+            let by_column = by_column.clone();
+            let slice = *slice;
+            let sort_options = sort_options.clone();
+            let phys_input = lower_ir!(*input)?;
+
+            if by_column
+                .iter()
+                .all(|e| matches!(expr_arena.get(e.node()), AExpr::Column(_)))
+            {
+                PhysNodeKind::Sort {
+                    input: phys_input,
+                    by_column,
+                    slice,
+                    sort_options,
+                }
+            } else {
+                // The sort node only sorts by columns, so we first materialize
+                // the sort keys as temporary columns and drop them afterwards.
+                let input_schema = phys_sm[phys_input.node].output_schema.clone();
+                let mut selectors = input_schema
+                    .iter_names()
+                    .map(|name| {
+                        ExprIR::new(
+                            expr_arena.add(AExpr::Column(name.clone())),
+                            OutputName::ColumnLhs(name.clone()),
+                        )
+                    })
+                    .collect_vec();
+                let mut key_columns = Vec::with_capacity(by_column.len());
+                for key in &by_column {
+                    let name = unique_column_name();
+                    selectors.push(key.with_alias(name.clone()));
+                    key_columns.push(ExprIR::new(
+                        expr_arena.add(AExpr::Column(name.clone())),
+                        OutputName::ColumnLhs(name),
+                    ));
+                }
+                let keyed_input = build_length_preserving_select_stream(
+                    phys_input, &selectors, expr_arena, phys_sm, expr_cache, ctx,
+                )?;
+                let sort_node = phys_sm.insert(PhysNode::new(
+                    phys_sm[keyed_input.node].output_schema.clone(),
+                    PhysNodeKind::Sort {
+                        input: keyed_input,
+                        by_column: key_columns,
+                        slice,
+                        sort_options,
+                    },
+                ));
+                PhysNodeKind::SimpleProjection {
+                    input: PhysStream::first(sort_node),
+                    columns: input_schema.iter_names_cloned().collect(),
+                }
+            }
         },
 
         IR::Union { inputs, options } => {
@@ -523,6 +599,12 @@ pub fn lower_ir(
                         first_metadata: first_metadata.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro {
+                        options: polars_io::avro::AvroScanOptions {},
+                    } => Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder)
+                        as Arc<dyn FileReaderBuilder>,
+
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { options } => {
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
@@ -589,6 +671,8 @@ pub fn lower_ir(
                     let extra_columns_policy = match &*scan_type {
                         #[cfg(feature = "parquet")]
                         FileScanIR::Parquet { .. } => unified_scan_args.extra_columns_policy,
+                        #[cfg(feature = "avro")]
+                        FileScanIR::Avro { .. } => unified_scan_args.extra_columns_policy,
 
                         _ => {
                             if unified_scan_args.projection.is_some() {
@@ -927,15 +1011,65 @@ pub fn lower_ir(
                     stream = build_slice_stream(stream, offset, len, phys_sm);
                 }
                 return Ok(stream);
-            } else {
-                PhysNodeKind::InMemoryJoin {
-                    input_left: phys_left,
-                    input_right: phys_right,
-                    left_on,
-                    right_on,
-                    args,
-                    options,
+            }
+
+            #[cfg(feature = "asof_join")]
+            if let Some((left_key, right_key)) =
+                asof_join_keys(&args, &options, &left_on, &right_on, expr_arena)
+            {
+                let node = phys_sm.insert(PhysNode::new(
+                    output_schema,
+                    PhysNodeKind::AsOfJoin {
+                        input_left: phys_left,
+                        input_right: phys_right,
+                        left_on: left_key,
+                        right_on: right_key,
+                        args: args.clone(),
+                    },
+                ));
+                let mut stream = PhysStream::first(node);
+                if let Some((offset, len)) = args.slice {
+                    stream = build_slice_stream(stream, offset, len, phys_sm);
                 }
+                return Ok(stream);
+            }
+
+            // The IEJoin node evaluates its keys on the morsels directly, so
+            // only elementwise keys are supported.
+            #[cfg(feature = "iejoin")]
+            if let Some(polars_plan::dsl::JoinTypeOptionsIR::IEJoin(ie_options)) = &options {
+                if !args.validation.needs_checks()
+                    && left_on
+                        .iter()
+                        .chain(&right_on)
+                        .all(|e| is_elementwise_rec_cached(e.node(), expr_arena, expr_cache))
+                {
+                    let node = phys_sm.insert(PhysNode::new(
+                        output_schema,
+                        PhysNodeKind::IEJoin {
+                            input_left: phys_left,
+                            input_right: phys_right,
+                            left_on,
+                            right_on,
+                            args: args.clone(),
+                            options: ie_options.clone(),
+                        },
+                    ));
+                    let mut stream = PhysStream::first(node);
+                    if let Some((offset, len)) = args.slice {
+                        stream = build_slice_stream(stream, offset, len, phys_sm);
+                    }
+                    return Ok(stream);
+                }
+            }
+
+            PhysNodeKind::InMemoryJoin {
+                input_left: phys_left,
+                input_right: phys_right,
+                left_on,
+                right_on,
+                args,
+                options,
             }
         },
 
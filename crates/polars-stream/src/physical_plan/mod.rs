@@ -265,6 +265,28 @@ pub enum PhysNodeKind {
         args: JoinArgs,
     },
 
+    /// As-of join of two inputs sorted by their (column) key.
+    #[cfg(feature = "asof_join")]
+    AsOfJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: PlSmallStr,
+        right_on: PlSmallStr,
+        args: JoinArgs,
+    },
+
+    /// Inequality join, the right input is buffered in sorted blocks which are
+    /// pruned against each left morsel by their key bounds.
+    #[cfg(feature = "iejoin")]
+    IEJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+        options: polars_ops::frame::IEJoinOptions,
+    },
+
     /// Generic fallback for (as-of-yet) unsupported streaming joins.
     /// Fully sinks all data to in-memory data frames and uses the in-memory
     /// engine to perform the join.
@@ -354,6 +376,30 @@ fn visit_node_inputs_mut(
                 visit(input_right);
             },
 
+            #[cfg(feature = "asof_join")]
+            PhysNodeKind::AsOfJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                rec!(input_left.node);
+                rec!(input_right.node);
+                visit(input_left);
+                visit(input_right);
+            },
+
+            #[cfg(feature = "iejoin")]
+            PhysNodeKind::IEJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                rec!(input_left.node);
+                rec!(input_right.node);
+                visit(input_left);
+                visit(input_right);
+            },
+
             #[cfg(feature = "merge_sorted")]
             PhysNodeKind::MergeSorted {
                 input_left,
@@ -265,10 +265,11 @@ fn to_graph_rec<'a>(
                     [(input_key, input.port)],
                 ),
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
+                FileType::Json(json_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
                         target.clone(),
                         sink_options,
+                        *json_writer_options,
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -296,11 +297,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "avro")]
+                FileType::Avro(avro_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::avro::AvroSinkNode::new(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        avro_writer_options.clone(),
+                        cloud_options.clone(),
+                    )?),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "avro"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -437,31 +450,24 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: *slice,
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                None,
-            )?);
+            let key_cols = by_column
+                .iter()
+                .map(|e| {
+                    let AExpr::Column(name) = ctx.expr_arena.get(e.node()) else {
+                        unreachable!("sort keys should be lowered to columns")
+                    };
+                    input_schema.index_of(name).unwrap()
+                })
+                .collect();
 
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
+                nodes::sort::SortNode::new(
                     input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
+                    key_cols,
+                    sort_options.clone(),
+                    *slice,
+                    ctx.num_pipelines,
                 ),
                 [(input_key, input.port)],
             )
@@ -810,6 +816,89 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let args = args.clone();
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    left_input_schema,
+                    right_input_schema,
+                    left_on.clone(),
+                    right_on.clone(),
+                    args,
+                )?,
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "iejoin")]
+        IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let args = args.clone();
+            let options = options.clone();
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let left_key_schema =
+                compute_output_schema(&left_input_schema, left_on, ctx.expr_arena)?;
+            let right_key_schema =
+                compute_output_schema(&right_input_schema, right_on, ctx.expr_arena)?;
+            polars_ensure!(
+                left_on.len() == right_on.len() &&
+                left_on.iter().zip(right_on.iter()).all(|(l, r)| {
+                    let l_dtype = left_key_schema.get(l.output_name()).unwrap();
+                    let r_dtype = right_key_schema.get(r.output_name()).unwrap();
+                    l_dtype == r_dtype
+                }),
+                SchemaMismatch: "join received different key types on left and right side"
+            );
+
+            let left_key_selectors = left_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &left_input_schema))
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &right_input_schema))
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                nodes::joins::iejoin::IEJoinNode::new(
+                    right_input_schema,
+                    left_key_selectors,
+                    right_key_selectors,
+                    args,
+                    options,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
         #[cfg(feature = "merge_sorted")]
         MergeSorted {
             input_left,
@@ -0,0 +1,273 @@
+//! Spilling of intermediate node state to local disk.
+//!
+//! Frames are written as LZ4-compressed Arrow IPC files in a `spill` directory
+//! below the Polars temporary directory. Files are removed as soon as their
+//! owning [`SpilledFrames`] is dropped.
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
+
+use arrow::io::ipc::read::{FileReader, read_file_metadata};
+use polars_core::config;
+use polars_core::frame::DataFrame;
+use polars_core::schema::Schema;
+use polars_error::{PolarsResult, polars_err, polars_warn};
+use polars_io::SerWriter;
+use polars_io::ipc::{BatchedWriter, IpcCompression, IpcWriter};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+use polars_utils::sys::MEMINFO;
+
+static DEFAULT_SPILL_MEMORY_LIMIT: LazyLock<usize> =
+    LazyLock::new(|| (MEMINFO.free() / 2) as usize);
+
+/// The amount of bytes a single node may keep in memory before it starts
+/// spilling its state to disk.
+///
+/// Defaults to half the memory available when first queried, can be set with
+/// `POLARS_STREAMING_MEMORY_LIMIT` (in bytes). Invalid values are ignored with
+/// a warning.
+pub fn spill_memory_limit() -> usize {
+    match std::env::var("POLARS_STREAMING_MEMORY_LIMIT") {
+        Ok(v) => v.parse().unwrap_or_else(|_| {
+            polars_warn!(
+                "invalid value for POLARS_STREAMING_MEMORY_LIMIT: {}, using the default limit",
+                v
+            );
+            *DEFAULT_SPILL_MEMORY_LIMIT
+        }),
+        Err(_) => *DEFAULT_SPILL_MEMORY_LIMIT,
+    }
+}
+
+static SPILL_DIR: LazyLock<PathBuf> = LazyLock::new(|| POLARS_TEMP_DIR_BASE_PATH.join("spill"));
+static SPILL_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Keeps track of the memory in use by the (spillable) state of a node,
+/// shared between its pipelines.
+pub struct MemoryTracker {
+    in_use: AtomicUsize,
+    limit: usize,
+}
+
+impl MemoryTracker {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            in_use: AtomicUsize::new(0),
+            limit,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn in_use(&self) -> usize {
+        self.in_use.load(Ordering::Relaxed)
+    }
+
+    /// Creates an empty reservation, to be grown with
+    /// [`MemoryReservation::try_grow`].
+    pub fn reservation(self: &Arc<Self>) -> MemoryReservation {
+        MemoryReservation {
+            tracker: self.clone(),
+            bytes: 0,
+        }
+    }
+
+    /// Reserve `bytes`, returns None (and reserves nothing) if that would
+    /// exceed the limit. The bytes are released when the reservation is
+    /// dropped.
+    pub fn try_reserve(self: &Arc<Self>, bytes: usize) -> Option<MemoryReservation> {
+        let mut reservation = self.reservation();
+        reservation.try_grow(bytes).then_some(reservation)
+    }
+
+    /// Releases `bytes` that were previously reserved.
+    pub fn release(&self, bytes: usize) {
+        let prev = self.in_use.fetch_sub(bytes, Ordering::Relaxed);
+        debug_assert!(prev >= bytes, "released more memory than was reserved");
+    }
+}
+
+/// Memory reserved in a [`MemoryTracker`] for some node state, released when
+/// the state (and with it the reservation) is dropped.
+pub struct MemoryReservation {
+    tracker: Arc<MemoryTracker>,
+    bytes: usize,
+}
+
+impl MemoryReservation {
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Reserve another `bytes`, returns false (and reserves nothing) if that
+    /// would exceed the limit.
+    pub fn try_grow(&mut self, bytes: usize) -> bool {
+        let tracker = &self.tracker;
+        let reserved = tracker
+            .in_use
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |in_use| {
+                let new = in_use.checked_add(bytes)?;
+                (new <= tracker.limit).then_some(new)
+            })
+            .is_ok();
+        if reserved {
+            self.bytes += bytes;
+        }
+        reserved
+    }
+
+    /// Releases up to `bytes` of this reservation early, e.g. once part of
+    /// the state it accounts for has been freed.
+    pub fn shrink(&mut self, bytes: usize) {
+        let bytes = bytes.min(self.bytes);
+        self.tracker.release(bytes);
+        self.bytes -= bytes;
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.tracker.release(self.bytes);
+    }
+}
+
+/// A spill file on disk, removed when dropped.
+struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    fn create() -> PolarsResult<(Self, File)> {
+        std::fs::create_dir_all(&*SPILL_DIR)?;
+        let id = SPILL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = SPILL_DIR.join(format!("{}-{id}.arrow", std::process::id()));
+        let file = File::create(&path).map_err(
+            |e| polars_err!(ComputeError: "failed to create spill file {}: {e}", path.display()),
+        )?;
+        if config::verbose() {
+            eprintln!("[spill]: spilling to {}", path.display());
+        }
+        Ok((Self { path }, file))
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Writes a sequence of frames with the same schema to a new spill file.
+pub struct SpillWriter {
+    file: SpillFile,
+    writer: BatchedWriter<BufWriter<File>>,
+    num_rows: usize,
+}
+
+impl SpillWriter {
+    pub fn new(schema: &Schema) -> PolarsResult<Self> {
+        let (file, handle) = SpillFile::create()?;
+        let writer = IpcWriter::new(BufWriter::new(handle))
+            .with_compression(Some(IpcCompression::LZ4))
+            .batched(schema)?;
+        Ok(Self {
+            file,
+            writer,
+            num_rows: 0,
+        })
+    }
+
+    /// Writes a frame, each frame is read back separately by [`SpillReader`].
+    pub fn write(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        self.num_rows += df.height();
+        let mut df = df.clone();
+        df.align_chunks();
+        self.writer.write_batch(&df)
+    }
+
+    pub fn finish(mut self) -> PolarsResult<SpilledFrames> {
+        self.writer.finish()?;
+        Ok(SpilledFrames {
+            file: self.file,
+            num_rows: self.num_rows,
+        })
+    }
+}
+
+/// A sequence of frames spilled to disk.
+pub struct SpilledFrames {
+    file: SpillFile,
+    num_rows: usize,
+}
+
+impl SpilledFrames {
+    /// Spills the given frames to a new file.
+    pub fn spill<'a>(
+        schema: &Schema,
+        frames: impl IntoIterator<Item = &'a DataFrame>,
+    ) -> PolarsResult<Self> {
+        let mut writer = SpillWriter::new(schema)?;
+        for df in frames {
+            writer.write(df)?;
+        }
+        writer.finish()
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Opens a reader over the spilled frames, in the order they were written.
+    pub fn reader(&self) -> PolarsResult<SpillReader> {
+        let mut handle = BufReader::new(File::open(&self.file.path)?);
+        let metadata = read_file_metadata(&mut handle)?;
+        Ok(SpillReader {
+            reader: FileReader::new(handle, metadata, None, None),
+        })
+    }
+}
+
+pub struct SpillReader {
+    reader: FileReader<BufReader<File>>,
+}
+
+impl SpillReader {
+    pub fn next_frame(&mut self) -> PolarsResult<Option<DataFrame>> {
+        self.reader
+            .next()
+            .transpose()
+            .map(|batch| batch.map(DataFrame::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::MemoryTracker;
+
+    #[test]
+    fn test_memory_reservation_release() {
+        let memory = Arc::new(MemoryTracker::new(100));
+        let mut a = memory.reservation();
+        assert!(a.try_grow(60));
+        assert!(!a.try_grow(60));
+        assert_eq!((a.bytes(), memory.in_use()), (60, 60));
+
+        // Freed state makes room for new reservations.
+        a.shrink(40);
+        let b = memory.try_reserve(70).unwrap();
+        assert_eq!(memory.in_use(), 90);
+        drop(b);
+        drop(a);
+        assert_eq!(memory.in_use(), 0);
+        assert!(memory.try_reserve(100).is_some());
+    }
+}
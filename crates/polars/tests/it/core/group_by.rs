@@ -100,3 +100,35 @@ fn test_sorted_group_by() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn test_group_by_fixed_size_binary() -> PolarsResult<()> {
+    let ids = [
+        Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+        None,
+        Some("f81d4fae-7dec-11d0-a765-00a0c91e6bf6"),
+        Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+        None,
+    ];
+    let s = Series::new("id".into(), ids);
+    for s in [
+        s.strict_cast(&DataType::Uuid)?,
+        s.cast(&DataType::Binary)?
+            .strict_cast(&DataType::FixedSizeBinary(36))?,
+    ] {
+        assert_eq!(s.n_unique()?, 3);
+        for mt in [true, false] {
+            let out = s.group_tuples(mt, false)?;
+            let mut groups = out
+                .unwrap_idx()
+                .all()
+                .iter()
+                .map(|g| g.to_vec())
+                .collect::<Vec<_>>();
+            groups.sort();
+            assert_eq!(groups, [vec![0, 3], vec![1, 4], vec![2]]);
+        }
+    }
+    Ok(())
+}
@@ -682,3 +682,71 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+/// Cast uuid strings to a fixed-size binary key of `dtype`.
+#[cfg(feature = "dtype-uuid")]
+fn fixed_size_binary_keys(ids: &[Option<&str>], dtype: &DataType) -> PolarsResult<Column> {
+    let s = Series::new("id".into(), ids);
+    let s = match dtype {
+        DataType::Uuid => s.strict_cast(dtype)?,
+        _ => s.cast(&DataType::Binary)?.strict_cast(dtype)?,
+    };
+    Ok(s.into_column())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "dtype-uuid")]
+fn test_join_fixed_size_binary() -> PolarsResult<()> {
+    let a = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let b = "f81d4fae-7dec-11d0-a765-00a0c91e6bf6";
+    let c = "00000000-0000-0000-0000-000000000000";
+
+    for dtype in [DataType::Uuid, DataType::FixedSizeBinary(36)] {
+        let left = DataFrame::new(vec![
+            fixed_size_binary_keys(&[Some(a), Some(b), None, Some(c)], &dtype)?,
+            Column::new("x".into(), [1, 2, 3, 4]),
+        ])?;
+        let right = DataFrame::new(vec![
+            fixed_size_binary_keys(&[Some(b), None, Some(a), Some(a)], &dtype)?,
+            Column::new("y".into(), [10, 20, 30, 40]),
+        ])?;
+        let join = |args: JoinArgs| {
+            left.join(&right, ["id"], ["id"], args, None)?
+                .sort(["x", "y"], SortMultipleOptions::default())
+        };
+
+        let out = join(JoinArgs::new(JoinType::Inner))?;
+        assert_eq!(out.column("id")?.dtype(), &dtype);
+        assert_eq!(
+            out.column("x")?.i32()?.to_vec(),
+            [Some(1), Some(1), Some(2)]
+        );
+        assert_eq!(
+            out.column("y")?.i32()?.to_vec(),
+            [Some(30), Some(40), Some(10)]
+        );
+
+        // Null keys only match when nulls are considered equal.
+        let out = join(JoinArgs {
+            nulls_equal: true,
+            ..JoinArgs::new(JoinType::Inner)
+        })?;
+        assert_eq!(
+            out.column("x")?.i32()?.to_vec(),
+            [Some(1), Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            out.column("y")?.i32()?.to_vec(),
+            [Some(30), Some(40), Some(10), Some(20)]
+        );
+
+        let out = join(JoinArgs::new(JoinType::Left))?;
+        assert_eq!(
+            out.column("y")?.i32()?.to_vec(),
+            [Some(30), Some(40), Some(10), None, None]
+        );
+        assert_eq!(out.column("id")?.null_count(), 1);
+    }
+    Ok(())
+}
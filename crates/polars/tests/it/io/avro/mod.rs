@@ -2,5 +2,9 @@
 
 mod read;
 mod read_async;
+#[cfg(feature = "lazy")]
+mod scan;
+#[cfg(feature = "lazy")]
+mod sink;
 mod write;
 mod write_async;
@@ -375,3 +375,41 @@ fn test_list() -> PolarsResult<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_read_blocks() -> PolarsResult<()> {
+    let (avro, _) = schema_list();
+    let mut writer = Writer::with_codec(&avro, Vec::new(), Codec::Deflate);
+    for values in [vec![1, 2], vec![], vec![3, 4, 5]] {
+        let mut record = Record::new(writer.schema()).unwrap();
+        record.put(
+            "h",
+            Value::Array(values.into_iter().map(Value::Int).collect()),
+        );
+        writer.append(record).unwrap();
+        // Flush every record into its own block.
+        writer.flush().unwrap();
+    }
+    let avro = writer.into_inner().unwrap();
+
+    let mut reader = avro.as_slice();
+    let metadata = read_metadata(&mut reader)?;
+    let header_len = avro.len() - reader.len();
+    let schema = read::infer_schema(&metadata.record)?;
+
+    let blocks = read::read_blocks_info(&avro, header_len, metadata.marker)?;
+    assert_eq!(blocks.len(), 3);
+    assert!(blocks.iter().all(|block| block.num_rows == 1));
+    assert_eq!(blocks.last().unwrap().range.end, avro.len());
+
+    let expected = read::Reader::new(reader, metadata.clone(), schema.clone(), None)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    for (block, expected) in blocks.iter().zip(expected) {
+        let result = read::read_block(&avro[block.range.clone()], &metadata, &schema, &[true])?;
+        assert_eq!(result, expected);
+    }
+
+    // A truncated file is rejected instead of read partially.
+    assert!(read::read_blocks_info(&avro[..avro.len() - 1], header_len, metadata.marker).is_err());
+    Ok(())
+}
@@ -0,0 +1,224 @@
+use std::path::{Path, PathBuf};
+
+use polars::io::avro::{AvroReader, AvroWriter};
+use polars::io::{SerReader, SerWriter};
+use polars::prelude::*;
+
+/// Creates an empty directory for the files of a test.
+pub(super) fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("polars-avro-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+pub(super) fn read_avro(path: &Path) -> PolarsResult<DataFrame> {
+    AvroReader::new(std::fs::File::open(path)?).finish()
+}
+
+fn write_avro(path: &Path, mut df: DataFrame) -> PolarsResult<()> {
+    AvroWriter::new(std::fs::File::create(path)?).finish(&mut df)
+}
+
+fn scan(paths: &[PathBuf], args: ScanArgsAvro) -> PolarsResult<LazyFrame> {
+    let paths = paths
+        .iter()
+        .map(|p| PlPath::new(p.to_str().unwrap()))
+        .collect();
+    LazyFrame::scan_avro_files(paths, args)
+}
+
+/// Collects `lf` with both engines and checks that the results are equal to `expected`.
+fn assert_collects_to(lf: LazyFrame, expected: &DataFrame) -> PolarsResult<()> {
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let out = lf.clone().collect_with_engine(engine)?;
+        assert!(
+            out.equals_missing(expected),
+            "{engine:?}: {out} != {expected}"
+        );
+    }
+    Ok(())
+}
+
+/// Writes three files with several blocks each and returns their paths.
+fn write_files(dir: &Path) -> PolarsResult<Vec<PathBuf>> {
+    (0..3)
+        .map(|i| {
+            let path = dir.join(format!("{i}.avro"));
+            let start = i * 1000;
+            let df = df!(
+                "a" => (start..start + 1000).collect::<Vec<i64>>(),
+                "b" => (start..start + 1000).map(|v| format!("s{v}")).collect::<Vec<_>>(),
+                "c" => (start..start + 1000).map(|v| (v % 3 != 0).then_some(v as f64)).collect::<Vec<_>>(),
+            )?;
+            // Every chunk is written as a block.
+            write_avro(&path, df.slice(0, 400).vstack(&df.slice(400, 600))?)?;
+            Ok(path)
+        })
+        .collect()
+}
+
+fn read_files(paths: &[PathBuf]) -> PolarsResult<DataFrame> {
+    let mut out = read_avro(&paths[0])?;
+    for path in &paths[1..] {
+        out.vstack_mut(&read_avro(path)?)?;
+    }
+    Ok(out)
+}
+
+#[test]
+fn test_scan_avro_multiple_files() -> PolarsResult<()> {
+    let dir = test_dir("scan-multiple-files");
+    let paths = write_files(&dir)?;
+    let expected = read_files(&paths)?;
+    let lf = scan(&paths, Default::default())?;
+
+    assert_collects_to(lf.clone(), &expected)?;
+    assert_collects_to(
+        lf.clone().select([len()]),
+        &df!("len" => [3000 as IdxSize])?,
+    )?;
+
+    // A glob expands to the same files.
+    let glob = dir.join("*.avro");
+    let lf = LazyFrame::scan_avro(PlPath::new(glob.to_str().unwrap()), Default::default())?;
+    assert_collects_to(lf, &expected)?;
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_avro_projection_and_predicate() -> PolarsResult<()> {
+    let dir = test_dir("scan-projection-predicate");
+    let paths = write_files(&dir)?;
+    let expected = read_files(&paths)?;
+    let lf = scan(&paths, Default::default())?;
+
+    assert_collects_to(
+        lf.clone().select([col("c"), col("a")]),
+        &expected.select(["c", "a"])?,
+    )?;
+
+    let predicate = col("a").gt(lit(1500)).and(col("c").is_not_null());
+    assert_collects_to(
+        lf.clone().filter(predicate.clone()).select([col("b")]),
+        &expected
+            .lazy()
+            .filter(predicate)
+            .select([col("b")])
+            .collect()?,
+    )?;
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_avro_slice_and_row_index() -> PolarsResult<()> {
+    let dir = test_dir("scan-slice-row-index");
+    let paths = write_files(&dir)?;
+    let expected = read_files(&paths)?;
+
+    let lf = scan(
+        &paths,
+        ScanArgsAvro {
+            n_rows: Some(1500),
+            ..Default::default()
+        },
+    )?;
+    assert_collects_to(lf, &expected.head(Some(1500)))?;
+
+    let lf = scan(&paths, Default::default())?;
+    // The slice spans the end of the first file and the start of the second one.
+    assert_collects_to(lf.clone().slice(900, 200), &expected.slice(900, 200))?;
+
+    let lf = scan(
+        &paths,
+        ScanArgsAvro {
+            row_index: Some(RowIndex {
+                name: "index".into(),
+                offset: 10,
+            }),
+            ..Default::default()
+        },
+    )?;
+    let with_index = expected.with_row_index("index".into(), Some(10))?;
+    assert_collects_to(lf.clone(), &with_index)?;
+    assert_collects_to(
+        lf.clone().filter(col("a").gt_eq(lit(2990))),
+        &with_index.slice(2990, 10),
+    )?;
+    assert_collects_to(lf.slice(1999, 2), &with_index.slice(1999, 2))?;
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_avro_hive() -> PolarsResult<()> {
+    let dir = test_dir("scan-hive");
+    let mut paths = vec![];
+    for (key, values) in [(1, [1i64, 2]), (2, [3, 4])] {
+        let part_dir = dir.join(format!("key={key}"));
+        std::fs::create_dir_all(&part_dir)?;
+        let path = part_dir.join("0.avro");
+        write_avro(&path, df!("a" => values)?)?;
+        paths.push(path);
+    }
+
+    let lf = scan(
+        &paths,
+        ScanArgsAvro {
+            hive_options: HiveOptions::new_enabled(),
+            ..Default::default()
+        },
+    )?;
+    let expected = df!("a" => [1i64, 2, 3, 4], "key" => [1i64, 1, 2, 2])?;
+    assert_collects_to(lf.clone(), &expected)?;
+    assert_collects_to(
+        lf.filter(col("key").eq(lit(2))).select([col("a")]),
+        &df!("a" => [3i64, 4])?,
+    )?;
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_avro_schema_differs() -> PolarsResult<()> {
+    let dir = test_dir("scan-schema-differs");
+    let paths = [dir.join("0.avro"), dir.join("1.avro")];
+    write_avro(&paths[0], df!("a" => [1i64, 2])?)?;
+    // A later schema version adds a field.
+    write_avro(&paths[1], df!("a" => [3i64], "b" => ["x"])?)?;
+
+    let expected = df!("a" => [1i64, 2, 3], "b" => [None, None, Some("x")])?;
+    let lf = scan(
+        &[paths[1].clone(), paths[0].clone()],
+        ScanArgsAvro {
+            allow_missing_columns: true,
+            ..Default::default()
+        },
+    )?;
+    assert_collects_to(lf.sort(["a"], Default::default()), &expected)?;
+
+    // The schema is taken from the first file, so the extra field in the second file raises.
+    let lf = scan(&paths, Default::default())?;
+    assert!(lf.clone().collect().is_err());
+    let lf = scan(
+        &paths,
+        ScanArgsAvro {
+            extra_columns_policy: ExtraColumnsPolicy::Ignore,
+            ..Default::default()
+        },
+    )?;
+    assert_collects_to(lf, &expected.select(["a"])?)?;
+
+    // Missing fields raise unless they are allowed.
+    let lf = scan(&[paths[1].clone(), paths[0].clone()], Default::default())?;
+    assert!(lf.collect().is_err());
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
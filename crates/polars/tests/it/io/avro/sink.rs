@@ -0,0 +1,153 @@
+use std::path::{Path, PathBuf};
+
+use polars::io::avro::AvroCompression;
+use polars::prelude::*;
+
+use super::scan::{read_avro, test_dir};
+
+const CODECS: [Option<AvroCompression>; 3] = [
+    None,
+    Some(AvroCompression::Deflate),
+    Some(AvroCompression::Snappy),
+];
+
+fn df() -> PolarsResult<DataFrame> {
+    df!(
+        "k" => (0..1000).map(|v| v % 3).collect::<Vec<i64>>(),
+        "v" => (0..1000).collect::<Vec<i64>>(),
+        "s" => (0..1000).map(|v| (v % 7 != 0).then(|| format!("s{v}"))).collect::<Vec<_>>(),
+    )
+}
+
+fn options(compression: Option<AvroCompression>) -> AvroWriterOptions {
+    AvroWriterOptions {
+        compression,
+        ..Default::default()
+    }
+}
+
+fn sink_options() -> SinkOptions {
+    SinkOptions {
+        mkdir: true,
+        ..Default::default()
+    }
+}
+
+fn to_path(path: &Path) -> PlPath {
+    PlPath::new(path.to_str().unwrap())
+}
+
+/// Lists the files below `dir` in sorted order.
+fn list_files(dir: &Path) -> PolarsResult<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(list_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[test]
+fn test_sink_avro_codecs() -> PolarsResult<()> {
+    let dir = test_dir("sink-codecs");
+    let df = df()?;
+
+    for compression in CODECS {
+        let path = dir.join(format!("{compression:?}.avro"));
+        df.clone()
+            .lazy()
+            .sink_avro(
+                SinkTarget::Path(to_path(&path)),
+                options(compression),
+                None,
+                sink_options(),
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let out = read_avro(&path)?;
+        assert!(out.equals_missing(&df), "{compression:?}: {out}");
+    }
+    assert_eq!(list_files(&dir)?.len(), CODECS.len());
+
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_sink_avro_partition_by_key() -> PolarsResult<()> {
+    let df = df()?;
+
+    for compression in CODECS {
+        let dir = test_dir("sink-partition-by-key");
+        df.clone()
+            .lazy()
+            .sink_avro_partitioned(
+                Arc::new(to_path(&dir)),
+                None,
+                PartitionVariant::ByKey {
+                    key_exprs: vec![col("k")],
+                    include_key: true,
+                },
+                options(compression),
+                None,
+                sink_options(),
+                None,
+                None,
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let files = list_files(&dir)?;
+        let expected_files: Vec<_> = (0..3).map(|k| dir.join(format!("k={k}/0.avro"))).collect();
+        assert_eq!(files, expected_files);
+        for (k, file) in files.iter().enumerate() {
+            let expected = df
+                .clone()
+                .lazy()
+                .filter(col("k").eq(lit(k as i64)))
+                .collect()?;
+            let out = read_avro(file)?;
+            assert!(out.equals_missing(&expected), "{compression:?}: {out}");
+        }
+
+        std::fs::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sink_avro_partition_max_size() -> PolarsResult<()> {
+    let df = df()?;
+
+    for compression in CODECS {
+        let dir = test_dir("sink-partition-max-size");
+        df.clone()
+            .lazy()
+            .sink_avro_partitioned(
+                Arc::new(to_path(&dir)),
+                None,
+                PartitionVariant::MaxSize(400),
+                options(compression),
+                None,
+                sink_options(),
+                None,
+                None,
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let files = list_files(&dir)?;
+        assert_eq!(files.len(), 3);
+        for (i, file) in files.iter().enumerate() {
+            let out = read_avro(file)?;
+            let expected = df.slice(i as i64 * 400, 400);
+            assert!(out.equals_missing(&expected), "{compression:?}: {out}");
+        }
+
+        std::fs::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
@@ -485,3 +485,24 @@ fn test_with_columns() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_write_multiple_chunks() -> PolarsResult<()> {
+    let mut df = df!(
+        "i64" => &[1, 2],
+        "string" => &["a", "b"]
+    )?;
+    df.vstack_mut(&df.clone())?;
+    assert_eq!(df.first_col_n_chunks(), 2);
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+    AvroWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    let read_df = AvroReader::new(buf).finish()?;
+
+    assert!(df.equals(&read_df));
+
+    Ok(())
+}
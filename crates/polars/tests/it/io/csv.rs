@@ -39,6 +39,27 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+#[cfg(all(feature = "compress", feature = "decompress"))]
+fn write_csv_compressed() {
+    use polars::io::utils::compression::ExternalCompression;
+
+    let mut df = create_df();
+    for compression in [
+        ExternalCompression::Gzip { level: None },
+        ExternalCompression::Zstd { level: Some(5) },
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_compression(compression)
+            .finish(&mut df)
+            .expect("csv written");
+
+        let out = CsvReader::new(Cursor::new(buf)).finish().unwrap();
+        assert!(out.equals(&df));
+    }
+}
+
 #[test]
 #[cfg(feature = "timezones")]
 fn write_dates() {
@@ -151,3 +151,53 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn write_and_read_ipc_map() -> PolarsResult<()> {
+    let keys = Series::new(
+        "m".into(),
+        [
+            Series::new("".into(), ["a", "b"]),
+            Series::new("".into(), ["c"]),
+        ],
+    );
+    let values = Series::new(
+        "m".into(),
+        [
+            Series::new("".into(), [Some(1i64), None]),
+            Series::new("".into(), [Some(3i64)]),
+        ],
+    );
+    let m = map_from_lists(keys.list()?, values.list()?)?;
+    let mut df = DataFrame::new(vec![m.into_column()])?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish()?;
+    assert_eq!(df.schema(), df_read.schema());
+    assert!(df.equals_missing(&df_read));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn write_and_read_ipc_uuid() -> PolarsResult<()> {
+    let id = Series::new(
+        "id".into(),
+        [Some("67e55044-10b1-426f-9247-bb680e5fe0c8"), None],
+    )
+    .strict_cast(&DataType::Uuid)?;
+    let mut df = DataFrame::new(vec![id.into_column()])?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish()?;
+    assert_eq!(df.schema(), df_read.schema());
+    assert!(df.equals_missing(&df_read));
+    Ok(())
+}
@@ -135,6 +135,66 @@ fn int64_required_delta() -> PolarsResult<()> {
     )
 }
 
+#[test]
+fn int64_optional_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "int64",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions::default_with_encoding(Encoding::ByteStreamSplit)
+                .into_default_column_write_options(),
+        ],
+    )
+}
+
+#[test]
+fn uint32_optional_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "uint32",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions::default_with_encoding(Encoding::ByteStreamSplit)
+                .into_default_column_write_options(),
+        ],
+    )
+}
+
+#[test]
+fn float64_optional_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions::default_with_encoding(Encoding::ByteStreamSplit)
+                .into_default_column_write_options(),
+        ],
+    )
+}
+
+#[test]
+fn timestamp_us_optional_adaptive() -> PolarsResult<()> {
+    round_trip(
+        "timestamp_us",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions {
+                encoding: Encoding::Plain,
+                bloom_filter: None,
+                adaptive_encoding: true,
+            }
+            .into_default_column_write_options(),
+        ],
+    )
+}
+
 #[cfg(feature = "parquet")]
 #[test]
 fn int64_optional_v2_compressed() -> PolarsResult<()> {
@@ -236,6 +296,66 @@ fn utf8_required_v2_compressed() -> PolarsResult<()> {
     )
 }
 
+#[test]
+fn utf8_optional_delta_length_byte_array() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions::default_with_encoding(Encoding::DeltaLengthByteArray)
+                .into_default_column_write_options(),
+        ],
+    )
+}
+
+#[test]
+fn utf8_optional_delta_byte_array() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions::default_with_encoding(Encoding::DeltaByteArray)
+                .into_default_column_write_options(),
+        ],
+    )
+}
+
+#[test]
+fn utf8_required_delta_byte_array() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "required",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions::default_with_encoding(Encoding::DeltaByteArray)
+                .into_default_column_write_options(),
+        ],
+    )
+}
+
+#[test]
+fn utf8_optional_adaptive() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions {
+                encoding: Encoding::Plain,
+                bloom_filter: None,
+                adaptive_encoding: true,
+            }
+            .into_default_column_write_options(),
+        ],
+    )
+}
+
 #[test]
 fn bool_optional_v1() -> PolarsResult<()> {
     round_trip(
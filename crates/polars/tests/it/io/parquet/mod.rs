@@ -206,3 +206,63 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_map_roundtrip() -> PolarsResult<()> {
+    let keys = Series::new(
+        "m".into(),
+        [
+            Series::new("".into(), ["a", "b"]),
+            Series::new("".into(), ["c"]),
+            Series::new_empty("".into(), &DataType::String),
+        ],
+    );
+    let values = Series::new(
+        "m".into(),
+        [
+            Series::new("".into(), [Some(1i64), None]),
+            Series::new("".into(), [Some(3i64)]),
+            Series::new_empty("".into(), &DataType::Int64),
+        ],
+    );
+    let m = map_from_lists(keys.list()?, values.list()?)?;
+    let mut df = DataFrame::new(vec![m.into_column()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(
+        read_df.schema().get("m"),
+        Some(&DataType::Map(
+            Box::new(DataType::String),
+            Box::new(DataType::Int64)
+        ))
+    );
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn test_uuid_roundtrip() -> PolarsResult<()> {
+    let id = Series::new(
+        "id".into(),
+        [
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            None,
+            Some("00000000-0000-0000-0000-000000000000"),
+        ],
+    )
+    .strict_cast(&DataType::Uuid)?;
+    let fsb = Series::new("fsb".into(), [Some(&b"abc"[..]), None, Some(&b"xyz"[..])])
+        .strict_cast(&DataType::FixedSizeBinary(3))?;
+    let mut df = DataFrame::new(vec![id.into_column(), fsb.into_column()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(df.schema(), read_df.schema());
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use arrow::array::{ArrayRef, Utf8ViewArray};
+use arrow::array::{ArrayRef, PrimitiveArray, Utf8ViewArray};
 use arrow::datatypes::{ArrowSchema, Field};
 use arrow::record_batch::RecordBatchT;
+use arrow::types::f16;
 use polars_error::PolarsResult;
 use polars_parquet::arrow::write::{FileWriter, WriteOptions};
 use polars_parquet::read::read_metadata;
@@ -88,3 +89,23 @@ fn roundtrip_binview() -> PolarsResult<()> {
         ],
     )
 }
+
+#[test]
+fn roundtrip_f16() -> PolarsResult<()> {
+    let array = PrimitiveArray::<f16>::from([
+        Some(f16::from_f32(1.5)),
+        None,
+        Some(f16::from_f32(-0.099975586)),
+        Some(f16::from_f32(65504.0)),
+    ]);
+
+    round_trip(
+        &array.boxed(),
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![
+            FieldWriteOptions::default_with_encoding(Encoding::Plain)
+                .into_default_column_write_options(),
+        ],
+    )
+}
@@ -61,6 +61,8 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    FixedSizeBinary,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -69,6 +71,7 @@ from polars.datatypes import (
     Int64,
     Int128,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -80,6 +83,7 @@ from polars.datatypes import (
     UInt64,
     Unknown,
     Utf8,
+    Uuid,
 )
 from polars.expr import Expr
 from polars.functions import (
@@ -266,6 +270,8 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "FixedSizeBinary",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
@@ -274,6 +280,7 @@ __all__ = [
     "Int64",
     "Int128",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -285,6 +292,7 @@ __all__ = [
     "UInt64",
     "Unknown",
     "Utf8",
+    "Uuid",
     # polars.io
     "defer",
     "KeyedPartition",
@@ -105,7 +105,9 @@ Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
-CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CsvEncoding: TypeAlias = Literal[
+    "utf8", "utf8-lossy", "latin1", "windows-1252", "utf16-le", "utf16-be"
+]
 DeletionFiles: TypeAlias = tuple[
     Literal["iceberg-position-delete"], dict[int, list[str]]
 ]
@@ -128,6 +130,15 @@ ParallelStrategy: TypeAlias = Literal[
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
+ParquetEncoding: TypeAlias = Literal[
+    "plain",
+    "dictionary",
+    "delta_binary_packed",
+    "delta_length_byte_array",
+    "delta_byte_array",
+    "byte_stream_split",
+    "adaptive",
+]
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
@@ -139,6 +150,7 @@ Roll: TypeAlias = Literal["raise", "forward", "backward"]
 RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero"]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
 Endianness: TypeAlias = Literal["little", "big"]
+ExternalCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 SizeUnit: TypeAlias = Literal[
     "b",
     "kb",
@@ -382,6 +394,7 @@ __all__ = [
     "Endianness",
     "EngineType",
     "EpochTimeUnit",
+    "ExternalCompression",
     "ExcelSpreadsheetEngine",
     "ExplainFormat",
     "FileSource",
@@ -414,6 +427,7 @@ __all__ = [
     "ParallelStrategy",
     "ParametricProfileNames",
     "ParquetCompression",
+    "ParquetEncoding",
     "PartitioningScheme",
     "PivotAgg",
     "PolarsDataType",
@@ -34,6 +34,7 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
     List,
     Null,
     Object,
@@ -165,6 +166,7 @@ def sequence_to_pyseries(
             Categorical,
             Enum,
             Decimal,
+            Float16,
         ) or isinstance(dtype, Categorical):
             if pyseries.dtype() != dtype:
                 pyseries = pyseries.cast(dtype, strict=strict, wrap_numerical=False)
@@ -479,11 +481,15 @@ def numpy_to_pyseries(
     values = np.ascontiguousarray(values)
 
     if values.ndim == 1:
+        is_half = values.dtype == np.float16
         values, dtype = numpy_values_and_dtype(values)
         constructor = numpy_type_to_constructor(values, dtype)
-        return constructor(
+        pyseries = constructor(
             name, values, nan_to_null if dtype in (np.float32, np.float64) else strict
         )
+        if is_half:
+            pyseries = pyseries.cast(Float16, strict=True, wrap_numerical=False)
+        return pyseries
     else:
         original_shape = values.shape
         values_1d = values.reshape(-1)
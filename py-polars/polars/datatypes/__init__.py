@@ -17,6 +17,8 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    FixedSizeBinary,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -26,6 +28,7 @@ from polars.datatypes.classes import (
     Int128,
     IntegerType,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -38,6 +41,7 @@ from polars.datatypes.classes import (
     UInt64,
     Unknown,
     Utf8,
+    Uuid,
 )
 from polars.datatypes.constants import (
     DTYPE_TEMPORAL_UNITS,
@@ -75,6 +79,8 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "FixedSizeBinary",
+    "Float16",
     "Float32",
     "Float64",
     "Int16",
@@ -84,6 +90,7 @@ __all__ = [
     "Int8",
     "IntegerType",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -96,6 +103,7 @@ __all__ = [
     "UInt8",
     "Unknown",
     "Utf8",
+    "Uuid",
     # constants
     "N_INFER_DEFAULT",
     "DTYPE_TEMPORAL_UNITS",
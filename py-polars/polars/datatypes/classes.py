@@ -388,6 +388,10 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class Float16(FloatType):
+    """16-bit floating point type."""
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -468,6 +472,57 @@ class Binary(DataType):
     """Binary type."""
 
 
+class FixedSizeBinary(DataType):
+    """
+    Binary type where every value holds the same number of bytes.
+
+    Parameters
+    ----------
+    size
+        The number of bytes in each value.
+
+    Examples
+    --------
+    >>> dtype = pl.FixedSizeBinary(4)
+    >>> dtype
+    FixedSizeBinary(4)
+    """
+
+    size: int
+
+    def __init__(self, size: int) -> None:
+        if size < 0:
+            msg = f"invalid size for FixedSizeBinary type: {size}"
+            raise ValueError(msg)
+        self.size = size
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, FixedSizeBinary):
+            return True
+        elif isinstance(other, FixedSizeBinary):
+            return self.size == other.size
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.size))
+
+    def __repr__(self) -> str:
+        return f"{self.__class__.__name__}({self.size})"
+
+
+class Uuid(DataType):
+    """
+    Universally unique identifier type.
+
+    Notes
+    -----
+    The underlying representation of this type is a 16-byte fixed-size binary.
+    Values are displayed and converted to Python as lowercase hyphenated strings.
+    """
+
+
 class Date(TemporalType):
     """
     Data type representing a calendar date.
@@ -1076,6 +1131,54 @@ class Array(NestedType):
         return self.size
 
 
+class Map(NestedType):
+    """
+    Map type of key-value pairs.
+
+    Every map holds a variable number of entries. Keys cannot be null.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys within each map.
+    value
+        The `DataType` of the values within each map.
+
+    Examples
+    --------
+    >>> dtype = pl.Map(pl.String, pl.Int64)
+    >>> dtype
+    Map(String, Int64)
+    """
+
+    key: PolarsDataType
+    value: PolarsDataType
+
+    def __init__(
+        self,
+        key: PolarsDataType | PythonDataType,
+        value: PolarsDataType | PythonDataType,
+    ) -> None:
+        self.key = polars.datatypes.parse_into_dtype(key)
+        self.value = polars.datatypes.parse_into_dtype(value)
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Map):
+            return True
+        elif isinstance(other, Map):
+            return self.key == other.key and self.value == other.value
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.key, self.value))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({self.key!r}, {self.value!r})"
+
+
 class Field:
     """
     Definition of a single field within a `Struct` DataType.
@@ -22,6 +22,8 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    FixedSizeBinary,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -30,6 +32,7 @@ from polars.datatypes.classes import (
     Int64,
     Int128,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -40,6 +43,7 @@ from polars.datatypes.classes import (
     UInt32,
     UInt64,
     Unknown,
+    Uuid,
 )
 from polars.dependencies import numpy as np
 from polars.dependencies import pyarrow as pa
@@ -125,6 +129,12 @@ def unpack_dtypes(
             if include_compound:
                 unpacked.add(tp)
             unpacked.update(unpack_dtypes(tp.inner, include_compound=include_compound))
+        elif isinstance(tp, Map):
+            if include_compound:
+                unpacked.add(tp)
+            unpacked.update(
+                unpack_dtypes(tp.key, tp.value, include_compound=include_compound)
+            )
         elif isinstance(tp, Struct):
             if include_compound:
                 unpacked.add(tp)
@@ -148,6 +158,7 @@ class _DataTypeMappings:
             Datetime: "datetime",
             Decimal: "decimal",
             Duration: "duration",
+            Float16: "f16",
             Float32: "f32",
             Float64: "f64",
             Int128: "i128",
@@ -177,6 +188,8 @@ class _DataTypeMappings:
             Datetime: datetime,
             Decimal: PyDecimal,
             Duration: timedelta,
+            FixedSizeBinary: bytes,
+            Float16: float,
             Float32: float,
             Float64: float,
             Int128: int,
@@ -185,6 +198,7 @@ class _DataTypeMappings:
             Int64: int,
             Int8: int,
             List: list,
+            Map: dict,
             Null: None.__class__,
             Object: object,
             String: str,
@@ -194,6 +208,7 @@ class _DataTypeMappings:
             UInt32: int,
             UInt64: int,
             UInt8: int,
+            Uuid: str,
             # the below mappings are appropriate as we restrict cat/enum to strings
             Enum: str,
             Categorical: str,
@@ -206,7 +221,7 @@ class _DataTypeMappings:
             # (np.dtype().kind, np.dtype().itemsize)
             ("M", 8): Datetime,
             ("b", 1): Boolean,
-            ("f", 2): Float32,
+            ("f", 2): Float16,
             ("f", 4): Float32,
             ("f", 8): Float64,
             ("i", 1): Int8,
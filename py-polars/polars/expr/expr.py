@@ -3935,6 +3935,80 @@ class Expr:
         quantile = parse_into_expression(quantile)
         return wrap_expr(self._pyexpr.quantile(quantile, interpolation))
 
+    def approx_quantile(self, quantile: float, accuracy: float = 0.01) -> Expr:
+        """
+        Get an approximation of the quantile value.
+
+        This is done using a t-digest, which needs a fixed amount of memory per
+        group and can be computed in a single pass. The estimate is exact for
+        small inputs and interpolates linearly, like
+        `quantile(..., interpolation="linear")`.
+
+        If the input consists of sketches created with
+        :meth:`approx_quantile_sketch`, they are merged before estimating the
+        quantile.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        accuracy
+            The targeted error in rank, e.g. `0.01` for an estimate within 1% of
+            the exact rank. Smaller values use more memory.
+
+        See Also
+        --------
+        quantile
+        approx_quantile_sketch
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [0, 1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.3))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.approx_quantile(quantile, accuracy))
+
+    def approx_quantile_sketch(self, accuracy: float = 0.01) -> Expr:
+        """
+        Get a serialized t-digest of the values.
+
+        The resulting binary sketches can be stored and combined later, e.g.
+        across groups or jobs, with :meth:`approx_quantile`.
+
+        Parameters
+        ----------
+        accuracy
+            The targeted error in rank, e.g. `0.01` for an estimate within 1% of
+            the exact rank. Smaller values use more memory.
+
+        See Also
+        --------
+        approx_quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"g": [1, 1, 2, 2, 2], "a": [1, 5, 2, 4, 3]})
+        >>> sketches = df.group_by("g").agg(pl.col("a").approx_quantile_sketch())
+        >>> sketches.select(pl.col("a").approx_quantile(0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.0 │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.approx_quantile_sketch(accuracy))
+
     @unstable()
     def cut(
         self,
@@ -7,6 +7,11 @@ if TYPE_CHECKING:
 
     from polars import DataFrame
 
+# Encodings the CSV reader decodes natively, other encodings are decoded in Python.
+_NATIVE_ENCODINGS = frozenset(
+    {"utf8", "utf8-lossy", "latin1", "windows-1252", "utf16-le", "utf16-be"}
+)
+
 
 def _check_arg_is_1byte(
     arg_name: str, arg: str | None, *, can_be_empty: bool = False
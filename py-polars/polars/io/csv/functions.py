@@ -28,7 +28,11 @@ from polars.io._utils import (
 from polars.io.cloud.credential_provider._builder import (
     _init_credential_provider_builder,
 )
-from polars.io.csv._utils import _check_arg_is_1byte, _update_columns
+from polars.io.csv._utils import (
+    _NATIVE_ENCODINGS,
+    _check_arg_is_1byte,
+    _update_columns,
+)
 from polars.io.csv.batched_reader import BatchedCsvReader
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -117,7 +121,7 @@ def read_csv(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character(s) to use as separator in the file, e.g. `,` or `||`.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -181,11 +185,12 @@ def read_csv(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', 'windows-1252', 'windows-1252-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be', 'windows-1252-lossy', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. The `latin1`, `windows-1252`, `utf16-le` and `utf16-be`
+        encodings are transcoded to utf8 in memory before parsing. When using
+        other encodings, the input is first decoded in memory with python.
+        Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -276,7 +281,9 @@ def read_csv(
     │ 3   ┆ Charlie ┆ 2002-03-08 │
     └─────┴─────────┴────────────┘
     """
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    if not separator:
+        msg = "`separator` cannot be empty"
+        raise ValueError(msg)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
     _check_arg_is_1byte("eol_char", eol_char, can_be_empty=False)
 
@@ -305,6 +312,7 @@ def read_csv(
         and n_threads is None
         and not low_memory
         and null_values is None
+        and len(separator) == 1
     ):
         include_columns: Sequence[str] | None = None
         if columns:
@@ -460,7 +468,7 @@ def read_csv(
 
     # TODO: scan_csv doesn't support a "dtype slice" (i.e. list[DataType])
     schema_overrides_is_list = isinstance(schema_overrides, Sequence)
-    encoding_supported_in_lazy = encoding in {"utf8", "utf8-lossy"}
+    encoding_supported_in_lazy = encoding in _NATIVE_ENCODINGS
 
     new_streaming = (
         os.getenv("POLARS_FORCE_NEW_STREAMING") == "1"
@@ -539,9 +547,10 @@ def read_csv(
         df = lf.collect()
 
     else:
+        # Native encodings are transcoded by the reader itself.
         with prepare_file_arg(
             source,
-            encoding=encoding,
+            encoding=None if encoding in _NATIVE_ENCODINGS else encoding,
             use_pyarrow=False,
             raise_if_empty=raise_if_empty,
             storage_options=storage_options,
@@ -565,7 +574,7 @@ def read_csv(
                 infer_schema_length=infer_schema_length,
                 batch_size=batch_size,
                 n_rows=n_rows,
-                encoding=encoding if encoding == "utf8-lossy" else "utf8",
+                encoding=encoding if encoding in _NATIVE_ENCODINGS else "utf8",  # type: ignore[arg-type]
                 low_memory=low_memory,
                 rechunk=rechunk,
                 skip_rows_after_header=skip_rows_after_header,
@@ -799,7 +808,7 @@ def read_csv_batched(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character(s) to use as separator in the file, e.g. `,` or `||`.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -850,11 +859,10 @@ def read_csv_batched(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be'}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. The other encodings are transcoded to utf8 in memory
+        before parsing. Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -1036,7 +1044,7 @@ def read_csv_batched(
         infer_schema_length=infer_schema_length,
         batch_size=batch_size,
         n_rows=n_rows,
-        encoding=encoding if encoding == "utf8-lossy" else "utf8",
+        encoding=encoding if encoding in _NATIVE_ENCODINGS else "utf8",  # type: ignore[arg-type]
         low_memory=low_memory,
         rechunk=rechunk,
         skip_rows_after_header=skip_rows_after_header,
@@ -1126,7 +1134,7 @@ def scan_csv(
         column names will be autogenerated in the following format: `column_x`, with
         `x` being an enumeration over every column in the dataset, starting at 1.
     separator
-        Single byte character to use as separator in the file.
+        Character(s) to use as separator in the file, e.g. `,` or `||`.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -1181,9 +1189,10 @@ def scan_csv(
         Set `infer_schema=False` to read all columns as `pl.String`.
     n_rows
         Stop reading from CSV file after reading `n_rows`.
-    encoding : {'utf8', 'utf8-lossy'}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be'}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. Defaults to "utf8".
+        characters. The other encodings are transcoded to utf8 in memory
+        before parsing. Defaults to "utf8".
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -1336,7 +1345,9 @@ def scan_csv(
             else:
                 return new_columns  # type: ignore[return-value]
 
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    if not separator:
+        msg = "`separator` cannot be empty"
+        raise ValueError(msg)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
 
     if isinstance(source, (str, Path)):
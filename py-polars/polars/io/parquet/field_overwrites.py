@@ -1,7 +1,10 @@
 from __future__ import annotations
 
 from collections.abc import Mapping, Sequence
-from typing import Any
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from polars._typing import ParquetEncoding
 
 
 def _parquet_field_overwrites_dict_to_dict_list(
@@ -46,6 +49,13 @@ def _parquet_field_overwrites_to_dict(pqo: ParquetFieldOverwrites) -> dict[str,
     if pqo.required is not None:
         d["required"] = pqo.required
 
+    # Bloom filter
+    if pqo.bloom_filter:
+        d["bloom_filter"] = (pqo.bloom_filter_fpp, pqo.bloom_filter_ndv)
+
+    if pqo.encoding is not None:
+        d["encoding"] = pqo.encoding
+
     return d
 
 
@@ -108,6 +118,25 @@ class ParquetFieldOverwrites:
         dict[str, None | str] | None
     )  #: Arrow metadata added to the field before writing
     required: bool | None = None  #: Is the field not allowed to have missing values
+    bloom_filter: bool = False  #: Write a bloom filter for the values of the field
+    bloom_filter_fpp: (
+        float | None
+    ) = None  #: False positive probability of the bloom filter (defaults to 0.05)
+    bloom_filter_ndv: (
+        int | None
+    ) = None  #: Number of distinct values the bloom filter is sized for
+    #
+    # If `bloom_filter_ndv` is `None`, the bloom filter of every row group is sized for
+    # the number of non-null values in that row group. Bloom filters are used to skip
+    # row groups when scanning with `==` or `is_in` predicates on the field.
+    encoding: ParquetEncoding | None = None  #: Encoding of the values of the field
+    #
+    # `'delta_binary_packed'` is supported for integer and temporal columns,
+    # `'byte_stream_split'` for integer, temporal and float columns, and
+    # `'delta_length_byte_array'` and `'delta_byte_array'` for string and binary
+    # columns. These can only be given for top-level columns. `'adaptive'` keeps
+    # dictionary encoding for columns with few distinct values and otherwise picks the
+    # encoding that results in the smallest data page.
 
     def __init__(
         self,
@@ -122,6 +151,10 @@ class ParquetFieldOverwrites:
         field_id: int | None = None,
         metadata: Mapping[str, None | str] | None = None,
         required: bool | None = None,
+        bloom_filter: bool = False,
+        bloom_filter_fpp: float | None = None,
+        bloom_filter_ndv: int | None = None,
+        encoding: ParquetEncoding | None = None,
     ) -> None:
         self.name = name
 
@@ -138,3 +171,7 @@ class ParquetFieldOverwrites:
         else:
             self.metadata = metadata
         self.required = required
+        self.bloom_filter = bloom_filter
+        self.bloom_filter_fpp = bloom_filter_fpp
+        self.bloom_filter_ndv = bloom_filter_ndv
+        self.encoding = encoding
@@ -125,6 +125,7 @@ if TYPE_CHECKING:
         ColumnNameOrSelector,
         CsvQuoteStyle,
         EngineType,
+        ExternalCompression,
         ExplainFormat,
         FillNullStrategy,
         FrameInitTypes,
@@ -3034,6 +3035,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3066,6 +3069,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3097,6 +3102,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3172,6 +3179,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the output with gzip or zstd. Blocks of the output are
+            compressed independently and in parallel, the result is a regular
+            multi-member gzip or multi-frame zstd file.
+        compression_level
+            The level of compression to use. Higher compression means smaller files
+            on disk.
+
+            - "gzip" : min-level: 0, max-level: 9, default: 6.
+            - "zstd" : min-level: 1, max-level: 22, default: 3.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3297,6 +3314,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            compression=compression,
+            compression_level=compression_level,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -3315,6 +3334,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3333,6 +3354,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3350,6 +3373,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3371,6 +3396,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ----------
         path
             File path to which the file should be written.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the output with gzip or zstd. Blocks of the output are
+            compressed independently and in parallel, the result is a regular
+            multi-member gzip or multi-frame zstd file.
+        compression_level
+            The level of compression to use. Higher compression means smaller files
+            on disk.
+
+            - "gzip" : min-level: 0, max-level: 9, default: 6.
+            - "zstd" : min-level: 1, max-level: 22, default: 3.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3476,6 +3511,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         ldf = self._ldf.sink_json(
             target=target,
+            compression=compression,
+            compression_level=compression_level,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -64,6 +64,7 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
     Float32,
     Float64,
     Int32,
@@ -804,6 +805,10 @@ class Series:
         elif self.dtype in [Categorical, Enum] and not isinstance(other, Series):
             other = Series([other])
 
+        elif self.dtype == Float16 and isinstance(other, (int, float)):
+            # Float16 has no scalar kernels, compare against a unit Series instead
+            other = Series([other], dtype=Float16)
+
         elif isinstance(other, date) and self.dtype == Date:
             d = date_to_int(other)
             f = get_ffi_func(op + "_<>", Int32, self._s)
@@ -1077,6 +1082,13 @@ class Series:
                 return self._from_pyseries(getattr(_s, op_s)(self._s))
             else:
                 return self._from_pyseries(getattr(self._s, op_s)(_s))
+        elif isinstance(other, (int, float)) and self.dtype == Float16:
+            # Float16 has no scalar kernels, keep the result in half precision
+            _s = sequence_to_pyseries(self.name, [other], dtype=Float16)
+            if "rhs" in op_ffi:
+                return self._from_pyseries(getattr(_s, op_s)(self._s))
+            else:
+                return self._from_pyseries(getattr(self._s, op_s)(_s))
 
         if self.dtype.is_decimal() and isinstance(other, (PyDecimal, int)):
             if isinstance(other, int):
@@ -1492,6 +1504,9 @@ class Series:
                     args.append(arg)
                 elif isinstance(arg, Series):
                     phys_arg = arg.to_physical()
+                    if phys_arg.dtype == Float16:
+                        # NumPy views need a native buffer, widen half floats
+                        phys_arg = phys_arg.cast(Float32)
                     if phys_arg._s.n_chunks() > 1:
                         phys_arg._s.rechunk(in_place=True)
                     args.append(phys_arg._s.to_numpy_view())
@@ -1551,13 +1566,14 @@ class Series:
             else:
                 allocate_output = True
 
-            f = get_ffi_func("apply_ufunc_<>", numpy_char_code_to_dtype(dtype_char), s)
+            dtype_out = numpy_char_code_to_dtype(dtype_char)
+            if dtype_out == Float16:
+                # there are no half precision kernels, NumPy casts into Float32
+                dtype_out = Float32
+            f = get_ffi_func("apply_ufunc_<>", dtype_out, s)
 
             if f is None:
-                msg = (
-                    "could not find "
-                    f"`apply_ufunc_{numpy_char_code_to_dtype(dtype_char)}`"
-                )
+                msg = f"could not find `apply_ufunc_{dtype_out}`"
                 raise NotImplementedError(msg)
 
             series = f(
@@ -2271,6 +2287,32 @@ class Series:
         """  # noqa: W505
         return self._s.quantile(quantile, interpolation)
 
+    def approx_quantile(self, quantile: float, accuracy: float = 0.01) -> float | None:
+        """
+        Get an approximation of the quantile value of this Series.
+
+        This is done using a t-digest, see :meth:`Expr.approx_quantile`.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        accuracy
+            The targeted error in rank, e.g. `0.01` for an estimate within 1% of
+            the exact rank. Smaller values use more memory.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3, 4])
+        >>> s.approx_quantile(0.5)
+        2.5
+        """
+        return (
+            self.to_frame()
+            .select(F.col(self.name).approx_quantile(quantile, accuracy))
+            .item()
+        )
+
     def to_dummies(
         self,
         *,
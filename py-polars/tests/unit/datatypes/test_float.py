@@ -308,4 +308,57 @@ def test_arrow_float16_read_empty_20946() -> None:
 
     df = pl.from_arrow(table)
     assert df.shape == (0, 1)
-    assert df.schema == pl.Schema([("float_column", pl.Float32)])  # type: ignore[union-attr]
+    assert df.schema == pl.Schema([("float_column", pl.Float16)])  # type: ignore[union-attr]
+
+
+def test_float16_arithmetic_and_comparison() -> None:
+    s = pl.Series("x", [1.5, -2.25, None, 65504.0], dtype=pl.Float16)
+    assert s.dtype == pl.Float16
+    assert s.to_list() == [1.5, -2.25, None, 65504.0]
+
+    assert_series_equal(
+        s + s, pl.Series("x", [3.0, -4.5, None, float("inf")], dtype=pl.Float16)
+    )
+    assert (s * 2).dtype == pl.Float16
+    assert (s * 2).to_list() == [3.0, -4.5, None, float("inf")]
+    assert (s / 2).dtype == pl.Float16
+    assert (s > 0).to_list() == [True, False, None, True]
+    assert (s == 1.5).to_list() == [True, False, None, False]
+
+    # small integers fit in a half float, wider types win
+    assert (s + pl.Series([1, 2, 3, 4], dtype=pl.Int8)).dtype == pl.Float16
+    assert (s + pl.Series([1, 2, 3, 4], dtype=pl.Int32)).dtype == pl.Float64
+    assert (s + pl.Series([1.0, 2.0, 3.0, 4.0], dtype=pl.Float32)).dtype == pl.Float32
+
+
+def test_float16_cast_precision() -> None:
+    s = pl.Series([0.1, 1.0, 70000.0], dtype=pl.Float64).cast(pl.Float16)
+    assert s.to_list() == [0.0999755859375, 1.0, float("inf")]
+    assert s.cast(pl.Float64).to_list() == [0.0999755859375, 1.0, float("inf")]
+    assert s.cast(pl.Int32, strict=False).to_list() == [0, 1, None]
+
+
+def test_float16_aggregations() -> None:
+    df = pl.DataFrame(
+        {"g": [1, 1, 2, 2], "x": [1.0, 2.0, 3.0, 5.0]},
+        schema_overrides={"x": pl.Float16},
+    )
+    out = (
+        df.group_by("g", maintain_order=True)
+        .agg(
+            sum=pl.col("x").sum(),
+            mean=pl.col("x").mean(),
+            min=pl.col("x").min(),
+            max=pl.col("x").max(),
+        )
+    )
+    assert out.schema == pl.Schema(
+        {
+            "g": pl.Int64,
+            "sum": pl.Float16,
+            "mean": pl.Float16,
+            "min": pl.Float16,
+            "max": pl.Float16,
+        }
+    )
+    assert out.rows() == [(1, 3.0, 1.5, 1.0, 2.0), (2, 8.0, 4.0, 3.0, 5.0)]
@@ -0,0 +1,69 @@
+import io
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_map_dtype() -> None:
+    dtype = pl.Map(pl.String, pl.Int64)
+    assert repr(dtype) == "Map(String, Int64)"
+    assert dtype.is_nested()
+
+    assert dtype == pl.Map
+    assert dtype == pl.Map(str, int)
+    assert dtype != pl.Map(pl.String, pl.Int32)
+    assert dtype != pl.List(pl.Int64)
+    assert hash(dtype) == hash(pl.Map(pl.String, pl.Int64))
+    assert dtype.to_python() is dict
+
+
+def test_cast_list_map() -> None:
+    s = pl.Series(
+        "m",
+        [
+            [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+            [],
+            None,
+        ],
+    )
+    dtype = pl.Map(pl.String, pl.Int64)
+    out = s.cast(dtype)
+
+    assert out.dtype == dtype
+    assert out.to_list() == [{"a": 1, "b": 2}, {}, None]
+
+    # the map is stored as a list of key/value structs
+    assert_series_equal(out.cast(s.dtype), s)
+
+    # the values are cast as well
+    assert out.cast(pl.Map(pl.String, pl.Float64)).to_list() == [
+        {"a": 1.0, "b": 2.0},
+        {},
+        None,
+    ]
+
+    with pytest.raises(InvalidOperationError):
+        out.cast(pl.String)
+
+
+def test_map_null_keys() -> None:
+    s = pl.Series([[{"key": None, "value": 1}]])
+    with pytest.raises(ComputeError, match="map keys cannot be null"):
+        s.cast(pl.Map(pl.String, pl.Int64))
+
+
+def test_map_dtype_roundtrip() -> None:
+    dtype = pl.Map(pl.String, pl.List(pl.Int64))
+    s = pl.Series([[{"key": "a", "value": [1, 2]}], None]).cast(dtype)
+    df = pl.DataFrame({"m": s})
+
+    assert df.schema == pl.Schema({"m": dtype})
+    assert df.lazy().collect_schema() == pl.Schema({"m": dtype})
+
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert pl.read_ipc(f).schema == pl.Schema({"m": dtype})
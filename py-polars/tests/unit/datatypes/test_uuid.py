@@ -0,0 +1,81 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+UUID_A = "67e55044-10b1-426f-9247-bb680e5fe0c8"
+UUID_B = "f81d4fae-7dec-11d0-a765-00a0c91e6bf6"
+
+
+def test_fixed_size_binary_dtype() -> None:
+    dtype = pl.FixedSizeBinary(4)
+    assert repr(dtype) == "FixedSizeBinary(4)"
+    assert dtype == pl.FixedSizeBinary
+    assert dtype != pl.FixedSizeBinary(8)
+    assert dtype != pl.Binary
+    assert hash(dtype) == hash(pl.FixedSizeBinary(4))
+
+    with pytest.raises(ValueError, match="invalid size"):
+        pl.FixedSizeBinary(-1)
+
+
+def test_fixed_size_binary_roundtrip() -> None:
+    dtype = pl.FixedSizeBinary(4)
+    s = pl.Series("b", [b"abcd", None, b"\x00\x01\x02\x03"]).cast(dtype)
+    assert s.dtype == dtype
+    assert s.to_list() == [b"abcd", None, b"\x00\x01\x02\x03"]
+
+    df = pl.DataFrame({"b": s})
+    assert df.schema == pl.Schema({"b": dtype})
+    assert df.lazy().collect_schema() == pl.Schema({"b": dtype})
+
+    with pytest.raises(InvalidOperationError):
+        pl.Series([b"abc"]).cast(dtype)
+    assert pl.Series([b"abc"]).cast(dtype, strict=False).to_list() == [None]
+
+
+def test_uuid_roundtrip() -> None:
+    s = pl.Series("id", [UUID_A.upper(), None, UUID_B]).cast(pl.Uuid)
+    assert s.dtype == pl.Uuid
+    assert s.to_list() == [UUID_A, None, UUID_B]
+    assert_series_equal(s.cast(pl.String), pl.Series("id", [UUID_A, None, UUID_B]))
+
+    df = pl.DataFrame({"id": s})
+    assert df.schema == pl.Schema({"id": pl.Uuid})
+    assert df.lazy().collect_schema() == pl.Schema({"id": pl.Uuid})
+    assert df.row(0) == (UUID_A,)
+
+
+def test_uuid_cast_invalid() -> None:
+    s = pl.Series(["not-a-uuid", UUID_A])
+    with pytest.raises(InvalidOperationError):
+        s.cast(pl.Uuid)
+    assert s.cast(pl.Uuid, strict=False).to_list() == [None, UUID_A]
+
+
+@pytest.mark.parametrize("dtype", [pl.Uuid(), pl.FixedSizeBinary(36)])
+def test_fixed_size_binary_join_group_by(dtype: pl.DataType) -> None:
+    def frame(ids: list[str | None], **columns: list[int]) -> pl.DataFrame:
+        key = pl.col("id") if dtype == pl.Uuid else pl.col("id").cast(pl.Binary)
+        return pl.DataFrame({"id": ids, **columns}).with_columns(key.cast(dtype))
+
+    left = frame([UUID_A, UUID_B, None, UUID_A], x=[1, 2, 3, 4])
+    right = frame([UUID_B, None, UUID_A], y=[10, 20, 30])
+
+    out = left.join(right, on="id", how="left").sort("x")
+    assert out.schema["id"] == dtype
+    assert out.get_column("y").to_list() == [30, 10, None, 30]
+
+    out = left.join(right, on="id", nulls_equal=True).sort("x")
+    assert out.get_column("y").to_list() == [30, 10, 20, 30]
+
+    out = left.group_by("id").agg(pl.col("x").sum()).sort("x")
+    assert out.schema["id"] == dtype
+    assert_frame_equal(
+        out.select(pl.col("x")),
+        pl.DataFrame({"x": [2, 3, 5]}),
+    )
+    assert out.get_column("id").null_count() == 1
@@ -25,7 +25,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import CsvQuoteStyle, TimeUnit
+    from polars._typing import CsvEncoding, CsvQuoteStyle, TimeUnit
     from tests.unit.conftest import MemoryUsage
 
 
@@ -533,6 +533,108 @@ def test_read_csv_encoding_lossy(tmp_path: Path) -> None:
         )
 
 
+def test_read_csv_multi_byte_separator() -> None:
+    # The first byte of the separator also occurs inside the fields.
+    data = b"a||b\nx|y||1\n|z||2\n"
+    expected = pl.DataFrame({"a": ["x|y", "|z"], "b": [1, 2]})
+
+    assert_frame_equal(pl.read_csv(data, separator="||"), expected)
+    assert_frame_equal(
+        pl.read_csv(gzip.compress(data), separator="||", n_rows=1), expected.head(1)
+    )
+
+    with pytest.raises(ValueError, match="`separator` cannot be empty"):
+        pl.read_csv(data, separator="")
+
+
+@pytest.mark.write_disk
+def test_read_csv_batched_multi_byte_separator(tmp_path: Path) -> None:
+    file_path = tmp_path / "multi_byte_separator.csv"
+    file_path.write_bytes(b"a||b\nx|y||1\n|z||2\n")
+
+    reader = pl.read_csv_batched(file_path, separator="||")
+    batches = reader.next_batches(5)
+    assert batches is not None
+    assert_frame_equal(
+        pl.concat(batches), pl.DataFrame({"a": ["x|y", "|z"], "b": [1, 2]})
+    )
+
+
+@pytest.mark.parametrize(
+    ("encoding", "python_encoding"),
+    [
+        ("latin1", "latin-1"),
+        ("windows-1252", "cp1252"),
+        ("utf16-le", "utf-16-le"),
+        ("utf16-be", "utf-16-be"),
+    ],
+)
+@pytest.mark.write_disk
+def test_read_csv_native_encoding(
+    encoding: CsvEncoding, python_encoding: str, tmp_path: Path
+) -> None:
+    data = "name,price\ncafé,1\nstraße,2\n".encode(python_encoding)
+    expected = pl.DataFrame({"name": ["café", "straße"], "price": [1, 2]})
+
+    file_path = tmp_path / "encoding.csv"
+    file_path.write_bytes(data)
+
+    for file in [file_path, data, io.BytesIO(data)]:
+        assert_frame_equal(pl.read_csv(file, encoding=encoding), expected)
+
+    reader = pl.read_csv_batched(file_path, encoding=encoding)
+    batches = reader.next_batches(5)
+    assert batches is not None
+    assert_frame_equal(pl.concat(batches), expected)
+
+
+@pytest.mark.parametrize(
+    ("encoding", "python_encoding", "text"),
+    [("utf16-le", "utf-16-le", "é\n😀"), ("latin1", "latin-1", "é\nß")],
+)
+@pytest.mark.write_disk
+def test_read_csv_native_encoding_chunked(
+    encoding: CsvEncoding, python_encoding: str, text: str, tmp_path: Path
+) -> None:
+    # The data is transcoded in chunks, which must not split multi-line quoted
+    # fields or characters outside the BMP.
+    n = 5000
+    expected = pl.DataFrame({"a": range(n), "b": [f"{text}{i}" for i in range(n)]})
+    csv = "a,b\n" + "".join(f'{a},"{b}"\n' for a, b in expected.iter_rows())
+    data = csv.encode(python_encoding)
+
+    file_path = tmp_path / "encoding.csv"
+    file_path.write_bytes(data)
+
+    assert_frame_equal(pl.read_csv(file_path, encoding=encoding), expected)
+    assert_frame_equal(
+        pl.read_csv(file_path, encoding=encoding, n_rows=3000), expected.head(3000)
+    )
+    assert_frame_equal(
+        pl.read_csv(file_path, encoding=encoding, row_index_name="idx"),
+        expected.with_row_index("idx"),
+    )
+    assert_frame_equal(pl.read_csv(gzip.compress(data), encoding=encoding), expected)
+
+    reader = pl.read_csv_batched(file_path, encoding=encoding, batch_size=100)
+    batches = []
+    while (next_batches := reader.next_batches(5)) is not None:
+        batches.extend(next_batches)
+    assert_frame_equal(pl.concat(batches), expected)
+
+
+def test_read_csv_utf16_compressed_n_rows() -> None:
+    # The UTF-16 code units of "ਊ" are newline bytes, so lines must be counted
+    # after transcoding.
+    expected = pl.DataFrame({"a": [f"ਊ{i}" for i in range(1000)]})
+    data = ("a\n" + "".join(f"{a}\n" for a in expected["a"])).encode("utf-16-le")
+
+    assert_frame_equal(
+        pl.read_csv(gzip.compress(data), encoding="utf16-le", n_rows=300),
+        expected.head(300),
+    )
+
+
 @pytest.mark.may_fail_auto_streaming  # read->scan_csv dispatch
 def test_column_rename_and_schema_overrides() -> None:
     csv = textwrap.dedent(
@@ -499,3 +499,49 @@ a,b
 
     q = pl.scan_csv(2 * [f], comment_prefix="#").tail(100)
     assert_frame_equal(q.collect(), pl.DataFrame({"a": [1, 1], "b": [1, 1]}))
+
+
+@pytest.mark.parametrize("separator", ["||", "\x1f\x1e"])
+def test_scan_csv_multi_byte_separator(separator: str) -> None:
+    data = f"a{separator}b\n1{separator}x\n2{separator}\n".encode()
+
+    q = pl.scan_csv(data, separator=separator)
+    assert_frame_equal(q.collect(), pl.DataFrame({"a": [1, 2], "b": ["x", None]}))
+    assert q.select(pl.len()).collect().item() == 2
+
+
+@pytest.mark.parametrize(
+    ("encoding", "python_encoding"),
+    [
+        ("latin1", "latin-1"),
+        ("windows-1252", "cp1252"),
+        ("utf16-le", "utf-16-le"),
+        ("utf16-be", "utf-16-be"),
+    ],
+)
+def test_scan_csv_encoding(encoding: str, python_encoding: str) -> None:
+    data = "name,price\ncafé,1\nstraße,2\n".encode(python_encoding)
+
+    q = pl.scan_csv(data, encoding=encoding)  # type: ignore[arg-type]
+    assert_frame_equal(
+        q.collect(), pl.DataFrame({"name": ["café", "straße"], "price": [1, 2]})
+    )
+    assert q.select(pl.len()).collect().item() == 2
+
+
+def test_scan_csv_utf16_chunked() -> None:
+    # The data is transcoded in chunks, which must not split multi-line quoted
+    # fields or characters outside the BMP.
+    n = 5000
+    expected = pl.DataFrame({"a": range(n), "b": [f"é\n😀{i}" for i in range(n)]})
+    csv = "a,b\n" + "".join(f'{a},"{b}"\n' for a, b in expected.iter_rows())
+    data = csv.encode("utf-16-le")
+
+    q = pl.scan_csv(data, encoding="utf16-le")
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(q.slice(2500, 1000).collect(), expected.slice(2500, 1000))
+    assert_frame_equal(
+        q.with_row_index().filter(pl.col("a") > 4000).collect(),
+        expected.with_row_index().filter(pl.col("a") > 4000),
+    )
+    assert q.select(pl.len()).collect().item() == n
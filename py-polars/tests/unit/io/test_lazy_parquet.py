@@ -271,6 +271,98 @@ def test_parquet_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> Non
     assert "Predicate pushdown: reading 1 / 2 row groups" in captured
 
 
+@pytest.mark.write_disk
+def test_parquet_page_index(monkeypatch: Any, capfd: Any, tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    n = 100_000
+    df = pl.DataFrame(
+        {
+            "idx": pl.arange(0, n, eager=True),
+            "s": pl.Series([None if i % 7 == 0 else f"s{i:06}" for i in range(n)]),
+        }
+    )
+
+    file_path = tmp_path / "page_index.parquet"
+    df.write_parquet(
+        file_path, statistics=True, use_pyarrow=False, data_page_size=1024
+    )
+
+    for pred in [
+        (pl.col("idx") >= 1000) & (pl.col("idx") < 1234),
+        pl.col("s") == "s054321",
+        pl.col("s").is_null() & (pl.col("idx") < 100),
+        pl.col("idx") < 0,
+    ]:
+        q = pl.scan_parquet(file_path).filter(pred)
+        assert_frame_equal(q.collect(), df.filter(pred))
+        assert_frame_equal(q.select("s").collect(), df.filter(pred).select("s"))
+
+        q = pl.scan_parquet(file_path, row_index_name="ri").filter(pred).slice(1, 50)
+        assert_frame_equal(
+            q.collect(), df.with_row_index("ri").filter(pred).slice(1, 50)
+        )
+
+    captured = capfd.readouterr().err
+    assert "Page index pushdown: reading 1 / " in captured
+
+
+@pytest.mark.write_disk
+def test_parquet_bloom_filter(monkeypatch: Any, capfd: Any, tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    # Shuffled so that the statistics cannot skip row groups.
+    n = 40_000
+    ids = [(i * 7919) % n for i in range(n)]
+    df = pl.DataFrame(
+        {
+            "id": ids,
+            "s": [None if i % 11 == 0 else f"u{i}" for i in ids],
+            "x": ids,
+        }
+    )
+
+    file_path = tmp_path / "bloom_filter.parquet"
+    df.lazy().sink_parquet(
+        file_path,
+        row_group_size=5_000,
+        field_overwrites=[
+            pl.io.parquet.ParquetFieldOverwrites(name="id", bloom_filter=True),
+            pl.io.parquet.ParquetFieldOverwrites(
+                name="s", bloom_filter=True, bloom_filter_fpp=0.01
+            ),
+        ],
+    )
+
+    for pred in [
+        pl.col("id") == 12345,
+        pl.col("id").is_in([5, 12345, 39999]),
+        pl.col("s") == "u777",
+        pl.col("id") == -1,
+        pl.col("s").is_null() & (pl.col("id") == 11),
+        pl.col("x") == 12345,
+    ]:
+        q = pl.scan_parquet(file_path).filter(pred)
+        assert_frame_equal(q.collect(), df.filter(pred))
+        assert_frame_equal(q.collect(engine="streaming"), df.filter(pred))
+
+    captured = capfd.readouterr().err
+    assert "Bloom filter pushdown: reading 1 / 8 row groups" in captured
+    assert "Bloom filter pushdown: reading 2 / 8 row groups" in captured
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="false positive"):
+        df.lazy().sink_parquet(
+            tmp_path / "invalid.parquet",
+            field_overwrites=pl.io.parquet.ParquetFieldOverwrites(
+                name="id", bloom_filter=True, bloom_filter_fpp=1.5
+            ),
+        )
+
+
 @pytest.mark.write_disk
 def test_categorical(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
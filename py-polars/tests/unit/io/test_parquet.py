@@ -31,6 +31,7 @@ if TYPE_CHECKING:
     from pathlib import Path
 
     from polars._typing import (
+        EngineType,
         ParallelStrategy,
         ParquetCompression,
         ParquetMetadata,
@@ -2169,8 +2170,7 @@ def test_conserve_sortedness(
 
         captured = capfd.readouterr().err
 
-        # @NOTE: We don't conserve sortedness for anything except integers at the
-        # moment.
+        # @NOTE: We don't conserve sortedness for floats.
         assert (
             captured.count("Parquet conserved SortingColumn for column chunk of") == 1
         )
@@ -2180,6 +2180,59 @@ def test_conserve_sortedness(
         )
 
 
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.parametrize(
+    ("col", "descending", "nulls_last"),
+    [
+        ("a", False, False),
+        ("a", True, True),
+        ("s", False, True),
+        ("d", True, False),
+        ("b", False, False),
+    ],
+)
+def test_write_sorting_columns(
+    engine: EngineType, col: str, descending: bool, nulls_last: bool
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [3, None, 1, 2, 5, 4],
+            "s": ["c", "a", None, "b", "e", "d"],
+            "d": [date(2025, 1, i) for i in [6, 2, 3, 1, 5, 4]],
+            "b": [3.0, 1.0, 2.0, 6.0, 5.0, 4.0],
+        }
+    )
+
+    f = io.BytesIO()
+    df.lazy().sort(col, descending=descending, nulls_last=nulls_last).sink_parquet(
+        f, engine=engine
+    )
+
+    f.seek(0)
+    sorting_columns = pq.read_metadata(f).row_group(0).sorting_columns
+    has_nulls = df[col].null_count() > 0
+
+    # @NOTE: Floats are not written as sorted since Parquet orders NaNs differently.
+    if col == "b":
+        assert sorting_columns == ()
+        return
+
+    assert sorting_columns == (
+        pq.SortingColumn(
+            df.get_column_index(col),
+            descending=descending,
+            nulls_first=has_nulls and not nulls_last,
+        ),
+    )
+
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert_frame_equal(
+        out, df.sort(col, descending=descending, nulls_last=nulls_last)
+    )
+    assert out[col].flags["SORTED_DESC" if descending else "SORTED_ASC"]
+
+
 @pytest.mark.parametrize("use_dictionary", [True, False])
 @pytest.mark.parametrize(
     "values",
@@ -2259,7 +2312,7 @@ def test_decode_f16() -> None:
         }
     )
 
-    df = pl.Series("x", values, pl.Float32).to_frame()
+    df = pl.Series("x", values, pl.Float16).to_frame()
 
     f = io.BytesIO()
     pq.write_table(table, f)
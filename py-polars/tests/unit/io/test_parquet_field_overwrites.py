@@ -1,10 +1,18 @@
+from __future__ import annotations
+
 import io
+from datetime import datetime, timedelta
+from typing import TYPE_CHECKING
 
 import pyarrow.parquet as pq
 import pytest
 
 import polars as pl
 from polars.io.parquet import ParquetFieldOverwrites
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType, ParquetEncoding
 
 
 def test_required_flat() -> None:
@@ -141,3 +149,85 @@ def test_required_struct() -> None:
     schema = pq.read_schema(f)
     assert not schema.field(0).nullable
     assert not schema.field(0).type.fields[0].nullable
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_encoding(engine: EngineType) -> None:
+    n = 10_000
+    df = pl.DataFrame(
+        {
+            "ts": pl.datetime_range(
+                datetime(2025, 1, 1),
+                datetime(2025, 1, 1) + timedelta(seconds=n - 1),
+                "1s",
+                eager=True,
+            ),
+            "id": pl.Series(range(n), dtype=pl.Int64),
+            "f": pl.Series([i * 0.37 for i in range(n)], dtype=pl.Float64),
+            "s": [f"https://example.com/{i:08}" if i % 7 else None for i in range(n)],
+        }
+    )
+
+    encodings: dict[str, ParquetEncoding] = {
+        "ts": "delta_binary_packed",
+        "id": "byte_stream_split",
+        "f": "byte_stream_split",
+        "s": "delta_byte_array",
+    }
+
+    f = io.BytesIO()
+    df.lazy().sink_parquet(
+        f,
+        field_overwrites={
+            name: ParquetFieldOverwrites(encoding=encoding)
+            for name, encoding in encodings.items()
+        },
+        engine=engine,
+    )
+
+    f.seek(0)
+    metadata = pq.read_metadata(f)
+    for i, encoding in enumerate(encodings.values()):
+        column = metadata.row_group(0).column(i)
+        assert encoding.upper() in column.encodings
+
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    f = io.BytesIO()
+    df.lazy().sink_parquet(
+        f,
+        field_overwrites=[
+            ParquetFieldOverwrites(name=name, encoding="adaptive") for name in df.columns
+        ],
+        engine=engine,
+    )
+
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+
+@pytest.mark.parametrize(
+    ("name", "encoding"),
+    [
+        ("s", "delta_binary_packed"),
+        ("f", "delta_binary_packed"),
+        ("id", "delta_byte_array"),
+        ("l", "byte_stream_split"),
+    ],
+)
+def test_encoding_invalid(name: str, encoding: ParquetEncoding) -> None:
+    lf = pl.LazyFrame(
+        {"id": [1, 2], "f": [1.0, 2.0], "s": ["a", "b"], "l": [[1], [2]]}
+    )
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="parquet encoding"):
+        lf.sink_parquet(
+            io.BytesIO(),
+            field_overwrites=ParquetFieldOverwrites(name=name, encoding=encoding),
+        )
+
+    with pytest.raises(ValueError, match="parquet `encoding` must be one of"):
+        lf.sink_parquet(
+            io.BytesIO(),
+            field_overwrites=ParquetFieldOverwrites(name="id", encoding="rle"),  # type: ignore[arg-type]
+        )
@@ -0,0 +1,136 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+import numpy as np
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def assert_rank_error(approx: float, values: pl.Series, quantile: float) -> None:
+    # The estimate must lie within `accuracy` of the quantile in rank.
+    sorted_values = values.drop_nulls().sort()
+    n = len(sorted_values)
+    lo = sorted_values.search_sorted(approx, side="left")
+    hi = sorted_values.search_sorted(approx, side="right")
+    target = quantile * n
+    assert lo - 0.01 * n <= target <= hi + 0.01 * n
+
+
+def test_approx_quantile_small_is_exact() -> None:
+    df = pl.DataFrame({"a": [5, None, 1, 4, 2, 3]})
+    for q in [0.0, 0.1, 0.3, 0.5, 0.9, 1.0]:
+        assert_frame_equal(
+            df.select(pl.col("a").approx_quantile(q)),
+            df.select(pl.col("a").quantile(q, interpolation="linear")),
+        )
+    assert df["a"].approx_quantile(0.5) == 3.0
+    assert pl.Series([None], dtype=pl.Int64).approx_quantile(0.5) is None
+
+
+@pytest.mark.parametrize("quantile", [0.001, 0.25, 0.5, 0.9, 0.999])
+def test_approx_quantile_accuracy(quantile: float) -> None:
+    values = pl.Series("a", np.random.default_rng(0).lognormal(size=100_000))
+    approx = values.approx_quantile(quantile, accuracy=0.01)
+    assert_rank_error(approx, values, quantile)
+
+
+def test_approx_quantile_dtypes() -> None:
+    df = pl.DataFrame(
+        {
+            "f32": pl.Series([1.0, 2.0], dtype=pl.Float32),
+            "bool": [True, False],
+            "dt": [datetime(2020, 1, 1), datetime(2020, 1, 3)],
+            "dur": [timedelta(seconds=1), timedelta(seconds=3)],
+        }
+    )
+    out = df.select(pl.all().approx_quantile(0.5))
+    assert out.schema == {
+        "f32": pl.Float32,
+        "bool": pl.Float64,
+        "dt": pl.Datetime("us"),
+        "dur": pl.Duration("us"),
+    }
+    assert out.row(0) == (1.5, 0.5, datetime(2020, 1, 2), timedelta(seconds=2))
+    lf = df.lazy().select(pl.all().approx_quantile(0.5))
+    assert lf.collect_schema() == out.schema
+
+    with pytest.raises(InvalidOperationError):
+        pl.select(pl.lit("a").approx_quantile(0.5))
+
+
+def test_approx_quantile_invalid_args() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    with pytest.raises(ComputeError, match="quantile"):
+        df.select(pl.col("a").approx_quantile(1.5))
+    with pytest.raises(ComputeError, match="accuracy"):
+        df.select(pl.col("a").approx_quantile(0.5, accuracy=0.0))
+    with pytest.raises(ComputeError, match="accuracy"):
+        df.select(pl.col("a").approx_quantile_sketch(accuracy=2.0))
+    with pytest.raises(ComputeError, match="t-digest"):
+        pl.select(pl.lit(b"abc").approx_quantile(0.5))
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_approx_quantile_group_by(engine: pl.EngineType) -> None:
+    n = 100_000
+    rng = np.random.default_rng(1)
+    df = pl.DataFrame(
+        {
+            "g": rng.integers(0, 5, n),
+            "h": rng.integers(0, 100, n),
+            "v": rng.normal(size=n),
+        }
+    )
+    out = (
+        df.lazy()
+        .group_by("g")
+        .agg(pl.col("v").approx_quantile(0.9).alias("q"))
+        .sort("g")
+        .collect(engine=engine)
+    )
+    for g, q in out.iter_rows():
+        assert_rank_error(q, df.filter(pl.col("g") == g)["v"], 0.9)
+
+    # Sketches of partial groups can be merged later on.
+    sketches = (
+        df.lazy()
+        .group_by("g", "h")
+        .agg(pl.col("v").approx_quantile_sketch())
+        .collect(engine=engine)
+    )
+    assert sketches.schema["v"] == pl.Binary
+    merged = (
+        sketches.lazy()
+        .group_by("g")
+        .agg(pl.col("v").approx_quantile(0.9).alias("q"))
+        .sort("g")
+        .collect(engine=engine)
+    )
+    for g, q in merged.iter_rows():
+        assert_rank_error(q, df.filter(pl.col("g") == g)["v"], 0.9)
+
+
+def test_approx_quantile_over_and_rolling() -> None:
+    df = pl.DataFrame(
+        {
+            "t": range(10),
+            "g": [0, 1] * 5,
+            "v": [3, 1, 4, 1, 5, 9, 2, 6, 5, 3],
+        }
+    )
+    assert_series_equal(
+        df.select(pl.col("v").approx_quantile(0.5).over("g")).to_series(),
+        df.select(
+            pl.col("v").quantile(0.5, interpolation="linear").over("g")
+        ).to_series(),
+    )
+    assert_frame_equal(
+        df.rolling("t", period="3i").agg(pl.col("v").approx_quantile(0.5)),
+        df.rolling("t", period="3i").agg(
+            pl.col("v").quantile(0.5, interpolation="linear")
+        ),
+    )
@@ -250,3 +250,38 @@ def test_group_by_output_struct() -> None:
     df = pl.DataFrame({"g": [1], "x": [2], "y": [3]})
     out = df.group_by("g").agg(pl.struct(pl.col.x.min(), pl.col.y.sum()))
     assert out.rows() == [(1, {"x": 2, "y": 3})]
+
+
+def test_group_by_rollup_cube() -> None:
+    df = pl.DataFrame(
+        {
+            "region": ["eu", "eu", "us"],
+            "product": ["a", "b", "a"],
+            "amount": [1, 2, 3],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT region, product, SUM(amount) AS total, GROUPING(region, product) AS g
+        FROM self
+        GROUP BY ROLLUP(region, product)
+        ORDER BY g, region, product
+        """
+    )
+    assert res.rows() == [
+        ("eu", "a", 1, 0),
+        ("eu", "b", 2, 0),
+        ("us", "a", 3, 0),
+        ("eu", None, 3, 1),
+        ("us", None, 3, 1),
+        (None, None, 6, 3),
+    ]
+
+    res = df.sql(
+        """
+        SELECT product, COUNT(*) AS n FROM self
+        GROUP BY GROUPING SETS ((product), ())
+        ORDER BY product NULLS LAST
+        """
+    )
+    assert res.rows() == [("a", 2), ("b", 1), (None, 3)]
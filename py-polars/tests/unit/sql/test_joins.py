@@ -309,49 +309,53 @@ def test_join_misc_16255() -> None:
 
 
 @pytest.mark.parametrize(
-    "constraint", ["tbl.a != tbl.b", "tbl.a > tbl.b", "a >= b", "a < b", "b <= a"]
-)
-def test_non_equi_joins(constraint: str) -> None:
-    # no support (yet) for non equi-joins in polars joins
-    # TODO: integrate awareness of new IEJoin
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"only equi-join constraints \(combined with 'AND'\) are currently supported",
+    ("constraint", "expected"),
+    [
+        ("t1.a != t2.b", [(1, 4), (1, 3), (1, 2), (2, 4), (2, 3), (3, 4), (3, 2)]),
+        ("t1.a > t2.b", [(1, None), (2, None), (3, 2)]),
+        ("t1.a >= t2.b", [(1, None), (2, 2), (3, 3), (3, 2)]),
+        (
+            "t1.a < t2.b OR t1.a = 3",
+            [(1, 4), (1, 3), (1, 2), (2, 4), (2, 3), (3, 4), (3, 3), (3, 2)],
         ),
-        pl.SQLContext({"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})}) as ctx,
-    ):
-        ctx.execute(
+    ],
+)
+def test_non_equi_joins(
+    constraint: str, expected: list[tuple[int, int | None]]
+) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})
+    with pl.SQLContext({"tbl": df}) as ctx:
+        res = ctx.execute(
             f"""
-            SELECT *
-            FROM tbl
-            LEFT JOIN tbl ON {constraint}  -- not an equi-join
-            """
+            SELECT t1.a, t2.b
+            FROM tbl AS t1
+            LEFT JOIN tbl AS t2 ON {constraint}  -- not an equi-join
+            ORDER BY t1.a, t2.b DESC
+            """,
+            eager=True,
         )
+        assert res.rows() == expected
 
 
 def test_implicit_joins() -> None:
-    # no support for this yet; ensure we catch it
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"not currently supported .* use explicit JOIN syntax instead",
-        ),
-        pl.SQLContext(
-            {
-                "tbl": pl.DataFrame(
-                    {"a": [1, 2, 3], "b": [4, 3, 2], "c": ["x", "y", "z"]}
-                )
-            }
-        ) as ctx,
-    ):
-        ctx.execute(
+    with pl.SQLContext(
+        {"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2], "c": ["x", "y", "z"]})}
+    ) as ctx:
+        res = ctx.execute(
             """
-            SELECT t1.*
+            SELECT t1.*, t2.c AS c2
             FROM tbl AS t1, tbl AS t2
             WHERE t1.a = t2.b
-            """
+            ORDER BY t1.a
+            """,
+            eager=True,
         )
+        assert res.to_dict(as_series=False) == {
+            "a": [2, 3],
+            "b": [3, 2],
+            "c": ["y", "z"],
+            "c2": ["z", "y"],
+        }
 
 
 @pytest.mark.parametrize(
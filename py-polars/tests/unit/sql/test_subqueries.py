@@ -1,7 +1,7 @@
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLSyntaxError
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -146,3 +146,48 @@ def test_in_subquery() -> None:
             """,
             eager=True,
         )
+
+
+def test_correlated_subqueries() -> None:
+    customers = pl.DataFrame({"id": [1, 2, 3], "name": ["a", "b", "c"]})
+    orders = pl.DataFrame({"customer_id": [1, 1, 2], "amount": [10, 30, 5]})
+
+    with pl.SQLContext(customers=customers, orders=orders) as ctx:
+        res = ctx.execute(
+            """
+            SELECT
+              name,
+              (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id) AS n_orders
+            FROM customers c
+            WHERE NOT EXISTS (
+              SELECT 1 FROM orders o WHERE o.customer_id = c.id AND o.amount > 20
+            )
+            ORDER BY name
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {"name": ["b", "c"], "n_orders": [1, 0]}
+
+        res = ctx.execute(
+            """
+            SELECT customer_id, amount FROM orders o
+            WHERE amount = (SELECT MAX(amount) FROM orders WHERE customer_id = o.customer_id)
+            ORDER BY customer_id
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {
+            "customer_id": [1, 2],
+            "amount": [30, 5],
+        }
+
+        with pytest.raises(
+            SQLInterfaceError,
+            match="correlated subquery predicates must be equality comparisons",
+        ):
+            ctx.execute(
+                """
+                SELECT name FROM customers c
+                WHERE EXISTS (SELECT 1 FROM orders o WHERE o.amount > c.id)
+                """
+            )
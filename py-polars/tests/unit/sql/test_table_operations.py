@@ -117,3 +117,86 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_insert_update_merge() -> None:
+    stock = pl.LazyFrame({"id": [1, 2, 3], "qty": [10, 0, 5]})
+    updates = pl.LazyFrame({"id": [2, 3, 4], "qty": [7, 0, 3]})
+
+    with pl.SQLContext(stock=stock, updates=updates, eager=True) as ctx:
+        ctx.execute("INSERT INTO stock VALUES (5, 1)")
+        ctx.execute("UPDATE stock SET qty = qty * 2 WHERE id > 2")
+        ctx.execute(
+            """
+            MERGE INTO stock AS t USING updates AS s ON t.id = s.id
+            WHEN MATCHED AND s.qty = 0 THEN DELETE
+            WHEN MATCHED THEN UPDATE SET qty = s.qty
+            WHEN NOT MATCHED THEN INSERT VALUES (s.id, s.qty)
+            """
+        )
+        assert_frame_equal(
+            ctx.execute("SELECT * FROM stock"),
+            pl.DataFrame({"id": [1, 2, 5, 4], "qty": [10, 7, 2, 3]}),
+        )
+
+    # the registered source frames are left untouched
+    assert_frame_equal(
+        stock.collect(),
+        pl.DataFrame({"id": [1, 2, 3], "qty": [10, 0, 5]}),
+    )
+
+    with pytest.raises(SQLInterfaceError, match="table 'missing' does not exist"):
+        pl.SQLContext().execute("UPDATE missing SET qty = 0")
+
+
+def test_pivot_unpivot_qualify_tablesample() -> None:
+    sales = pl.LazyFrame(
+        {
+            "region": ["north", "north", "south", "south"],
+            "quarter": ["q1", "q2", "q1", "q1"],
+            "amount": [10, 20, 30, 5],
+        }
+    )
+    with pl.SQLContext(sales=sales, eager=True) as ctx:
+        wide = ctx.execute(
+            """
+            SELECT * FROM sales
+            PIVOT (SUM(amount) FOR quarter IN ('q1', 'q2'))
+            ORDER BY region
+            """
+        )
+        assert_frame_equal(
+            wide,
+            pl.DataFrame(
+                {"region": ["north", "south"], "q1": [10, 35], "q2": [20, None]}
+            ),
+        )
+        ctx.register("wide", wide)
+        assert_frame_equal(
+            ctx.execute(
+                """
+                SELECT * FROM wide
+                UNPIVOT (amount FOR quarter IN (q1, q2))
+                ORDER BY region, quarter
+                """
+            ),
+            pl.DataFrame(
+                {
+                    "region": ["north", "north", "south"],
+                    "quarter": ["q1", "q2", "q1"],
+                    "amount": [10, 20, 35],
+                }
+            ),
+        )
+        assert_frame_equal(
+            ctx.execute(
+                """
+                SELECT region, amount FROM sales
+                QUALIFY amount = MIN(amount) OVER (PARTITION BY region)
+                ORDER BY region
+                """
+            ),
+            pl.DataFrame({"region": ["north", "south"], "amount": [10, 5]}),
+        )
+        sample = ctx.execute("SELECT * FROM sales TABLESAMPLE (2 ROWS) REPEATABLE (1)")
+        assert sample.height == 2
@@ -512,3 +512,69 @@ def test_streaming_group_by_all_null_21593() -> None:
 
     out = df.lazy().group_by(pl.all()).min().collect(engine="streaming")
     assert_frame_equal(df, out, check_row_order=False)
+
+
+def test_streaming_group_by_spill(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1")
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "1000")
+
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 20_000, 100_000),
+            "b": np.random.choice(["x", "y", None], 100_000),
+            "c": pl.int_range(100_000, eager=True),
+        }
+    )
+    q = (
+        df.lazy()
+        .group_by("a", "b")
+        .agg(
+            pl.col("c").sum().alias("sum"),
+            pl.col("c").mean().alias("mean"),
+            pl.col("c").first().alias("first"),
+            pl.len(),
+        )
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.parametrize("hot_table_size", ["4", "1000000"])
+def test_streaming_group_by_median_quantile_n_unique_implode(
+    hot_table_size: str, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setenv("POLARS_HOT_TABLE_SIZE", hot_table_size)
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "1000")
+
+    n = 20_000
+    df = pl.DataFrame(
+        {
+            "g": np.random.randint(0, 500, n),
+            "i": [
+                None if v % 10 == 0 else v
+                for v in np.random.randint(0, 200, n).tolist()
+            ],
+            "f": np.random.rand(n),
+            "s": np.random.choice(["x", "y", "z", None], n),
+            "b": np.random.choice([True, False], n),
+        }
+    )
+    q = (
+        df.lazy()
+        .group_by("g")
+        .agg(
+            pl.col("i", "f", "b").median().name.suffix("_median"),
+            pl.col("i").quantile(0.3, "linear").alias("i_linear"),
+            pl.col("i").quantile(0.3, "nearest").alias("i_nearest"),
+            pl.col("f").quantile(0.9, "higher").alias("f_higher"),
+            pl.col("f").quantile(0.9, "midpoint").alias("f_midpoint"),
+            pl.col("i", "f", "s").n_unique().name.suffix("_n_unique"),
+            pl.col("i", "s").implode().name.suffix("_implode"),
+            pl.col("i").agg_groups().alias("groups"),
+        )
+        .sort("g")
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import zlib
 from typing import TYPE_CHECKING, Any
 
 import pytest
@@ -197,6 +198,59 @@ def test_sink_ndjson_should_write_same_data(
     assert_frame_equal(df, expected)
 
 
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", ["gzip", "zstd"])
+@pytest.mark.parametrize("engine", ["streaming", "in-memory"])
+def test_sink_compressed(compression: Any, engine: Any, tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": range(100_000), "b": ["x", "yy", None, "zzz"] * 25_000})
+    lf = df.lazy()
+
+    csv_path = tmp_path / "out.csv"
+    lf.sink_csv(csv_path, compression=compression, engine=engine)
+    assert csv_path.stat().st_size < len(df.write_csv())
+    assert_frame_equal(pl.read_csv(csv_path), df)
+
+    ndjson_path = tmp_path / "out.ndjson"
+    lf.sink_ndjson(
+        ndjson_path, compression=compression, compression_level=1, engine=engine
+    )
+    assert_frame_equal(pl.read_ndjson(ndjson_path), df)
+
+    # An empty result is still a valid compressed file.
+    lf.head(0).sink_csv(
+        csv_path, compression=compression, include_header=False, engine=engine
+    )
+    assert csv_path.read_bytes() != b""
+
+
+@pytest.mark.write_disk
+def test_sink_compressed_frames(tmp_path: Path) -> None:
+    # Small morsels are buffered into larger frames instead of one frame per morsel.
+    df = pl.DataFrame({"a": range(100_000)})
+    lf = pl.concat([df.slice(i, 1_000).lazy() for i in range(0, 100_000, 1_000)])
+
+    path = tmp_path / "out.csv.gz"
+    lf.sink_csv(path, compression="gzip", engine="streaming")
+
+    data = path.read_bytes()
+    n_frames = 0
+    while data:
+        decompressor = zlib.decompressobj(wbits=31)
+        decompressor.decompress(data)
+        data = decompressor.unused_data
+        n_frames += 1
+    assert n_frames == 1
+    assert_frame_equal(pl.read_csv(path), df)
+
+
+@pytest.mark.write_disk
+def test_sink_compressed_invalid_level(tmp_path: Path) -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        pl.LazyFrame({"a": [1]}).sink_csv(
+            tmp_path / "out.csv", compression="gzip", compression_level=10
+        )
+
+
 @pytest.mark.write_disk
 @pytest.mark.parametrize("streaming", [False, True])
 def test_parquet_eq_statistics(
@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import JoinStrategy
+    from polars._typing import AsofJoinStrategy, JoinStrategy
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -357,3 +357,169 @@ def test_invert_order_full_join_22295() -> None:
     lf.join(lf, on=["value", "value_at"], how="full", coalesce=True).collect(
         engine="streaming"
     )
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full"])
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_streaming_join_spill(
+    monkeypatch: pytest.MonkeyPatch, how: JoinStrategy, nulls_equal: bool
+) -> None:
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1")
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "1000")
+
+    lhs = pl.LazyFrame(
+        {
+            "a": pl.Series(np.random.randint(0, 5_000, 50_000)).set(
+                pl.Series(np.random.rand(50_000) < 0.01), None
+            ),
+            "x": pl.int_range(50_000, eager=True),
+        }
+    )
+    rhs = pl.LazyFrame(
+        {
+            "a": np.random.randint(0, 10_000, 20_000),
+            "y": pl.int_range(20_000, eager=True),
+        }
+    )
+    q = lhs.join(rhs, on="a", how=how, nulls_equal=nulls_equal)
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.filterwarnings(
+    "ignore:Sortedness of columns cannot be checked:UserWarning"
+)
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("by", [None, "g"])
+@pytest.mark.parametrize("tolerance", [None, 4])
+@pytest.mark.parametrize("allow_exact_matches", [True, False])
+def test_streaming_join_asof(
+    monkeypatch: pytest.MonkeyPatch,
+    strategy: AsofJoinStrategy,
+    by: str | None,
+    tolerance: int | None,
+    allow_exact_matches: bool,
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+
+    lhs = pl.LazyFrame(
+        {
+            "t": pl.int_range(10_000, eager=True) * 3 // 2,
+            "g": pl.int_range(10_000, eager=True) * 7 % 13,
+            "x": pl.int_range(10_000, eager=True),
+        }
+    )
+    rhs = pl.LazyFrame(
+        {
+            "t": pl.Series(np.sort(np.random.randint(0, 15_000, 5_000))),
+            "g": pl.int_range(5_000, eager=True) * 5 % 11,
+            "y": pl.int_range(5_000, eager=True),
+        }
+    )
+    q = lhs.join_asof(
+        rhs,
+        on="t",
+        by=by,
+        strategy=strategy,
+        tolerance=tolerance,
+        allow_exact_matches=allow_exact_matches,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.filterwarnings(
+    "ignore:Sortedness of columns cannot be checked:UserWarning"
+)
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+def test_streaming_join_asof_sorted_per_group(
+    monkeypatch: pytest.MonkeyPatch, strategy: AsofJoinStrategy
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+
+    # Both inputs are only sorted within each group, not across groups.
+    lhs = pl.LazyFrame(
+        {
+            "t": pl.int_range(3_000, eager=True) % 1_000 * 3,
+            "g": pl.int_range(3_000, eager=True) // 1_000,
+            "x": pl.int_range(3_000, eager=True),
+        }
+    )
+    rhs = pl.LazyFrame(
+        {
+            "t": pl.int_range(2_000, eager=True) % 500 * 7,
+            "g": 3 - pl.int_range(2_000, eager=True) // 500,
+            "y": pl.int_range(2_000, eager=True),
+        }
+    )
+    q = lhs.join_asof(rhs, on="t", by="g", strategy=strategy)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_join_asof_tolerance_str() -> None:
+    lhs = pl.LazyFrame(
+        {
+            "t": pl.datetime_range(
+                datetime(2024, 1, 1), datetime(2024, 1, 2), "1m", eager=True
+            ),
+        }
+    ).with_row_index()
+    rhs = pl.LazyFrame(
+        {
+            "t": pl.datetime_range(
+                datetime(2024, 1, 1), datetime(2024, 1, 2), "7m", eager=True
+            ),
+        }
+    ).with_row_index("y")
+    q = lhs.join_asof(rhs, on="t", strategy="nearest", tolerance="2m")
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_join_asof_unsorted() -> None:
+    lhs = pl.LazyFrame({"t": [1, 2, 3]})
+    rhs = pl.LazyFrame({"t": [3, 1, 2], "y": [1, 2, 3]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not sorted"):
+        lhs.join_asof(rhs, on="t").collect(engine="streaming")
+
+
+@pytest.mark.parametrize(
+    "predicates",
+    [
+        [pl.col("start") <= pl.col("ts"), pl.col("ts") < pl.col("end")],
+        [pl.col("start") > pl.col("ts") * 30],
+        [pl.col("start") + 1 >= pl.col("ts"), pl.col("id") < pl.col("id_right")],
+        [
+            pl.col("start") <= pl.col("ts"),
+            pl.col("ts") < pl.col("end"),
+            (pl.col("id") + pl.col("id_right")) % 3 == 0,
+        ],
+    ],
+)
+def test_streaming_join_where(
+    monkeypatch: pytest.MonkeyPatch, predicates: list[pl.Expr]
+) -> None:
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+
+    lhs = pl.LazyFrame(
+        {
+            "start": pl.int_range(2_000, eager=True) * 37 % 5_000,
+            "len": pl.int_range(2_000, eager=True) * 13 % 40,
+            "id": pl.int_range(2_000, eager=True),
+        }
+    ).with_columns(end=pl.col("start") + pl.col("len"))
+    rhs = pl.LazyFrame(
+        {
+            "ts": pl.Series(np.random.randint(0, 6_000, 3_000)),
+            "id": pl.int_range(3_000, eager=True),
+        }
+    ).with_columns(
+        ts=pl.when(pl.col("id") % 89 == 0).then(None).otherwise(pl.col("ts"))
+    )
+    q = lhs.join_where(rhs, *predicates)
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
@@ -183,3 +183,38 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_streaming_sort_spill(
+    monkeypatch: pytest.MonkeyPatch, maintain_order: bool
+) -> None:
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_LIMIT", "1")
+    monkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "1000")
+
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 100, 50_000),
+            "b": np.random.randint(0, 100, 50_000),
+            "c": pl.int_range(50_000, eager=True),
+        }
+    )
+    # Without maintain_order ties may come out in any order, so break them on
+    # the unique "c" column.
+    by = [pl.col("a"), -pl.col("b")]
+    if not maintain_order:
+        by.append(pl.col("c"))
+    q = df.lazy().sort(by, maintain_order=maintain_order)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize(("offset", "length"), [(0, 10), (25, 100), (-50, 20)])
+def test_streaming_sort_slice(offset: int, length: int) -> None:
+    df = pl.DataFrame(
+        {
+            "a": np.random.randint(0, 1000, 10_000),
+            "b": pl.int_range(10_000, eager=True),
+        }
+    )
+    q = df.lazy().sort("a", "b").slice(offset, length)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
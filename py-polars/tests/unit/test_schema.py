@@ -271,11 +271,11 @@ def test_lazy_agg_lit_explode() -> None:
 
 @pytest.mark.parametrize(
     "expr_op", [
-        "approx_n_unique", "arg_max", "arg_min", "bitwise_and", "bitwise_or",
-        "bitwise_xor", "count", "entropy", "first", "has_nulls", "implode", "kurtosis",
-        "last", "len", "lower_bound", "max", "mean", "median", "min", "n_unique", "nan_max",
-        "nan_min", "null_count", "product", "sample", "skew", "std", "sum", "upper_bound",
-        "var"
+        "approx_n_unique", "approx_quantile_sketch", "arg_max", "arg_min", "bitwise_and",
+        "bitwise_or", "bitwise_xor", "count", "entropy", "first", "has_nulls", "implode",
+        "kurtosis", "last", "len", "lower_bound", "max", "mean", "median", "min", "n_unique",
+        "nan_max", "nan_min", "null_count", "product", "sample", "skew", "std", "sum",
+        "upper_bound", "var"
     ]
 )  # fmt: skip
 @pytest.mark.parametrize("lhs", [pl.col("b"), pl.lit(1, dtype=pl.Int64).alias("b")])